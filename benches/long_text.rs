@@ -0,0 +1,45 @@
+//! How long it takes to show a 64 KiB label 100 times per frame, with and without a cache key.
+//!
+//! Run with `cargo bench --bench long_text`.
+
+use egui_twemoji::EmojiLabel;
+use std::time::{Duration, Instant};
+
+const FRAMES: u32 = 10;
+const LABELS_PER_FRAME: u64 = 100;
+
+fn time_frames(text: &str, cache_key: bool) -> Duration {
+    let ctx = egui::Context::default();
+    let mut total = Duration::ZERO;
+    for _ in 0..FRAMES {
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                // only measure the labels, not the rest of the frame:
+                let start = Instant::now();
+                for i in 0..LABELS_PER_FRAME {
+                    let label = EmojiLabel::new(text).id_salt(i);
+                    let label = match cache_key {
+                        true => label.with_cache_key(1),
+                        false => label,
+                    };
+                    label.max_width(10.0).truncate().show(ui);
+                }
+                total += start.elapsed();
+            });
+        });
+    }
+    total / FRAMES
+}
+
+fn main() {
+    let text = "some text 🦀 ".repeat(64 * 1024 / "some text 🦀 ".len());
+    println!("64 KiB label, {LABELS_PER_FRAME} times per frame:");
+    println!(
+        "  hashing the text:   {:?} per frame",
+        time_frames(&text, false)
+    );
+    println!(
+        "  with_cache_key:     {:?} per frame",
+        time_frames(&text, true)
+    );
+}
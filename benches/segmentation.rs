@@ -0,0 +1,73 @@
+//! How long it takes to split a chat history into text and emojis, and how many allocations that
+//! needs.
+//!
+//! Run with `cargo bench --bench segmentation`.
+
+use egui::RichText;
+use egui_twemoji::PrecomputedSegments;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, Instant},
+};
+
+/// The system allocator, counting every allocation.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const MESSAGES: usize = 2000;
+const RUNS: u32 = 10;
+
+/// The time and allocations per message it takes to segment every message with `segment`.
+fn measure<T>(messages: &[RichText], segment: impl Fn(&RichText) -> T) -> (Duration, f64) {
+    let mut total = Duration::ZERO;
+    let mut allocations = 0;
+    for _ in 0..RUNS {
+        let before = ALLOCATIONS.load(Ordering::Relaxed);
+        let start = Instant::now();
+        for message in messages {
+            std::hint::black_box(segment(message));
+        }
+        total += start.elapsed();
+        allocations += ALLOCATIONS.load(Ordering::Relaxed) - before;
+    }
+    let per_message = (MESSAGES as u32) * RUNS;
+    (total / per_message, allocations as f64 / per_message as f64)
+}
+
+fn main() {
+    let messages: Vec<RichText> = (0..MESSAGES)
+        .map(|i| match i % 4 {
+            0 => RichText::new(format!("message {i}: sounds good 👍")),
+            1 => RichText::new("haha 😂😂 that's the best thing I've read all week 🎉").strong(),
+            2 => RichText::new("no emojis in this one, just a longer line of plain text"),
+            _ => RichText::new("👨‍👩‍👧 family trip 🏖️ next week\nwho's bringing the 🍉?").italics(),
+        })
+        .collect();
+
+    println!("{MESSAGES} chat messages:");
+    let (time, allocations) = measure(&messages, |message| {
+        PrecomputedSegments::new(message.clone())
+    });
+    println!("  {time:?} and {allocations:.1} allocations per message");
+}
@@ -0,0 +1,66 @@
+//! A chat log with thousands of messages, of which only the visible ones are shown.
+//!
+//! Run with `cargo run --example chat_log`.
+
+use egui_twemoji::EmojiLabel;
+
+const MESSAGES: usize = 10_000;
+const EMOJIS: &[&str] = &["👋", "😂", "🔥", "👍", "🎉", "🦀", "❤️", "🤔", "🚀", "🍕"];
+const WORDS: &[&str] = &[
+    "hello", "nice", "ship it", "lgtm", "pizza?", "brb", "wow", "same",
+];
+
+fn main() -> Result<(), eframe::Error> {
+    let options = eframe::NativeOptions {
+        viewport: egui::ViewportBuilder::default().with_inner_size([400.0, 600.0]),
+        ..Default::default()
+    };
+    eframe::run_native(
+        "egui-twemoji chat log",
+        options,
+        Box::new(|cc| {
+            egui_extras::install_image_loaders(&cc.egui_ctx);
+            // every message gets its own cache entry, so bound how many are kept around:
+            EmojiLabel::set_cache_capacity(&cc.egui_ctx, 1000);
+
+            Ok(Box::new(ChatLog {
+                messages: (0..MESSAGES).map(message).collect(),
+            }))
+        }),
+    )
+}
+
+/// A made-up chat message.
+fn message(i: usize) -> String {
+    let emoji = EMOJIS[i % EMOJIS.len()];
+    let word = WORDS[i * 7 % WORDS.len()];
+    format!("user{}: {word} {emoji} #{i}", i % 13)
+}
+
+struct ChatLog {
+    messages: Vec<String>,
+}
+
+impl eframe::App for ChatLog {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            EmojiLabel::new(format!("💬 {} messages", self.messages.len())).show(ui);
+            ui.separator();
+
+            // every row is one line of body text, so `show_rows` only has to build the visible ones:
+            let row_height = ui.text_style_height(&egui::TextStyle::Body);
+            egui::ScrollArea::vertical()
+                .auto_shrink(false)
+                .stick_to_bottom(true)
+                .show_rows(ui, row_height, self.messages.len(), |ui, rows| {
+                    for i in rows {
+                        // the index identifies the message, so its text doesn't have to be hashed:
+                        EmojiLabel::new(self.messages[i].as_str())
+                            .with_cache_key(i as u64)
+                            .truncate()
+                            .show(ui);
+                    }
+                });
+        });
+    }
+}
@@ -21,6 +21,7 @@ fn main() -> Result<(), eframe::Error> {
 #[derive(Default)]
 struct ExampleApp {
     paste_field: String,
+    disabled: bool,
 }
 
 impl eframe::App for ExampleApp {
@@ -37,12 +38,58 @@ impl eframe::App for ExampleApp {
                     EmojiLabel::new("hovered! 😸").show(ui);
                 }
                 EmojiLabel::new("Yes 👍, you 🤟 can 🎥 select 📝 and copy 🍝 this 👌").show(ui);
+                EmojiLabel::new(RichText::new("Underlines 🔗 go under 🖇 emojis").underline())
+                    .show(ui);
+                EmojiLabel::new(
+                    RichText::new("Strikethrough 🗑 goes through 🚮 too").strikethrough(),
+                )
+                .show(ui);
+                EmojiLabel::new(
+                    RichText::new("Highlighted 🖍 backgrounds 🎨 too")
+                        .background_color(egui::Color32::from_rgb(90, 80, 20)),
+                )
+                .show(ui);
+                EmojiLabel::new("🎉").jumbo_if_emoji_only(3.0).show(ui);
+                ui.horizontal(|ui| {
+                    for size in [10.0, 18.0, 28.0] {
+                        EmojiLabel::new(RichText::new(format!("{size}pt 🔠")).size(size)).show(ui);
+                    }
+                });
 
                 ui.separator();
                 EmojiLabel::new("Paste 🆒 text here 📝📜:").show(ui);
                 ui.text_edit_multiline(&mut self.paste_field);
 
-                ui.collapsing("Emoji Madness (laggy)", |ui| {
+                ui.collapsing("Wrapped row", |ui| {
+                    ui.horizontal_wrapped(|ui| {
+                        for (i, emoji) in
+                            "🍎🍌🍇🍉🍒🍑🍍🥝🥥🥑".chars().cycle().take(20).enumerate()
+                        {
+                            EmojiLabel::new(format!("fruit {i} {emoji}")).show(ui);
+                        }
+                    });
+                    ui.separator();
+                    ui.horizontal_wrapped(|ui| {
+                        EmojiLabel::new("a a a 👍 a a a a a a a a a a a a 👍 a a a a a").show(ui);
+                        EmojiLabel::new("b b b 👍 b b b b b b b b b b b b 👍 b b b b b").show(ui);
+                    });
+                });
+
+                ui.collapsing("Disabled", |ui| {
+                    ui.checkbox(&mut self.disabled, "Disable 🚫 the labels below");
+                    ui.add_enabled_ui(!self.disabled, |ui| {
+                        EmojiLabel::new("Disabled 😴 emojis fade 🌫 out with the text").show(ui);
+                        EmojiLabel::new("🎉🎈🎂").single_galley(true).show(ui);
+                    });
+                });
+
+                ui.collapsing("Links", |ui| {
+                    EmojiLabel::new("Star us on GitHub ⭐")
+                        .link("https://github.com/zeozeozeo/egui-twemoji")
+                        .show(ui);
+                });
+
+                ui.collapsing("Emoji Madness", |ui| {
                     emoji_madness(ui);
                 });
             });
@@ -50,32 +97,38 @@ impl eframe::App for ExampleApp {
     }
 }
 
+const EMOJI_MADNESS: &[&str] = &[
+    "🙅🙆🙇🙋🙌🙍🙎🙏✂✈✉✊✋✌✏❄❤🚀🚃🚄🚅",
+    "🚇🚉🚌🚏🚑🚒🚓🚕🚗🚙🚚🚢🚤🚥🚧🚨🚩🚪🚫",
+    "🚬🚲🚶🚽🛀⌚⌛⏰⏳☁☎☔☕♨♻♿⚓⚡⚽⚾⛄⛅",
+    "⛪⛲⛳⛵⛺⭐⛽🌀🌁🌂🌃🌄🌅🌆🌇🌈🌉🌊🌋",
+    "🌏🌙🌛🌟🌠🌰🌱🌴🌵🌷🌸🌹🌺🌻🌼🌽🌾🌿🍀🍁",
+    "🍂🍃🍄🍅🍆🍇🍈🍉🍊🍌🍍🍎🍏🍑🍒🍓🍔🍕🍖",
+    "🍗🍘🍙🍚🍛🍜🍝🍞🍟🍠🍡🍢🍣🍤🍥🍦🍧🍨🍩",
+    "🍪🍫🍬🍭🍮🍯🍰🍱🍲🍳🍴🍵🍶🍷🍸🍹🍺🍻🎀🎁",
+    "🎂🎃🎄🎅🎆🎇🎈🎉🎊🎋🎌🎍🎎🎏🎐🎑🎒🎓🎠",
+    "🎡🎢🎣🎤🎥🎦🎧🎨🎩🎪🎫🎬🎭🎮🎯🎰🎱🎲",
+    "🎳🎴🎵🎶🎷🎸🎹🎺🎻🎽🎾🎿🏀🏁🏂🏃🏄🏆",
+    "🏈🏊🏠🏡🏢🏣🏥🏦🏧🏨🏩🏪🏫🏬🏭🏮🏯🏰",
+    "🐌🐍🐎🐑🐒🐔🐗🐘🐙🐚🐛🐜🐝🐞🐟🐠🐡🐢",
+    "🐣🐤🐥🐦🐧🐨🐩🐫🐬🐭🐮🐯🐰🐱🐲🐳🐴🐵",
+    "🐶🐷🐸🐹🐺🐻🐼🐽🐾👀👂👃👄👅👆👇👈👉",
+    "👊👋👌👍👎👏👐👑👒👓👔👕👖👗👘👙👚👛👜",
+    "👝👞👟👠👡👢👣👤👦👧👨👩👪👫👮👯👰👱",
+    "👴👶👷👸👹👺👻👼👽👾👿💀💁💂💃💄💅💆💇",
+    "💈💉💊💋💌💍💎💏💐💑💒💓💔💕💖💗💘💙",
+    "💚💛💜💝💞💟💠💡💢💣💤💥💦💧💨💩💪💫",
+    "💬💮💯💰💲💳💵💸💺💻💼💽💾💿📀📃📅📆📈",
+    "📉📌📍📎📓📔📕📖📞📟📠📡📣📦📧📫📰📱📷📹",
+    "📺📻📼🔊🔋🔌🔎🔐🔑🔒🔓🔔🔜🔥🔦🔧🔨🔩🔪",
+    "🔫🔮🗻🗼🗽🗾🗿😴🚁🚂🚆🚈🚊🚍🚎🚐🚔🚖🚘",
+    "🚛🚜🚝🚞🚟🚠🚡🚣🚦🚮🚵🚿🛁🌍🌎🌜🌝🌞🌲",
+    "🌳🍋🍐🍼🏇🏉🏤🐀🐁🐂🐃🐄🐅🐆🐇🐈🐉🐊🐋",
+    "🐏🐐🐓🐕🐖🐪👬👭📬📭📯🔬🔭",
+];
+
 fn emoji_madness(ui: &mut egui::Ui) {
-    EmojiLabel::new("🙅🙆🙇🙋🙌🙍🙎🙏✂✈✉✊✋✌✏❄❤🚀🚃🚄🚅").show(ui);
-    EmojiLabel::new("🚇🚉🚌🚏🚑🚒🚓🚕🚗🚙🚚🚢🚤🚥🚧🚨🚩🚪🚫").show(ui);
-    EmojiLabel::new("🚬🚲🚶🚽🛀⌚⌛⏰⏳☁☎☔☕♨♻♿⚓⚡⚽⚾⛄⛅").show(ui);
-    EmojiLabel::new("⛪⛲⛳⛵⛺⭐⛽🌀🌁🌂🌃🌄🌅🌆🌇🌈🌉🌊🌋").show(ui);
-    EmojiLabel::new("🌏🌙🌛🌟🌠🌰🌱🌴🌵🌷🌸🌹🌺🌻🌼🌽🌾🌿🍀🍁").show(ui);
-    EmojiLabel::new("🍂🍃🍄🍅🍆🍇🍈🍉🍊🍌🍍🍎🍏🍑🍒🍓🍔🍕🍖").show(ui);
-    EmojiLabel::new("🍗🍘🍙🍚🍛🍜🍝🍞🍟🍠🍡🍢🍣🍤🍥🍦🍧🍨🍩").show(ui);
-    EmojiLabel::new("🍪🍫🍬🍭🍮🍯🍰🍱🍲🍳🍴🍵🍶🍷🍸🍹🍺🍻🎀🎁").show(ui);
-    EmojiLabel::new("🎂🎃🎄🎅🎆🎇🎈🎉🎊🎋🎌🎍🎎🎏🎐🎑🎒🎓🎠").show(ui);
-    EmojiLabel::new("🎡🎢🎣🎤🎥🎦🎧🎨🎩🎪🎫🎬🎭🎮🎯🎰🎱🎲").show(ui);
-    EmojiLabel::new("🎳🎴🎵🎶🎷🎸🎹🎺🎻🎽🎾🎿🏀🏁🏂🏃🏄🏆").show(ui);
-    EmojiLabel::new("🏈🏊🏠🏡🏢🏣🏥🏦🏧🏨🏩🏪🏫🏬🏭🏮🏯🏰").show(ui);
-    EmojiLabel::new("🐌🐍🐎🐑🐒🐔🐗🐘🐙🐚🐛🐜🐝🐞🐟🐠🐡🐢").show(ui);
-    EmojiLabel::new("🐣🐤🐥🐦🐧🐨🐩🐫🐬🐭🐮🐯🐰🐱🐲🐳🐴🐵").show(ui);
-    EmojiLabel::new("🐶🐷🐸🐹🐺🐻🐼🐽🐾👀👂👃👄👅👆👇👈👉").show(ui);
-    EmojiLabel::new("👊👋👌👍👎👏👐👑👒👓👔👕👖👗👘👙👚👛👜").show(ui);
-    EmojiLabel::new("👝👞👟👠👡👢👣👤👦👧👨👩👪👫👮👯👰👱").show(ui);
-    EmojiLabel::new("👴👶👷👸👹👺👻👼👽👾👿💀💁💂💃💄💅💆💇").show(ui);
-    EmojiLabel::new("💈💉💊💋💌💍💎💏💐💑💒💓💔💕💖💗💘💙").show(ui);
-    EmojiLabel::new("💚💛💜💝💞💟💠💡💢💣💤💥💦💧💨💩💪💫").show(ui);
-    EmojiLabel::new("💬💮💯💰💲💳💵💸💺💻💼💽💾💿📀📃📅📆📈").show(ui);
-    EmojiLabel::new("📉📌📍📎📓📔📕📖📞📟📠📡📣📦📧📫📰📱📷📹").show(ui);
-    EmojiLabel::new("📺📻📼🔊🔋🔌🔎🔐🔑🔒🔓🔔🔜🔥🔦🔧🔨🔩🔪").show(ui);
-    EmojiLabel::new("🔫🔮🗻🗼🗽🗾🗿😴🚁🚂🚆🚈🚊🚍🚎🚐🚔🚖🚘").show(ui);
-    EmojiLabel::new("🚛🚜🚝🚞🚟🚠🚡🚣🚦🚮🚵🚿🛁🌍🌎🌜🌝🌞🌲").show(ui);
-    EmojiLabel::new("🌳🍋🍐🍼🏇🏉🏤🐀🐁🐂🐃🐄🐅🐆🐇🐈🐉🐊🐋").show(ui);
-    EmojiLabel::new("🐏🐐🐓🐕🐖🐪👬👭📬📭📯🔬🔭").show(ui);
+    for line in EMOJI_MADNESS {
+        EmojiLabel::new(*line).single_galley(true).show(ui);
+    }
 }
@@ -0,0 +1,151 @@
+#!/usr/bin/env python3
+"""Generates `src/table.rs` from the code and name tables of the `twemoji-assets` crate.
+
+Usage: python3 scripts/generate_table.py [path/to/twemoji-assets]
+
+Without a path, the `twemoji-assets` sources that Cargo downloaded for this crate are used.
+The labels are the Emojibase labels that `twemoji-assets` ships. The emojis it has no label for
+get one here:
+
+* emojis that only differ by variation selectors (U+FE0F) share their labels and shortcodes,
+* skin tone variants get the label of their base emoji followed by the names of their tones, like
+  Emojibase does, e.g. "thumbs up: medium skin tone",
+* the keycaps and a few other emojis that are missing from the name table are listed in `EXTRA`.
+"""
+
+import collections
+import json
+import os
+import re
+import subprocess
+import sys
+
+ROOT = os.path.dirname(os.path.dirname(os.path.abspath(__file__)))
+OUT = os.path.join(ROOT, "src", "table.rs")
+
+FE0F = "️"
+
+TONES = {
+    "\U0001f3fb": "light skin tone",
+    "\U0001f3fc": "medium-light skin tone",
+    "\U0001f3fd": "medium skin tone",
+    "\U0001f3fe": "medium-dark skin tone",
+    "\U0001f3ff": "dark skin tone",
+}
+
+# Sequences of people joined by ZWJs that are another form of a single code point emoji, which
+# is how the skin tone variants of these emojis are written.
+ALIASES = {
+    "👨‍🤝‍👨": "👬",
+    "👩‍🤝‍👨": "👫",
+    "👩‍🤝‍👩": "👭",
+    "🧑‍❤‍🧑": "💑",
+    "🧑‍❤‍💋‍🧑": "💏",
+    "🫱‍🫲": "🤝",
+    "🧑‍🐰‍🧑": "👯",
+    "👨‍🐰‍👨": "👯‍♂",
+    "👩‍🐰‍👩": "👯‍♀",
+    "🧑‍🫯‍🧑": "🤼",
+    "👨‍🫯‍👨": "🤼‍♂",
+    "👩‍🫯‍👩": "🤼‍♀",
+    "👨‍🎄": "🎅",
+    "👩‍🎄": "🤶",
+}
+
+# `(label, shortcodes)` of the Twemoji assets that have no entry in the name table.
+EXTRA = {
+    "#⃣": ("keycap: #", ["hash"]),
+    "*⃣": ("keycap: *", ["asterisk"]),
+    "0⃣": ("keycap: 0", ["zero"]),
+    "1⃣": ("keycap: 1", ["one"]),
+    "2⃣": ("keycap: 2", ["two"]),
+    "3⃣": ("keycap: 3", ["three"]),
+    "4⃣": ("keycap: 4", ["four"]),
+    "5⃣": ("keycap: 5", ["five"]),
+    "6⃣": ("keycap: 6", ["six"]),
+    "7⃣": ("keycap: 7", ["seven"]),
+    "8⃣": ("keycap: 8", ["eight"]),
+    "9⃣": ("keycap: 9", ["nine"]),
+    "©": ("copyright", ["copyright"]),
+    "®": ("registered", ["registered"]),
+    "👁‍🗨": ("eye in speech bubble", ["eye_in_speech_bubble"]),
+    "🛘": ("landslide", []),
+    "🪊": ("trombone", []),
+    "🪎": ("treasure chest", []),
+    "🫈": ("hairy creature", []),
+    "🫍": ("orca", []),
+    "🫪": ("distorted face", []),
+    "🫯": ("fight cloud", []),
+    "🧑‍🩰": ("ballet dancers", []),
+}
+
+
+def crate_dir():
+    metadata = json.loads(
+        subprocess.check_output(
+            ["cargo", "metadata", "--format-version", "1", "--manifest-path", os.path.join(ROOT, "Cargo.toml")]
+        )
+    )
+    for package in metadata["packages"]:
+        if package["name"] == "twemoji-assets":
+            return os.path.dirname(package["manifest_path"])
+    sys.exit("twemoji-assets is not a dependency")
+
+
+def key(emoji):
+    return emoji.replace(FE0F, "")
+
+
+def main():
+    src = os.path.join(sys.argv[1] if len(sys.argv) > 1 else crate_dir(), "src", "svg")
+    codes = re.findall(
+        r'svg_code!\((\w+), "([^"]*)", "([^"]*)", "[^"]*"\);',
+        open(os.path.join(src, "codes.rs"), encoding="utf-8").read(),
+    )
+    names = open(os.path.join(src, "names.rs"), encoding="utf-8").read()
+
+    labels = {}
+    for _, emoji, label in codes:
+        if label:
+            labels.setdefault(key(emoji), label)
+    shortcodes = collections.defaultdict(list)
+    name_emojis = {}
+    for name, emoji, label in re.findall(r'svg_name!\((\w+), "([^"]*)", "([^"]*)", \w+, "[^"]*"\);', names):
+        name_emojis[name] = key(emoji)
+        if label:
+            labels.setdefault(key(emoji), label)
+    for shortcode, name in re.findall(r'\(\(\d+, "([^"]*)"\), (\w+)\),', names):
+        shortcodes[name_emojis[name]].append(shortcode)
+    for emoji, (label, extra) in EXTRA.items():
+        labels.setdefault(key(emoji), label)
+        shortcodes[key(emoji)].extend(extra)
+
+    def label_of(emoji):
+        label = labels.get(key(emoji)) or labels.get(ALIASES.get(key(emoji), ""))
+        if label:
+            return label
+        tones = [TONES[c] for c in emoji if c in TONES]
+        base = "".join(c for c in key(emoji) if c not in TONES)
+        base = labels.get(base) or labels.get(ALIASES.get(base, ""))
+        if not tones or not base:
+            return ""
+        return base + ": " + ", ".join(dict.fromkeys(tones))
+
+    with open(OUT, "w", encoding="utf-8") as out:
+        out.write(
+            "// @generated by `scripts/generate_table.py` from the `twemoji-assets` code and name tables\n"
+            "// (Twemoji 17.0.2).\n\n"
+            "//! A table of every emoji in the Twemoji set together with its\n"
+            "//! [Emojibase](https://github.com/milesj/emojibase) label and shortcodes.\n\n"
+            "/// `(emoji, label, shortcodes)` for every Twemoji asset, in the order of `twemoji_assets::svg::codes`.\n"
+            "pub(crate) const EMOJIS: &[(&str, &str, &[&str])] = &[\n"
+        )
+        for _, emoji, _ in codes:
+            listed = ", ".join('"%s"' % s for s in sorted(set(shortcodes.get(key(emoji), []))))
+            out.write('    ("%s", "%s", &[%s]),\n' % (emoji, label_of(emoji), listed))
+        out.write("];\n")
+    subprocess.check_call(["rustfmt", "--edition", "2021", OUT])
+
+
+if __name__ == "__main__":
+    main()
@@ -3,6 +3,7 @@ use egui::RichText;
 // ugh...
 
 #[derive(Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExposedRichText {
     pub text: String,
     pub size: Option<f32>,
@@ -21,6 +22,13 @@ pub struct ExposedRichText {
     pub raised: bool,
 }
 
+// `transmute` already refuses types of different sizes, but the pointer cast in
+// `ExposedRichText::from_ref` doesn't, and neither catches a different alignment:
+const _: () = {
+    assert!(std::mem::size_of::<ExposedRichText>() == std::mem::size_of::<RichText>());
+    assert!(std::mem::align_of::<ExposedRichText>() == std::mem::align_of::<RichText>());
+};
+
 impl From<RichText> for ExposedRichText {
     fn from(value: RichText) -> Self {
         unsafe { std::mem::transmute(value) }
@@ -33,11 +41,155 @@ impl From<ExposedRichText> for RichText {
     }
 }
 
+impl std::hash::Hash for ExposedRichText {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.text.hash(state);
+        self.size.map(f32::to_bits).hash(state);
+        self.extra_letter_spacing.to_bits().hash(state);
+        self.line_height.map(f32::to_bits).hash(state);
+        self.family.hash(state);
+        self.text_style.hash(state);
+        self.background_color.hash(state);
+        self.text_color.hash(state);
+        self.code.hash(state);
+        self.strong.hash(state);
+        self.weak.hash(state);
+        self.strikethrough.hash(state);
+        self.underline.hash(state);
+        self.italics.hash(state);
+        self.raised.hash(state);
+    }
+}
+
+/// (De)serialize a [`RichText`] through its [`ExposedRichText`] fields, for `#[serde(with = ...)]`.
+#[cfg(feature = "serde")]
+pub mod serde_rich_text {
+    use super::ExposedRichText;
+    use egui::RichText;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(text: &RichText, serializer: S) -> Result<S::Ok, S::Error> {
+        ExposedRichText::from_ref(text).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<RichText, D::Error> {
+        ExposedRichText::deserialize(deserializer).map(Into::into)
+    }
+}
+
 impl ExposedRichText {
+    /// View a [`RichText`] as an [`ExposedRichText`] without cloning it.
+    pub fn from_ref(value: &RichText) -> &Self {
+        unsafe { &*(value as *const RichText).cast::<Self>() }
+    }
+
+    /// Create a new [`ExposedRichText`] with the styling of `old`, without cloning its text.
     pub fn new_keep_properties(text: impl Into<String>, old: &RichText) -> Self {
+        let old = Self::from_ref(old);
         Self {
             text: text.into(),
-            ..old.clone().into()
+            size: old.size,
+            extra_letter_spacing: old.extra_letter_spacing,
+            line_height: old.line_height,
+            family: old.family.clone(),
+            text_style: old.text_style.clone(),
+            background_color: old.background_color,
+            text_color: old.text_color,
+            code: old.code,
+            strong: old.strong,
+            weak: old.weak,
+            strikethrough: old.strikethrough,
+            underline: old.underline,
+            italics: old.italics,
+            raised: old.raised,
         }
     }
+
+    /// Approximate a [`egui::text::LayoutJob`] with a single style.
+    ///
+    /// A [`RichText`] can't have more than one style, so only the format of the first section is kept.
+    pub fn from_layout_job(job: &egui::text::LayoutJob) -> Self {
+        let Some(section) = job.sections.first() else {
+            return Self {
+                text: job.text.clone(),
+                ..Default::default()
+            };
+        };
+        let format = &section.format;
+        Self {
+            text: job.text.clone(),
+            size: Some(format.font_id.size),
+            extra_letter_spacing: format.extra_letter_spacing,
+            line_height: format.line_height,
+            family: Some(format.font_id.family.clone()),
+            background_color: format.background,
+            // the placeholder means "let the widget decide", just like no color in a `RichText`:
+            text_color: (format.color != egui::Color32::PLACEHOLDER).then_some(format.color),
+            strikethrough: !format.strikethrough.is_empty(),
+            underline: !format.underline.is_empty(),
+            italics: format.italics,
+            raised: format.valign == egui::Align::TOP,
+            ..Default::default()
+        }
+    }
+
+    /// The color egui uses for the underline and strikethrough lines of this text.
+    pub fn line_color(&self, visuals: &egui::Visuals) -> egui::Color32 {
+        if let Some(text_color) = self.text_color {
+            text_color
+        } else if self.strong {
+            visuals.strong_text_color()
+        } else if self.weak {
+            visuals.weak_text_color()
+        } else {
+            visuals.text_color()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fields_line_up_with_rich_text() {
+        // every field is set to something other than its default, so a reordered field shows up:
+        let text = RichText::new("text")
+            .code()
+            .size(20.0)
+            .extra_letter_spacing(2.0)
+            .line_height(Some(30.0))
+            .family(egui::FontFamily::Name("custom".into()))
+            .text_style(egui::TextStyle::Heading)
+            .background_color(egui::Color32::YELLOW)
+            .color(egui::Color32::RED)
+            .strong()
+            .weak()
+            .strikethrough()
+            .underline()
+            .italics()
+            .raised();
+        let expected = ExposedRichText {
+            text: "text".to_owned(),
+            size: Some(20.0),
+            extra_letter_spacing: 2.0,
+            line_height: Some(30.0),
+            family: Some(egui::FontFamily::Name("custom".into())),
+            text_style: Some(egui::TextStyle::Heading),
+            background_color: egui::Color32::YELLOW,
+            text_color: Some(egui::Color32::RED),
+            code: true,
+            strong: true,
+            weak: true,
+            strikethrough: true,
+            underline: true,
+            italics: true,
+            raised: true,
+        };
+        assert!(*ExposedRichText::from_ref(&text) == expected);
+
+        let round_trip = ExposedRichText::from(RichText::from(expected.clone()));
+        assert!(round_trip == expected);
+        assert_eq!(RichText::from(expected).text(), "text");
+    }
 }
@@ -0,0 +1,190 @@
+//! Grayscale emoji images, see [`EmojiLabel::grayscale`](crate::EmojiLabel::grayscale).
+
+use crate::{provider::provider_key, EmojiAssetProvider, MAX_UNUSED_PASSES};
+use egui::{load::SizedTexture, ImageSource};
+use std::collections::HashMap;
+
+/// How many grayscale textures are kept at once, so a lot of different emojis (or sizes) can't
+/// fill up the GPU memory.
+const MAX_TEXTURES: usize = 256;
+
+/// The provider, emoji and height in pixels of a grayscale texture.
+type GrayKey = (&'static str, String, u32);
+
+/// A grayscale texture, and the pass it was last used in.
+#[derive(Clone)]
+struct GrayTexture {
+    handle: egui::TextureHandle,
+    last_used_pass: u64,
+}
+
+/// The grayscale textures of the emoji images shown recently.
+///
+/// Textures that haven't been used in [`MAX_UNUSED_PASSES`] passes are dropped, which frees them.
+#[derive(Clone, Default)]
+struct GrayTextures {
+    textures: HashMap<GrayKey, GrayTexture>,
+    last_eviction_pass: u64,
+}
+
+impl GrayTextures {
+    fn with<R>(ctx: &egui::Context, f: impl FnOnce(&mut Self) -> R) -> R {
+        ctx.data_mut(|d| f(d.get_temp_mut_or_default(egui::Id::new("egui_twemoji::gray"))))
+    }
+
+    /// The texture for `key`, if there is one, marked as used in `pass_nr`.
+    fn get(&mut self, key: &GrayKey, pass_nr: u64) -> Option<egui::TextureHandle> {
+        if self.last_eviction_pass != pass_nr {
+            (self.textures)
+                .retain(|_, texture| texture.last_used_pass + MAX_UNUSED_PASSES >= pass_nr);
+            self.last_eviction_pass = pass_nr;
+        }
+        let texture = self.textures.get_mut(key)?;
+        texture.last_used_pass = pass_nr;
+        Some(texture.handle.clone())
+    }
+
+    /// Add the texture for `key`, making room for it if there are [`MAX_TEXTURES`] already.
+    fn insert(&mut self, key: GrayKey, handle: egui::TextureHandle, pass_nr: u64) {
+        if self.textures.len() >= MAX_TEXTURES {
+            let lru = (self.textures.iter()).min_by_key(|(_, texture)| texture.last_used_pass);
+            if let Some((lru, _)) = lru {
+                let lru = lru.clone();
+                self.textures.remove(&lru);
+            }
+        }
+        let texture = GrayTexture {
+            handle,
+            last_used_pass: pass_nr,
+        };
+        self.textures.insert(key, texture);
+    }
+}
+
+/// A grayscale copy of `source`, the image of `emoji` from `P` for a texture of `size` points,
+/// or [`None`] if it isn't loaded yet, or needs to be shown in color.
+///
+/// The color image is loaded by egui's image loaders as usual, and its grayscale copy is kept in
+/// its own texture, so it is only converted once per size. Textures can't be read back, so
+/// [`ImageSource::Texture`]s are never turned gray.
+pub(crate) fn gray_source<P: EmojiAssetProvider>(
+    ctx: &egui::Context,
+    emoji: &str,
+    source: &ImageSource<'_>,
+    size: egui::Vec2,
+) -> Option<ImageSource<'static>> {
+    let uri = match source {
+        ImageSource::Uri(uri) | ImageSource::Bytes { uri, .. } => uri.to_string(),
+        ImageSource::Texture(_) => return None,
+    };
+
+    let size = (size * ctx.pixels_per_point()).round();
+    let key = (provider_key::<P>(), emoji.to_owned(), size.y as u32);
+    let pass_nr = ctx.cumulative_pass_nr();
+    let cached = GrayTextures::with(ctx, |gray| gray.get(&key, pass_nr));
+    let texture = match cached {
+        Some(texture) => texture,
+        None => {
+            if let ImageSource::Bytes { bytes, .. } = source {
+                ctx.include_bytes(uri.clone(), bytes.clone());
+            }
+            let size_hint = egui::SizeHint::Size(size.x as u32, size.y as u32);
+            let Ok(egui::load::ImagePoll::Ready { image }) = ctx.try_load_image(&uri, size_hint)
+            else {
+                return None;
+            };
+            let texture = ctx.load_texture(
+                format!("{uri}#gray"),
+                to_grayscale(&image),
+                egui::TextureOptions::default(),
+            );
+            GrayTextures::with(ctx, |gray| gray.insert(key, texture.clone(), pass_nr));
+            texture
+        }
+    };
+    Some(ImageSource::Texture(SizedTexture::from_handle(&texture)))
+}
+
+/// `image` with every pixel turned into its luma, keeping its alpha.
+fn to_grayscale(image: &egui::ColorImage) -> egui::ColorImage {
+    let pixels = (image.pixels.iter())
+        .map(|pixel| {
+            let [r, g, b, a] = pixel.to_array();
+            // the Rec. 601 luma, on the premultiplied channels so the result stays premultiplied:
+            let luma = (0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32).round() as u8;
+            egui::Color32::from_rgba_premultiplied(luma, luma, luma, a)
+        })
+        .collect();
+    egui::ColorImage {
+        size: image.size,
+        pixels,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grayscale_keeps_alpha() {
+        let image = egui::ColorImage {
+            size: [3, 1],
+            pixels: vec![
+                egui::Color32::RED,
+                egui::Color32::WHITE,
+                egui::Color32::from_rgba_premultiplied(0, 100, 0, 100),
+            ],
+        };
+        let gray = to_grayscale(&image);
+        assert_eq!(
+            gray.pixels,
+            [
+                egui::Color32::from_gray(76),
+                egui::Color32::WHITE,
+                egui::Color32::from_rgba_premultiplied(59, 59, 59, 100),
+            ]
+        );
+    }
+
+    #[cfg(all(
+        feature = "loaders",
+        any(feature = "svg", feature = "png", feature = "both")
+    ))]
+    #[test]
+    fn gray_textures_are_converted_once() {
+        use crate::EmojiAssetProvider;
+
+        let ctx = egui::Context::default();
+        crate::require_image_loaders(&ctx);
+        let source = crate::TwemojiProvider::lookup("🔥").unwrap();
+        let size = egui::vec2(16.0, 16.0);
+        let texture_id = |source: Option<ImageSource<'_>>| match source {
+            Some(ImageSource::Texture(texture)) => texture.id,
+            _ => unreachable!(),
+        };
+        let gray = |source| gray_source::<crate::TwemojiProvider>(&ctx, "🔥", source, size);
+        let first = texture_id(gray(&source));
+        assert_eq!(texture_id(gray(&source)), first);
+        assert!(gray(&ImageSource::Texture(SizedTexture::new(first, size))).is_none());
+    }
+
+    #[test]
+    fn unused_gray_textures_are_freed() {
+        let ctx = egui::Context::default();
+        let image = egui::ColorImage::new([1, 1], egui::Color32::RED);
+        let texture = |i| ctx.load_texture(format!("{i}"), image.clone(), Default::default());
+        let key = |i: usize| (provider_key::<crate::TwemojiProvider>(), i.to_string(), 16);
+
+        let mut gray = GrayTextures::default();
+        for i in 0..=MAX_TEXTURES {
+            gray.insert(key(i), texture(i), i as u64);
+        }
+        assert_eq!(gray.textures.len(), MAX_TEXTURES);
+        assert!(gray.get(&key(0), MAX_TEXTURES as u64).is_none());
+        assert!(gray.get(&key(MAX_TEXTURES), MAX_TEXTURES as u64).is_some());
+
+        let pass_nr = 2 * MAX_TEXTURES as u64 + MAX_UNUSED_PASSES;
+        assert!(gray.get(&key(1), pass_nr).is_none());
+        assert_eq!(gray.textures.len(), 0);
+    }
+}
@@ -7,13 +7,17 @@
 //!
 //! # How to use
 //!
-//! Make sure you've installed `egui_extras` image loaders (required for rendering SVG and PNG emotes):
+//! Make sure you've installed `egui_extras` image loaders (required for rendering SVG and PNG emotes),
+//! otherwise every emoji is shown as a "⚠" (and a warning is logged once in debug builds):
 //!
 //! ```ignore
 //! // don't do this every frame - only when the app is created!
 //! egui_extras::install_image_loaders(&cc.egui_ctx);
 //! ```
 //!
+//! With the `loaders` feature, `require_image_loaders` does this for you, which is handy when the
+//! first frame may be shown before your setup code ran (e.g. on the web).
+//!
 //! And then:
 //!
 //! ```rust
@@ -26,15 +30,33 @@
 //!
 //! For a more sophisticated example, see the `demo` example (`cargo run --example demo`)
 //!
+//! For thousands of labels, like the messages of a chat log, only show the visible ones with
+//! `egui::ScrollArea::show_rows`, see the `chat_log` example (`cargo run --example chat_log`).
+//!
 //! `EmojiLabel` supports all functions that a normal
 //! [Label](https://docs.rs/egui/latest/egui/widgets/struct.Label.html) does.
 //!
+//! To render a different emoji set than Twemoji, implement [`EmojiAssetProvider`] and create labels
+//! with [`EmojiLabel::with_provider`].
+//!
 //! # Features
 //!
 //! * `svg`: use SVG emoji assets (`egui_extras/svg` is required)
 //! * `png`: use PNG emoji assets (`egui_extras/image` is required)
+//! * `both`: use the SVG asset of every emoji, or its PNG asset if there is no SVG one (both
+//!   `egui_extras/svg` and `egui_extras/image` are required)
+//! * `loaders`: `require_image_loaders`, which installs the `egui_extras` image loaders
+//! * `shortcodes`: the [`shortcode`] module and [`EmojiLabel::shortcodes`], for turning `:smile:` into 😄
+//! * `unicode-names`: show the code points and name of every emoji in a tooltip when it is
+//!   hovered, like `😂 U+1F602 FACE WITH TEARS OF JOY`. The names are the Emojibase (CLDR) labels,
+//!   not the Unicode character names: ❤️ is `RED HEART`, not `HEAVY BLACK HEART`
+//! * `serde`: (de)serialize [`EmojiLabelConfig`]s
+//! * `persistence`: keep the segmented labels in egui's persisted memory, so they don't have to be
+//!   segmented again on startup (implies `serde` and egui's `persistence` feature)
 //!
-//! By default, the `svg` feature is activated.
+//! By default, the `svg` feature is activated. Only one of `svg`, `png` and `both` can be enabled, so
+//! turn off the default features to use `png` or `both`. If none of them is enabled, no Twemoji
+//! assets are bundled and all text is rendered as-is.
 //!
 //! # License
 //!
@@ -43,31 +65,116 @@
 #![warn(missing_docs)]
 
 mod exposed;
+mod gray;
+#[cfg(feature = "unicode-names")]
+mod names;
+mod preload;
+mod provider;
+mod search;
+#[cfg(feature = "shortcodes")]
+pub mod shortcode;
+mod table;
+pub mod util;
+
+#[cfg(feature = "loaders")]
+pub use preload::require_image_loaders;
+pub use preload::{preload_all, preload_emoji};
+pub use provider::{is_emoji, EmojiAssetProvider, TwemojiProvider};
+use provider::{provider_key, sized_source};
+pub use search::search_emojis;
 
-use egui::{ImageSource, Layout, RichText, Sense, TextWrapMode};
+use egui::{Layout, RichText, Sense, TextWrapMode};
 use exposed::ExposedRichText;
+use std::{
+    borrow::Cow,
+    collections::{hash_map::Entry, BTreeMap, HashSet},
+    marker::PhantomData,
+    ops::Range,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, OnceLock,
+    },
+};
 use unicode_segmentation::UnicodeSegmentation;
 
-#[cfg(all(feature = "svg", feature = "png"))]
-compile_error!("features 'svg' and 'png' are mutually exclusive and cannot be enabled together");
-
-/// Represents a segment of text which can be either plain text or an emoji.
+/// A piece of the text of an [`EmojiLabel`]: plain text, an emoji or a line break.
 ///
-/// * `Text` variant wraps the `RichText` struct, which includes text and its styling information.
-/// * `Emoji` variant contains a `String` representing the emoji character.
+/// See [`EmojiLabel::segments`].
 #[derive(PartialEq, Clone)]
-enum TextSegment {
-    Text(RichText),
-    Emoji(String),
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TextSegment {
+    /// A run of text without any emojis, with its styling.
+    Text(#[cfg_attr(feature = "serde", serde(with = "exposed::serde_rich_text"))] RichText),
+    /// A single emoji, and the styling of the text it was in (as a [`RichText`] without any text),
+    /// for its size, background and decorations.
+    ///
+    /// The emoji strings are shared, so all segments of the same emoji point to the same string.
+    Emoji(
+        #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_emoji"))] Arc<str>,
+        #[cfg_attr(feature = "serde", serde(with = "exposed::serde_rich_text"))] RichText,
+    ),
+    /// A `\n` (or `\r\n`) in text that also contains emojis.
+    Newline,
 }
 
-#[inline]
-fn is_emoji(text: &str) -> bool {
-    #[cfg(feature = "svg")]
-    return twemoji_assets::svg::SvgTwemojiAsset::from_emoji(text).is_some();
+impl TextSegment {
+    /// The text this segment renders.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Text(text) => text.text(),
+            Self::Emoji(emoji, _) => emoji,
+            Self::Newline => "\n",
+        }
+    }
+}
+
+// `RichText` isn't `Debug`, so only the text of the segments is shown:
+impl std::fmt::Debug for TextSegment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Text(text) => f.debug_tuple("Text").field(&text.text()).finish(),
+            Self::Emoji(emoji, _) => f.debug_tuple("Emoji").field(emoji).finish(),
+            Self::Newline => f.write_str("Newline"),
+        }
+    }
+}
+
+impl std::fmt::Display for TextSegment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Get a shared copy of `emoji`, so that all segments of the same emoji point to the same string.
+///
+/// Emojis are a small, closed set that show up over and over again, e.g. in chat messages, so
+/// the strings are never freed.
+fn intern_emoji(emoji: &str) -> Arc<str> {
+    static EMOJIS: OnceLock<Mutex<HashSet<Arc<str>>>> = OnceLock::new();
+    // the set is only ever inserted into, so it is still valid if another thread panicked
+    let mut emojis = (EMOJIS.get_or_init(Default::default).lock())
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    match emojis.get(emoji) {
+        Some(interned) => interned.clone(),
+        None => {
+            let interned: Arc<str> = emoji.into();
+            emojis.insert(interned.clone());
+            interned
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_emoji<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Arc<str>, D::Error> {
+    let emoji: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+    Ok(intern_emoji(&emoji))
+}
 
-    #[cfg(feature = "png")]
-    return twemoji_assets::png::PngTwemojiAsset::from_emoji(text).is_some();
+#[inline]
+fn is_newline(grapheme: &str) -> bool {
+    grapheme == "\n" || grapheme == "\r\n"
 }
 
 /// Returns a vector of [`TextSegment`]s from a [`RichText`], segmented by emojis.
@@ -75,65 +182,442 @@ fn is_emoji(text: &str) -> bool {
 /// ## Example:
 ///
 /// "hello 😤 world" -> `[TextSegment::Text("hello "), TextSegment::Emoji("😤"), TextSegment::Text(" world")]`
-fn segment_text(input: &RichText) -> Vec<TextSegment> {
-    let mut result = Vec::new();
-    let mut text = String::new();
-
-    for grapheme in UnicodeSegmentation::graphemes(input.text(), true) {
-        if is_emoji(grapheme) {
-            if !text.is_empty() {
-                result.push(TextSegment::Text(
-                    ExposedRichText::new_keep_properties(text.clone(), input).into(),
-                ));
-                text.clear();
-            }
-            result.push(TextSegment::Emoji(grapheme.to_string()));
-        } else {
-            text.push_str(grapheme);
+///
+/// Text without any emojis is returned as a single segment, even if it contains newlines,
+/// since [`egui::Label`] already breaks those itself.
+fn segment_text<P: EmojiAssetProvider>(input: &RichText) -> Vec<TextSegment> {
+    let text = input.text();
+
+    // find all emojis and newlines first, so we only materialize the text runs between them once:
+    let breaks: Vec<Range<usize>> = text
+        .grapheme_indices(true)
+        .filter(|(_, grapheme)| is_newline(grapheme) || P::is_emoji(grapheme))
+        .map(|(start, grapheme)| start..start + grapheme.len())
+        .collect();
+
+    if breaks.iter().all(|range| is_newline(&text[range.clone()])) {
+        return match text.is_empty() {
+            true => Vec::new(),
+            false => vec![TextSegment::Text(input.clone())],
+        };
+    }
+
+    // every break can be followed by at most one text run, plus the one in front of the first break:
+    let mut result = Vec::with_capacity(breaks.len() * 2 + 1);
+    let mut text_start = 0;
+
+    for range in breaks {
+        if text_start < range.start {
+            result.push(TextSegment::Text(
+                ExposedRichText::new_keep_properties(&text[text_start..range.start], input).into(),
+            ));
         }
+        text_start = range.end;
+        let grapheme = &text[range];
+        result.push(match is_newline(grapheme) {
+            true => TextSegment::Newline,
+            false => TextSegment::Emoji(
+                intern_emoji(grapheme),
+                ExposedRichText::new_keep_properties("", input).into(),
+            ),
+        });
     }
 
-    if !text.is_empty() {
+    if text_start < text.len() {
         result.push(TextSegment::Text(
-            ExposedRichText::new_keep_properties(text.clone(), input).into(),
+            ExposedRichText::new_keep_properties(&text[text_start..], input).into(),
         ));
     }
 
     result
 }
 
-/// The state of an [EmojiLabel], stored in egui's [`egui::Memory`].
-/// This includes memoized text segments and whether the state was newly created.
-#[derive(Default, Clone)]
+/// How many passes a [`LabelState`] may go unused before it is evicted from the [`SegmentCache`].
+const MAX_UNUSED_PASSES: u64 = 60;
+
+/// The state of an [EmojiLabel], stored in the [`SegmentCache`].
+/// This includes memoized text segments and when they were last used.
+///
+/// The segments are shared, so handing a state out of the cache is cheap.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct LabelState {
-    segments: Vec<TextSegment>,
-    is_saved: bool,
+    segments: Arc<[TextSegment]>,
+    /// The [`egui::Id`] of the plain text the segments were made from, for [`EmojiLabel::forget`].
+    text_id: egui::Id,
+    /// A hash of the styled text and options the segments were made from, so that a salted label
+    /// whose text changed under the same cache key gets segmented again.
+    source_id: egui::Id,
+    // pass numbers start over when the app is restarted:
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_used_pass: u64,
+    /// The value of [`SegmentCache::uses`] when this state was last used, to find the least
+    /// recently used state when the cache is full.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_use: u64,
 }
 
 impl LabelState {
-    /// Create a new state from a [`RichText`], segmenting it by emojis.
-    fn from_text(text: impl Into<RichText>) -> Self {
-        let rich_text = text.into();
+    /// Create a new state from the segments of `text`.
+    fn new(segments: Vec<TextSegment>, text: &str) -> Self {
         Self {
-            segments: segment_text(&rich_text),
-            is_saved: false,
+            segments: segments.into(),
+            text_id: egui::Id::new(text),
+            source_id: egui::Id::NULL,
+            last_used_pass: 0,
+            last_use: 0,
         }
     }
 
-    /// Load the state from egui's [`egui::Memory`].
-    fn load(ctx: &egui::Context, id: egui::Id, text: &RichText) -> Self {
-        ctx.data_mut(|d| {
-            d.get_temp(id)
-                .unwrap_or_else(|| Self::from_text(text.clone()))
+    /// Load the state from the [`SegmentCache`] in egui's [`egui::Memory`].
+    ///
+    /// If there's no state for `id` yet, or it was made from a different `source_id`, a new one
+    /// is created with `make_state` and inserted.
+    fn load(
+        ctx: &egui::Context,
+        id: egui::Id,
+        source_id: egui::Id,
+        make_state: impl FnOnce() -> Self,
+    ) -> Self {
+        let pass_nr = ctx.cumulative_pass_nr();
+        SegmentCache::with(ctx, |cache| {
+            cache.evict_stale_once(pass_nr);
+            cache.uses += 1;
+            let uses = cache.uses;
+            // (a state made from a different source is replaced, so this is the use of the old one)
+            let last_use = cache.states.get(&id).map(|state| state.last_use);
+            let state = match cache.states.entry(id) {
+                Entry::Occupied(entry) if entry.get().source_id == source_id => entry.into_mut(),
+                entry => {
+                    let state = Self {
+                        source_id,
+                        ..make_state()
+                    };
+                    entry.insert_entry(state).into_mut()
+                }
+            };
+            state.last_used_pass = pass_nr;
+            state.last_use = uses;
+            let state = state.clone();
+            if let Some(last_use) = last_use {
+                cache.lru.remove(&last_use);
+            }
+            cache.lru.insert(uses, id);
+            cache.evict_over_capacity();
+            state
         })
     }
+}
+
+/// All [`LabelState`]s, stored in a single slot of egui's [`egui::Memory`] so
+/// that states which haven't been used in a while can be found and evicted.
+///
+/// With the `persistence` feature, the cache is persisted along with the rest of egui's memory.
+#[derive(Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct SegmentCache {
+    states: egui::IdMap<LabelState>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_eviction_pass: u64,
+    /// How many states may be cached at once, see [`EmojiLabel::set_cache_capacity`].
+    capacity: Option<usize>,
+    /// How many times a state was loaded in total.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    uses: u64,
+    /// The id of every state by its [`LabelState::last_use`], so the least recently used one comes
+    /// first.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    lru: BTreeMap<u64, egui::Id>,
+}
+
+impl SegmentCache {
+    #[cfg(not(feature = "persistence"))]
+    fn with<R>(ctx: &egui::Context, f: impl FnOnce(&mut Self) -> R) -> R {
+        ctx.data_mut(|d| f(d.get_temp_mut_or_default(Self::id())))
+    }
+
+    #[cfg(feature = "persistence")]
+    fn with<R>(ctx: &egui::Context, f: impl FnOnce(&mut Self) -> R) -> R {
+        ctx.data_mut(|d| f(d.get_persisted_mut_or_default(Self::id())))
+    }
+
+    /// Where the cache is kept in egui's [`egui::Memory`].
+    fn id() -> egui::Id {
+        egui::Id::new("egui_twemoji::segment_cache")
+    }
+
+    /// Keep only the states `f` returns `true` for.
+    fn retain(&mut self, mut f: impl FnMut(&LabelState) -> bool) {
+        let lru = &mut self.lru;
+        self.states.retain(|_, state| {
+            let keep = f(state);
+            if !keep {
+                lru.remove(&state.last_use);
+            }
+            keep
+        });
+    }
+
+    /// Evict all states that haven't been used in the last [`MAX_UNUSED_PASSES`] passes.
+    fn evict_stale(&mut self, pass_nr: u64) {
+        self.retain(|state| state.last_used_pass + MAX_UNUSED_PASSES >= pass_nr);
+        self.last_eviction_pass = pass_nr;
+    }
+
+    /// Evict the least recently used states until there are no more than [`Self::capacity`].
+    fn evict_over_capacity(&mut self) {
+        let Some(capacity) = self.capacity else {
+            return;
+        };
+        self.index_unordered();
+        while self.states.len() > capacity {
+            let Some((_, id)) = self.lru.pop_first() else {
+                break;
+            };
+            self.states.remove(&id);
+        }
+    }
+
+    /// Put the states that aren't in [`Self::lru`] yet at its start.
+    ///
+    /// The order isn't persisted, so this is the case for all states right after the cache was
+    /// restored. Those were last used in a previous run of the app, before any other state.
+    fn index_unordered(&mut self) {
+        if self.lru.len() == self.states.len() {
+            return;
+        }
+        let indexed = std::mem::take(&mut self.lru);
+        let is_indexed =
+            |id: &egui::Id, state: &LabelState| indexed.get(&state.last_use) == Some(id);
+        let mut unordered: Vec<(egui::Id, u64)> = (self.states.iter())
+            .filter(|(id, state)| !is_indexed(id, state))
+            .map(|(&id, state)| (id, state.last_use))
+            .collect();
+        unordered.sort_by_key(|&(_, last_use)| last_use);
+        let ordered: Vec<egui::Id> = (indexed.values())
+            .filter(|&id| {
+                self.states
+                    .get(id)
+                    .is_some_and(|state| is_indexed(id, state))
+            })
+            .copied()
+            .collect();
+
+        // renumber everything, so all uses stay unique:
+        self.uses = 0;
+        for id in unordered.into_iter().map(|(id, _)| id).chain(ordered) {
+            self.uses += 1;
+            if let Some(state) = self.states.get_mut(&id) {
+                state.last_use = self.uses;
+            }
+            self.lru.insert(self.uses, id);
+        }
+    }
+
+    /// Like [`Self::evict_stale`], but only does work once per pass.
+    fn evict_stale_once(&mut self, pass_nr: u64) {
+        if self.last_eviction_pass != pass_nr {
+            self.evict_stale(pass_nr);
+        }
+    }
+}
+
+/// Text that was already split into text and emojis, for showing it with
+/// [`EmojiLabel::with_precomputed`].
+///
+/// These can be made on any thread, so the segmentation can happen in the background and the UI
+/// thread only has to lay the text out. Cloning them is cheap, and they can be reused across
+/// frames and by any number of labels.
+///
+/// ```rust
+/// use egui_twemoji::{EmojiLabel, PrecomputedSegments};
+///
+/// let messages = vec!["hi 👋".to_owned(), "how are you? 😊".to_owned()];
+/// let segmented: Vec<PrecomputedSegments> = std::thread::spawn(move || {
+///     messages.into_iter().map(PrecomputedSegments::new).collect()
+/// })
+/// .join()
+/// .unwrap();
+///
+/// fn show_messages(ui: &mut egui::Ui, segmented: &[PrecomputedSegments]) {
+///     for segments in segmented {
+///         EmojiLabel::with_precomputed(segments).show(ui);
+///     }
+/// }
+/// ```
+pub struct PrecomputedSegments<P: EmojiAssetProvider = TwemojiProvider> {
+    text: RichText,
+    state: LabelState,
+    provider: PhantomData<fn() -> P>,
+}
+
+// not derived, since that would require `P: Clone`:
+impl<P: EmojiAssetProvider> Clone for PrecomputedSegments<P> {
+    fn clone(&self) -> Self {
+        Self {
+            text: self.text.clone(),
+            state: self.state.clone(),
+            provider: PhantomData,
+        }
+    }
+}
+
+impl PrecomputedSegments {
+    /// Segment a [`RichText`] for a label that renders [Twemojis](https://github.com/twitter/twemoji).
+    pub fn new(text: impl Into<RichText>) -> Self {
+        Self::with_provider(text)
+    }
+}
+
+impl<P: EmojiAssetProvider> PrecomputedSegments<P> {
+    /// Segment a [`RichText`] for a label that gets its emoji images from `P`.
+    pub fn with_provider(text: impl Into<RichText>) -> Self {
+        let text = text.into();
+        Self {
+            state: LabelState::new(segment_text::<P>(&text), text.text()),
+            text,
+            provider: PhantomData,
+        }
+    }
+
+    /// Get the text that was segmented.
+    pub fn text(&self) -> &str {
+        self.text.text()
+    }
+}
+
+/// A callback that gets an emoji, like the one for [`EmojiLabel::on_hover_emoji`].
+type EmojiCallback = Box<dyn FnMut(&str)>;
+
+/// Information about how an [`EmojiLabel`] was shown, returned by [`EmojiLabel::show_with_info`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ShowInfo {
+    /// Whether some of the content was cut off, because the label was truncated
+    /// (see [`EmojiLabel::truncate`]).
+    pub truncated: bool,
+    /// Every emoji that was shown as an image, and where, e.g. for anchoring a popup to one.
+    pub emoji_rects: Vec<(String, egui::Rect)>,
+}
+
+/// A text whose segments are cached, see [`EmojiLabel::cache_entries`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct CacheEntry {
+    /// The [`egui::Id`] of the plain text the segments were made from, i.e. `egui::Id::new(text)`.
+    pub text_id: egui::Id,
+    /// How many segments the text was split into.
+    pub segments: usize,
+    /// The [`egui::Context::cumulative_pass_nr`] of the last pass in which it was shown.
+    pub last_used_pass: u64,
+}
+
+/// Defaults for all [`EmojiLabel`]s shown with an [`egui::Context`], for options you would
+/// otherwise set the same way on every label.
+///
+/// Labels use these unless they set the option themselves, like egui's widgets use the style
+/// unless they're told otherwise:
+///
+/// ```rust
+/// use egui_twemoji::EmojiLabelOptions;
+///
+/// fn setup(ctx: &egui::Context) {
+///     EmojiLabelOptions {
+///         auto_inline: false,
+///         ..Default::default()
+///     }
+///     .store(ctx);
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct EmojiLabelOptions {
+    /// The default for [`EmojiLabel::auto_inline`], `true` by default.
+    pub auto_inline: bool,
+    /// The default for [`EmojiLabel::emoji_size_scale`], `1.0` by default.
+    pub emoji_scale: f32,
+    /// The default for [`EmojiLabel::selectable`]. If this is [`None`] as well,
+    /// [`egui::style::Interaction::selectable_labels`] is used.
+    pub selectable: Option<bool>,
+}
+
+impl Default for EmojiLabelOptions {
+    fn default() -> Self {
+        Self {
+            auto_inline: true,
+            emoji_scale: 1.0,
+            selectable: None,
+        }
+    }
+}
+
+impl EmojiLabelOptions {
+    /// The options stored in `ctx`, or the defaults if none were stored.
+    pub fn load(ctx: &egui::Context) -> Self {
+        ctx.data(|d| d.get_temp(Self::id())).unwrap_or_default()
+    }
+
+    /// Use these options for all labels shown with `ctx` from now on.
+    pub fn store(self, ctx: &egui::Context) {
+        ctx.data_mut(|d| d.insert_temp(Self::id(), self));
+    }
+
+    /// Where the options are kept in egui's [`egui::Memory`].
+    fn id() -> egui::Id {
+        egui::Id::new("egui_twemoji::options")
+    }
+}
+
+/// The layout options of an [`EmojiLabel`], which can be kept in a config file with the `serde`
+/// feature, e.g. for presets:
+///
+/// ```rust
+/// use egui_twemoji::{EmojiLabel, EmojiLabelConfig};
+///
+/// fn show_label(ui: &mut egui::Ui, preset: EmojiLabelConfig) {
+///     EmojiLabel::with_config("Hello 🦀", preset).show(ui);
+/// }
+/// ```
+///
+/// Unlike [`EmojiLabelOptions`], this applies to a single label and always overrides the defaults
+/// of the context, except for the fields that are [`None`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct EmojiLabelConfig {
+    /// See [`EmojiLabel::wrap_mode`].
+    pub wrap_mode: Option<TextWrapMode>,
+    /// See [`EmojiLabel::selectable`].
+    pub selectable: Option<bool>,
+    /// See [`EmojiLabel::auto_inline`], `true` by default.
+    pub auto_inline: bool,
+    /// See [`EmojiLabel::emoji_size_scale`], `1.0` by default.
+    pub emoji_scale: f32,
+    /// See [`EmojiLabel::emoji_spacing`], `0.0` by default.
+    pub emoji_spacing: f32,
+}
 
-    /// Save the state to egui's [`egui::Memory`]. Only call this if [`Self::is_saved`] is `false`.
-    fn save(self, ctx: &egui::Context, id: egui::Id) {
-        ctx.data_mut(|d| d.insert_temp(id, self));
+impl Default for EmojiLabelConfig {
+    fn default() -> Self {
+        Self {
+            wrap_mode: None,
+            selectable: None,
+            auto_inline: true,
+            emoji_scale: 1.0,
+            emoji_spacing: 0.0,
+        }
     }
 }
 
+/// How emoji images are sized, see [`EmojiLabel::emoji_fit`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EmojiFit {
+    /// Squeeze every image into a square as tall as the text. This is right for Twemojis, which
+    /// are all square.
+    #[default]
+    ExactSquare,
+    /// Make images as tall as the text and as wide as their aspect ratio needs, e.g. for wide
+    /// custom emotes. Images are shown as squares until they're loaded.
+    HeightPreserveAspect,
+}
+
 /// An [egui](https://egui.rs/) widget that renders colored [Twemojis](https://github.com/twitter/twemoji).
 ///
 /// ```rust
@@ -143,45 +627,319 @@ impl LabelState {
 ///     EmojiLabel::new("⭐ egui-twemoji 🐦✨").show(ui);
 /// }
 /// ```
+///
+/// By default, [Twemojis](https://github.com/twitter/twemoji) are rendered using the [`TwemojiProvider`].
+/// See [`EmojiAssetProvider`] for how to render your own emoji images instead.
 #[must_use = "You should put this widget in an ui by calling `.show(ui);`"]
-pub struct EmojiLabel {
+pub struct EmojiLabel<P: EmojiAssetProvider = TwemojiProvider> {
     text: RichText,
+    /// The spans when made with [`EmojiLabel::from_spans`], in which case `text` is all of them combined.
+    spans: Vec<RichText>,
     wrap_mode: Option<TextWrapMode>,
     sense: Option<Sense>,
     selectable: Option<bool>,
-    auto_inline: bool,
+    copyable: bool,
+    auto_inline: Option<bool>,
+    max_width: Option<f32>,
+    stateless: bool,
+    emoji_scale: Option<f32>,
+    emoji_spacing: f32,
+    emoji_fit: EmojiFit,
+    emoji_rounding: f32,
+    emoji_tint: egui::Color32,
+    grayscale: bool,
+    emoji_box: bool,
+    link: Option<String>,
+    jumbo_scale: Option<f32>,
+    max_rendered_emoji: Option<usize>,
+    hover_zoom: Option<f32>,
+    single_galley: bool,
+    justify: bool,
+    halign: Option<egui::Align>,
+    id_salt: Option<egui::Id>,
+    cache_key: Option<u64>,
+    /// [`Self::source_id`], so the text is only hashed once even if it's needed several times.
+    source_id: std::cell::OnceCell<egui::Id>,
+    #[cfg(feature = "shortcodes")]
+    shortcodes: bool,
+    on_hover_emoji: Option<EmojiCallback>,
+    /// Where every segment was shown, when collecting them for [`Self::layout_rects`].
+    segment_rects: Option<Vec<(egui::Rect, TextSegment)>>,
+    precomputed: Option<LabelState>,
+    provider: PhantomData<fn() -> P>,
 }
 
-fn get_source_for_emoji(emoji: &str) -> Option<ImageSource> {
-    #[cfg(feature = "svg")]
-    {
-        let svg_data = twemoji_assets::svg::SvgTwemojiAsset::from_emoji(emoji)?;
-        let source = ImageSource::Bytes {
-            uri: format!("{emoji}.svg").into(),
-            bytes: egui::load::Bytes::Static(svg_data.as_bytes()),
-        };
-        Some(source)
+/// Paint the background color of `style` behind the space of an emoji, `rect`,
+/// the same way egui fills it behind the lines of text glyphs.
+fn paint_background(ui: &egui::Ui, rect: egui::Rect, style: &ExposedRichText) {
+    let color = if style.code {
+        ui.visuals().code_bg_color
+    } else {
+        style.background_color
+    };
+    if color == egui::Color32::TRANSPARENT {
+        return;
+    }
+
+    // egui expands text backgrounds by a point on each side, so do the same to overlap with them
+    // instead of leaving a seam:
+    ui.painter().rect_filled(rect.expand(1.0), 0.0, color);
+}
+
+/// Paint an emoji image enlarged around `rect`, on top of everything else so it doesn't move any
+/// other widget. Returns `false` if the image isn't loaded yet, so it can be painted normally.
+fn paint_zoomed(ui: &egui::Ui, id: egui::Id, image: &egui::Image<'_>, rect: egui::Rect) -> bool {
+    let layer_id = egui::LayerId::new(egui::Order::Foreground, id.with("hover_zoom"));
+    let mut painter = ui
+        .ctx()
+        .layer_painter(layer_id)
+        .with_clip_rect(ui.clip_rect());
+    // fade like everything else the ui paints:
+    painter.set_opacity(ui.opacity());
+    paint_loaded(&painter, image, rect)
+}
+
+/// Paint an emoji image of a disabled [`egui::Ui`] at `rect`, blended halfway into the background.
+///
+/// egui grays out disabled text by moving its color halfway towards the background color, but
+/// doing the same to the tint of an image barely changes it in light themes. Returns `false` if
+/// the image isn't loaded yet, so it can be painted normally.
+fn paint_disabled(ui: &egui::Ui, image: &egui::Image<'_>, rect: egui::Rect) -> bool {
+    // the painter of the ui would gray out the tint again:
+    let painter = egui::Painter::new(ui.ctx().clone(), ui.layer_id(), ui.clip_rect());
+    let tint = (image.image_options().tint).gamma_multiply(0.5 * ui.painter().opacity());
+    paint_loaded(&painter, &image.clone().tint(tint), rect)
+}
+
+/// Paint `image` at `rect` with `painter`, or return `false` if it isn't loaded yet.
+fn paint_loaded(painter: &egui::Painter, image: &egui::Image<'_>, rect: egui::Rect) -> bool {
+    let Ok(egui::load::TexturePoll::Ready { texture }) =
+        image.load_for_size(painter.ctx(), rect.size())
+    else {
+        return false;
+    };
+    egui::widgets::paint_texture_at(painter, rect, image.image_options(), &texture);
+    true
+}
+
+/// In debug builds, log a warning the first time an emoji image can't be shown because no image
+/// loader can load it, which is easy to miss as egui only paints a "⚠" in its place.
+///
+/// Once an image was loaded (or the warning was logged), the loaders are known to be there (or
+/// not), so nothing is checked anymore.
+fn warn_if_no_loaders(ctx: &egui::Context, image: &egui::Image<'_>, size: egui::Vec2) {
+    static CHECKED: AtomicBool = AtomicBool::new(false);
+    if !cfg!(debug_assertions) || CHECKED.load(Ordering::Relaxed) {
+        return;
+    }
+    match image.load_for_size(ctx, size) {
+        Ok(_) => CHECKED.store(true, Ordering::Relaxed),
+        Err(err) if is_missing_loader(&err) && !CHECKED.swap(true, Ordering::Relaxed) => {
+            log::warn!(
+                "egui_twemoji: emoji images can't be loaded ({err}). Call \
+                `egui_extras::install_image_loaders` when creating your app, \
+                or `egui_twemoji::require_image_loaders` with the `loaders` feature."
+            );
+        }
+        // e.g. broken image data, which has nothing to do with the loaders:
+        Err(_) => {}
+    }
+}
+
+/// Whether `err` means the image loaders needed to show an emoji aren't installed.
+fn is_missing_loader(err: &egui::load::LoadError) -> bool {
+    use egui::load::LoadError;
+    matches!(
+        err,
+        LoadError::NoImageLoaders
+            | LoadError::NoMatchingBytesLoader
+            | LoadError::NoMatchingImageLoader { .. }
+    )
+}
+
+/// Paint the underline and strikethrough of `style` across the space of an emoji, `rect`,
+/// the same way egui draws them under and through the lines of text glyphs.
+fn paint_decorations(ui: &egui::Ui, rect: egui::Rect, style: &ExposedRichText) {
+    if !style.underline && !style.strikethrough {
+        return;
+    }
+
+    let stroke = egui::Stroke::new(1.0, style.line_color(ui.visuals()));
+    if style.underline {
+        ui.painter()
+            .line_segment([rect.left_bottom(), rect.right_bottom()], stroke);
+    }
+    if style.strikethrough {
+        ui.painter()
+            .line_segment([rect.left_center(), rect.right_center()], stroke);
+    }
+}
+
+/// `segments` in the style of a hyperlink, underlined if `underline` is set.
+fn link_segments(ui: &egui::Ui, segments: &[TextSegment], underline: bool) -> Vec<TextSegment> {
+    let link_style = |text: &RichText| {
+        let text = text.clone().color(ui.visuals().hyperlink_color);
+        match underline {
+            true => text.underline(),
+            false => text,
+        }
+    };
+    (segments.iter())
+        .map(|segment| match segment {
+            TextSegment::Text(text) => TextSegment::Text(link_style(text)),
+            TextSegment::Emoji(emoji, style) => {
+                TextSegment::Emoji(emoji.clone(), link_style(style))
+            }
+            TextSegment::Newline => TextSegment::Newline,
+        })
+        .collect()
+}
+
+/// Whether `text` would get cut off with an ellipsis when truncated to `available_width`,
+/// the way an [`egui::Label`] would lay it out.
+fn is_elided(ui: &egui::Ui, text: &RichText, available_width: f32) -> bool {
+    egui::WidgetText::from(text.clone())
+        .into_galley(
+            ui,
+            Some(TextWrapMode::Truncate),
+            available_width,
+            egui::FontSelection::Default,
+        )
+        .elided
+}
+
+/// An emoji as text in the style it was in, for when there's no image for it.
+fn emoji_as_text(emoji: &str, style: &RichText) -> RichText {
+    ExposedRichText::new_keep_properties(emoji, style).into()
+}
+
+/// The invisible stand-in for an emoji in a single-galley label, see [`EmojiLabel::single_galley`].
+struct EmojiPlaceholder {
+    /// The index of its section in the [`LayoutJob`](egui::text::LayoutJob).
+    section: u32,
+    emoji: Arc<str>,
+    source: egui::ImageSource<'static>,
+    /// How many glyphs the emoji text is laid out as.
+    glyphs: usize,
+    image_size: egui::Vec2,
+    slot_height: f32,
+    /// Where the image goes from the left end and the top of the slot for the emoji.
+    image_offset: egui::Vec2,
+    /// The format of the emoji text before it was made invisible.
+    format: egui::TextFormat,
+}
+
+/// Append all of `other` to the end of `job`.
+fn append_job(job: &mut egui::text::LayoutJob, other: egui::text::LayoutJob) {
+    let offset = job.text.len();
+    job.text += &other.text;
+    job.sections
+        .extend(other.sections.into_iter().map(|mut section| {
+            section.byte_range = section.byte_range.start + offset..section.byte_range.end + offset;
+            section
+        }));
+}
+
+/// Lay out a wrapping `job` with the gaps between its words widened, so every row but the last one
+/// of each paragraph is as wide as the wrap width, see [`EmojiLabel::justify`].
+///
+/// Words are moved by giving them sections of their own with some leading space, so this also
+/// returns the section of `job` every section of the galley was split off from.
+fn justify_job(
+    fonts: &egui::epaint::Fonts,
+    job: egui::text::LayoutJob,
+) -> (Arc<egui::Galley>, Vec<u32>) {
+    let galley = fonts.layout_job(job.clone());
+    let origins: Vec<u32> = (0..job.sections.len() as u32).collect();
+    let max_width = job.wrap.max_width;
+    if !max_width.is_finite() || galley.rows.len() < 2 {
+        return (galley, origins);
     }
 
-    #[cfg(feature = "png")]
-    {
-        let png_data: &[u8] = twemoji_assets::png::PngTwemojiAsset::from_emoji(emoji)?;
-        let source = ImageSource::Bytes {
-            uri: format!("{emoji}.png").into(),
-            bytes: egui::load::Bytes::Static(png_data),
+    // the extra space in front of every word that is moved, by the byte offset of its first char.
+    // line breaks are the only chars that aren't laid out as glyphs:
+    let mut chars = job.text.char_indices().filter(|&(_, chr)| chr != '\n');
+    let mut extra_space = Vec::new();
+    for (i, row) in galley.rows.iter().enumerate() {
+        let offsets: Vec<usize> = chars
+            .by_ref()
+            .take(row.glyphs.len())
+            .map(|(offset, _)| offset)
+            .collect();
+        if i + 1 == galley.rows.len() || row.ends_with_newline {
+            continue;
+        }
+        let glyphs = &row.glyphs;
+        let (Some(first), Some(last)) = (
+            glyphs.iter().position(|glyph| !glyph.chr.is_whitespace()),
+            glyphs.iter().rposition(|glyph| !glyph.chr.is_whitespace()),
+        ) else {
+            continue;
         };
-        Some(source)
+        let words: Vec<usize> = (first + 1..=last)
+            .filter(|&j| glyphs[j - 1].chr.is_whitespace() && !glyphs[j].chr.is_whitespace())
+            .collect();
+
+        // the space the row ends with has to fit as well, or it's wrapped before its last word.
+        // in whole pixels only, so nothing is rounded past the end of the row:
+        let leftover = max_width - glyphs[glyphs.len() - 1].max_x();
+        let pixels_per_point = fonts.pixels_per_point();
+        let mut moved = 0.0;
+        for (k, &j) in words.iter().enumerate() {
+            let total = (k + 1) as f32 * leftover / words.len() as f32;
+            let total = (total * pixels_per_point).floor() / pixels_per_point;
+            extra_space.push((offsets[j], total - moved));
+            moved = total;
+        }
+    }
+    if extra_space.is_empty() {
+        return (galley, origins);
+    }
+
+    let mut sections = Vec::with_capacity(job.sections.len() + extra_space.len());
+    let mut section_origins = Vec::with_capacity(sections.capacity());
+    let mut extra_space = extra_space.into_iter().peekable();
+    for (i, section) in job.sections.iter().enumerate() {
+        let (mut start, mut leading_space) = (section.byte_range.start, section.leading_space);
+        while let Some((offset, space)) =
+            extra_space.next_if(|&(offset, _)| offset < section.byte_range.end)
+        {
+            if offset > start {
+                sections.push(egui::text::LayoutSection {
+                    leading_space,
+                    byte_range: start..offset,
+                    format: section.format.clone(),
+                });
+                section_origins.push(i as u32);
+                (start, leading_space) = (offset, 0.0);
+            }
+            leading_space += space;
+        }
+        sections.push(egui::text::LayoutSection {
+            leading_space,
+            byte_range: start..section.byte_range.end,
+            format: section.format.clone(),
+        });
+        section_origins.push(i as u32);
+    }
+
+    let justified = fonts.layout_job(egui::text::LayoutJob { sections, ..job });
+    if justified.rows.len() != galley.rows.len() {
+        // a row still ended up too wide, which would break all the rows after it differently:
+        return (galley, origins);
     }
+    (justified, section_origins)
 }
 
 #[inline]
-fn empty_response(ctx: egui::Context) -> egui::Response {
+fn empty_response(ui: &egui::Ui, id: egui::Id) -> egui::Response {
     egui::Response {
-        ctx,
-        layer_id: egui::LayerId::background(),
-        id: egui::Id::NULL,
-        rect: egui::Rect::ZERO,
-        interact_rect: egui::Rect::ZERO,
+        ctx: ui.ctx().clone(),
+        layer_id: ui.layer_id(),
+        id,
+        // the union with any other rect is that rect, so merged responses only cover what was shown:
+        rect: egui::Rect::NOTHING,
+        interact_rect: egui::Rect::NOTHING,
         sense: Sense::click(),
         enabled: false,
         contains_pointer: false,
@@ -203,12 +961,234 @@ fn empty_response(ctx: egui::Context) -> egui::Response {
 impl EmojiLabel {
     /// Create a new [`EmojiLabel`] from a [`RichText`].
     pub fn new(text: impl Into<RichText>) -> Self {
+        Self::with_provider(text)
+    }
+
+    /// Create a new [`EmojiLabel`] from a [`WidgetText`](egui::WidgetText).
+    ///
+    /// [`RichText`]s are used as-is. A [`LayoutJob`](egui::text::LayoutJob) (or the job of a
+    /// [`Galley`](egui::Galley)) only keeps its text and the format of its first section,
+    /// since the text is split into several labels that each have a single style.
+    pub fn from_widget_text(text: impl Into<egui::WidgetText>) -> Self {
+        let text = match text.into() {
+            egui::WidgetText::RichText(text) => text,
+            egui::WidgetText::LayoutJob(job) => ExposedRichText::from_layout_job(&job).into(),
+            egui::WidgetText::Galley(galley) => {
+                ExposedRichText::from_layout_job(&galley.job).into()
+            }
+        };
+        Self::new(text)
+    }
+
+    /// Create a new [`EmojiLabel`] with a stable `id`, e.g. the id of a message whose text changes.
+    ///
+    /// This is the same as `EmojiLabel::new(text).id_salt(id)`, see [`Self::id_salt`].
+    pub fn new_with_id(text: impl Into<RichText>, id: egui::Id) -> Self {
+        Self::new(text).id_salt(id)
+    }
+
+    /// Create a new [`EmojiLabel`] with the options of `config`.
+    pub fn with_config(text: impl Into<RichText>, config: EmojiLabelConfig) -> Self {
+        Self {
+            wrap_mode: config.wrap_mode,
+            selectable: config.selectable,
+            auto_inline: Some(config.auto_inline),
+            emoji_scale: Some(config.emoji_scale),
+            emoji_spacing: config.emoji_spacing,
+            ..Self::new(text)
+        }
+    }
+
+    /// Create a new [`EmojiLabel`] from several [`RichText`] spans that each keep their own styling,
+    /// e.g. to highlight search matches:
+    ///
+    /// ```rust
+    /// use egui::{Color32, RichText};
+    /// use egui_twemoji::EmojiLabel;
+    ///
+    /// fn show_label(ui: &mut egui::Ui) {
+    ///     EmojiLabel::from_spans([
+    ///         RichText::new("I 💚 "),
+    ///         RichText::new("egui 🦀").background_color(Color32::YELLOW),
+    ///     ])
+    ///     .show(ui);
+    /// }
+    /// ```
+    ///
+    /// [`Self::text`] and [`Self::rich_text`] return the text of all spans combined,
+    /// in the style of the first one.
+    pub fn from_spans(spans: impl IntoIterator<Item = RichText>) -> Self {
+        let spans: Vec<RichText> = spans.into_iter().collect();
+        let text: String = spans.iter().map(RichText::text).collect();
+        let text = match spans.first() {
+            Some(first) => ExposedRichText::new_keep_properties(text, first).into(),
+            None => RichText::new(text),
+        };
+        Self {
+            spans,
+            ..Self::new(text)
+        }
+    }
+
+    /// Create a new [`EmojiLabel`] with [`RichText::heading`] text.
+    pub fn heading(text: impl Into<String>) -> Self {
+        Self::new(RichText::new(text).heading())
+    }
+
+    /// Create a new [`EmojiLabel`] with [`RichText::strong`] text.
+    pub fn strong(text: impl Into<String>) -> Self {
+        Self::new(RichText::new(text).strong())
+    }
+
+    /// Create a new [`EmojiLabel`] with [`RichText::small`] text.
+    pub fn small(text: impl Into<String>) -> Self {
+        Self::new(RichText::new(text).small())
+    }
+
+    /// Create a new [`EmojiLabel`] with [`RichText::monospace`] text.
+    pub fn monospace(text: impl Into<String>) -> Self {
+        Self::new(RichText::new(text).monospace())
+    }
+
+    /// Create a new [`EmojiLabel`] with [`RichText::code`] text.
+    pub fn code(text: impl Into<String>) -> Self {
+        Self::new(RichText::new(text).code())
+    }
+
+    /// Evict the cached segments of all labels that haven't been shown in a while.
+    ///
+    /// This already happens automatically once per pass, when the first [`EmojiLabel`] is shown,
+    /// so you only need to call this if you want to get rid of stale entries right now
+    /// (e.g. when no labels will be shown for a while).
+    pub fn gc(ctx: &egui::Context) {
+        let pass_nr = ctx.cumulative_pass_nr();
+        SegmentCache::with(ctx, |cache| cache.evict_stale(pass_nr));
+    }
+
+    /// Limit the number of texts whose segments are cached at once.
+    ///
+    /// When the cache is full, the segments of the least recently shown label are evicted to make
+    /// room for new ones. By default, the cache is unbounded, and only states that haven't been
+    /// used in a while are evicted (see [`Self::gc`]). This gives predictable memory usage for
+    /// apps that show an unbounded number of texts, like long chat sessions.
+    pub fn set_cache_capacity(ctx: &egui::Context, capacity: usize) {
+        SegmentCache::with(ctx, |cache| {
+            cache.capacity = Some(capacity);
+            cache.evict_over_capacity();
+        });
+    }
+
+    /// Remove the cached segments of all labels showing `text`, whatever their styling, salt or
+    /// emoji provider.
+    ///
+    /// Use this to free the memory of labels that won't be shown again, e.g. the messages of a chat
+    /// that was closed. To remove everything, use [`Self::forget_all`].
+    pub fn forget(ctx: &egui::Context, text: &str) {
+        let text_id = egui::Id::new(text);
+        SegmentCache::with(ctx, |cache| {
+            cache.retain(|state| state.text_id != text_id);
+        });
+    }
+
+    /// Remove the cached segments of all labels, so they get segmented again the next time they are shown.
+    ///
+    /// Use this when something that changes how every text is segmented changed, e.g. an emoji
+    /// setting of your app.
+    pub fn forget_all(ctx: &egui::Context) {
+        SegmentCache::with(ctx, |cache| {
+            cache.states.clear();
+            cache.lru.clear();
+        });
+    }
+
+    /// Remove the cached segments of all labels, the same as [`Self::forget_all`].
+    #[deprecated(note = "use forget_all")]
+    pub fn clear_cache(ctx: &egui::Context) {
+        Self::forget_all(ctx);
+    }
+
+    /// Every text whose segments are cached, the least recently shown one first.
+    ///
+    /// This is meant for debugging tools, e.g. to see how full the cache is with
+    /// [`Self::set_cache_capacity`], and how long ago the entries were used.
+    pub fn cache_entries(ctx: &egui::Context) -> Vec<CacheEntry> {
+        SegmentCache::with(ctx, |cache| {
+            cache.index_unordered();
+            (cache.lru.values())
+                .map(|id| {
+                    let state = &cache.states[id];
+                    CacheEntry {
+                        text_id: state.text_id,
+                        segments: state.segments.len(),
+                        last_used_pass: state.last_used_pass,
+                    }
+                })
+                .collect()
+        })
+    }
+}
+
+impl<P: EmojiAssetProvider> EmojiLabel<P> {
+    /// Create a new [`EmojiLabel`] from a [`RichText`] that gets its emoji images from `P`.
+    pub fn with_provider(text: impl Into<RichText>) -> Self {
         Self {
             text: text.into(),
+            spans: Vec::new(),
             wrap_mode: None,
             sense: None,
             selectable: None,
-            auto_inline: true,
+            copyable: true,
+            auto_inline: None,
+            max_width: None,
+            stateless: false,
+            emoji_scale: None,
+            emoji_spacing: 0.0,
+            emoji_fit: EmojiFit::default(),
+            emoji_rounding: 0.0,
+            emoji_tint: egui::Color32::WHITE,
+            grayscale: false,
+            emoji_box: false,
+            link: None,
+            jumbo_scale: None,
+            max_rendered_emoji: None,
+            hover_zoom: None,
+            single_galley: false,
+            justify: false,
+            halign: None,
+            id_salt: None,
+            cache_key: None,
+            source_id: std::cell::OnceCell::new(),
+            #[cfg(feature = "shortcodes")]
+            shortcodes: false,
+            on_hover_emoji: None,
+            segment_rects: None,
+            precomputed: None,
+            provider: PhantomData,
+        }
+    }
+
+    /// Create a new [`EmojiLabel`] from text that was already segmented, see [`PrecomputedSegments`].
+    ///
+    /// The segment cache isn't used at all for these labels.
+    pub fn with_precomputed(segments: &PrecomputedSegments<P>) -> Self {
+        Self {
+            precomputed: Some(segments.state.clone()),
+            ..Self::with_provider(segments.text.clone())
+        }
+    }
+
+    /// The options of this label as an [`EmojiLabelConfig`], e.g. to save them as a preset.
+    ///
+    /// Options the label doesn't set itself are returned with their default value, not the one
+    /// of the [`EmojiLabelOptions`] of the context.
+    pub fn config(&self) -> EmojiLabelConfig {
+        let defaults = EmojiLabelConfig::default();
+        EmojiLabelConfig {
+            wrap_mode: self.wrap_mode,
+            selectable: self.selectable,
+            auto_inline: self.auto_inline.unwrap_or(defaults.auto_inline),
+            emoji_scale: self.emoji_scale.unwrap_or(defaults.emoji_scale),
+            emoji_spacing: self.emoji_spacing,
         }
     }
 
@@ -257,13 +1237,31 @@ impl EmojiLabel {
 
     /// Can the user select the text with the mouse?
     ///
-    /// Overrides [`egui::style::Interaction::selectable_labels`].
-    #[inline]
+    /// Overrides [`EmojiLabelOptions::selectable`] and [`egui::style::Interaction::selectable_labels`].
+    ///
+    /// The whole label is selected like a single [`egui::Label`], so triple-clicking selects an entire
+    /// line, emojis included.
+    ///
+    /// Emojis are not words of their own when double-clicking: egui picks the word, and its word
+    /// boundaries treat an emoji and the whitespace around it as one word. egui has no API for a
+    /// widget to set the selection of a label, so this can't be changed here.
+    #[inline]
     pub fn selectable(mut self, selectable: bool) -> Self {
         self.selectable = Some(selectable);
         self
     }
 
+    /// Can the text be selected and copied at all? On by default.
+    ///
+    /// Selection works through an invisible label with the whole text on top of the segments.
+    /// Turning this off skips that label, which saves a widget per label in read-only views with
+    /// lots of emojis and keeps it from covering the emoji images for your own hit-testing.
+    #[inline]
+    pub fn copyable(mut self, copyable: bool) -> Self {
+        self.copyable = copyable;
+        self
+    }
+
     /// Make the label respond to clicks and/or drags.
     ///
     /// By default, a label is inert and does not respond to click or drags.
@@ -280,105 +1278,3268 @@ impl EmojiLabel {
     ///
     /// In vertical layouts, the widget will create a new horizontal layout so text segments stay on the
     /// same line.
+    ///
+    /// Overrides [`EmojiLabelOptions::auto_inline`], which is `true` by default.
     #[inline]
     pub fn auto_inline(mut self, auto_inline: bool) -> Self {
-        self.auto_inline = auto_inline;
+        self.auto_inline = Some(auto_inline);
+        self
+    }
+
+    /// Set the [`RichText::line_height`] of all the text of the label, e.g. to space out the lines of a
+    /// chat bubble.
+    ///
+    /// Lines with emojis are as tall as the ones without, with the emojis centered vertically in
+    /// the taller line.
+    #[inline]
+    pub fn line_height(mut self, line_height: f32) -> Self {
+        self.text = self.text.line_height(Some(line_height));
+        for span in &mut self.spans {
+            *span = std::mem::take(span).line_height(Some(line_height));
+        }
+        self.source_id.take();
+        self
+    }
+
+    /// Set the maximum width of the label.
+    ///
+    /// With [`TextWrapMode::Wrap`], the text wraps at this width. With [`TextWrapMode::Truncate`],
+    /// everything past it is cut off and replaced by an ellipsis, including emojis.
+    ///
+    /// The label always gets its own layout when a maximum width is set, regardless of [`Self::auto_inline`].
+    #[inline]
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Don't cache the segmented text in [`egui::Memory`], and segment it again every frame instead.
+    ///
+    /// By default, the segments of every text ever shown are stored in memory, so they don't have to be
+    /// recomputed each frame. This is a good tradeoff for text that stays the same for a while, but
+    /// apps that show lots of unique strings, or text that changes every frame, will keep accumulating
+    /// entries. A stateless label trades some CPU time each frame for bounded memory usage.
+    ///
+    /// Use this for labels whose text changes all the time, like timers or progress (`"⏳ 42%"`).
+    /// Text without any emojis is shown as a plain [`egui::Label`], so those cost about the same as
+    /// a normal label.
+    #[inline]
+    pub fn stateless(mut self, stateless: bool) -> Self {
+        self.stateless = stateless;
+        self
+    }
+
+    /// The size of the emojis as a multiple of the font height. Overrides
+    /// [`EmojiLabelOptions::emoji_scale`], which is `1.0` by default.
+    ///
+    /// The scale is clamped to `0.25..=8.0`. Emojis more than twice as tall as the text make
+    /// their lines taller, and may overlap adjacent lines when they're shown inline.
+    #[inline]
+    pub fn emoji_size_scale(mut self, scale: f32) -> Self {
+        self.emoji_scale = Some(scale);
+        self
+    }
+
+    /// Add `spacing` points of space on either side of every emoji image, on top of the
+    /// [`RichText::extra_letter_spacing`] of the text.
+    #[inline]
+    pub fn emoji_spacing(mut self, spacing: f32) -> Self {
+        self.emoji_spacing = spacing;
+        self
+    }
+
+    /// How to size emoji images that aren't square, which only matters for custom
+    /// [`EmojiAssetProvider`]s.
+    #[inline]
+    pub fn emoji_fit(mut self, fit: EmojiFit) -> Self {
+        self.emoji_fit = fit;
+        self
+    }
+
+    /// Round the corners of the emoji images by `rounding` points, e.g. for custom emojis that are
+    /// square pictures. Twemojis have transparent corners, so this doesn't change how they look.
+    #[inline]
+    pub fn emoji_rounding(mut self, rounding: f32) -> Self {
+        self.emoji_rounding = rounding;
+        self
+    }
+
+    /// Multiply the colors of the emoji images by `tint`, e.g. to match a themed app. The text of
+    /// the label isn't tinted. The default [`egui::Color32::WHITE`] leaves the images unchanged.
+    #[inline]
+    pub fn emoji_tint(mut self, tint: egui::Color32) -> Self {
+        self.emoji_tint = tint;
+        self
+    }
+
+    /// Show the emoji images in grayscale, e.g. for muted conversations. The text of the label keeps
+    /// its color.
+    ///
+    /// The grayscale version of every image is made once, when it is first shown. This doesn't work
+    /// for [`egui::ImageSource::Texture`]s of custom [`EmojiAssetProvider`]s, which stay in color.
+    #[inline]
+    pub fn grayscale(mut self, grayscale: bool) -> Self {
+        self.grayscale = grayscale;
+        self
+    }
+
+    /// Paint a rounded box behind every emoji image, like chat apps do for custom emojis.
+    ///
+    /// The box sticks out of the image by [`egui::style::Spacing::button_padding`] on each side,
+    /// without taking up any more space than the emoji.
+    #[inline]
+    pub fn wrap_emoji_in_box(mut self, enabled: bool) -> Self {
+        self.emoji_box = enabled;
+        self
+    }
+
+    /// Turn the label into a hyperlink to `url`, like an [`egui::Hyperlink`].
+    ///
+    /// The whole label is clickable and opens `url` (in a new tab when middle-clicked or clicked
+    /// with a modifier held). Its text is shown in the hyperlink color, and everything is underlined
+    /// while it is hovered, the emojis included.
+    #[inline]
+    pub fn link(mut self, url: impl ToString) -> Self {
+        self.link = Some(url.to_string());
+        self
+    }
+
+    /// Scale the emojis up by `scale` if the label contains nothing but emojis (and whitespace),
+    /// like chat apps do for messages that are just a single 🎉.
+    ///
+    /// Labels that mix text and emojis are shown normally. This takes precedence over
+    /// [`Self::emoji_size_scale`].
+    #[inline]
+    pub fn jumbo_if_emoji_only(mut self, scale: f32) -> Self {
+        self.jumbo_scale = Some(scale);
+        self
+    }
+
+    /// Only draw the first `max` emojis of this label as images, and show the rest as plain text.
+    ///
+    /// Every emoji image costs a bit to draw, so this bounds the cost of pathological inputs like
+    /// spam messages that are nothing but emojis.
+    #[inline]
+    pub fn max_rendered_emoji(mut self, max: usize) -> Self {
+        self.max_rendered_emoji = Some(max);
+        self
+    }
+
+    /// Enlarge emojis by `zoom` (e.g. `1.3`) while the mouse pointer is over them, with a short animation.
+    ///
+    /// The enlarged emoji is drawn on top of everything else, so the layout doesn't move.
+    #[inline]
+    pub fn hover_zoom(mut self, zoom: f32) -> Self {
+        self.hover_zoom = Some(zoom);
+        self
+    }
+
+    /// Lay out the whole label as a single galley, and paint the emoji images over invisible
+    /// placeholders in it.
+    ///
+    /// By default, every run of text and every emoji is a widget of its own, which adds up for
+    /// labels with hundreds of emojis. With this, the label costs about as much as a normal
+    /// [`egui::Label`] plus its images, and wraps, truncates and selects exactly like one.
+    ///
+    /// The placeholders are the emoji text itself, sized to be as wide as the image, so a long
+    /// emoji sequence could be split over two lines if it is laid out as several glyphs. Such an
+    /// emoji is drawn whole on the line it starts on.
+    #[inline]
+    pub fn single_galley(mut self, single_galley: bool) -> Self {
+        self.single_galley = single_galley;
+        self
+    }
+
+    /// Widen the gaps between words so that every row of wrapped text but the last one of each
+    /// paragraph is as wide as the label, like in a justified paragraph of a book.
+    ///
+    /// The gaps next to emojis are widened too. Rows that were wrapped in the middle of a word
+    /// and text that doesn't wrap are left as they are. The rows have to be known before anything
+    /// can be placed on them, so justified labels are always laid out as one galley, see
+    /// [`Self::single_galley`].
+    #[inline]
+    pub fn justify(mut self, justify: bool) -> Self {
+        self.justify = justify;
+        self
+    }
+
+    /// Align every row of the label within the available width, like [`egui::Label::halign`].
+    ///
+    /// Wrapped rows are aligned one by one, so like [`Self::justify`], this lays the label out as
+    /// one galley. Justified rows fill the whole width anyway, except for the last row of each
+    /// paragraph, which stays at the start.
+    #[inline]
+    pub fn halign(mut self, halign: egui::Align) -> Self {
+        self.halign = Some(halign);
+        self
+    }
+
+    /// A source for the unique [`egui::Id`] of this label, e.g. `.id_salt("pinned")`.
+    ///
+    /// By default, the segments are cached by text, so all labels showing the same text share one
+    /// cache entry, and the id of the returned [`egui::Response`] depends on where the label is placed.
+    /// If you show the same text in several places and want to control which state belongs to which
+    /// label, give each one its own salt. It is mixed into both the cache key and the response id.
+    ///
+    /// Changing the text of a salted label re-segments it and replaces its old segments in the cache,
+    /// so this is what to use for text that changes while the label stays the same, like a live
+    /// message. [`EmojiLabel::new_with_id`] is a shorthand for it.
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(egui::Id::new(id_salt));
+        self
+    }
+
+    /// Identify the text of this label by `key` instead of hashing it every frame.
+    ///
+    /// Hashing is cheap for short labels, but adds up for long messages that are shown every frame.
+    /// If you already have a key for the text, like a message id or a hash you computed once, pass
+    /// it here. It must change whenever the text or its styling changes: labels with the same key
+    /// share their segments, whatever their text is.
+    #[inline]
+    pub fn with_cache_key(mut self, key: u64) -> Self {
+        self.cache_key = Some(key);
+        self.source_id.take();
+        self
+    }
+
+    /// Replace `:shortcode:`s in the text with their emoji before segmenting it,
+    /// see [`shortcode::expand_shortcodes`].
+    #[cfg(feature = "shortcodes")]
+    #[inline]
+    pub fn shortcodes(mut self, enabled: bool) -> Self {
+        self.shortcodes = enabled;
+        self.source_id.take();
+        self
+    }
+
+    /// Call `f` with the emoji under the mouse pointer, every frame one is hovered.
+    ///
+    /// This is useful for showing e.g. the name of the emoji in a tooltip. Emojis are hit-tested
+    /// on their own, so this works for inert labels too, whatever their [`Self::sense`] is.
+    #[inline]
+    pub fn on_hover_emoji(mut self, f: impl FnMut(&str) + 'static) -> Self {
+        self.on_hover_emoji = Some(Box::new(f));
         self
     }
 
-    fn show_segments(&self, ui: &mut egui::Ui, state: &mut LabelState) -> egui::Response {
-        let mut resp = empty_response(ui.ctx().clone());
-        let font_height = ui.text_style_height(&egui::TextStyle::Body);
+    /// The key of the segments of this label in the [`SegmentCache`].
+    ///
+    /// The segments only depend on the text (and the emoji provider), so they are cached by content
+    /// and shared between all labels showing the same text, unless they were explicitly given a salt.
+    /// A salted label keeps its key when its text changes, see [`Self::source_id`].
+    fn cache_id(&self) -> egui::Id {
+        match self.id_salt {
+            Some(id_salt) => id_salt.with(provider_key::<P>()),
+            None => self.source_id(),
+        }
+    }
+
+    /// A hash of everything the segments of this label are made from.
+    ///
+    /// This is a 64-bit hash, so two different texts could in theory end up with the same segments,
+    /// but the chance of that is astronomically small. If it's a concern anyway, labels can be told
+    /// apart with [`Self::id_salt`] or [`Self::with_cache_key`].
+    fn source_id(&self) -> egui::Id {
+        *self.source_id.get_or_init(|| self.hash_source())
+    }
+
+    fn hash_source(&self) -> egui::Id {
+        let provider = provider_key::<P>();
+        #[allow(unused_mut)]
+        let mut id = match self.cache_key {
+            Some(key) => egui::Id::new((provider, key)),
+            None => {
+                // the styling is baked into the segments, so it has to be part of the key too:
+                let spans: Vec<&ExposedRichText> =
+                    self.spans().iter().map(ExposedRichText::from_ref).collect();
+                egui::Id::new((provider, spans))
+            }
+        };
+        #[cfg(feature = "shortcodes")]
+        if self.shortcodes {
+            id = id.with("shortcodes");
+        }
+        id
+    }
+
+    /// Segment the text of this label into a new [`LabelState`].
+    fn segment(&self) -> LabelState {
+        let segments = self.spans().iter().flat_map(|span| {
+            #[cfg(feature = "shortcodes")]
+            if self.shortcodes {
+                let expanded = shortcode::expand_shortcodes(span.text());
+                let span = ExposedRichText::new_keep_properties(expanded, span).into();
+                return segment_text::<P>(&span);
+            }
+            segment_text::<P>(span)
+        });
+        LabelState::new(segments.collect(), self.text())
+    }
+
+    /// The segments the text of this label is made of, in order.
+    ///
+    /// These are the same segments [`Self::show`] uses, so they come from the cache if the label
+    /// was shown before (unless it is stateless), and are cached for the next time otherwise.
+    pub fn segments(&self, ctx: &egui::Context) -> Arc<[TextSegment]> {
+        self.load_state(ctx, self.cache_id()).segments
+    }
+
+    /// Get the segments of this label: precomputed, freshly made if it is stateless, or from the cache.
+    fn load_state(&self, ctx: &egui::Context, cache_id: egui::Id) -> LabelState {
+        if let Some(state) = &self.precomputed {
+            state.clone()
+        } else if self.stateless {
+            self.segment()
+        } else {
+            LabelState::load(ctx, cache_id, self.source_id(), || self.segment())
+        }
+    }
+
+    /// The spans of this label, each with their own styling.
+    fn spans(&self) -> &[RichText] {
+        match self.spans.is_empty() {
+            true => std::slice::from_ref(&self.text),
+            false => &self.spans,
+        }
+    }
+
+    /// Turn the emojis past [`Self::max_rendered_emoji`] back into text.
+    fn limit_emojis<'a>(&self, segments: &'a [TextSegment]) -> Cow<'a, [TextSegment]> {
+        let Some(max) = self.max_rendered_emoji else {
+            return Cow::Borrowed(segments);
+        };
+        let mut emojis = segments
+            .iter()
+            .enumerate()
+            .filter(|(_, segment)| matches!(segment, TextSegment::Emoji(..)));
+        let Some((first_as_text, _)) = emojis.nth(max) else {
+            return Cow::Borrowed(segments);
+        };
+
+        let mut limited = segments.to_vec();
+        for segment in &mut limited[first_as_text..] {
+            if let TextSegment::Emoji(emoji, style) = segment {
+                *segment = TextSegment::Text(emoji_as_text(emoji, style));
+            }
+        }
+        Cow::Owned(limited)
+    }
+
+    /// How much bigger than their text the emoji images are when showing `segments`.
+    fn emoji_scale(&self, options: &EmojiLabelOptions, segments: &[TextSegment]) -> f32 {
+        let is_emoji_only = || {
+            segments.iter().all(|segment| match segment {
+                TextSegment::Text(text) => text.text().trim().is_empty(),
+                TextSegment::Emoji(..) | TextSegment::Newline => true,
+            })
+        };
+        match self.jumbo_scale {
+            Some(scale) if is_emoji_only() => scale,
+            _ => options.emoji_scale.clamp(0.25, 8.0),
+        }
+    }
+
+    /// The [`EmojiLabelOptions`] of `ctx`, with the ones this label sets itself in place.
+    fn options(&self, ctx: &egui::Context) -> EmojiLabelOptions {
+        let defaults = EmojiLabelOptions::load(ctx);
+        EmojiLabelOptions {
+            auto_inline: self.auto_inline.unwrap_or(defaults.auto_inline),
+            emoji_scale: self.emoji_scale.unwrap_or(defaults.emoji_scale),
+            selectable: self.selectable.or(defaults.selectable),
+        }
+    }
+
+    /// Whether the text of this label can be selected with the mouse, see [`Self::selectable`].
+    fn is_selectable(&self, ui: &egui::Ui, options: &EmojiLabelOptions) -> bool {
+        (options.selectable).unwrap_or_else(|| ui.style().interaction.selectable_labels)
+    }
+
+    /// The height of the image of an emoji in the text `style`, scaled by `scale`.
+    fn emoji_size(ui: &egui::Ui, style: &RichText, scale: f32) -> f32 {
+        ui.fonts(|fonts| style.font_height(fonts, ui.style())) * scale
+    }
+
+    /// The height of the space an emoji image of `emoji_size` takes up in the text `style`, and
+    /// where its image goes from the top of that space.
+    ///
+    /// The space is as tall as a line of the text, so it's aligned like the text around it in any
+    /// layout, and the image is centered in it.
+    fn emoji_slot(ui: &egui::Ui, style: &RichText, emoji_size: f32) -> (f32, f32) {
+        let font_height = Self::emoji_size(ui, style, 1.0);
+        let line_height = (ExposedRichText::from_ref(style).line_height).unwrap_or(font_height);
+        let slot_height = line_height.max(emoji_size);
+        (slot_height, (slot_height - emoji_size) / 2.0)
+    }
+
+    /// The space on either side of an emoji image in the text `style`, so emojis are spaced out
+    /// like the letters around them, plus [`Self::emoji_spacing`].
+    fn emoji_padding(&self, style: &RichText) -> f32 {
+        ExposedRichText::from_ref(style).extra_letter_spacing + self.emoji_spacing
+    }
+
+    /// The width of the image for an emoji that is `height` tall.
+    fn emoji_width(&self, ctx: &egui::Context, source: egui::ImageSource<'_>, height: f32) -> f32 {
+        if self.emoji_fit == EmojiFit::ExactSquare {
+            return height;
+        }
+        match egui::Image::new(source).load_for_size(ctx, egui::vec2(height, height)) {
+            Ok(egui::load::TexturePoll::Ready { texture }) if texture.size.y > 0.0 => {
+                height * texture.size.x / texture.size.y
+            }
+            _ => height,
+        }
+    }
+
+    /// Paint the image of `emoji` at `image_rect`, enlarged while it is hovered, and add it to `info`.
+    fn paint_emoji(
+        &mut self,
+        ui: &egui::Ui,
+        id: egui::Id,
+        emoji: &str,
+        source: egui::ImageSource<'_>,
+        image_rect: egui::Rect,
+        info: &mut ShowInfo,
+    ) {
+        // the selected text is on top of the image, so `hovered()` of its response is never set:
+        let hovered = ui.rect_contains_pointer(image_rect);
+        // disabled widgets don't react to the pointer:
+        let zoom = self.hover_zoom.filter(|_| ui.is_enabled());
+        let zoom = zoom.map_or(1.0, |zoom| {
+            let zoom_id = id.with(("hover_zoom", info.emoji_rects.len()));
+            let t = ui.ctx().animate_bool(zoom_id, hovered);
+            1.0 + (zoom - 1.0) * t
+        });
+        if self.emoji_box {
+            let box_rect = image_rect.expand(ui.spacing().button_padding.x);
+            if ui.is_rect_visible(box_rect) {
+                let fill = ui
+                    .visuals()
+                    .widgets
+                    .noninteractive
+                    .bg_fill
+                    .gamma_multiply(0.5);
+                ui.painter().rect_filled(box_rect, 4.0, fill);
+            }
+        }
+        if ui.is_rect_visible(image_rect) {
+            let (source, texture_size) =
+                sized_source(source, image_rect.size(), ui.ctx().pixels_per_point());
+            let gray = (self.grayscale)
+                .then(|| gray::gray_source::<P>(ui.ctx(), emoji, &source, texture_size))
+                .flatten();
+            let source = gray.unwrap_or(source);
+            let image =
+                (egui::Image::new(source).fit_to_exact_size(texture_size)).tint(self.emoji_tint);
+            warn_if_no_loaders(ui.ctx(), &image, image_rect.size());
+            let zoomed =
+                egui::Rect::from_center_size(image_rect.center(), image_rect.size() * zoom);
+            let zoomed_image = image.clone().rounding(self.emoji_rounding * zoom);
+            if zoom == 1.0 || !paint_zoomed(ui, id, &zoomed_image, zoomed) {
+                let image = image.rounding(self.emoji_rounding);
+                if ui.is_enabled() || !paint_disabled(ui, &image, image_rect) {
+                    image.paint_at(ui, image_rect);
+                }
+            }
+        }
+        #[cfg(feature = "unicode-names")]
+        if hovered {
+            let tooltip_id = id.with(("emoji_name", info.emoji_rects.len()));
+            egui::show_tooltip_text(
+                ui.ctx(),
+                ui.layer_id(),
+                tooltip_id,
+                names::hover_text(emoji),
+            );
+        }
+        info.emoji_rects.push((emoji.to_string(), image_rect));
+        if hovered {
+            if let Some(on_hover_emoji) = &mut self.on_hover_emoji {
+                on_hover_emoji(emoji);
+            }
+        }
+    }
+
+    /// The text of `segments` as a single job, with an invisible placeholder for every emoji image.
+    fn layout_job(
+        &self,
+        ui: &egui::Ui,
+        options: &EmojiLabelOptions,
+        segments: &[TextSegment],
+    ) -> (egui::text::LayoutJob, Vec<EmojiPlaceholder>) {
+        let emoji_scale = self.emoji_scale(options, segments);
+        let to_job = |text: RichText| {
+            egui::WidgetText::from(text).into_layout_job(
+                ui.style(),
+                egui::FontSelection::Default,
+                ui.text_valign(),
+            )
+        };
+        let mut job = egui::text::LayoutJob::default();
+        let mut placeholders = Vec::new();
+
+        for segment in segments {
+            let (emoji, style, source) = match segment {
+                TextSegment::Emoji(emoji, style) => match P::lookup(emoji) {
+                    Some(source) => (emoji, style, source),
+                    None => {
+                        append_job(&mut job, to_job(emoji_as_text(emoji, style)));
+                        continue;
+                    }
+                },
+                TextSegment::Text(text) => {
+                    append_job(&mut job, to_job(text.clone()));
+                    continue;
+                }
+                TextSegment::Newline => {
+                    let newline = ExposedRichText::new_keep_properties("\n", &self.text).into();
+                    append_job(&mut job, to_job(newline));
+                    continue;
+                }
+            };
+
+            let emoji_size = Self::emoji_size(ui, style, emoji_scale);
+            let emoji_width = self.emoji_width(ui.ctx(), source.clone(), emoji_size);
+            let (slot_height, image_top) = Self::emoji_slot(ui, style, emoji_size);
+            let padding = self.emoji_padding(style);
+
+            // the emoji text itself, so it's selected and copied like the rest, scaled to be as
+            // wide as the image and its padding:
+            let mut placeholder = to_job(emoji_as_text(emoji, style));
+            placeholder.sections[0].format.extra_letter_spacing = 0.0;
+            let text_width = ui
+                .fonts(|fonts| fonts.layout_job(placeholder.clone()))
+                .size()
+                .x;
+            let format = placeholder.sections[0].format.clone();
+            for section in &mut placeholder.sections {
+                section.format.color = egui::Color32::TRANSPARENT;
+                section.format.line_height = Some(slot_height);
+                if text_width > 0.0 {
+                    section.format.font_id.size *= (emoji_width + 2.0 * padding) / text_width;
+                }
+            }
+
+            placeholders.push(EmojiPlaceholder {
+                section: job.sections.len() as u32,
+                emoji: emoji.clone(),
+                source,
+                glyphs: emoji.chars().count(),
+                image_size: egui::vec2(emoji_width, emoji_size),
+                slot_height,
+                image_offset: egui::vec2(padding, image_top),
+                format,
+            });
+            append_job(&mut job, placeholder);
+        }
+        (job, placeholders)
+    }
+
+    /// Show `segments` as one galley with the emoji images painted over it, see [`Self::single_galley`].
+    fn show_galley(
+        &mut self,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        segments: &[TextSegment],
+        wrap_mode: TextWrapMode,
+        options: &EmojiLabelOptions,
+        info: &mut ShowInfo,
+    ) -> egui::Response {
+        let (mut job, mut placeholders) = self.layout_job(ui, options, segments);
+        // the segment of every section of the galley, which are the same unless it is justified:
+        let (text, section_segments): (egui::WidgetText, Vec<u32>) =
+            if self.justify && wrap_mode == TextWrapMode::Wrap {
+                job.wrap = egui::text::TextWrapping::wrap_at_width(ui.available_width());
+                let (galley, origins) = ui.fonts(|fonts| justify_job(fonts, job));
+                for placeholder in &mut placeholders {
+                    // emojis contain no spaces, so they are never split up:
+                    placeholder.section =
+                        origins.partition_point(|&origin| origin < placeholder.section) as u32;
+                }
+                (galley.into(), origins)
+            } else {
+                let sections = (0..job.sections.len() as u32).collect();
+                (job.into(), sections)
+            };
+        let selectable = self.copyable && self.is_selectable(ui, options);
+        let label = egui::Label::new(text)
+            .wrap_mode(wrap_mode)
+            .selectable(selectable);
+        let (galley_pos, galley, mut label_resp) = label.layout_in_ui(ui);
+        label_resp.widget_info(|| {
+            egui::WidgetInfo::labeled(egui::WidgetType::Label, ui.is_enabled(), galley.text())
+        });
+        info.truncated = galley.elided;
+
+        // the text is painted the same way `egui::Label` does it:
+        let text_color = ui.visuals().text_color();
+        let is_visible = ui.is_rect_visible(label_resp.rect);
+        if is_visible {
+            if galley.elided {
+                label_resp = label_resp.on_hover_text(galley.text());
+            }
+            if selectable {
+                egui::text_selection::LabelSelectionState::label_text_selection(
+                    ui,
+                    &label_resp,
+                    galley_pos,
+                    galley.clone(),
+                    text_color,
+                    egui::Stroke::NONE,
+                );
+            } else {
+                let text = egui::epaint::TextShape::new(galley_pos, galley.clone(), text_color);
+                ui.painter().add(text);
+            }
+        }
+
+        let placeholder = |glyph: &egui::epaint::text::Glyph| {
+            placeholders
+                .binary_search_by_key(&glyph.section_index, |placeholder| placeholder.section)
+                .ok()
+                .map(|i| &placeholders[i])
+        };
+        let slot_top = |row: &egui::epaint::text::Row, placeholder: &EmojiPlaceholder| {
+            let valign = placeholder.format.valign.to_factor();
+            row.rect.min.y + valign * (row.rect.height() - placeholder.slot_height)
+        };
+        // the section of the last emoji that was painted, as it may continue on the next row:
+        let mut painted_section = None;
+        for (i, row) in galley.rows.iter().enumerate() {
+            let mut glyphs = row.glyphs.as_slice();
+            let is_cut_off = galley.elided && i == galley.rows.len() - 1;
+            if is_cut_off {
+                let Some((ellipsis, rest)) = glyphs.split_last() else {
+                    continue;
+                };
+                glyphs = rest;
+                // the ellipsis gets the format of the glyph before it, which may be invisible:
+                if let Some(placeholder) = placeholder(ellipsis).filter(|_| is_visible) {
+                    let color = match placeholder.format.color {
+                        egui::Color32::PLACEHOLDER => text_color,
+                        color => color,
+                    };
+                    let pos = egui::pos2(ellipsis.pos.x, slot_top(row, placeholder));
+                    ui.painter().text(
+                        galley_pos + pos.to_vec2(),
+                        egui::Align2::LEFT_TOP,
+                        "…",
+                        placeholder.format.font_id.clone(),
+                        color,
+                    );
+                }
+            }
+
+            for run in glyphs.chunk_by(|a, b| a.section_index == b.section_index) {
+                let Some(placeholder) = placeholder(&run[0]) else {
+                    continue;
+                };
+                if painted_section == Some(placeholder.section) {
+                    // the rest of an emoji that was split over two rows, which is painted where
+                    // it starts:
+                    continue;
+                }
+                if is_cut_off && run.len() != placeholder.glyphs {
+                    continue;
+                }
+                painted_section = Some(placeholder.section);
+                let slot_min = galley_pos + egui::vec2(run[0].pos.x, slot_top(row, placeholder));
+                let image_rect = egui::Rect::from_min_size(
+                    slot_min + placeholder.image_offset,
+                    placeholder.image_size,
+                );
+                let source = placeholder.source.clone();
+                self.paint_emoji(ui, id, &placeholder.emoji, source, image_rect, info);
+            }
+        }
+
+        if let Some(segment_rects) = &mut self.segment_rects {
+            // every segment is one section of the job:
+            let mut rects = vec![egui::Rect::NOTHING; segments.len()];
+            for row in &galley.rows {
+                for glyph in &row.glyphs {
+                    let glyph_rect = egui::Rect::from_x_y_ranges(
+                        glyph.pos.x..=glyph.max_x(),
+                        row.rect.y_range(),
+                    );
+                    let segment = section_segments[glyph.section_index as usize];
+                    if let Some(rect) = rects.get_mut(segment as usize) {
+                        *rect = rect.union(glyph_rect.translate(galley_pos.to_vec2()));
+                    }
+                }
+            }
+            // line breaks and cut off segments don't have any glyphs:
+            let shown = rects
+                .into_iter()
+                .zip(segments)
+                .filter(|(rect, _)| rect.is_positive());
+            segment_rects.extend(shown.map(|(rect, segment)| (rect, segment.clone())));
+        }
+
+        let mut resp = empty_response(ui, id);
+        resp |= label_resp;
+        resp
+    }
+
+    fn show_segments(
+        &mut self,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        segments: &[TextSegment],
+        wrap_mode: TextWrapMode,
+        options: &EmojiLabelOptions,
+        info: &mut ShowInfo,
+    ) -> egui::Response {
+        let mut resp = empty_response(ui, id);
+        let emoji_scale = self.emoji_scale(options, segments);
+        let truncate = wrap_mode == TextWrapMode::Truncate;
+        // everything the segments cover, for the selection overlay:
+        let mut content_rect = egui::Rect::NOTHING;
+        // segments are put right next to each other, but `ui` may belong to the caller:
+        let item_spacing = ui.spacing().item_spacing;
+        ui.spacing_mut().item_spacing.x = 0.0;
+
+        for segment in segments {
+            // the overlay below takes care of selection:
+            let segment_rects = &mut self.segment_rects;
+            let mut add_text = |ui: &mut egui::Ui, text: &RichText| {
+                let label = egui::Label::new(text.clone())
+                    .wrap_mode(wrap_mode)
+                    .selectable(false);
+                let is_cut_off = truncate && is_elided(ui, text, ui.available_width());
+                let label_resp = ui.add(label);
+                content_rect = content_rect.union(label_resp.rect);
+                if let Some(segment_rects) = segment_rects {
+                    segment_rects.push((label_resp.rect, segment.clone()));
+                }
+                resp |= label_resp;
+                is_cut_off
+            };
 
-        for segment in &state.segments {
-            ui.spacing_mut().item_spacing.x = 0.0;
             match segment {
                 TextSegment::Text(text) => {
-                    let mut label = egui::Label::new(text.clone());
-                    if let Some(wrap_mode) = self.wrap_mode {
-                        label = label.wrap_mode(wrap_mode);
+                    if add_text(ui, text) {
+                        // the label was elided, so nothing after it is visible anyway:
+                        info.truncated = true;
+                        break;
                     }
-                    resp |= ui.add(label);
                 }
-                TextSegment::Emoji(emoji) => {
-                    let Some(source) = get_source_for_emoji(emoji) else {
+                TextSegment::Emoji(emoji, style) => {
+                    let Some(source) = P::lookup(emoji) else {
+                        // show emojis without an image as text, instead of dropping them:
+                        if add_text(ui, &emoji_as_text(emoji, style)) {
+                            info.truncated = true;
+                            break;
+                        }
                         continue;
                     };
 
-                    let image_rect = ui
-                        .add(
-                            egui::Image::new(source)
-                                .fit_to_exact_size(egui::vec2(font_height, font_height)),
-                        )
-                        .rect;
-
-                    // for emoji selection and copying:
-                    resp |= ui.put(
-                        image_rect,
-                        egui::Label::new(RichText::new(emoji).color(egui::Color32::TRANSPARENT)),
+                    let emoji_size = Self::emoji_size(ui, style, emoji_scale);
+                    let emoji_width = self.emoji_width(ui.ctx(), source.clone(), emoji_size);
+                    let padding = self.emoji_padding(style);
+                    let slot_width = emoji_width + 2.0 * padding;
+                    if truncate && slot_width > ui.available_width() {
+                        let ellipsis: RichText =
+                            ExposedRichText::new_keep_properties("…", style).into();
+                        let ellipsis_resp =
+                            ui.add(egui::Label::new(ellipsis).extend().selectable(false));
+                        content_rect = content_rect.union(ellipsis_resp.rect);
+                        resp |= ellipsis_resp;
+                        info.truncated = true;
+                        break;
+                    }
+
+                    let (slot_height, image_top) = Self::emoji_slot(ui, style, emoji_size);
+                    let style = ExposedRichText::from_ref(style);
+                    // in wrapping rows, egui makes labels as tall as the row so far and aligns
+                    // their glyphs within it (raised ones at the top), so do that for emojis too:
+                    let layout = ui.layout();
+                    let row_height = match wrap_mode == TextWrapMode::Wrap
+                        && layout.main_dir() == egui::Direction::LeftToRight
+                        && layout.main_wrap()
+                    {
+                        true => ui.cursor().height().max(slot_height),
+                        false => slot_height,
+                    };
+                    let valign = match style.raised {
+                        true => egui::Align::TOP,
+                        false => ui.text_valign(),
+                    };
+                    let (row_rect, image_resp) =
+                        ui.allocate_exact_size(egui::vec2(slot_width, row_height), Sense::hover());
+                    let slot_rect = egui::Rect::from_min_size(
+                        row_rect.min
+                            + egui::vec2(0.0, valign.to_factor() * (row_height - slot_height)),
+                        egui::vec2(slot_width, slot_height),
                     );
+                    let image_rect = egui::Rect::from_min_size(
+                        slot_rect.min + egui::vec2(padding, image_top),
+                        egui::vec2(emoji_width, emoji_size),
+                    );
+                    if ui.is_rect_visible(slot_rect) {
+                        paint_background(ui, slot_rect, style);
+                    }
+                    self.paint_emoji(ui, id, emoji, source, image_rect, info);
+                    if ui.is_rect_visible(slot_rect) {
+                        // across the padding too, so the lines continue into the text around it:
+                        paint_decorations(ui, slot_rect, style);
+                    }
+                    content_rect = content_rect.union(slot_rect);
+                    if let Some(segment_rects) = &mut self.segment_rects {
+                        segment_rects.push((slot_rect, segment.clone()));
+                    }
+                    resp |= image_resp;
                 }
+                TextSegment::Newline => {
+                    // only used by inline labels in wrapping layouts, otherwise `show` puts
+                    // every line into its own row:
+                    ui.end_row();
+                }
+            }
+        }
+        ui.spacing_mut().item_spacing = item_spacing;
+
+        // a single invisible label with the whole text on top of everything, so selecting and
+        // copying works like it does for one label, and emoji sequences are copied in one piece.
+        // its emojis are as wide as their images, so the selection lines up with what's shown.
+        // it's put into a child ui so it doesn't move the cursor of the (possibly wrapping) layout.
+        if !content_rect.is_positive() {
+            content_rect = egui::Rect::from_min_size(ui.cursor().min, egui::Vec2::ZERO);
+        } else if self.copyable && !self.is_selectable(ui, options) {
+            // nothing to select, so only sense hovers like a label that isn't selectable, without
+            // the text cursor:
+            resp |= ui.interact(content_rect, id.with("hover"), Sense::hover());
+        } else if self.copyable {
+            let (mut job, placeholders) = self.layout_job(ui, options, segments);
+            for section in &mut job.sections {
+                // the segments already painted all of this:
+                section.format.color = egui::Color32::TRANSPARENT;
+                section.format.background = egui::Color32::TRANSPARENT;
+                section.format.underline = egui::Stroke::NONE;
+                section.format.strikethrough = egui::Stroke::NONE;
             }
+            let overlay = egui::Label::new(job).wrap_mode(wrap_mode).selectable(true);
+            // glyph widths are rounded to whole pixels, so every emoji may be a bit wider than its
+            // image. don't let that wrap the last word of a row:
+            let slack = placeholders.len() as f32;
+            let overlay_rect = content_rect.with_max_x(content_rect.max.x + slack);
+            resp |= ui
+                .new_child(egui::UiBuilder::new().max_rect(overlay_rect))
+                .add(overlay);
         }
+        resp.rect = content_rect;
+        resp.interact_rect = content_rect;
         resp
     }
 
-    /// Add the label to an [`egui::Ui`].
-    pub fn show(self, ui: &mut egui::Ui) -> egui::Response {
-        let id = egui::Id::new(self.text());
-        let mut state = LabelState::load(ui.ctx(), id, &self.text);
+    /// Compute the size this label will take up when shown in `ui`, without adding anything to it.
+    ///
+    /// This respects the wrap mode, [`Self::max_width`] and the available width of `ui`, and assumes
+    /// that the label starts at the beginning of a row.
+    pub fn measure(&self, ui: &egui::Ui) -> egui::Vec2 {
+        self.measure_with(ui, &self.options(ui.ctx()))
+    }
 
-        // if the state was newly created, write it back to memory:
-        if !state.is_saved {
-            state.is_saved = true;
-            state.clone().save(ui.ctx(), id);
-        }
+    /// [`Self::measure`] with the [`EmojiLabelOptions`] that were already loaded.
+    fn measure_with(&self, ui: &egui::Ui, options: &EmojiLabelOptions) -> egui::Vec2 {
+        let state = self.load_state(ui.ctx(), self.cache_id());
+        let segments = self.limit_emojis(&state.segments);
 
-        if ui.layout().is_horizontal() && self.auto_inline {
-            self.show_segments(ui, &mut state)
-        } else {
-            ui.with_layout(Layout::left_to_right(egui::Align::Min), |ui| {
-                self.show_segments(ui, &mut state)
-            })
-            .inner
+        let wrap_mode = self.wrap_mode.unwrap_or_else(|| ui.wrap_mode());
+        let available_width = match self.max_width {
+            Some(max_width) => max_width.min(ui.available_width()),
+            None => ui.available_width(),
+        };
+        let justify = self.justify && wrap_mode == TextWrapMode::Wrap;
+        if justify {
+            let (mut job, _) = self.layout_job(ui, options, &segments);
+            job.wrap = egui::text::TextWrapping::wrap_at_width(available_width);
+            return ui.fonts(|fonts| justify_job(fonts, job)).0.size();
+        }
+        if self.single_galley || self.halign.is_some() {
+            let (mut job, _) = self.layout_job(ui, options, &segments);
+            job.wrap = match wrap_mode {
+                TextWrapMode::Extend => egui::text::TextWrapping::no_max_width(),
+                TextWrapMode::Wrap => egui::text::TextWrapping::wrap_at_width(available_width),
+                TextWrapMode::Truncate => {
+                    egui::text::TextWrapping::truncate_at_width(available_width)
+                }
+            };
+            // aligned rows don't count the spaces they start or end with:
+            job.halign = self.halign.unwrap_or(job.halign);
+            return ui.fonts(|fonts| fonts.layout_job(job)).size();
         }
-    }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let emoji_scale = self.emoji_scale(options, &segments);
+        let row_spacing = ui.spacing().item_spacing.y;
 
-    #[test]
-    fn emoji_segmentation() {
-        let text = "Hello😤world";
-        let segments = segment_text(&RichText::new(text));
-        assert!(
-            segments
-                == vec![
-                    TextSegment::Text("Hello".into()),
-                    TextSegment::Emoji("😤".to_owned()),
-                    TextSegment::Text("world".into())
-                ]
-        );
-        let text = "😅 2,*:привет|3 🤬";
-        let segments = segment_text(&RichText::new(text));
+        // the width of the widest row, and the cursor, top and height of the current row:
+        let mut width = 0.0_f32;
+        let (mut x, mut row_top, mut row_height) = (0.0_f32, 0.0_f32, 0.0_f32);
+        // whether the rest of the current line was truncated:
+        let mut is_cut_off = false;
+
+        for segment in segments.iter() {
+            if is_cut_off && *segment != TextSegment::Newline {
+                continue;
+            }
+
+            // emojis without an image are shown as text:
+            let fallback;
+            let segment = match segment {
+                TextSegment::Emoji(emoji, style) if P::lookup(emoji).is_none() => {
+                    fallback = TextSegment::Text(emoji_as_text(emoji, style));
+                    &fallback
+                }
+                segment => segment,
+            };
+
+            match segment {
+                TextSegment::Text(text) if wrap_mode == TextWrapMode::Wrap => {
+                    // lay out the text like `egui::Label` does in a wrapping horizontal layout:
+                    // starting after the previous segment and continuing on the rows below
+                    let mut job = egui::WidgetText::from(text.clone()).into_layout_job(
+                        ui.style(),
+                        egui::FontSelection::Default,
+                        egui::Align::Min,
+                    );
+                    job.wrap.max_width = available_width;
+                    job.first_row_min_height = row_height;
+                    if let Some(section) = job.sections.first_mut() {
+                        section.leading_space = x;
+                    }
+                    let galley = ui.fonts(|fonts| fonts.layout_job(job));
+
+                    let galley_top = row_top;
+                    for (i, row) in galley.rows.iter().enumerate() {
+                        if i > 0 {
+                            width = width.max(x);
+                            row_top = galley_top + row.rect.min.y;
+                            row_height = 0.0;
+                        }
+                        x = row.rect.max.x;
+                        row_height = row_height.max(row.rect.height());
+                    }
+                }
+                TextSegment::Text(text) => {
+                    let galley = egui::WidgetText::from(text.clone()).into_galley(
+                        ui,
+                        Some(wrap_mode),
+                        available_width - x,
+                        egui::FontSelection::Default,
+                    );
+                    x += galley.size().x;
+                    row_height = row_height.max(galley.size().y);
+                    is_cut_off = galley.elided;
+                }
+                TextSegment::Emoji(emoji, style) => {
+                    let emoji_size = Self::emoji_size(ui, style, emoji_scale);
+                    let emoji_width = P::lookup(emoji).map_or(emoji_size, |source| {
+                        self.emoji_width(ui.ctx(), source, emoji_size)
+                    }) + 2.0 * self.emoji_padding(style);
+                    if wrap_mode == TextWrapMode::Truncate && emoji_width > available_width - x {
+                        let ellipsis: RichText =
+                            ExposedRichText::new_keep_properties("…", style).into();
+                        let galley = egui::WidgetText::from(ellipsis).into_galley(
+                            ui,
+                            Some(TextWrapMode::Extend),
+                            f32::INFINITY,
+                            egui::FontSelection::Default,
+                        );
+                        x += galley.size().x;
+                        row_height = row_height.max(galley.size().y);
+                        is_cut_off = true;
+                        continue;
+                    }
+
+                    if wrap_mode == TextWrapMode::Wrap
+                        && x > 0.0
+                        && x + emoji_width > available_width
+                    {
+                        width = width.max(x);
+                        row_top += row_height + row_spacing;
+                        (x, row_height) = (0.0, 0.0);
+                    }
+                    x += emoji_width;
+                    let (slot_height, _) = Self::emoji_slot(ui, style, emoji_size);
+                    row_height = row_height.max(slot_height);
+                }
+                TextSegment::Newline => {
+                    if x == 0.0 {
+                        // empty lines are as tall as an empty label:
+                        row_height = self.empty_line(ui).size().y;
+                    }
+                    width = width.max(x);
+                    row_top += row_height + row_spacing;
+                    (x, row_height, is_cut_off) = (0.0, 0.0, false);
+                }
+            }
+        }
+
+        if x == 0.0 && segments.last() == Some(&TextSegment::Newline) {
+            row_height = self.empty_line(ui).size().y;
+        }
+        egui::vec2(width.max(x), row_top + row_height)
+    }
+
+    /// The galley of an empty line in the style of this label.
+    fn empty_line(&self, ui: &egui::Ui) -> Arc<egui::Galley> {
+        let empty: RichText = ExposedRichText::new_keep_properties("", &self.text).into();
+        egui::WidgetText::from(empty).into_galley(
+            ui,
+            Some(TextWrapMode::Extend),
+            f32::INFINITY,
+            egui::FontSelection::Default,
+        )
+    }
+
+    /// Whether the segments are added directly to `ui`, instead of to a layout of their own.
+    fn is_inline(
+        &self,
+        ui: &egui::Ui,
+        options: &EmojiLabelOptions,
+        segments: &[TextSegment],
+    ) -> bool {
+        // line breaks only work in wrapping layouts:
+        let can_break_lines = ui.layout().main_wrap() || !segments.contains(&TextSegment::Newline);
+        ui.layout().is_horizontal()
+            && options.auto_inline
+            && self.max_width.is_none()
+            && can_break_lines
+    }
+
+    /// Add the label to an [`egui::Ui`].
+    pub fn show(self, ui: &mut egui::Ui) -> egui::Response {
+        self.show_with_info(ui).0
+    }
+
+    /// Add the label to an [`egui::Ui`], and also return some information about how it was shown.
+    pub fn show_with_info(mut self, ui: &mut egui::Ui) -> (egui::Response, ShowInfo) {
+        self.show_in(ui)
+    }
+
+    /// Lay out the label in an [`egui::Ui`] without painting it, and return where every segment went.
+    ///
+    /// The space is allocated in `ui` like [`Self::show`] does, so the rects are the ones the
+    /// segments would have been shown in, in the order they are shown. Line breaks don't take up
+    /// any space and are left out, as is everything after the point where the text is truncated.
+    pub fn layout_rects(mut self, ui: &mut egui::Ui) -> Vec<(egui::Rect, TextSegment)> {
+        self.segment_rects = Some(Vec::new());
+        ui.scope_builder(egui::UiBuilder::new().invisible(), |ui| self.show_in(ui));
+        self.segment_rects.unwrap_or_default()
+    }
+
+    fn show_in(&mut self, ui: &mut egui::Ui) -> (egui::Response, ShowInfo) {
+        let cache_id = self.cache_id();
+        // while the response belongs to this particular label:
+        let id = match self.id_salt {
+            Some(id_salt) => ui.make_persistent_id(id_salt),
+            None => ui.auto_id_with(cache_id),
+        };
+
+        let state = self.load_state(ui.ctx(), cache_id);
+        let segments = self.limit_emojis(&state.segments);
+        let segments = match self.link {
+            Some(_) => {
+                // the segments are laid out before they can be hovered, so go by the last frame:
+                let underline = (ui.ctx().read_response(id))
+                    .is_some_and(|resp| resp.hovered() || resp.has_focus());
+                Cow::Owned(link_segments(ui, &segments, underline))
+            }
+            None => segments,
+        };
+
+        // loaded once, as every segment needs them:
+        let options = self.options(ui.ctx());
+        let wrap_mode = self.wrap_mode.unwrap_or_else(|| ui.wrap_mode());
+        let justify = self.justify && wrap_mode == TextWrapMode::Wrap;
+        // justified and aligned text has to know its rows before anything is placed on them:
+        let needs_rows = justify || self.halign.is_some();
+        let mut info = ShowInfo::default();
+        let resp = if let ([TextSegment::Text(text)], None, false) =
+            (&*segments, self.max_width, needs_rows)
+        {
+            // no emojis, so this is just a normal label:
+            info.truncated =
+                wrap_mode == TextWrapMode::Truncate && is_elided(ui, text, ui.available_width());
+            let label = egui::Label::new(text.clone())
+                .wrap_mode(wrap_mode)
+                .selectable(self.copyable && self.is_selectable(ui, &options));
+            let resp = ui.add(label);
+            if let Some(segment_rects) = &mut self.segment_rects {
+                segment_rects.push((resp.rect, segments[0].clone()));
+            }
+            resp
+        } else if self.single_galley || needs_rows {
+            match (self.max_width, self.halign) {
+                (None, None) => self.show_galley(ui, id, &segments, wrap_mode, &options, &mut info),
+                (max_width, halign) => {
+                    // the label aligns its rows the way the layout places it:
+                    let layout = Layout::top_down(halign.unwrap_or(egui::Align::Min));
+                    ui.with_layout(layout, |ui| {
+                        if let Some(max_width) = max_width {
+                            ui.set_max_width(max_width);
+                        }
+                        self.show_galley(ui, id, &segments, wrap_mode, &options, &mut info)
+                    })
+                    .inner
+                }
+            }
+        } else if self.is_inline(ui, &options, &segments) {
+            let resp = self.show_segments(ui, id, &segments, wrap_mode, &options, &mut info);
+            // the last segment was added without spacing, so keep the next widget from touching it:
+            ui.allocate_space(egui::Vec2::ZERO);
+            resp
+        } else {
+            // wrap between segments (and therefore between emojis) if the text should wrap:
+            let row_layout = Layout::left_to_right(egui::Align::Min)
+                .with_main_wrap(wrap_mode == TextWrapMode::Wrap);
+            // centered, right-aligned and justified layouts (like menus) place a widget by its size,
+            // so it has to be known before the rows are added:
+            let layout = *ui.layout();
+            let placed_size = (layout.is_vertical()
+                && (layout.horizontal_align() != egui::Align::Min || layout.horizontal_justify()))
+            .then(|| self.measure_with(ui, &options));
+            let mut show_lines = |ui: &mut egui::Ui| {
+                if let Some(max_width) = self.max_width {
+                    ui.set_max_width(max_width);
+                }
+                let mut resp = empty_response(ui, id);
+                for line in segments.split(|segment| *segment == TextSegment::Newline) {
+                    // no height, so rows only take up the space of their contents:
+                    let size = egui::vec2(ui.available_width(), 0.0);
+                    resp |= ui
+                        .allocate_ui_with_layout(size, row_layout, |ui| match line.is_empty() {
+                            true => ui.add(egui::Label::new(self.empty_line(ui))),
+                            false => {
+                                self.show_segments(ui, id, line, wrap_mode, &options, &mut info)
+                            }
+                        })
+                        .inner;
+                }
+                resp
+            };
+
+            match placed_size {
+                Some(size) => {
+                    // allocated like an `egui::Label`, so it's aligned and justified like one:
+                    let (_, widget_rect) = ui.allocate_space(size);
+                    let align = egui::Align2([layout.horizontal_align(), egui::Align::Min]);
+                    let rect = align.align_size_within_rect(size, widget_rect);
+                    let lines_layout = Layout::top_down(egui::Align::Min);
+                    let mut resp = show_lines(
+                        &mut ui
+                            .new_child(egui::UiBuilder::new().max_rect(rect).layout(lines_layout)),
+                    );
+                    resp.rect = resp.rect.union(widget_rect);
+                    resp.interact_rect = resp.rect;
+                    resp
+                }
+                None => ui.vertical(show_lines).inner,
+            }
+        };
+
+        // the segments don't sense anything themselves, so the label is interacted with as a whole:
+        let sense = match self.link {
+            Some(_) => Some(self.sense.unwrap_or(Sense::hover()) | Sense::click()),
+            None => self.sense,
+        };
+        let resp = match sense {
+            Some(sense) => ui.interact(resp.rect, id, sense).union(resp),
+            None => resp,
+        };
+        let resp = match &self.link {
+            Some(url) => self.open_link(ui, url, resp),
+            None => resp,
+        };
+        (resp, info)
+    }
+
+    /// Follow the hyperlink to `url` if the label was clicked, see [`Self::link`].
+    fn open_link(&self, ui: &egui::Ui, url: &str, resp: egui::Response) -> egui::Response {
+        resp.widget_info(|| {
+            egui::WidgetInfo::labeled(egui::WidgetType::Link, ui.is_enabled(), self.text())
+        });
+        if resp.hovered() {
+            ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
+        }
+        if resp.clicked() || resp.middle_clicked() {
+            let modifiers = ui.input(|i| i.modifiers);
+            ui.ctx().open_url(egui::OpenUrl {
+                url: url.to_owned(),
+                new_tab: resp.middle_clicked() || modifiers.any(),
+            });
+        }
+        match ui.style().url_in_tooltip {
+            true => resp.on_hover_text(url),
+            false => resp,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run `add_contents` inside a central panel of a single headless egui frame.
+    fn run_ui(mut add_contents: impl FnMut(&mut egui::Ui)) -> egui::FullOutput {
+        let ctx = egui::Context::default();
+        ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| add_contents(ui));
+        })
+    }
+
+    fn is_cached(ctx: &egui::Context, cache_id: egui::Id) -> bool {
+        SegmentCache::with(ctx, |cache| cache.states.contains_key(&cache_id))
+    }
+
+    fn cached_segments(ctx: &egui::Context, cache_id: egui::Id) -> Arc<[TextSegment]> {
+        SegmentCache::with(ctx, |cache| cache.states[&cache_id].segments.clone())
+    }
+
+    /// Emojis with the font texture as their image, which is always loaded, so they are painted
+    /// even though there are no image loaders. Only 🔍 and 🖼 are emojis.
+    struct FontTexture;
+
+    impl EmojiAssetProvider for FontTexture {
+        fn lookup(_emoji: &str) -> Option<egui::ImageSource<'static>> {
+            let texture = egui::load::SizedTexture::new(egui::TextureId::default(), [1.0, 1.0]);
+            Some(egui::ImageSource::Texture(texture))
+        }
+
+        fn is_emoji(emoji: &str) -> bool {
+            matches!(emoji, "🔍" | "🖼")
+        }
+    }
+
+    /// All images painted in a frame, which are textured rects, in paint order.
+    fn painted_images(output: &egui::FullOutput) -> Vec<egui::epaint::RectShape> {
+        (output.shapes.iter())
+            .filter_map(|clipped| match &clipped.shape {
+                egui::Shape::Rect(rect) if rect.uv != egui::Rect::ZERO => Some(*rect),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// All lines painted in a frame (like underlines), in paint order.
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    fn painted_lines(
+        output: &egui::FullOutput,
+    ) -> Vec<([egui::Pos2; 2], egui::epaint::PathStroke)> {
+        (output.shapes.iter())
+            .filter_map(|clipped| match &clipped.shape {
+                egui::Shape::LineSegment { points, stroke } => Some((*points, stroke.clone())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// All rects filled with `color` in a frame (like backgrounds), in paint order.
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    fn filled_rects(output: &egui::FullOutput, color: egui::Color32) -> Vec<egui::Rect> {
+        (output.shapes.iter())
+            .filter_map(|clipped| match &clipped.shape {
+                egui::Shape::Rect(rect) if rect.fill == color => Some(rect.rect),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The first text shape painted in a frame that shows `text`.
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    fn painted_text_shape(output: &egui::FullOutput, text: &str) -> egui::epaint::TextShape {
+        (output.shapes.iter())
+            .find_map(|clipped| match &clipped.shape {
+                egui::Shape::Text(shape) if shape.galley.text() == text => Some(shape.clone()),
+                _ => None,
+            })
+            .unwrap_or_else(|| panic!("{text:?} is not painted"))
+    }
+
+    /// All text painted in a frame, in paint order.
+    ///
+    /// No image loaders are installed in tests, so every emoji image shows up as a "⚠".
+    fn painted_text(output: &egui::FullOutput) -> Vec<String> {
+        fn collect(shape: &egui::Shape, texts: &mut Vec<String>) {
+            match shape {
+                // collect the glyphs instead of the text, so elided text ends with "…":
+                egui::Shape::Text(text) => texts.push(
+                    (text.galley.rows.iter())
+                        .flat_map(|row| row.glyphs.iter().map(|glyph| glyph.chr))
+                        .collect(),
+                ),
+                egui::Shape::Vec(shapes) => shapes.iter().for_each(|shape| collect(shape, texts)),
+                _ => {}
+            }
+        }
+
+        let mut texts = Vec::new();
+        for clipped in &output.shapes {
+            collect(&clipped.shape, &mut texts);
+        }
+        texts
+    }
+
+    #[test]
+    fn long_emoji_runs_wrap() {
+        run_ui(|ui| {
+            let font_height = ui.text_style_height(&egui::TextStyle::Body);
+            let rect = ui
+                .vertical(|ui| {
+                    ui.set_max_width(100.0);
+                    EmojiLabel::new("😀".repeat(50)).wrap().show(ui);
+                })
+                .response
+                .rect;
+            assert!(rect.width() <= 100.0, "{rect:?}");
+            assert!(rect.height() > font_height * 2.0, "{rect:?}");
+        });
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn emoji_segmentation() {
+        let text = "Hello😤world";
+        let segments = segment_text::<TwemojiProvider>(&RichText::new(text));
+        assert_eq!(
+            segments,
+            vec![
+                TextSegment::Text("Hello".into()),
+                TextSegment::Emoji("😤".into(), RichText::default()),
+                TextSegment::Text("world".into())
+            ]
+        );
+        let text = "😅 2,*:привет|3 🤬";
+        let segments = segment_text::<TwemojiProvider>(&RichText::new(text));
+        assert_eq!(
+            segments,
+            vec![
+                TextSegment::Emoji("😅".into(), RichText::default()),
+                TextSegment::Text(" 2,*:привет|3 ".into()),
+                TextSegment::Emoji("🤬".into(), RichText::default()),
+            ]
+        );
+        let text = "Hello world 🥰!";
+        let segments = segment_text::<TwemojiProvider>(&RichText::new(text));
+        assert_eq!(
+            segments,
+            vec![
+                TextSegment::Text("Hello world ".into()),
+                TextSegment::Emoji("🥰".into(), RichText::default()),
+                TextSegment::Text("!".into())
+            ]
+        );
+        assert_eq!(
+            segments.iter().map(ToString::to_string).collect::<String>(),
+            text
+        );
+        assert_eq!(format!("{:?}", segments[1]), r#"Emoji("🥰")"#);
+    }
+
+    #[test]
+    fn max_width_truncates_with_ellipsis() {
+        let output = run_ui(|ui| {
+            let left = ui.cursor().left();
+            let rect = EmojiLabel::new("Hello 😀 world 😀 this text is way too long 😀")
+                .max_width(80.0)
+                .truncate()
+                .show(ui)
+                .rect;
+            assert!(rect.right() <= left + 80.0, "{rect:?}");
+        });
+        let texts = painted_text(&output);
+        let visible: String = texts
+            .iter()
+            .filter(|text| !text.trim().is_empty())
+            .cloned()
+            .collect();
+        assert!(visible.ends_with('…'), "{texts:?}");
+        assert!(!visible.contains("long"), "{texts:?}");
+    }
+
+    #[test]
+    fn truncation_shows_one_ellipsis() {
+        for max_width in [30.0, 60.0, 75.0, 90.0, 120.0] {
+            let mut emoji_rects = Vec::new();
+            let mut left = 0.0;
+            let output = run_ui(|ui| {
+                left = ui.cursor().left();
+                let (_, info) = EmojiLabel::new("Hello wor 😀 more text 😀 end")
+                    .max_width(max_width)
+                    .truncate()
+                    .copyable(false)
+                    .show_with_info(ui);
+                assert!(info.truncated);
+                emoji_rects = info.emoji_rects;
+            });
+            let texts = painted_text(&output);
+            let ellipses: usize = texts.iter().map(|text| text.matches('…').count()).sum();
+            assert_eq!(ellipses, 1, "{max_width}: {texts:?}");
+            // no images past the cut-off:
+            let images = texts.iter().filter(|text| *text == "⚠").count();
+            assert_eq!(images, emoji_rects.len(), "{max_width}: {texts:?}");
+            assert!(emoji_rects
+                .iter()
+                .all(|(_, rect)| rect.right() <= left + max_width));
+        }
+    }
+
+    #[test]
+    fn show_info_reports_truncation() {
+        let truncated = |text: &'static str| {
+            let mut truncated = false;
+            run_ui(|ui| {
+                truncated = EmojiLabel::new(text)
+                    .max_width(80.0)
+                    .truncate()
+                    .show_with_info(ui)
+                    .1
+                    .truncated
+            });
+            truncated
+        };
+        assert!(!truncated("short 👍"));
+        assert!(truncated("a label that is way too long to fit 🦀"));
+        assert!(truncated("🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀"));
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn show_info_reports_emoji_rects() {
+        run_ui(|ui| {
+            let (resp, info) = EmojiLabel::new("apple 🍎 or\nbanana 🍌").show_with_info(ui);
+            let emojis: Vec<&str> = info.emoji_rects.iter().map(|(e, _)| e.as_str()).collect();
+            assert_eq!(emojis, ["🍎", "🍌"]);
+            let (apple, banana) = (info.emoji_rects[0].1, info.emoji_rects[1].1);
+            assert!(banana.min.y >= apple.max.y);
+            assert!(resp.rect.contains_rect(apple) && resp.rect.contains_rect(banana));
+        });
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn layout_rects_match_the_shown_segments() {
+        for single_galley in [false, true] {
+            let label = || EmojiLabel::new("apple 🍎 or\nbanana 🍌").single_galley(single_galley);
+            let output = run_ui(|ui| {
+                let rects = ui.scope(|ui| label().layout_rects(ui)).inner;
+                let (_, info) = ui.scope(|ui| label().show_with_info(ui)).inner;
+
+                let segments: Vec<String> = rects.iter().map(|(_, s)| s.to_string()).collect();
+                assert_eq!(segments, ["apple ", "🍎", " or", "banana ", "🍌"]);
+                // the shown label is below the laid out one, but the emojis are in the same columns:
+                for ((rect, _), (_, image)) in
+                    [&rects[1], &rects[4]].into_iter().zip(&info.emoji_rects)
+                {
+                    assert!((rect.min.x - image.min.x).abs() < 0.5, "{rect:?} {image:?}");
+                    assert!(
+                        (rect.width() - image.width()).abs() < 1.0,
+                        "{rect:?} {image:?}"
+                    );
+                }
+                assert!(rects[3].0.min.y >= rects[0].0.max.y);
+            });
+            // the first label isn't painted, so only the emojis of the second one are:
+            let warnings = painted_text(&output).iter().filter(|t| *t == "⚠").count();
+            assert_eq!(warnings, 2, "single galley: {single_galley}");
+        }
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn tag_sequence_flags_are_one_emoji() {
+        // England, Wales and Scotland: a black flag, tag characters and a cancel tag
+        for flag in ["🏴󠁧󠁢󠁥󠁮󠁧󠁿", "🏴󠁧󠁢󠁷󠁬󠁳󠁿", "🏴󠁧󠁢󠁳󠁣󠁴󠁿"]
+        {
+            let segments = segment_text::<TwemojiProvider>(&format!("go {flag}!").into());
+            let [TextSegment::Text(_), TextSegment::Emoji(emoji, _), TextSegment::Text(_)] =
+                &segments[..]
+            else {
+                panic!("{flag} was split up");
+            };
+            assert_eq!(&**emoji, flag);
+            assert!(TwemojiProvider::lookup(flag).is_some());
+        }
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn hovered_emojis_are_reported() {
+        // inert labels, labels without the selection overlay and single galleys all see hovers:
+        let labels: [fn() -> EmojiLabel; 4] = [
+            || EmojiLabel::new("🦀 and 🎉"),
+            || EmojiLabel::new("🦀 and 🎉").sense(Sense::hover()),
+            || EmojiLabel::new("🦀 and 🎉").copyable(false),
+            || EmojiLabel::new("🦀 and 🎉").single_galley(true),
+        ];
+        for label in labels {
+            let hovered = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+            let ctx = egui::Context::default();
+            // the pointer is over the first emoji:
+            let input = egui::RawInput {
+                events: vec![egui::Event::PointerMoved(egui::pos2(12.0, 12.0))],
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let hovered = hovered.clone();
+                    label()
+                        .on_hover_emoji(move |emoji| hovered.borrow_mut().push(emoji.to_owned()))
+                        .show(ui);
+                });
+            });
+            assert_eq!(*hovered.borrow(), ["🦀"]);
+        }
+    }
+
+    #[cfg(all(
+        feature = "unicode-names",
+        any(feature = "svg", feature = "png", feature = "both")
+    ))]
+    #[test]
+    fn hovered_emojis_are_named_in_a_tooltip() {
+        let ctx = egui::Context::default();
+        let show = |input| {
+            ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    EmojiLabel::new("😂 and 🎉").show(ui);
+                });
+            })
+        };
+        let _ = show(egui::RawInput {
+            events: vec![egui::Event::PointerMoved(egui::pos2(12.0, 12.0))],
+            ..Default::default()
+        });
+        // tooltips are invisible in the pass they're sized in:
+        let output = show(egui::RawInput::default());
+        let texts = painted_text(&output);
+        assert!(
+            texts.contains(&"😂 U+1F602 FACE WITH TEARS OF JOY".to_owned()),
+            "{texts:?}"
+        );
+        assert!(!texts.iter().any(|text| text.contains("PARTY POPPER")));
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn links_open_their_url() {
+        let ctx = egui::Context::default();
+        let show = |events| {
+            ctx.run(
+                egui::RawInput {
+                    events,
+                    ..Default::default()
+                },
+                |ctx| {
+                    egui::CentralPanel::default().show(ctx, |ui| {
+                        EmojiLabel::new("⭐ Star us")
+                            .link("https://example.com")
+                            .show(ui);
+                    });
+                },
+            )
+        };
+        let is_underlined = |output: &egui::FullOutput| !painted_lines(output).is_empty();
+        let output = show(Vec::new());
+        assert!(!is_underlined(&output));
+        assert!(output.platform_output.open_url.is_none());
+
+        // the pointer is over the emoji:
+        let pos = egui::pos2(12.0, 12.0);
+        let _ = show(vec![egui::Event::PointerMoved(pos)]);
+        let output = show(Vec::new());
+        assert!(is_underlined(&output));
+        assert_eq!(
+            output.platform_output.cursor_icon,
+            egui::CursorIcon::PointingHand
+        );
+
+        let click = |pressed| egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed,
+            modifiers: egui::Modifiers::NONE,
+        };
+        let _ = show(vec![click(true)]);
+        let output = show(vec![click(false)]);
+        let open_url = output.platform_output.open_url.unwrap();
+        assert_eq!(open_url.url, "https://example.com");
+        assert!(!open_url.new_tab);
+    }
+
+    #[test]
+    fn only_selectable_labels_show_a_text_cursor() {
+        for selectable in [true, false] {
+            let ctx = egui::Context::default();
+            let show = |input| {
+                ctx.run(input, |ctx| {
+                    egui::CentralPanel::default().show(ctx, |ui| {
+                        EmojiLabel::new("🦀 and 🎉").selectable(selectable).show(ui);
+                    });
+                })
+            };
+            // the pointer is over the first emoji:
+            let _ = show(egui::RawInput {
+                events: vec![egui::Event::PointerMoved(egui::pos2(12.0, 12.0))],
+                ..Default::default()
+            });
+            let output = show(egui::RawInput::default());
+            let expected = match selectable {
+                true => egui::CursorIcon::Text,
+                false => egui::CursorIcon::Default,
+            };
+            assert_eq!(output.platform_output.cursor_icon, expected);
+        }
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn spans_keep_their_styling() {
+        let highlight = RichText::new("match 🔍").background_color(egui::Color32::YELLOW);
+        let label = EmojiLabel::from_spans([RichText::new("a "), highlight.clone()]);
+        assert_eq!(label.text(), "a match 🔍");
+
+        let state = label.segment();
+        let match_style: RichText = ExposedRichText::new_keep_properties("", &highlight).into();
         assert!(
-            segments
-                == vec![
-                    TextSegment::Emoji("😅".to_owned()),
-                    TextSegment::Text(" 2,*:привет|3 ".into()),
-                    TextSegment::Emoji("🤬".to_owned()),
+            *state.segments
+                == [
+                    TextSegment::Text("a ".into()),
+                    TextSegment::Text(
+                        ExposedRichText::new_keep_properties("match ", &highlight).into()
+                    ),
+                    TextSegment::Emoji("🔍".into(), match_style),
                 ]
         );
-        let text = "Hello world 🥰!";
-        let segments = segment_text(&RichText::new(text));
+        assert_ne!(
+            label.cache_id(),
+            EmojiLabel::from_spans([RichText::new("a "), RichText::new("match 🔍")]).cache_id()
+        );
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn precomputed_segments_skip_the_cache() {
+        fn assert_send<T: Send + Sync>() {}
+        assert_send::<PrecomputedSegments>();
+
+        let segments = PrecomputedSegments::new("ready 🚀");
+        let output = run_ui(|ui| {
+            EmojiLabel::with_precomputed(&segments).show(ui);
+            EmojiLabel::with_precomputed(&segments.clone()).show(ui);
+            assert_eq!(SegmentCache::with(ui.ctx(), |cache| cache.states.len()), 0);
+        });
+        let texts = painted_text(&output);
+        assert_eq!(texts.iter().filter(|text| *text == "⚠").count(), 2);
+    }
+
+    #[test]
+    fn triple_click_selects_the_whole_line() {
+        let ctx = egui::Context::default();
+        let pos = egui::pos2(12.0, 12.0);
+        let click = |pressed| egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed,
+            modifiers: egui::Modifiers::NONE,
+        };
+
+        let mut events = vec![vec![egui::Event::PointerMoved(pos)]];
+        for _ in 0..3 {
+            events.push(vec![click(true)]);
+            events.push(vec![click(false)]);
+        }
+        events.push(vec![egui::Event::Copy]);
+
+        let mut copied = String::new();
+        for (i, events) in events.into_iter().enumerate() {
+            let input = egui::RawInput {
+                time: Some(i as f64 * 0.01),
+                events,
+                ..Default::default()
+            };
+            let output = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    EmojiLabel::new("🍝 copy 👨‍👩‍👧 this 👌").show(ui);
+                });
+            });
+            copied = output.platform_output.copied_text;
+        }
+        assert_eq!(copied, "🍝 copy 👨‍👩‍👧 this 👌");
+    }
+
+    #[test]
+    fn selections_across_labels_copy_the_original_text() {
+        const LINES: [&str; 2] = [
+            "Yes 👍, you 🤟 can 🎥 select 📝 and copy 🍝 this 👌",
+            "a 👨‍👩‍👧 family and 🇺🇸 flag",
+        ];
+        let (start, end) = (egui::pos2(8.0, 10.0), egui::pos2(20.0, 80.0));
+        let button = |pos, pressed| egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed,
+            modifiers: egui::Modifiers::NONE,
+        };
+        let events = [
+            vec![egui::Event::PointerMoved(start)],
+            vec![button(start, true)],
+            vec![egui::Event::PointerMoved(end)],
+            vec![button(end, false)],
+            vec![egui::Event::Copy],
+        ];
+
+        for single_galley in [false, true] {
+            let ctx = egui::Context::default();
+            let mut copied = String::new();
+            for (i, events) in events.iter().enumerate() {
+                let input = egui::RawInput {
+                    time: Some(i as f64 * 0.5),
+                    events: events.clone(),
+                    ..Default::default()
+                };
+                let output = ctx.run(input, |ctx| {
+                    egui::CentralPanel::default().show(ctx, |ui| {
+                        for line in LINES {
+                            EmojiLabel::new(line).single_galley(single_galley).show(ui);
+                        }
+                    });
+                });
+                copied = output.platform_output.copied_text;
+            }
+            assert_eq!(copied, LINES.join("\n"), "single galley: {single_galley}");
+        }
+    }
+
+    #[test]
+    fn identical_labels_have_unique_ids() {
+        run_ui(|ui| {
+            let first = EmojiLabel::new("lol 😂").show(ui);
+            let second = EmojiLabel::new("lol 😂").show(ui);
+            assert_ne!(first.id, second.id);
+            assert_ne!(first.id, egui::Id::NULL);
+
+            // but they still share the cached segments:
+            let cache_id = EmojiLabel::new("lol 😂").cache_id();
+            assert!(is_cached(ui.ctx(), cache_id));
+        });
+    }
+
+    #[test]
+    fn stateless_labels_are_not_cached() {
+        run_ui(|ui| {
+            EmojiLabel::new("⏳ 42%").stateless(true).show(ui);
+            let cache_id = EmojiLabel::new("⏳ 42%").cache_id();
+            assert!(!is_cached(ui.ctx(), cache_id));
+        });
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn segments_come_from_the_cache() {
+        run_ui(|ui| {
+            EmojiLabel::new("cached 🗃").show(ui);
+            let segments = EmojiLabel::new("cached 🗃").segments(ui.ctx());
+            let cache_id = EmojiLabel::new("cached 🗃").cache_id();
+            assert!(Arc::ptr_eq(&segments, &cached_segments(ui.ctx(), cache_id)));
+            assert_eq!(
+                *segments,
+                [
+                    TextSegment::Text("cached ".into()),
+                    TextSegment::Emoji("🗃".into(), RichText::default()),
+                ]
+            );
+        });
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn emojis_are_interned() {
+        let first = segment_text::<TwemojiProvider>(&"party 🎉".into());
+        let second = segment_text::<TwemojiProvider>(&RichText::new("🎉 time").strong());
+        match (&first[1], &second[0]) {
+            (TextSegment::Emoji(a, _), TextSegment::Emoji(b, _)) => assert!(Arc::ptr_eq(a, b)),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn styling_separates_state() {
+        run_ui(|ui| {
+            let red = EmojiLabel::new(RichText::new("hello 😀").color(egui::Color32::RED));
+            let blue = EmojiLabel::new(RichText::new("hello 😀").color(egui::Color32::BLUE));
+            let (red_cache, blue_cache) = (red.cache_id(), blue.cache_id());
+            red.show(ui);
+            blue.show(ui);
+
+            let text_color = |cache_id| match &cached_segments(ui.ctx(), cache_id)[0] {
+                TextSegment::Text(text) => ExposedRichText::from_ref(text).text_color,
+                _ => unreachable!(),
+            };
+            assert_eq!(text_color(red_cache), Some(egui::Color32::RED));
+            assert_eq!(text_color(blue_cache), Some(egui::Color32::BLUE));
+        });
+    }
+
+    #[test]
+    fn widget_text_keeps_styling() {
+        let rich = RichText::new("hi 👋").strong().color(egui::Color32::RED);
+        let label = EmojiLabel::from_widget_text(rich.clone());
+        assert!(ExposedRichText::from_ref(label.rich_text()) == ExposedRichText::from_ref(&rich));
+
+        let mut job = egui::text::LayoutJob::default();
+        let format = egui::TextFormat {
+            font_id: egui::FontId::monospace(20.0),
+            color: egui::Color32::BLUE,
+            italics: true,
+            ..Default::default()
+        };
+        job.append("hi 👋", 0.0, format);
+        let label = EmojiLabel::from_widget_text(job);
+        let text = ExposedRichText::from_ref(label.rich_text());
+        assert_eq!(label.text(), "hi 👋");
+        assert_eq!(text.size, Some(20.0));
+        assert_eq!(text.family, Some(egui::FontFamily::Monospace));
+        assert_eq!(text.text_color, Some(egui::Color32::BLUE));
+        assert!(text.italics);
+    }
+
+    #[test]
+    fn measure_matches_shown_size() {
+        const LONG: &str = "lots of words 🚀 and emojis 🦀🦀🦀 that should wrap onto a few rows 🎉";
+        let labels: [fn() -> EmojiLabel; 5] = [
+            || EmojiLabel::new("hi 👋 there").extend(),
+            || EmojiLabel::new(LONG).wrap(),
+            || EmojiLabel::new(LONG).truncate(),
+            || EmojiLabel::new(LONG).wrap().max_width(80.0),
+            || EmojiLabel::new("line 1 😀\nline 2 🎉\n\nline 4 🦀").extend(),
+        ];
+
+        // a fixed-width area with no height, so the label only takes up the space it needs:
+        fn area<R>(
+            ui: &mut egui::Ui,
+            add_contents: impl FnOnce(&mut egui::Ui) -> R,
+        ) -> egui::InnerResponse<R> {
+            let rect = egui::Rect::from_min_size(ui.cursor().min, egui::vec2(120.0, 0.0));
+            ui.scope_builder(egui::UiBuilder::new().max_rect(rect), add_contents)
+        }
+
+        for label in labels {
+            run_ui(|ui| {
+                let measured = area(ui, |ui| label().measure(ui)).inner;
+                let shown = area(ui, |ui| {
+                    label().show(ui);
+                    ui.min_rect().size()
+                })
+                .inner;
+                assert!(
+                    (measured - shown).length() < 0.5,
+                    "{measured:?} != {shown:?}"
+                );
+            });
+        }
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn single_galley_labels_are_one_text() {
+        const LONG: &str = "lots of words 🚀 and emojis 🦀🦀🦀 that should wrap onto a few rows 🎉";
+        let label = || EmojiLabel::new(LONG).single_galley(true);
+
+        let mut info = ShowInfo::default();
+        let output = run_ui(|ui| {
+            ui.vertical(|ui| {
+                ui.set_max_width(120.0);
+                let measured = label().wrap().measure(ui);
+                let (resp, shown) = label().wrap().show_with_info(ui);
+                assert!((measured - resp.rect.size()).length() < 0.5, "{measured:?}");
+                info = shown;
+            });
+        });
+        assert_eq!(painted_text(&output), [LONG, "⚠", "⚠", "⚠", "⚠", "⚠"]);
+        let emojis: Vec<&str> = info.emoji_rects.iter().map(|(e, _)| e.as_str()).collect();
+        assert_eq!(emojis, ["🚀", "🦀", "🦀", "🦀", "🎉"]);
+        let (first, last) = (info.emoji_rects[0].1, info.emoji_rects[4].1);
+        assert!(last.top() > first.bottom(), "{first:?} {last:?}");
+
+        // the images are as big as they are without a single galley:
+        let output = run_ui(|ui| {
+            let (_, single) = label().extend().show_with_info(ui);
+            let (_, segmented) = EmojiLabel::new(LONG).extend().show_with_info(ui);
+            for ((_, a), (_, b)) in single.emoji_rects.iter().zip(&segmented.emoji_rects) {
+                assert!((a.size() - b.size()).length() < 0.01, "{a:?} != {b:?}");
+            }
+        });
+        assert_eq!(painted_text(&output)[0], LONG);
+
+        run_ui(|ui| {
+            ui.vertical(|ui| {
+                ui.set_max_width(120.0);
+                let (_, info) = label().truncate().show_with_info(ui);
+                assert!(info.truncated);
+                assert_eq!(info.emoji_rects.len(), 1);
+            });
+        });
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn single_galley_emojis_split_over_rows_are_painted() {
+        // a "word" of emoji sequences that is wider than a row gets broken up between glyphs:
+        let family = "👨‍👩‍👧";
+        let text = family.repeat(12);
+        run_ui(|ui| {
+            ui.vertical(|ui| {
+                for width in [50.0, 67.0, 93.0] {
+                    ui.set_max_width(width);
+                    let (_, info) = EmojiLabel::new(text.as_str())
+                        .single_galley(true)
+                        .wrap()
+                        .show_with_info(ui);
+                    assert_eq!(info.emoji_rects.len(), 12, "width {width}");
+                    assert!(info.emoji_rects.iter().all(|(emoji, _)| emoji == family));
+                }
+            });
+        });
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn justified_rows_fill_the_label() {
+        const TEXT: &str =
+            "lots of words 🚀 and emojis 🦀🦀 that should wrap onto a few rows\nshort 🎉 line";
+        let label = |justify| {
+            EmojiLabel::new(TEXT)
+                .wrap()
+                .single_galley(true)
+                .justify(justify)
+        };
+
+        let mut shown = [ShowInfo::default(), ShowInfo::default()];
+        run_ui(|ui| {
+            ui.vertical(|ui| {
+                ui.set_max_width(150.0);
+                for (justify, shown) in [false, true].into_iter().zip(&mut shown) {
+                    let measured = label(justify).measure(ui);
+                    let (resp, info) = label(justify).show_with_info(ui);
+                    assert!((measured - resp.rect.size()).length() < 0.5, "{measured:?}");
+                    *shown = info;
+                }
+
+                let (label, options) = (label(true), EmojiLabelOptions::load(ui.ctx()));
+                let (mut job, _) = label.layout_job(ui, &options, &label.segments(ui.ctx()));
+                job.wrap = egui::text::TextWrapping::wrap_at_width(150.0);
+                let (galley, _) = ui.fonts(|fonts| justify_job(fonts, job));
+                assert!(galley.rows.len() > 3);
+                for row in &galley.rows {
+                    let end = row.glyphs.last().map_or(0.0, |glyph| glyph.max_x());
+                    let is_last =
+                        row.ends_with_newline || std::ptr::eq(row, galley.rows.last().unwrap());
+                    assert_eq!(is_last, end < 149.0, "{end}");
+                }
+            });
+        });
+
+        let [plain, justified] = shown.map(|info| info.emoji_rects);
+        assert_eq!(plain.len(), justified.len());
+        // the emojis stay on their rows, but move to the right unless they're in a last row:
+        for ((_, a), (_, b)) in plain.iter().zip(&justified) {
+            assert!((a.top() - b.top() - (plain[0].1.top() - justified[0].1.top())).abs() < 0.5);
+        }
+        assert!(justified[0].1.left() > plain[0].1.left() + 1.0);
+        let last = plain.len() - 1;
+        assert!((justified[last].1.left() - plain[last].1.left()).abs() < 0.5);
+    }
+
+    #[test]
+    fn sensing_labels_are_clicked_as_a_whole() {
+        let click = |pos, pressed| egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed,
+            modifiers: egui::Modifiers::NONE,
+        };
+        // on the emoji, and on the text after it:
+        for pos in [egui::pos2(12.0, 12.0), egui::pos2(40.0, 12.0)] {
+            let ctx = egui::Context::default();
+            let events = [
+                vec![egui::Event::PointerMoved(pos)],
+                vec![click(pos, true)],
+                vec![click(pos, false)],
+            ];
+            let mut clicked = false;
+            for (i, events) in events.into_iter().enumerate() {
+                let input = egui::RawInput {
+                    time: Some(i as f64 * 0.01),
+                    events,
+                    ..Default::default()
+                };
+                let _ = ctx.run(input, |ctx| {
+                    egui::CentralPanel::default().show(ctx, |ui| {
+                        // without the selection overlay, which senses clicks of its own:
+                        let label = EmojiLabel::new("🦀 click me").copyable(false);
+                        let resp = label.sense(Sense::click()).show(ui);
+                        assert!(resp.sense.click);
+                        clicked |= resp.clicked();
+                    });
+                });
+            }
+            assert!(clicked, "{pos:?}");
+        }
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn labels_are_placed_like_labels_in_menus_and_centered_layouts() {
+        const LONG: &str = "a long text 💡 that wraps onto a few rows 🦀 in the middle";
+        run_ui(|ui| {
+            // menus are justified:
+            let menu = Layout::top_down_justified(egui::Align::LEFT);
+            ui.allocate_ui_with_layout(egui::vec2(150.0, 100.0), menu, |ui| {
+                let plain = ui.label("plain");
+                let (resp, info) = EmojiLabel::new("tip 💡").show_with_info(ui);
+                assert_eq!(resp.rect.x_range(), plain.rect.x_range());
+                assert!(resp.rect.contains_rect(info.emoji_rects[0].1));
+            });
+
+            ui.allocate_ui(egui::vec2(200.0, 200.0), |ui| {
+                let left = EmojiLabel::new(LONG).wrap().show(ui);
+                ui.vertical_centered(|ui| {
+                    let (resp, info) = EmojiLabel::new("centered 💡").show_with_info(ui);
+                    assert!((resp.rect.center().x - ui.max_rect().center().x).abs() < 0.5);
+                    assert!(resp.rect.contains_rect(info.emoji_rects[0].1));
+
+                    let centered = EmojiLabel::new(LONG).wrap().show(ui);
+                    assert!((centered.rect.size() - left.rect.size()).length() < 0.01);
+                });
+            });
+        });
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn aligned_labels_align_every_row() {
+        const LONG: &str = "a long toast 🍞 that wraps onto a few rows 🦀 of different widths";
+        for halign in [egui::Align::Center, egui::Align::RIGHT] {
+            let mut ui_rect = egui::Rect::NOTHING;
+            let mut emoji_rects = Vec::new();
+            let output = run_ui(|ui| {
+                ui.allocate_ui(egui::vec2(150.0, 200.0), |ui| {
+                    ui_rect = ui.max_rect();
+                    let label = EmojiLabel::new(LONG).wrap().halign(halign);
+                    let measured = label.measure(ui);
+                    let (resp, info) = EmojiLabel::new(LONG)
+                        .wrap()
+                        .halign(halign)
+                        .show_with_info(ui);
+                    assert!((measured - resp.rect.size()).length() < 0.5, "{measured:?}");
+                    emoji_rects = info.emoji_rects;
+                });
+            });
+
+            let text = painted_text_shape(&output, LONG);
+            assert!(text.galley.rows.len() > 2);
+            for row in &text.galley.rows {
+                let row = row.rect.translate(text.pos.to_vec2());
+                let (aligned, target) = match halign {
+                    egui::Align::Center => (row.center().x, ui_rect.center().x),
+                    _ => (row.right(), ui_rect.right()),
+                };
+                assert!((aligned - target).abs() < 1.0, "{row:?} in {ui_rect:?}");
+            }
+            // the emojis move along with their rows:
+            assert_eq!(emoji_rects.len(), 2);
+            assert!(emoji_rects
+                .iter()
+                .all(|(_, rect)| rect.left() > ui_rect.left() + 4.0));
+        }
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn decorations_cross_emojis() {
+        let text = RichText::new("gone 🦀 text")
+            .underline()
+            .strikethrough()
+            .extra_letter_spacing(4.0)
+            .line_height(Some(30.0));
+        let mut info = ShowInfo::default();
+        let output = run_ui(|ui| info = EmojiLabel::new(text.clone()).show_with_info(ui).1);
+
+        let lines: Vec<[egui::Pos2; 2]> = (painted_lines(&output).into_iter())
+            .map(|(points, _)| points)
+            .collect();
+        // the lines go across the padding and line height around the image, like through text:
+        let image = info.emoji_rects[0].1;
+        let slot =
+            egui::Rect::from_center_size(image.center(), egui::vec2(image.width() + 8.0, 30.0));
+        for expected in [
+            [slot.left_bottom(), slot.right_bottom()],
+            [slot.left_center(), slot.right_center()],
+        ] {
+            let is_drawn = |points: &[egui::Pos2; 2]| {
+                (points[0] - expected[0]).length() + (points[1] - expected[1]).length() < 0.01
+            };
+            assert!(lines.iter().any(is_drawn), "{expected:?} not in {lines:?}");
+        }
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn backgrounds_continue_behind_emojis() {
+        let text = RichText::new("match 🔍 here")
+            .background_color(egui::Color32::YELLOW)
+            .extra_letter_spacing(4.0)
+            .line_height(Some(30.0));
+        let mut info = ShowInfo::default();
+        let output = run_ui(|ui| info = EmojiLabel::new(text.clone()).show_with_info(ui).1);
+
+        let backgrounds = filled_rects(&output, egui::Color32::YELLOW);
+        // behind the padding and line height around the image, overlapping the text around it:
+        let image = info.emoji_rects[0].1;
+        let slot =
+            egui::Rect::from_center_size(image.center(), egui::vec2(image.width() + 8.0, 30.0));
+        assert_eq!(backgrounds.len(), 1);
+        assert!((backgrounds[0].min - slot.expand(1.0).min).length() < 0.01);
+        assert!((backgrounds[0].max - slot.expand(1.0).max).length() < 0.01);
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn highlighted_mentions_have_no_gaps() {
+        let mention = RichText::new("@user 🎉").background_color(egui::Color32::YELLOW);
+        let mut text_rect = egui::Rect::NOTHING;
+        let output = run_ui(|ui| {
+            let rects = EmojiLabel::new(mention.clone()).layout_rects(ui);
+            text_rect = rects[0].0;
+            EmojiLabel::new(mention.clone()).show(ui);
+            EmojiLabel::new(mention.clone())
+                .single_galley(true)
+                .show(ui);
+        });
+
+        // the background behind the emoji overlaps the one behind the text:
+        let backgrounds = filled_rects(&output, egui::Color32::YELLOW);
+        assert_eq!(backgrounds.len(), 1);
+        assert!(backgrounds[0].left() < text_rect.right());
+
+        // and a single galley fills it in behind the emoji's placeholder, like behind its text:
+        // (after the invisible overlay of the other label, which has no backgrounds at all)
+        let galley = output
+            .shapes
+            .iter()
+            .rev()
+            .find_map(|clipped| match &clipped.shape {
+                egui::Shape::Text(text) if text.galley.job.sections.len() == 2 => {
+                    Some(text.galley.clone())
+                }
+                _ => None,
+            });
+        let galley = galley.expect("the single galley is painted");
+        let placeholder = &galley.job.sections[1].format;
+        assert_eq!(placeholder.color, egui::Color32::TRANSPARENT);
+        assert_eq!(placeholder.background, egui::Color32::YELLOW);
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn strikethroughs_match_the_text_around_emojis() {
+        let text = RichText::new("done 🎉 shipping").strikethrough();
+        let output = run_ui(|ui| {
+            EmojiLabel::new(text.clone()).show(ui);
+        });
+
+        let lines = painted_lines(&output);
+        let (points, stroke) = lines.first().expect("the emoji is struck through");
+        let struck_text = painted_text_shape(&output, "done ");
+
+        // as thick, in the same color and at about the same height as through the glyphs next to it:
+        let format = &struck_text.galley.job.sections[0].format;
+        assert_eq!(stroke.width, format.strikethrough.width);
+        assert!(stroke.color == egui::epaint::ColorMode::Solid(format.strikethrough.color));
+        let text_y = struck_text.pos.y + struck_text.galley.rows[0].rect.center().y;
+        // (the glyphs are rounded to whole pixels, so within one of those)
+        assert!((points[0].y - text_y).abs() < 0.5, "{points:?} {text_y}");
+        assert_eq!(points[0].y, points[1].y);
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn underlines_continue_under_emojis_on_every_row() {
+        let link = RichText::new("a link 🔗 that wraps onto the next row 🔗 too").underline();
+        let mut info = ShowInfo::default();
+        let output = run_ui(|ui| {
+            ui.allocate_ui(egui::vec2(150.0, 100.0), |ui| {
+                info = EmojiLabel::new(link.clone()).wrap().show_with_info(ui).1;
+            });
+        });
+
+        let lines = painted_lines(&output);
+        let underlined_text = painted_text_shape(&output, "a link ");
+        let format = &underlined_text.galley.job.sections[0].format;
+        let text_y = underlined_text.pos.y + underlined_text.galley.rows[0].rect.bottom();
+
+        // one line under each emoji, on its own row:
+        let [(_, first), (_, second)] = info.emoji_rects[..] else {
+            panic!("{:?}", info.emoji_rects);
+        };
+        assert!(second.top() > first.center().y);
+        assert_eq!(lines.len(), 2);
+        for ((points, stroke), image) in lines.iter().zip([first, second]) {
+            assert_eq!([points[0].x, points[1].x], [image.left(), image.right()]);
+            assert_eq!(points[0].y, points[1].y);
+            assert!((points[0].y - image.bottom()).abs() < 0.5);
+            assert_eq!(stroke.width, format.underline.width);
+            assert!(stroke.color == egui::epaint::ColorMode::Solid(format.underline.color));
+        }
+        // (the glyphs are rounded to whole pixels)
+        assert!((lines[0].0[0].y - text_y).abs() < 0.5, "{lines:?} {text_y}");
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn code_backgrounds_span_every_segment() {
+        let code = RichText::new("use `fn main()` 🦀 here").code();
+        let (mut rects, mut code_bg) = (Vec::new(), egui::Color32::TRANSPARENT);
+        let output = run_ui(|ui| {
+            code_bg = ui.visuals().code_bg_color;
+            rects = EmojiLabel::new(code.clone()).layout_rects(ui);
+            EmojiLabel::new(code.clone()).show(ui);
+        });
+        let [(before, _), (emoji, _), (after, _)] = &rects[..] else {
+            panic!("{rects:?}");
+        };
+
+        // the emoji's background overlaps the text on both sides, and is as tall as it.
+        // (the label is shown below the rects from `layout_rects`)
+        let backgrounds = filled_rects(&output, code_bg);
+        assert_eq!(backgrounds.len(), 1);
+        let background = backgrounds[0];
+        assert!(background.left() < before.right() && background.right() > after.left());
+        assert_eq!(background.height(), emoji.expand(1.0).height());
+        assert!((emoji.height() - before.height()).abs() < 0.5);
+
+        // and the text segments keep their code styling:
+        let texts: Vec<&egui::epaint::TextShape> = (output.shapes.iter())
+            .filter_map(|clipped| match &clipped.shape {
+                egui::Shape::Text(text)
+                    if matches!(text.galley.text(), "use `fn main()` " | " here") =>
+                {
+                    Some(text)
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(texts.len(), 2);
+        for text in texts {
+            assert_eq!(text.galley.job.sections[0].format.background, code_bg);
+        }
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn response_covers_the_whole_label() {
+        run_ui(|ui| {
+            let above = ui.label("above");
+            let text = "first 😀 line\nsecond 🎉";
+            let resp = EmojiLabel::new(text).show(ui);
+            assert!(resp.rect.min.y >= above.rect.max.y);
+            assert_eq!(resp.rect.min.x, above.rect.min.x);
+            assert!(resp.rect.height() >= 2.0 * ui.text_style_height(&egui::TextStyle::Body));
+            assert_eq!(resp.interact_rect, resp.rect);
+
+            ui.horizontal(|ui| {
+                let before = ui.label("before");
+                let resp = EmojiLabel::new("inline 😀").show(ui);
+                assert!(resp.rect.min.x >= before.rect.max.x);
+                assert_eq!(resp.interact_rect, resp.rect);
+            });
+        });
+    }
+
+    #[test]
+    fn text_without_emojis_is_a_plain_label() {
+        run_ui(|ui| {
+            let plain = ui.label("no emojis here").rect;
+            let label = EmojiLabel::new("no emojis here")
+                .stateless(true)
+                .show(ui)
+                .rect;
+            assert_eq!(plain.size(), label.size());
+        });
+    }
+
+    #[test]
+    fn overlay_contains_the_whole_text() {
+        let output = run_ui(|ui| {
+            EmojiLabel::new("family 👨‍👩‍👧 and flags 🇺🇸🇯🇵").show(ui);
+        });
+        let texts = painted_text(&output);
+        assert!(
+            texts.contains(&"family 👨‍👩‍👧 and flags 🇺🇸🇯🇵".to_owned()),
+            "{texts:?}"
+        );
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn inline_labels_wrap_between_labels() {
+        run_ui(|ui| {
+            ui.allocate_ui(egui::vec2(300.0, 0.0), |ui| {
+                ui.horizontal_wrapped(|ui| {
+                    let item_spacing = ui.spacing().item_spacing;
+                    let rects: Vec<egui::Rect> = (0..20)
+                        .map(|i| EmojiLabel::new(format!("{i} 😀")).show(ui).rect)
+                        .collect();
+                    assert_eq!(ui.spacing().item_spacing, item_spacing);
+
+                    let rows = rects.windows(2).filter(|w| w[1].min.y > w[0].min.y).count() + 1;
+                    assert!((2..10).contains(&rows), "{rows} rows");
+                    assert_eq!(rects[0].min.y, rects[1].min.y);
+                    assert_eq!(rects[1].min.x - rects[0].max.x, item_spacing.x);
+                });
+            });
+        });
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn consecutive_labels_share_rows() {
+        run_ui(|ui| {
+            ui.allocate_ui(egui::vec2(150.0, 0.0), |ui| {
+                ui.horizontal_wrapped(|ui| {
+                    let a =
+                        EmojiLabel::new("a a a 👍 a a a a a a a a a a a a 👍 a a a a a").show(ui);
+                    let (_, b_info) =
+                        EmojiLabel::new("b b b 👍 b b b b b b b b b b b b 👍 b b b b b")
+                            .show_with_info(ui);
+                    assert!(a.rect.height() > ui.text_style_height(&egui::TextStyle::Body) * 2.0);
+                    // the second label continues on the last row of the first one:
+                    let first_b_emoji = b_info.emoji_rects[0].1;
+                    assert!(first_b_emoji.min.y < a.rect.max.y);
+                });
+            });
+        });
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn labels_without_overlay() {
+        let output = run_ui(|ui| {
+            EmojiLabel::new("read only 📖").copyable(false).show(ui);
+        });
+        let texts = painted_text(&output);
+        assert!(!texts.contains(&"read only 📖".to_owned()), "{texts:?}");
+        assert!(texts.contains(&"read only ".to_owned()), "{texts:?}");
+    }
+
+    #[test]
+    fn plain_text_labels_without_overlay() {
+        run_ui(|ui| {
+            // labels without emojis can't be selected either:
+            let resp = EmojiLabel::new("read only").copyable(false).show(ui);
+            assert!(!resp.sense.drag);
+            let resp = EmojiLabel::new("read only").show(ui);
+            assert!(resp.sense.drag);
+        });
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn emoji_only_labels_are_one_line_tall() {
+        run_ui(|ui| {
+            let font_height = ui.text_style_height(&egui::TextStyle::Body);
+            let segments = segment_text::<TwemojiProvider>(&"😀😃😄".into());
+            assert!(segments.iter().all(|s| matches!(s, TextSegment::Emoji(..))));
+
+            let label = EmojiLabel::new("😀😃😄").show(ui).rect;
+            let next = ui.label("next").rect;
+            assert_eq!(label.height(), font_height);
+            let gap = next.top() - label.bottom();
+            assert!((gap - ui.spacing().item_spacing.y).abs() < 0.01, "{gap}");
+        });
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn jumbo_emoji_only_labels() {
+        run_ui(|ui| {
+            let font_height = ui.text_style_height(&egui::TextStyle::Body);
+            let size = |text| EmojiLabel::new(text).jumbo_if_emoji_only(3.0).measure(ui);
+            assert_eq!(size("🎉").y, font_height * 3.0);
+            assert_eq!(size(" 🎉 🎉 ").y, font_height * 3.0);
+            assert!(size("yay 🎉").y < font_height * 2.0);
+        });
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn emoji_size_scale_is_clamped() {
+        run_ui(|ui| {
+            let font_height = ui.text_style_height(&egui::TextStyle::Body);
+            let size = |scale| EmojiLabel::new("🎉").emoji_size_scale(scale).measure(ui);
+            assert_eq!(size(1.5).y, font_height * 1.5);
+            assert_eq!(size(100.0).y, font_height * 8.0);
+            assert_eq!(size(0.0).x, font_height * 0.25);
+        });
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn labels_use_the_options_of_the_context() {
+        run_ui(|ui| {
+            let font_height = ui.text_style_height(&egui::TextStyle::Body);
+            EmojiLabelOptions {
+                emoji_scale: 2.0,
+                ..Default::default()
+            }
+            .store(ui.ctx());
+            assert_eq!(EmojiLabel::new("🎉").measure(ui).y, font_height * 2.0);
+            let overridden = EmojiLabel::new("🎉").emoji_size_scale(1.0).measure(ui);
+            assert_eq!(overridden.y, font_height);
+
+            // a label that isn't inline is a block of its own, so the next widget goes next to it:
+            EmojiLabelOptions {
+                auto_inline: false,
+                ..Default::default()
+            }
+            .store(ui.ctx());
+            ui.horizontal_wrapped(|ui| {
+                const LINES: &str = "line 1 🅰\nline 2 🅱";
+                let block = EmojiLabel::new(LINES).show(ui).rect;
+                let next = ui.label("next").rect;
+                assert!(next.min.x >= block.max.x && next.min.y < block.center().y);
+
+                ui.end_row();
+                let inline = EmojiLabel::new(LINES).auto_inline(true).show(ui).rect;
+                let next = ui.label("next").rect;
+                assert!(next.min.y > inline.center().y, "{inline:?} {next:?}");
+            });
+
+            assert!(!EmojiLabelOptions::load(ui.ctx()).auto_inline);
+        });
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn labels_keep_their_config() {
+        let config = EmojiLabelConfig {
+            wrap_mode: Some(TextWrapMode::Truncate),
+            selectable: Some(false),
+            auto_inline: false,
+            emoji_scale: 1.5,
+            emoji_spacing: 4.0,
+        };
+        assert_eq!(EmojiLabel::with_config("🎉", config).config(), config);
+        assert_eq!(EmojiLabel::new("🎉").config(), EmojiLabelConfig::default());
+
+        run_ui(|ui| {
+            let plain = EmojiLabel::new("a🎉b").measure(ui);
+            let spaced = EmojiLabel::new("a🎉b").emoji_spacing(4.0).measure(ui);
+            assert!(
+                (spaced.x - plain.x - 8.0).abs() < 0.5,
+                "{plain:?} {spaced:?}"
+            );
+        });
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn configs_are_deserialized_with_defaults() {
+        let config: EmojiLabelConfig = serde_json::from_str(r#"{"emoji_scale": 2.0}"#).unwrap();
+        let expected = EmojiLabelConfig {
+            emoji_scale: 2.0,
+            ..Default::default()
+        };
+        assert_eq!(config, expected);
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(
+            serde_json::from_str::<EmojiLabelConfig>(&json).unwrap(),
+            config
+        );
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn emojis_are_as_tall_as_their_text() {
+        run_ui(|ui| {
+            let big = EmojiLabel::new(RichText::new("big 😀").size(32.0))
+                .show_with_info(ui)
+                .1;
+            let heading = EmojiLabel::heading("heading 😀").show_with_info(ui).1;
+            let body = EmojiLabel::new("body 😀").show_with_info(ui).1;
+
+            let emoji_height = |info: &ShowInfo| info.emoji_rects[0].1.height();
+            let heading_height = ui.text_style_height(&egui::TextStyle::Heading);
+            assert!(emoji_height(&big) > 32.0);
+            assert!((emoji_height(&heading) - heading_height).abs() < 0.01);
+            assert!(emoji_height(&body) < heading_height);
+        });
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn emojis_follow_the_text_style() {
+        run_ui(|ui| {
+            for text_style in [
+                egui::TextStyle::Heading,
+                egui::TextStyle::Monospace,
+                egui::TextStyle::Small,
+                egui::TextStyle::Button,
+            ] {
+                let text = RichText::new("styled 😀").text_style(text_style.clone());
+                let (_, info) = EmojiLabel::new(text).show_with_info(ui);
+                let expected = ui.text_style_height(&text_style);
+                let height = info.emoji_rects[0].1.height();
+                assert!((height - expected).abs() < 0.01, "{text_style:?}: {height}");
+            }
+
+            // every span sizes its emojis for its own style:
+            let spans = [
+                RichText::new("big 🚀").heading(),
+                RichText::new(" small 🐜").small(),
+            ];
+            let (_, info) = EmojiLabel::from_spans(spans).show_with_info(ui);
+            let heights: Vec<f32> = info.emoji_rects.iter().map(|(_, r)| r.height()).collect();
+            let heading = ui.text_style_height(&egui::TextStyle::Heading);
+            let small = ui.text_style_height(&egui::TextStyle::Small);
+            assert!((heights[0] - heading).abs() < 0.01 && (heights[1] - small).abs() < 0.01);
+
+            // text without a style of its own uses the one of the ui:
+            ui.style_mut().override_text_style = Some(egui::TextStyle::Heading);
+            let (_, info) = EmojiLabel::new("unstyled 😀").show_with_info(ui);
+            let expected = ui.text_style_height(&egui::TextStyle::Heading);
+            assert!((info.emoji_rects[0].1.height() - expected).abs() < 0.01);
+        });
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn emojis_line_up_with_their_text() {
+        run_ui(|ui| {
+            let body_height = ui.text_style_height(&egui::TextStyle::Body);
+            for align in [egui::Align::Min, egui::Align::Center, egui::Align::Max] {
+                ui.with_layout(Layout::left_to_right(align), |ui| {
+                    // emojis are centered in a tall line:
+                    let text = RichText::new("tall 😀 line").line_height(Some(40.0));
+                    let (resp, info) = EmojiLabel::new(text).show_with_info(ui);
+                    let emoji = info.emoji_rects[0].1;
+                    assert_eq!(resp.rect.height(), 40.0, "{align:?}");
+                    assert!(
+                        (emoji.center().y - resp.rect.center().y).abs() < 0.01,
+                        "{align:?}"
+                    );
+
+                    // and small emojis are centered on the glyphs:
+                    let (resp, info) = EmojiLabel::new("small 😀 emoji")
+                        .emoji_size_scale(0.5)
+                        .show_with_info(ui);
+                    let emoji = info.emoji_rects[0].1;
+                    let glyph_center = resp.rect.top() + body_height / 2.0;
+                    assert!((emoji.center().y - glyph_center).abs() < 0.01, "{align:?}");
+                });
+            }
+        });
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn line_height_spaces_lines_evenly() {
+        run_ui(|ui| {
+            let text =
+                RichText::new("text 😀\n😀😀\nonly text\n😀 and text").line_height(Some(28.0));
+            let rects = EmojiLabel::new(text).layout_rects(ui);
+            assert!(
+                rects.iter().all(|(rect, _)| rect.height() == 28.0),
+                "{rects:?}"
+            );
+
+            let mut tops: Vec<f32> = rects.iter().map(|(rect, _)| rect.top()).collect();
+            tops.dedup();
+            let gaps: Vec<f32> = tops.windows(2).map(|tops| tops[1] - tops[0]).collect();
+            assert_eq!(gaps.len(), 3);
+            assert!(gaps.iter().all(|gap| *gap == gaps[0]), "{gaps:?}");
+        });
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn line_height_applies_to_every_span() {
+        run_ui(|ui| {
+            let label = || {
+                EmojiLabel::from_spans([RichText::new("a 😀\n"), RichText::new("b")])
+                    .line_height(30.0)
+            };
+            let rects = label().layout_rects(ui);
+            assert_eq!(rects.len(), 3, "{rects:?}");
+            assert!(
+                rects.iter().all(|(rect, _)| rect.height() == 30.0),
+                "{rects:?}"
+            );
+        });
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn emojis_are_centered_in_tall_lines() {
+        for single_galley in [false, true] {
+            run_ui(|ui| {
+                let text = RichText::new("text 😀\n😀 and text").line_height(Some(36.0));
+                let label = || EmojiLabel::new(text.clone()).single_galley(single_galley);
+                let rows: Vec<egui::Rect> = (label().layout_rects(ui).into_iter())
+                    .filter(|(_, segment)| matches!(segment, TextSegment::Text(_)))
+                    .map(|(rect, _)| rect)
+                    .collect();
+                let (resp, info) = label().show_with_info(ui);
+                // the rows were laid out above the label:
+                let offset = egui::vec2(0.0, resp.rect.top() - rows[0].top());
+                assert_eq!(info.emoji_rects.len(), 2);
+                for ((_, emoji), row) in info.emoji_rects.iter().zip(&rows) {
+                    let row = row.translate(offset);
+                    assert_eq!(row.height(), 36.0, "single galley: {single_galley}");
+                    assert!(
+                        (emoji.center().y - row.center().y).abs() < 0.01,
+                        "single galley: {single_galley}, {emoji:?} {row:?}"
+                    );
+                }
+            });
+        }
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn raised_emojis_match_their_text() {
+        for single_galley in [false, true] {
+            for raised in [false, true] {
+                let small = RichText::new("x🎉").small();
+                let small = if raised { small.raised() } else { small };
+                let mut emoji = egui::Rect::NOTHING;
+                let output = run_ui(|ui| {
+                    let label =
+                        EmojiLabel::from_spans([RichText::new("big").size(28.0), small.clone()]);
+                    emoji = label
+                        .single_galley(single_galley)
+                        .show_with_info(ui)
+                        .1
+                        .emoji_rects[0]
+                        .1;
+                });
+                // the "x" in front of the emoji, which is in the last text shape that contains it
+                // (as the selection overlay is on top of everything):
+                let x = (output.shapes.iter().rev())
+                    .find_map(|clipped| match &clipped.shape {
+                        egui::Shape::Text(text) => (text.galley.rows.iter())
+                            .flat_map(|row| &row.glyphs)
+                            .find(|glyph| glyph.chr == 'x')
+                            .map(|glyph| glyph.logical_rect().translate(text.pos.to_vec2())),
+                        _ => None,
+                    })
+                    .unwrap();
+                let case = format!("single galley: {single_galley}, raised: {raised}");
+                assert!(
+                    (emoji.height() - x.height()).abs() < 0.5,
+                    "{case} {emoji:?} {x:?}"
+                );
+                assert!(
+                    (emoji.top() - x.top()).abs() < 1.0,
+                    "{case} {emoji:?} {x:?}"
+                );
+            }
+        }
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn letter_spacing_pads_emojis() {
+        for single_galley in [false, true] {
+            run_ui(|ui| {
+                let text = RichText::new("airy 🎈 text").extra_letter_spacing(4.0);
+                let label = || EmojiLabel::new(text.clone()).single_galley(single_galley);
+                let measured = label().measure(ui);
+                let slot = ui.scope(|ui| label().layout_rects(ui)).inner[1].0;
+                let (resp, info) = label().show_with_info(ui);
+                let image = info.emoji_rects[0].1;
+
+                assert!((measured - resp.rect.size()).length() < 0.5, "{measured:?}");
+                // the slot is laid out above the shown label, but in the same columns:
+                assert!(
+                    (image.left() - slot.left() - 4.0).abs() < 1.0,
+                    "{slot:?} {image:?}"
+                );
+                assert!(
+                    (slot.width() - image.width() - 8.0).abs() < 1.0,
+                    "{slot:?} {image:?}"
+                );
+            });
+        }
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn newlines_break_lines() {
+        let segments = segment_text::<TwemojiProvider>(&"line1 😀\nline2 🎉\r\n".into());
+        assert_eq!(
+            segments,
+            vec![
+                TextSegment::Text("line1 ".into()),
+                TextSegment::Emoji("😀".into(), RichText::default()),
+                TextSegment::Newline,
+                TextSegment::Text("line2 ".into()),
+                TextSegment::Emoji("🎉".into(), RichText::default()),
+                TextSegment::Newline,
+            ]
+        );
+
+        for wrap_mode in [TextWrapMode::Wrap, TextWrapMode::Extend] {
+            let output = run_ui(|ui| {
+                EmojiLabel::new("line1 😀\nline2 🎉")
+                    .wrap_mode(wrap_mode)
+                    .show(ui);
+            });
+            let top_of = |text: &str| {
+                output
+                    .shapes
+                    .iter()
+                    .find_map(|clipped| match &clipped.shape {
+                        egui::Shape::Text(shape) if shape.galley.text() == text => {
+                            Some(shape.pos.y)
+                        }
+                        _ => None,
+                    })
+            };
+            assert!(top_of("line2 ").unwrap() > top_of("line1 ").unwrap());
+        }
+    }
+
+    #[test]
+    fn id_salt_separates_state() {
+        run_ui(|ui| {
+            let main = EmojiLabel::new("pinned 📌").id_salt("main").show(ui);
+            let pinned = EmojiLabel::new("pinned 📌").id_salt("pinned").show(ui);
+            assert_ne!(main.id, pinned.id);
+
+            let main_cache = EmojiLabel::new("pinned 📌").id_salt("main").cache_id();
+            let pinned_cache = EmojiLabel::new("pinned 📌").id_salt("pinned").cache_id();
+            assert!(is_cached(ui.ctx(), main_cache));
+            assert!(is_cached(ui.ctx(), pinned_cache));
+        });
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn salted_labels_follow_text_changes() {
+        let ctx = egui::Context::default();
+        for text in ["hello 👋", "hello 🌍"] {
+            let _ = ctx.run(egui::RawInput::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    EmojiLabel::new(text).id_salt("message").show(ui);
+                });
+            });
+        }
+
+        let cache_id = EmojiLabel::new("").id_salt("message").cache_id();
+        let segments = cached_segments(&ctx, cache_id);
+        assert!(segments.iter().any(|segment| segment.as_str() == "🌍"));
+        assert!(!segments.iter().any(|segment| segment.as_str() == "👋"));
+        assert_eq!(SegmentCache::with(&ctx, |cache| cache.states.len()), 1);
+    }
+
+    #[test]
+    fn labels_with_ids_are_salted() {
+        let id = egui::Id::new("message");
+        let with_id = EmojiLabel::new_with_id("live 🔴", id);
+        assert_eq!(
+            with_id.cache_id(),
+            EmojiLabel::new("live 🔴").id_salt(id).cache_id()
+        );
+        // the response id stays the same while the text changes between frames:
+        let ctx = egui::Context::default();
+        let ids: Vec<egui::Id> = ["live 🔴", "live 🟢"]
+            .into_iter()
+            .map(|text| {
+                let mut resp_id = egui::Id::NULL;
+                let _ = ctx.run(egui::RawInput::default(), |ctx| {
+                    egui::CentralPanel::default().show(ctx, |ui| {
+                        resp_id = EmojiLabel::new_with_id(text, id).show(ui).id;
+                    });
+                });
+                resp_id
+            })
+            .collect();
+        assert_eq!(ids[0], ids[1]);
+    }
+
+    #[cfg(all(
+        feature = "serde",
+        any(feature = "svg", feature = "png", feature = "both")
+    ))]
+    #[test]
+    fn deserialized_cache_renders_the_same() {
+        let text = RichText::new("saved 💾 and\nrestored ♻").italics();
+        let show = |ctx: &egui::Context| {
+            ctx.run(egui::RawInput::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    EmojiLabel::new(text.clone()).show(ui);
+                });
+            })
+        };
+
+        let fresh = egui::Context::default();
+        let fresh_output = show(&fresh);
+        let json = SegmentCache::with(&fresh, |cache| serde_json::to_string(cache)).unwrap();
+
+        let restored = egui::Context::default();
+        let cache: SegmentCache = serde_json::from_str(&json).unwrap();
+        SegmentCache::with(&restored, |restored_cache| *restored_cache = cache);
+        let cache_id = EmojiLabel::new(text.clone()).cache_id();
+        let segments = cached_segments(&restored, cache_id);
+        assert_eq!(segments, cached_segments(&fresh, cache_id));
+        match &segments[1] {
+            TextSegment::Emoji(emoji, _) => assert!(Arc::ptr_eq(emoji, &intern_emoji("💾"))),
+            _ => unreachable!(),
+        }
+
+        assert!(show(&restored).shapes == fresh_output.shapes);
+    }
+
+    #[test]
+    fn cache_keys_replace_the_text_hash() {
+        let first = EmojiLabel::new("first 🍎").with_cache_key(7);
+        let second = EmojiLabel::new("second 🍌").with_cache_key(7);
+        assert_eq!(first.cache_id(), second.cache_id());
+        assert_ne!(first.cache_id(), EmojiLabel::new("first 🍎").cache_id());
+        assert_ne!(first.cache_id(), first.with_cache_key(8).cache_id());
+    }
+
+    #[test]
+    fn stale_states_are_evicted() {
+        let ctx = egui::Context::default();
+        for frame in 0..200 {
+            let _ = ctx.run(egui::RawInput::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    for i in 0..50 {
+                        EmojiLabel::new(format!("line {frame}/{i} 📜")).show(ui);
+                    }
+                });
+            });
+        }
+        let cached = SegmentCache::with(&ctx, |cache| cache.states.len());
+        assert!(cached <= 50 * (MAX_UNUSED_PASSES as usize + 1), "{cached}");
+
+        // no labels are shown anymore, so nothing triggers the automatic eviction:
+        for _ in 0..=MAX_UNUSED_PASSES {
+            let _ = ctx.run(egui::RawInput::default(), |_| {});
+        }
+        EmojiLabel::gc(&ctx);
+        assert_eq!(SegmentCache::with(&ctx, |cache| cache.states.len()), 0);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn clear_cache_removes_everything() {
+        let ctx = egui::Context::default();
+        let show = || {
+            ctx.run(egui::RawInput::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    EmojiLabel::new("first 🥇").show(ui);
+                    EmojiLabel::new("second 🥈").show(ui);
+                });
+            })
+        };
+        let _ = show();
+        assert_eq!(SegmentCache::with(&ctx, |cache| cache.states.len()), 2);
+        EmojiLabel::clear_cache(&ctx);
+        assert_eq!(SegmentCache::with(&ctx, |cache| cache.states.len()), 0);
+
+        let _ = show();
+        EmojiLabel::forget_all(&ctx);
+        assert_eq!(SegmentCache::with(&ctx, |cache| cache.states.len()), 0);
+    }
+
+    #[test]
+    fn forget_removes_one_text() {
+        let ctx = egui::Context::default();
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                EmojiLabel::new("first 🥇").show(ui);
+                EmojiLabel::strong("first 🥇").show(ui);
+                EmojiLabel::new("first 🥇").id_salt("salted").show(ui);
+                EmojiLabel::new("second 🥈").show(ui);
+            });
+        });
+        assert_eq!(SegmentCache::with(&ctx, |cache| cache.states.len()), 4);
+
+        EmojiLabel::forget(&ctx, "first 🥇");
+        assert_eq!(SegmentCache::with(&ctx, |cache| cache.states.len()), 1);
+        assert!(is_cached(&ctx, EmojiLabel::new("second 🥈").cache_id()));
+    }
+
+    #[test]
+    fn only_visible_rows_are_cached() {
+        let ctx = egui::Context::default();
+        let mut heights = Vec::new();
+        let input = egui::RawInput {
+            screen_rect: Some(egui::Rect::from_min_size(
+                egui::Pos2::ZERO,
+                egui::vec2(400.0, 600.0),
+            )),
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let row_height = ui.text_style_height(&egui::TextStyle::Body);
+                egui::ScrollArea::vertical().show_rows(ui, row_height, 10_000, |ui, rows| {
+                    for i in rows {
+                        let label = EmojiLabel::new(format!("message {i} 🎉"));
+                        let rect = label.with_cache_key(i as u64).truncate().show(ui).rect;
+                        heights.push(rect.height() - row_height);
+                    }
+                });
+            });
+        });
+        let cached = SegmentCache::with(&ctx, |cache| cache.states.len());
+        assert_eq!(cached, heights.len());
+        assert!(cached < 100, "{cached}");
+        // rows with emojis are as tall as `show_rows` expects:
+        assert!(heights.iter().all(|extra| extra.abs() < 0.5), "{heights:?}");
+    }
+
+    #[test]
+    fn cache_capacity_evicts_least_recently_used() {
+        let ctx = egui::Context::default();
+        EmojiLabel::set_cache_capacity(&ctx, 2);
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                EmojiLabel::new("first 🥇").show(ui);
+                EmojiLabel::new("second 🥈").show(ui);
+                EmojiLabel::new("first 🥇").show(ui);
+                EmojiLabel::new("third 🥉").show(ui);
+            });
+        });
+
+        assert_eq!(SegmentCache::with(&ctx, |cache| cache.states.len()), 2);
+        assert!(is_cached(&ctx, EmojiLabel::new("first 🥇").cache_id()));
+        assert!(!is_cached(&ctx, EmojiLabel::new("second 🥈").cache_id()));
+        assert!(is_cached(&ctx, EmojiLabel::new("third 🥉").cache_id()));
+
+        EmojiLabel::set_cache_capacity(&ctx, 1);
+        assert!(!is_cached(&ctx, EmojiLabel::new("first 🥇").cache_id()));
+        assert!(is_cached(&ctx, EmojiLabel::new("third 🥉").cache_id()));
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn cache_entries_are_in_lru_order() {
+        let ctx = egui::Context::default();
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                EmojiLabel::new("first 🥇").show(ui);
+                EmojiLabel::new("second 🥈").show(ui);
+                EmojiLabel::new("first 🥇").show(ui);
+                EmojiLabel::new("third 🥉").show(ui);
+            });
+        });
+        let texts = |ctx: &egui::Context| -> Vec<egui::Id> {
+            (EmojiLabel::cache_entries(ctx).iter())
+                .map(|entry| entry.text_id)
+                .collect()
+        };
+        let ids = ["second 🥈", "first 🥇", "third 🥉"].map(egui::Id::new);
+        assert_eq!(texts(&ctx), ids);
+        assert!((EmojiLabel::cache_entries(&ctx).iter())
+            .all(|entry| entry.segments == 2 && entry.last_used_pass == 0));
+
+        EmojiLabel::forget(&ctx, "first 🥇");
+        assert_eq!(texts(&ctx), [ids[0], ids[2]]);
+
+        // the order isn't persisted, so restored states come first, in no particular order:
+        SegmentCache::with(&ctx, |cache| cache.lru.clear());
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                EmojiLabel::new("first 🥇").show(ui);
+            });
+        });
+        assert_eq!(texts(&ctx).len(), 3);
+        EmojiLabel::set_cache_capacity(&ctx, 1);
+        assert_eq!(texts(&ctx), [ids[1]]);
+    }
+
+    #[test]
+    fn custom_provider_segmentation() {
+        struct CrabOnly;
+        impl EmojiAssetProvider for CrabOnly {
+            fn lookup(emoji: &str) -> Option<egui::ImageSource<'static>> {
+                (emoji == "🦀").then(|| egui::ImageSource::Uri("crab.png".into()))
+            }
+        }
+
+        let segments = segment_text::<CrabOnly>(&RichText::new("🦀 and 😀"));
+        assert_eq!(
+            segments,
+            vec![
+                TextSegment::Emoji("🦀".into(), RichText::default()),
+                TextSegment::Text(" and 😀".into()),
+            ]
+        );
+        assert_ne!(
+            EmojiLabel::<CrabOnly>::with_provider("🦀").cache_id(),
+            EmojiLabel::new("🦀").cache_id()
+        );
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn emojis_past_the_limit_are_shown_as_text() {
+        let output = run_ui(|ui| {
+            EmojiLabel::new("spam 🍖🍖🍖🍖")
+                .max_rendered_emoji(2)
+                .show(ui);
+        });
+        let texts = painted_text(&output);
+        assert_eq!(
+            texts.iter().filter(|text| *text == "⚠").count(),
+            2,
+            "{texts:?}"
+        );
+        assert_eq!(
+            texts.iter().filter(|text| *text == "🍖").count(),
+            2,
+            "{texts:?}"
+        );
+    }
+
+    #[test]
+    fn hovered_emojis_zoom_without_moving_anything() {
+        let ctx = egui::Context::default();
+        let mut rects: Vec<egui::Rect> = Vec::new();
+        let mut largest_image = 0.0f32;
+        for frame in 0..20 {
+            let mut input = egui::RawInput {
+                time: Some(frame as f64 * 0.1),
+                ..Default::default()
+            };
+            if let Some(&rect) = rects.first() {
+                input.events.push(egui::Event::PointerMoved(rect.center()));
+            }
+            let output = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let label = EmojiLabel::<FontTexture>::with_provider("🔍").hover_zoom(2.0);
+                    rects.push(label.show(ui).rect);
+                });
+            });
+            for image in painted_images(&output) {
+                largest_image = largest_image.max(image.rect.width());
+            }
+        }
+
+        assert!(rects.iter().all(|rect| *rect == rects[0]), "{rects:?}");
+        assert_eq!(largest_image, rects[0].width() * 2.0);
+    }
+
+    #[test]
+    fn emoji_images_can_be_rounded() {
+        for rounding in [0.0, 4.0] {
+            let output = run_ui(|ui| {
+                EmojiLabel::<FontTexture>::with_provider("🖼")
+                    .emoji_rounding(rounding)
+                    .show(ui);
+            });
+            let roundings: Vec<egui::Rounding> = (painted_images(&output).iter())
+                .map(|image| image.rounding)
+                .collect();
+            assert_eq!(roundings, [egui::Rounding::same(rounding)]);
+        }
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn emojis_can_be_wrapped_in_boxes() {
+        for single_galley in [false, true] {
+            let mut image = egui::Rect::NOTHING;
+            let mut expected_box = (egui::Rect::NOTHING, egui::Color32::TRANSPARENT);
+            let output = run_ui(|ui| {
+                let (_, info) = EmojiLabel::new("boxed 🎁")
+                    .single_galley(single_galley)
+                    .wrap_emoji_in_box(true)
+                    .show_with_info(ui);
+                image = info.emoji_rects[0].1;
+                let fill = ui
+                    .visuals()
+                    .widgets
+                    .noninteractive
+                    .bg_fill
+                    .gamma_multiply(0.5);
+                expected_box = (image.expand(ui.spacing().button_padding.x), fill);
+            });
+            let boxes: Vec<_> = (output.shapes.iter())
+                .filter_map(|clipped| match &clipped.shape {
+                    egui::Shape::Rect(rect) if rect.rounding == egui::Rounding::same(4.0) => {
+                        Some((rect.rect, rect.fill))
+                    }
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(boxes, [expected_box], "single galley: {single_galley}");
+            assert_eq!(expected_box.0.center(), image.center());
+        }
+    }
+
+    #[test]
+    fn emojis_fade_with_their_ui() {
+        // zoomed emojis are painted on a layer of their own, which has to fade as well:
+        for hover_zoom in [None, Some(2.0)] {
+            let ctx = egui::Context::default();
+            let input = egui::RawInput {
+                events: vec![egui::Event::PointerMoved(egui::pos2(12.0, 12.0))],
+                ..Default::default()
+            };
+            let output = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    ui.multiply_opacity(0.5);
+                    let label = EmojiLabel::<FontTexture>::with_provider("🖼 a");
+                    match hover_zoom {
+                        Some(zoom) => label.hover_zoom(zoom),
+                        None => label,
+                    }
+                    .show(ui);
+                });
+            });
+            let tints: Vec<egui::Color32> = (painted_images(&output).iter())
+                .map(|image| image.fill)
+                .collect();
+            assert_eq!(
+                tints,
+                [egui::Color32::WHITE.gamma_multiply(0.5)],
+                "{hover_zoom:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn emojis_are_tinted_and_dimmed_when_disabled() {
+        let sepia = egui::Color32::from_rgb(255, 230, 190);
+        for (enabled, tint) in [
+            (true, None),
+            (false, None),
+            (true, Some(sepia)),
+            (false, Some(sepia)),
+        ] {
+            let output = run_ui(|ui| {
+                ui.add_enabled_ui(enabled, |ui| {
+                    let label = EmojiLabel::<FontTexture>::with_provider("a 🖼");
+                    match tint {
+                        Some(tint) => label.emoji_tint(tint),
+                        None => label,
+                    }
+                    .show(ui);
+                });
+            });
+            let tints: Vec<egui::Color32> = (painted_images(&output).iter())
+                .map(|image| image.fill)
+                .collect();
+            let tint = tint.unwrap_or(egui::Color32::WHITE);
+            let expected = match enabled {
+                true => tint,
+                false => tint.gamma_multiply(0.5),
+            };
+            assert_eq!(tints, [expected]);
+        }
+    }
+
+    #[test]
+    fn wide_emojis_keep_their_aspect_ratio() {
+        struct Banners;
+        impl EmojiAssetProvider for Banners {
+            fn lookup(_emoji: &str) -> Option<egui::ImageSource<'static>> {
+                let texture =
+                    egui::load::SizedTexture::new(egui::TextureId::default(), [30.0, 10.0]);
+                Some(egui::ImageSource::Texture(texture))
+            }
+
+            fn is_emoji(emoji: &str) -> bool {
+                emoji == "🏳"
+            }
+        }
+
+        run_ui(|ui| {
+            let emoji_size = ui.text_style_height(&egui::TextStyle::Body);
+            for (fit, width) in [
+                (EmojiFit::ExactSquare, emoji_size),
+                (EmojiFit::HeightPreserveAspect, emoji_size * 3.0),
+            ] {
+                let label = || EmojiLabel::<Banners>::with_provider("🏳").emoji_fit(fit);
+                assert_eq!(label().measure(ui).x, width);
+                let (_, info) = label().show_with_info(ui);
+                assert_eq!(info.emoji_rects[0].1.size(), egui::vec2(width, emoji_size));
+            }
+        });
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn wrapped_text_flows_like_a_paragraph() {
+        let text = "This is a long sentence 😀 that keeps going and going and going";
+        run_ui(|ui| {
+            ui.allocate_ui(egui::vec2(200.0, 0.0), |ui| {
+                let (resp, info) = EmojiLabel::new(text).show_with_info(ui);
+                let plain = ui.label(text);
+                // the text after the emoji continues on the same row, instead of in its own box:
+                assert_eq!(resp.rect.height(), plain.rect.height());
+                assert_eq!(info.emoji_rects[0].1.min.y, resp.rect.min.y);
+                assert!(info.emoji_rects[0].1.min.x > resp.rect.min.x);
+            });
+        });
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn emojis_without_images_are_shown_as_text() {
+        // claims to know every emoji, but only has an image for crabs:
+        struct MissingImages;
+        impl EmojiAssetProvider for MissingImages {
+            fn lookup(emoji: &str) -> Option<egui::ImageSource<'static>> {
+                (emoji == "🦀").then(|| egui::ImageSource::Uri("crab.png".into()))
+            }
+            fn is_emoji(emoji: &str) -> bool {
+                TwemojiProvider::is_emoji(emoji)
+            }
+        }
+
+        let output = run_ui(|ui| {
+            EmojiLabel::<MissingImages>::with_provider("🦀 and 🦄").show(ui);
+        });
+        let texts = painted_text(&output);
+        assert!(texts.contains(&"🦄".to_owned()), "{texts:?}");
+        assert_eq!(texts.iter().filter(|text| *text == "⚠").count(), 1);
+    }
+
+    #[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+    #[test]
+    fn missing_loaders_are_detected() {
+        let ctx = egui::Context::default();
+        let source = TwemojiProvider::lookup("🔥").unwrap();
+        let Err(err) = egui::Image::new(source).load_for_size(&ctx, egui::vec2(16.0, 16.0)) else {
+            panic!("no image loaders are installed in tests");
+        };
+        assert!(is_missing_loader(&err), "{err:?}");
+        assert!(!is_missing_loader(&egui::load::LoadError::Loading(
+            "File not found".into()
+        )));
+    }
+
+    #[cfg(all(
+        feature = "shortcodes",
+        any(feature = "svg", feature = "png", feature = "both")
+    ))]
+    #[test]
+    fn label_expands_shortcodes() {
+        let state = EmojiLabel::new("nice :fire:").shortcodes(true).segment();
         assert!(
-            segments
-                == vec![
-                    TextSegment::Text("Hello world ".into()),
-                    TextSegment::Emoji("🥰".to_owned()),
-                    TextSegment::Text("!".into())
+            *state.segments
+                == [
+                    TextSegment::Text("nice ".into()),
+                    TextSegment::Emoji("🔥".into(), RichText::default()),
                 ]
         );
     }
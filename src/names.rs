@@ -0,0 +1,114 @@
+//! The names of emojis, for the tooltips of the `unicode-names` feature.
+//!
+//! Despite the name of the feature, these are the [Emojibase](https://github.com/milesj/emojibase)
+//! labels, which follow the Unicode CLDR short names, and not the character names of the Unicode
+//! standard: "❤️" is a "RED HEART", not a "HEAVY BLACK HEART".
+
+use crate::table::EMOJIS;
+use std::{borrow::Cow, collections::HashMap, sync::OnceLock};
+
+/// A map from every emoji to its label, built the first time it is needed.
+fn names() -> &'static HashMap<&'static str, &'static str> {
+    static NAMES: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+    NAMES.get_or_init(|| {
+        let mut names = HashMap::with_capacity(EMOJIS.len());
+        for &(emoji, label, _) in EMOJIS.iter().filter(|&&(_, label, _)| !label.is_empty()) {
+            // the first label of an emoji wins, as some emojis are listed more than once
+            names.entry(emoji).or_insert(label);
+        }
+        names
+    })
+}
+
+/// The tooltip of `emoji`: the emoji, its code points and its name, e.g.
+/// `"😂 U+1F602 FACE WITH TEARS OF JOY"`.
+///
+/// The name is the [Emojibase](https://github.com/milesj/emojibase) label of the whole emoji, so
+/// sequences like flags get one name instead of the names of each of their code points.
+pub(crate) fn hover_text(emoji: &str) -> String {
+    let mut text = emoji.to_owned();
+    for c in emoji.chars() {
+        text.push_str(&format!(" U+{:04X}", c as u32));
+    }
+    if let Some(name) = emoji_name(emoji) {
+        text.push(' ');
+        text.push_str(&name.to_uppercase());
+    }
+    text
+}
+
+/// The names of the skin tone modifiers, U+1F3FB to U+1F3FF.
+const TONES: [&str; 5] = [
+    "light skin tone",
+    "medium-light skin tone",
+    "medium skin tone",
+    "medium-dark skin tone",
+    "dark skin tone",
+];
+
+/// The name of the skin tone modifier `c`, if it is one.
+fn tone_name(c: char) -> Option<&'static str> {
+    let index = (c as u32).checked_sub(0x1F3FB)?;
+    TONES.get(index as usize).copied()
+}
+
+/// The label of `emoji`, which is looked up without its variation selectors if it has none
+/// of its own.
+///
+/// Skin tone variants without a label of their own are named after their base emoji and their
+/// tones, like Emojibase does: `"👍🏽"` is a `"thumbs up: medium skin tone"`.
+fn emoji_name(emoji: &str) -> Option<Cow<'static, str>> {
+    let names = names();
+    let find = |emoji: &str| {
+        (names.get(emoji))
+            .or_else(|| names.get(emoji.replace('\u{fe0f}', "").as_str()))
+            .copied()
+    };
+    if let Some(name) = find(emoji) {
+        return Some(name.into());
+    }
+
+    let mut tones: Vec<&str> = Vec::new();
+    let mut base = String::with_capacity(emoji.len());
+    for c in emoji.chars() {
+        match tone_name(c) {
+            Some(tone) if !tones.contains(&tone) => tones.push(tone),
+            Some(_) => {}
+            None => base.push(c),
+        }
+    }
+    if tones.is_empty() {
+        return None;
+    }
+    find(&base).map(|name| format!("{name}: {}", tones.join(", ")).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hover_text_names_the_emoji() {
+        assert_eq!(hover_text("😂"), "😂 U+1F602 FACE WITH TEARS OF JOY");
+        assert_eq!(hover_text("🇺🇸"), "🇺🇸 U+1F1FA U+1F1F8 FLAG: UNITED STATES");
+        assert!(hover_text("❤️").ends_with(" U+2764 U+FE0F RED HEART"));
+        assert_eq!(hover_text("a"), "a U+0061");
+    }
+
+    #[test]
+    fn hover_text_names_skin_tones_and_keycaps() {
+        assert_eq!(
+            hover_text("👍🏽"),
+            "👍🏽 U+1F44D U+1F3FD THUMBS UP: MEDIUM SKIN TONE"
+        );
+        assert_eq!(hover_text("©"), "© U+00A9 COPYRIGHT");
+        assert_eq!(hover_text("©️"), "©️ U+00A9 U+FE0F COPYRIGHT");
+        assert!(hover_text("0️⃣").ends_with(" KEYCAP: 0"));
+        // not in the table, but named after the base emoji:
+        assert_eq!(
+            emoji_name("👍🏽🏽").as_deref(),
+            Some("thumbs up: medium skin tone")
+        );
+        assert_eq!(emoji_name("a🏿"), None);
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{provider::sized_source, table::EMOJIS, EmojiAssetProvider, TwemojiProvider};
+use std::collections::HashSet;
+
+/// The emojis that were already preloaded into an [`egui::Context`].
+#[derive(Clone, Default)]
+struct Preloaded {
+    all: bool,
+    emojis: HashSet<String>,
+}
+
+impl Preloaded {
+    fn with<R>(ctx: &egui::Context, f: impl FnOnce(&mut Self) -> R) -> R {
+        ctx.data_mut(|d| f(d.get_temp_mut_or_default(egui::Id::new("egui_twemoji::preloaded"))))
+    }
+}
+
+/// Load the images of `emojis` now, instead of the first time they're shown.
+///
+/// Decoding the SVG or PNG data of an emoji takes a moment, which can cause a spike on the first
+/// frame that shows a lot of them. The images are loaded at the size of emojis in body text, using
+/// the image loaders installed in `ctx`, so call this during a pass (e.g. in `App::update`).
+///
+/// Emojis that were already preloaded are skipped, so it's fine to call this every frame. The ones
+/// that couldn't be loaded (e.g. because the image loaders aren't installed yet) are tried again
+/// the next time.
+pub fn preload_emoji(ctx: &egui::Context, emojis: &[&str]) {
+    let mut new: Vec<&str> = Preloaded::with(ctx, |preloaded| match preloaded.all {
+        true => Vec::new(),
+        false => (emojis.iter().copied())
+            .filter(|&emoji| !preloaded.emojis.contains(emoji))
+            .collect(),
+    });
+    new.sort_unstable();
+    new.dedup();
+    let (loaded, _) = load(ctx, new);
+    Preloaded::with(ctx, |preloaded| {
+        (preloaded.emojis).extend(loaded.into_iter().map(str::to_owned));
+    });
+}
+
+/// Load the images of every Twemoji now, see [`preload_emoji`].
+///
+/// This decodes a few thousand images, so it's best done behind a loading screen. Once all of them
+/// have loaded, calling this again does nothing.
+pub fn preload_all(ctx: &egui::Context) {
+    if Preloaded::with(ctx, |preloaded| preloaded.all) {
+        return;
+    }
+    let (loaded, all_loaded) = load(ctx, EMOJIS.iter().map(|&(emoji, _, _)| emoji));
+    Preloaded::with(ctx, |preloaded| match all_loaded {
+        true => (preloaded.all, preloaded.emojis) = (true, HashSet::new()),
+        false => (preloaded.emojis).extend(loaded.into_iter().map(str::to_owned)),
+    });
+}
+
+/// Install the `egui_extras` image loaders that are needed to show the emoji images, unless they
+/// already are.
+///
+/// Without them, every emoji is shown as a "⚠". Usually you'd call
+/// `egui_extras::install_image_loaders` when creating your app, but if that's hard to guarantee
+/// (e.g. on the web, where the first frame may be shown early), call this before showing any
+/// labels. It only does something the first time it's called for `ctx`, so calling it every frame is fine.
+#[cfg(feature = "loaders")]
+pub fn require_image_loaders(ctx: &egui::Context) {
+    let id = egui::Id::new("egui_twemoji::loaders_installed");
+    if !ctx.data(|d| d.get_temp::<bool>(id).unwrap_or(false)) {
+        egui_extras::install_image_loaders(ctx);
+        ctx.data_mut(|d| d.insert_temp(id, true));
+    }
+}
+
+/// Start loading the images of `emojis`, and return the emojis whose images load, and whether all
+/// of the images did.
+///
+/// Strings that aren't emojis are skipped. The errors of the images that can't be loaded (e.g.
+/// because of a missing image loader) show up when their emojis are shown.
+fn load<'a>(
+    ctx: &egui::Context,
+    emojis: impl IntoIterator<Item = &'a str>,
+) -> (Vec<&'a str>, bool) {
+    // the same size `EmojiLabel` uses, so the images don't have to be loaded again:
+    let font_id = egui::TextStyle::Body.resolve(&ctx.style());
+    let size = ctx.fonts(|fonts| fonts.row_height(&font_id));
+    let size = egui::vec2(size, size);
+    let (mut loaded, mut all_loaded) = (Vec::new(), true);
+    for emoji in emojis {
+        if let Some(source) = TwemojiProvider::lookup(emoji) {
+            let (source, texture_size) = sized_source(source, size, ctx.pixels_per_point());
+            let image = egui::Image::new(source).fit_to_exact_size(texture_size);
+            match image.load_for_size(ctx, size) {
+                Ok(_) => loaded.push(emoji),
+                Err(_) => all_loaded = false,
+            }
+        }
+    }
+    (loaded, all_loaded)
+}
+
+#[cfg(all(test, any(feature = "loaders", feature = "svg", feature = "both")))]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "loaders")]
+    #[test]
+    fn image_loaders_are_installed_once() {
+        let ctx = egui::Context::default();
+        require_image_loaders(&ctx);
+        let loaders = ctx.loaders().image.lock().len();
+        assert!(loaders > 0);
+        require_image_loaders(&ctx);
+        assert_eq!(ctx.loaders().image.lock().len(), loaders);
+    }
+
+    #[cfg(any(feature = "svg", feature = "both"))]
+    #[test]
+    fn preloading_includes_the_image_data() {
+        let ctx = egui::Context::default();
+        egui_extras::install_image_loaders(&ctx);
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            preload_emoji(ctx, &["🚀", "🚀", "not an emoji"]);
+        });
+        let font_id = egui::TextStyle::Body.resolve(&ctx.style());
+        let size = ctx.fonts(|fonts| fonts.row_height(&font_id));
+        let uri = |emoji| {
+            let source = TwemojiProvider::lookup(emoji).unwrap();
+            let (source, _) = sized_source(source, egui::vec2(size, size), ctx.pixels_per_point());
+            source.uri().unwrap().to_owned()
+        };
+        assert!(ctx.try_load_bytes(&uri("🚀")).is_ok());
+        assert!(ctx.try_load_bytes(&uri("🛸")).is_err());
+        // only the emojis are recorded:
+        Preloaded::with(&ctx, |preloaded| {
+            assert_eq!(preloaded.emojis, HashSet::from(["🚀".to_owned()]));
+        });
+    }
+
+    #[cfg(any(feature = "svg", feature = "both"))]
+    #[test]
+    fn failed_preloads_are_retried() {
+        let ctx = egui::Context::default();
+        let preload = || {
+            let _ = ctx.run(egui::RawInput::default(), |ctx| preload_emoji(ctx, &["🚀"]));
+            Preloaded::with(&ctx, |preloaded| preloaded.emojis.len())
+        };
+        // without the image loaders, the image can't be loaded yet:
+        assert_eq!(preload(), 0);
+        egui_extras::install_image_loaders(&ctx);
+        assert_eq!(preload(), 1);
+    }
+}
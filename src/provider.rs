@@ -0,0 +1,247 @@
+use egui::ImageSource;
+
+#[cfg(all(feature = "svg", feature = "png"))]
+compile_error!("features 'svg' and 'png' are mutually exclusive and cannot be enabled together");
+
+#[cfg(all(feature = "both", any(feature = "svg", feature = "png")))]
+compile_error!(
+    "feature 'both' includes 'svg' and 'png' and cannot be enabled together with them, \
+    so the default features have to be turned off to use it"
+);
+
+/// A source of emoji images for an [`EmojiLabel`](crate::EmojiLabel).
+///
+/// The built-in [`TwemojiProvider`] is used by default. To render a different emoji set, implement
+/// this trait and create your labels with [`EmojiLabel::with_provider`](crate::EmojiLabel::with_provider):
+///
+/// ```rust
+/// use egui::ImageSource;
+/// use egui_twemoji::{EmojiAssetProvider, EmojiLabel};
+///
+/// struct CrabEmojis;
+///
+/// impl EmojiAssetProvider for CrabEmojis {
+///     fn lookup(emoji: &str) -> Option<ImageSource<'static>> {
+///         match emoji {
+///             "🦀" => Some(ImageSource::Uri("https://example.com/ferris.png".into())),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// fn show_label(ui: &mut egui::Ui) {
+///     EmojiLabel::<CrabEmojis>::with_provider("Hello 🦀").show(ui);
+/// }
+/// ```
+///
+/// Like the Twemoji assets, the returned [`ImageSource`]s are loaded by egui's image loaders,
+/// so make sure the ones your images need are installed.
+pub trait EmojiAssetProvider: 'static {
+    /// Find the image for a single emoji grapheme, or [`None`] if this provider has no image for it.
+    fn lookup(emoji: &str) -> Option<ImageSource<'static>>;
+
+    /// Whether `emoji` is a grapheme this provider has an image for.
+    ///
+    /// This is called for every grapheme of every label when it is segmented, so override it if
+    /// [`Self::lookup`] is expensive.
+    fn is_emoji(emoji: &str) -> bool {
+        Self::lookup(emoji).is_some()
+    }
+}
+
+/// The default [`EmojiAssetProvider`], which renders [Twemojis](https://github.com/twitter/twemoji).
+///
+/// Whether SVG or PNG assets are used is decided by the `svg`, `png` and `both` features.
+pub struct TwemojiProvider;
+
+impl EmojiAssetProvider for TwemojiProvider {
+    fn lookup(emoji: &str) -> Option<ImageSource<'static>> {
+        get_source_for_emoji(emoji)
+    }
+
+    #[inline]
+    fn is_emoji(emoji: &str) -> bool {
+        is_emoji(emoji)
+    }
+}
+
+/// Whether `text` is a single emoji that there's a Twemoji for.
+///
+/// `text` has to be a whole grapheme cluster, including any variation selectors, skin tone
+/// modifiers and zero width joiners. This is what [`TwemojiProvider`] uses to split text into
+/// emojis, so it agrees with [`EmojiLabel`](crate::EmojiLabel) on what counts as one.
+///
+/// ```rust
+/// use egui_twemoji::is_emoji;
+///
+/// # #[cfg(any(feature = "svg", feature = "png", feature = "both"))] {
+/// assert!(is_emoji("👍"));
+/// assert!(is_emoji("👨‍👩‍👧"));
+/// assert!(!is_emoji("👍👍"));
+/// assert!(!is_emoji("a"));
+/// # }
+/// ```
+///
+/// With the `both` feature, this is `true` if there is either an SVG or a PNG Twemoji for `text`.
+/// It is always `false` if none of the `svg`, `png` and `both` features is enabled.
+#[inline]
+pub fn is_emoji(text: &str) -> bool {
+    #[cfg(feature = "svg")]
+    return twemoji_assets::svg::SvgTwemojiAsset::from_emoji(text).is_some();
+
+    #[cfg(feature = "png")]
+    return twemoji_assets::png::PngTwemojiAsset::from_emoji(text).is_some();
+
+    #[cfg(feature = "both")]
+    return twemoji_assets::svg::SvgTwemojiAsset::from_emoji(text).is_some()
+        || twemoji_assets::png::PngTwemojiAsset::from_emoji(text).is_some();
+
+    #[cfg(not(any(feature = "svg", feature = "png", feature = "both")))]
+    {
+        let _ = text;
+        false
+    }
+}
+
+fn get_source_for_emoji(emoji: &str) -> Option<ImageSource<'static>> {
+    #[cfg(feature = "svg")]
+    return svg_source(emoji);
+
+    #[cfg(feature = "png")]
+    return png_source(emoji);
+
+    #[cfg(feature = "both")]
+    return svg_source(emoji).or_else(|| png_source(emoji));
+
+    #[cfg(not(any(feature = "svg", feature = "png", feature = "both")))]
+    {
+        let _ = emoji;
+        None
+    }
+}
+
+/// The source of the bundled image `bytes` of `emoji`, in the format `extension`.
+///
+/// The URI follows egui's `bytes://` convention and is unique per emoji and format, so they never
+/// collide in the caches of the bytes and image loaders, on native and on the web alike. The
+/// extension has to stay at the end, as the `egui_extras` loaders pick the format by it.
+#[cfg(any(feature = "svg", feature = "png", feature = "both"))]
+fn bytes_source(emoji: &str, extension: &str, bytes: &'static [u8]) -> ImageSource<'static> {
+    ImageSource::Bytes {
+        uri: format!("bytes://twemoji/{emoji}.{extension}").into(),
+        bytes: egui::load::Bytes::Static(bytes),
+    }
+}
+
+#[cfg(any(feature = "svg", feature = "both"))]
+fn svg_source(emoji: &str) -> Option<ImageSource<'static>> {
+    let svg_data = twemoji_assets::svg::SvgTwemojiAsset::from_emoji(emoji)?;
+    Some(bytes_source(emoji, "svg", svg_data.as_bytes()))
+}
+
+#[cfg(any(feature = "png", feature = "both"))]
+fn png_source(emoji: &str) -> Option<ImageSource<'static>> {
+    let png_data: &[u8] = twemoji_assets::png::PngTwemojiAsset::from_emoji(emoji)?;
+    Some(bytes_source(emoji, "png", png_data))
+}
+
+/// How the caches of this crate tell the emojis of `P` apart from the ones of other providers.
+///
+/// Unlike its `TypeId`, the name of the provider stays the same across builds, so persisted states
+/// are still found after the app is rebuilt.
+pub(crate) fn provider_key<P: EmojiAssetProvider>() -> &'static str {
+    std::any::type_name::<P>()
+}
+
+/// `source` with the size it is rasterized at in its URI if it is an SVG, and that size.
+///
+/// egui caches the texture of an image by its URI alone, so an SVG shown at several sizes would
+/// only be rasterized at the first one and scaled to the rest, which e.g. makes the emojis of a
+/// heading blurry if they were shown in body text first. Only [`ImageSource::Bytes`] get a new
+/// URI, as the URI of any other source is also where it is loaded from. PNGs are decoded at their
+/// own size anyway, so they keep sharing a texture.
+///
+/// egui never frees these textures, so images aren't rasterized at the exact size they're shown
+/// at, `size` in points, but rounded up to a height of a power of two pixels. That way zooming or
+/// animating the font size only ever adds a handful of textures per emoji. Load the image at the
+/// returned size in points, e.g. with [`egui::Image::fit_to_exact_size`].
+pub(crate) fn sized_source(
+    source: ImageSource<'_>,
+    size: egui::Vec2,
+    pixels_per_point: f32,
+) -> (ImageSource<'_>, egui::Vec2) {
+    let height = (size.y * pixels_per_point).round().max(1.0);
+    let bucket = (height as u32).next_power_of_two() as f32;
+    let texture_size = size * (bucket / height);
+
+    let ImageSource::Bytes { uri, bytes } = source else {
+        return (source, texture_size);
+    };
+    let uri = match uri.strip_suffix(".svg") {
+        Some(stem) => {
+            let size = (texture_size * pixels_per_point).round();
+            format!("{stem}@{}x{}.svg", size.x, size.y).into()
+        }
+        None => uri,
+    };
+    (ImageSource::Bytes { uri, bytes }, texture_size)
+}
+
+#[cfg(all(test, any(feature = "svg", feature = "png", feature = "both")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sources_have_unique_bytes_uris() {
+        let uri = |emoji| match get_source_for_emoji(emoji) {
+            Some(ImageSource::Bytes { uri, .. }) => uri.into_owned(),
+            _ => unreachable!(),
+        };
+        let (fire, rocket) = (uri("🔥"), uri("🚀"));
+        assert!(fire.starts_with("bytes://twemoji/"), "{fire}");
+        assert!(fire.ends_with(".svg") || fire.ends_with(".png"), "{fire}");
+        assert_ne!(fire, rocket);
+        assert_eq!(fire, uri("🔥"));
+    }
+
+    #[cfg(feature = "loaders")]
+    #[test]
+    fn every_size_is_cached_on_its_own() {
+        let ctx = egui::Context::default();
+        crate::require_image_loaders(&ctx);
+        let texture = |size: f32| {
+            let size = egui::vec2(size, size);
+            let (source, size) = sized_source(get_source_for_emoji("🔥").unwrap(), size, 1.0);
+            match egui::Image::new(source)
+                .fit_to_exact_size(size)
+                .load_for_size(&ctx, size)
+            {
+                Ok(egui::load::TexturePoll::Ready { texture }) => texture,
+                _ => unreachable!(),
+            }
+        };
+        let (small, large) = (texture(14.0), texture(56.0));
+        assert_eq!(texture(14.0).id, small.id);
+        // sizes are rounded up to a power of two, so close ones share a texture:
+        assert_eq!(texture(15.0).id, small.id);
+        #[cfg(any(feature = "svg", feature = "both"))]
+        assert_eq!(
+            (small.size, large.size),
+            (egui::vec2(16.0, 16.0), egui::vec2(64.0, 64.0))
+        );
+        #[cfg(feature = "png")]
+        assert_eq!(small.id, large.id);
+    }
+
+    #[test]
+    fn sizes_are_bucketed() {
+        let size = |height: f32, pixels_per_point| {
+            let source = get_source_for_emoji("🔥").unwrap();
+            sized_source(source, egui::vec2(height, height), pixels_per_point).1
+        };
+        assert_eq!(size(14.0, 1.0), egui::vec2(16.0, 16.0));
+        assert_eq!(size(16.0, 1.0), egui::vec2(16.0, 16.0));
+        assert_eq!(size(17.0, 1.0), egui::vec2(32.0, 32.0));
+        assert_eq!(size(14.0, 2.0), egui::vec2(16.0, 16.0));
+    }
+}
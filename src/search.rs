@@ -0,0 +1,79 @@
+use crate::table::EMOJIS;
+
+/// How well an emoji name or shortcode matched a search query. Lower is better.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum MatchQuality {
+    Exact,
+    Prefix,
+    Substring,
+}
+
+/// Case-insensitively match `needle` (which must already be lowercase) against `haystack`.
+fn match_quality(haystack: &str, needle: &str) -> Option<MatchQuality> {
+    let (haystack, needle) = (haystack.as_bytes(), needle.as_bytes());
+    let pos = haystack
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle))?;
+
+    Some(if pos == 0 && haystack.len() == needle.len() {
+        MatchQuality::Exact
+    } else if pos == 0 {
+        MatchQuality::Prefix
+    } else {
+        MatchQuality::Substring
+    })
+}
+
+/// Search for emojis by their name or shortcode.
+///
+/// The query is matched case-insensitively against the [Emojibase](https://github.com/milesj/emojibase)
+/// label (e.g. `"face with tears of joy"`) and shortcodes (e.g. `"joy"`) of every emoji.
+/// Surrounding whitespace and colons are ignored, so `":joy:"` works too.
+///
+/// Exact matches come first, followed by prefix matches and then any other substring matches.
+///
+/// ```rust
+/// let results = egui_twemoji::search_emojis("fire");
+/// assert_eq!(results[0], "🔥");
+/// ```
+pub fn search_emojis(query: &str) -> Vec<&'static str> {
+    let query = query.trim().trim_matches(':').to_lowercase();
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let mut results: Vec<(MatchQuality, &'static str)> = EMOJIS
+        .iter()
+        .filter_map(|&(emoji, label, shortcodes)| {
+            let quality = shortcodes
+                .iter()
+                .chain(std::iter::once(&label))
+                .filter_map(|name| match_quality(name, &query))
+                .min()?;
+            Some((quality, emoji))
+        })
+        .collect();
+
+    // stable, so emojis with the same quality stay in table order:
+    results.sort_by_key(|&(quality, _)| quality);
+    results.into_iter().map(|(_, emoji)| emoji).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_by_name_and_shortcode() {
+        assert_eq!(search_emojis("fire")[0], "🔥");
+        assert_eq!(search_emojis("joy")[0], "😂");
+        assert_eq!(search_emojis("JOY")[0], "😂");
+        assert_eq!(search_emojis(":thumbsup:")[0], "👍");
+        assert!(search_emojis("tears of joy").contains(&"😂"));
+        assert_eq!(search_emojis("copyright")[0], "©");
+        assert_eq!(search_emojis(":zero:")[0], "0⃣");
+        assert!(search_emojis("thumbs up: medium skin tone").contains(&"👍🏽"));
+        assert!(search_emojis("").is_empty());
+        assert!(search_emojis("definitely not an emoji").is_empty());
+    }
+}
@@ -0,0 +1,121 @@
+//! Discord-style emoji shortcodes, like `:smile:` or `:thumbsup:`.
+//!
+//! The shortcodes are the ones provided by [Emojibase](https://github.com/milesj/emojibase),
+//! which include the common GitHub and Discord shortcodes. The Unicode CLDR names of all emojis
+//! work as well, in lowercase and with underscores instead of spaces (`:face_with_tears_of_joy:`).
+
+use crate::table::EMOJIS;
+use std::{collections::HashMap, sync::OnceLock};
+
+/// A map from every shortcode to its emoji, built the first time it is needed.
+fn shortcodes() -> &'static HashMap<String, &'static str> {
+    static SHORTCODES: OnceLock<HashMap<String, &'static str>> = OnceLock::new();
+    SHORTCODES.get_or_init(|| {
+        let mut map = HashMap::new();
+        for &(emoji, _, shortcodes) in EMOJIS {
+            for &code in shortcodes {
+                map.insert(code.to_owned(), emoji);
+            }
+        }
+        // the CLDR names work too, but shouldn't shadow any of the "real" shortcodes:
+        for &(emoji, label, _) in EMOJIS {
+            let code = label_to_shortcode(label);
+            if !code.is_empty() {
+                map.entry(code).or_insert(emoji);
+            }
+        }
+        map
+    })
+}
+
+/// Turn a CLDR name into a shortcode, e.g. `"flag: United States"` into `"flag_united_states"`.
+fn label_to_shortcode(label: &str) -> String {
+    label
+        .to_lowercase()
+        .split(|c: char| c.is_whitespace() || c == '-')
+        .map(|word| word.replace(|c: char| !is_shortcode_char(c), ""))
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Whether `c` can be part of a shortcode.
+fn is_shortcode_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '+')
+}
+
+/// Look up the emoji for a shortcode, without the surrounding colons.
+///
+/// ```rust
+/// use egui_twemoji::shortcode::shortcode_to_emoji;
+///
+/// assert_eq!(shortcode_to_emoji("joy"), Some("😂"));
+/// assert_eq!(shortcode_to_emoji("not_an_emoji"), None);
+/// ```
+pub fn shortcode_to_emoji(code: &str) -> Option<&'static str> {
+    shortcodes().get(code).copied()
+}
+
+/// Replace all `:shortcode:`s in `text` with their emoji.
+///
+/// Anything between colons that isn't a known shortcode is left as-is.
+///
+/// ```rust
+/// use egui_twemoji::shortcode::expand_shortcodes;
+///
+/// assert_eq!(expand_shortcodes("nice :+1: :fire:"), "nice 👍 🔥");
+/// assert_eq!(expand_shortcodes("12:30 :nope:"), "12:30 :nope:");
+/// ```
+pub fn expand_shortcodes(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find(':') {
+        let (before, after) = rest.split_at(start);
+        result.push_str(before);
+
+        // `after` starts with the opening colon:
+        let code_len = after[1..]
+            .find(|c: char| !is_shortcode_char(c))
+            .unwrap_or(after.len() - 1);
+        let code = &after[1..1 + code_len];
+        let closed = after[1 + code_len..].starts_with(':');
+
+        match shortcode_to_emoji(code).filter(|_| closed) {
+            Some(emoji) => {
+                result.push_str(emoji);
+                rest = &after[code_len + 2..];
+            }
+            None => {
+                // the closing colon may still open another shortcode:
+                result.push(':');
+                rest = &after[1..];
+            }
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shortcode_expansion() {
+        assert_eq!(shortcode_to_emoji("smile"), Some("😄"));
+        assert_eq!(shortcode_to_emoji("face_with_tears_of_joy"), Some("😂"));
+        assert_eq!(shortcode_to_emoji("flag_united_states"), Some("🇺🇸"));
+        assert_eq!(shortcode_to_emoji("zero"), Some("0⃣"));
+        assert_eq!(shortcode_to_emoji("copyright"), Some("©"));
+        assert_eq!(shortcode_to_emoji("keycap_0"), Some("0⃣"));
+        assert_eq!(expand_shortcodes(":smile:"), "😄");
+        assert_eq!(expand_shortcodes("a:smile:b"), "a😄b");
+        assert_eq!(expand_shortcodes(":wave::fire:"), "👋🔥");
+        assert_eq!(expand_shortcodes("time: 12:30:fire:"), "time: 12:30🔥");
+        assert_eq!(expand_shortcodes(":unknown: :"), ":unknown: :");
+        assert_eq!(expand_shortcodes("no codes here"), "no codes here");
+        assert_eq!(expand_shortcodes("::"), "::");
+    }
+}
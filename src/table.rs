@@ -0,0 +1,11692 @@
+// @generated by `scripts/generate_table.py` from the `twemoji-assets` code and name tables
+// (Twemoji 17.0.2).
+
+//! A table of every emoji in the Twemoji set together with its
+//! [Emojibase](https://github.com/milesj/emojibase) label and shortcodes.
+
+/// `(emoji, label, shortcodes)` for every Twemoji asset, in the order of `twemoji_assets::svg::codes`.
+pub(crate) const EMOJIS: &[(&str, &str, &[&str])] = &[
+    ("🀄", "mahjong red dragon", &["mahjong"]),
+    ("🃏", "joker", &["black_joker"]),
+    ("🅰", "A button (blood type)", &["a", "a_blood"]),
+    ("🅱", "B button (blood type)", &["b", "b_blood"]),
+    ("🅾", "O button (blood type)", &["o", "o_blood"]),
+    ("🅿", "P button", &["parking"]),
+    ("🆎", "AB button (blood type)", &["ab", "ab_blood"]),
+    ("🆑", "CL button", &["cl"]),
+    ("🆒", "COOL button", &["cool"]),
+    ("🆓", "FREE button", &["free"]),
+    ("🆔", "ID button", &["id"]),
+    ("🆕", "NEW button", &["new"]),
+    ("🆖", "NG button", &["ng"]),
+    ("🆗", "OK button", &["ok"]),
+    ("🆘", "SOS button", &["sos"]),
+    ("🆙", "UP! button", &["up2"]),
+    ("🆚", "VS button", &["vs"]),
+    (
+        "🇦🇨",
+        "flag: Ascension Island",
+        &["ascension_island", "flag_ac"],
+    ),
+    ("🇦🇩", "flag: Andorra", &["andorra", "flag_ad"]),
+    (
+        "🇦🇪",
+        "flag: United Arab Emirates",
+        &["flag_ae", "united_arab_emirates"],
+    ),
+    ("🇦🇫", "flag: Afghanistan", &["afghanistan", "flag_af"]),
+    (
+        "🇦🇬",
+        "flag: Antigua & Barbuda",
+        &["antigua_barbuda", "flag_ag"],
+    ),
+    ("🇦🇮", "flag: Anguilla", &["anguilla", "flag_ai"]),
+    ("🇦🇱", "flag: Albania", &["albania", "flag_al"]),
+    ("🇦🇲", "flag: Armenia", &["armenia", "flag_am"]),
+    ("🇦🇴", "flag: Angola", &["angola", "flag_ao"]),
+    ("🇦🇶", "flag: Antarctica", &["antarctica", "flag_aq"]),
+    ("🇦🇷", "flag: Argentina", &["argentina", "flag_ar"]),
+    ("🇦🇸", "flag: American Samoa", &["american_samoa", "flag_as"]),
+    ("🇦🇹", "flag: Austria", &["austria", "flag_at"]),
+    ("🇦🇺", "flag: Australia", &["australia", "flag_au"]),
+    ("🇦🇼", "flag: Aruba", &["aruba", "flag_aw"]),
+    ("🇦🇽", "flag: Åland Islands", &["aland_islands", "flag_ax"]),
+    ("🇦🇿", "flag: Azerbaijan", &["azerbaijan", "flag_az"]),
+    ("🇦", "regional indicator A", &["regional_indicator_a"]),
+    (
+        "🇧🇦",
+        "flag: Bosnia & Herzegovina",
+        &["bosnia_herzegovina", "flag_ba"],
+    ),
+    ("🇧🇧", "flag: Barbados", &["barbados", "flag_bb"]),
+    ("🇧🇩", "flag: Bangladesh", &["bangladesh", "flag_bd"]),
+    ("🇧🇪", "flag: Belgium", &["belgium", "flag_be"]),
+    ("🇧🇫", "flag: Burkina Faso", &["burkina_faso", "flag_bf"]),
+    ("🇧🇬", "flag: Bulgaria", &["bulgaria", "flag_bg"]),
+    ("🇧🇭", "flag: Bahrain", &["bahrain", "flag_bh"]),
+    ("🇧🇮", "flag: Burundi", &["burundi", "flag_bi"]),
+    ("🇧🇯", "flag: Benin", &["benin", "flag_bj"]),
+    ("🇧🇱", "flag: St. Barthélemy", &["flag_bl", "st_barthelemy"]),
+    ("🇧🇲", "flag: Bermuda", &["bermuda", "flag_bm"]),
+    ("🇧🇳", "flag: Brunei", &["brunei", "flag_bn"]),
+    ("🇧🇴", "flag: Bolivia", &["bolivia", "flag_bo"]),
+    (
+        "🇧🇶",
+        "flag: Caribbean Netherlands",
+        &["caribbean_netherlands", "flag_bq"],
+    ),
+    ("🇧🇷", "flag: Brazil", &["brazil", "flag_br"]),
+    ("🇧🇸", "flag: Bahamas", &["bahamas", "flag_bs"]),
+    ("🇧🇹", "flag: Bhutan", &["bhutan", "flag_bt"]),
+    ("🇧🇻", "flag: Bouvet Island", &["bouvet_island", "flag_bv"]),
+    ("🇧🇼", "flag: Botswana", &["botswana", "flag_bw"]),
+    ("🇧🇾", "flag: Belarus", &["belarus", "flag_by"]),
+    ("🇧🇿", "flag: Belize", &["belize", "flag_bz"]),
+    ("🇧", "regional indicator B", &["regional_indicator_b"]),
+    ("🇨🇦", "flag: Canada", &["canada", "flag_ca"]),
+    (
+        "🇨🇨",
+        "flag: Cocos (Keeling) Islands",
+        &["cocos_islands", "flag_cc"],
+    ),
+    (
+        "🇨🇩",
+        "flag: Congo - Kinshasa",
+        &["congo_kinshasa", "flag_cd"],
+    ),
+    (
+        "🇨🇫",
+        "flag: Central African Republic",
+        &["central_african_republic", "flag_cf"],
+    ),
+    (
+        "🇨🇬",
+        "flag: Congo - Brazzaville",
+        &["congo_brazzaville", "flag_cg"],
+    ),
+    ("🇨🇭", "flag: Switzerland", &["flag_ch", "switzerland"]),
+    ("🇨🇮", "flag: Côte d’Ivoire", &["cote_divoire", "flag_ci"]),
+    ("🇨🇰", "flag: Cook Islands", &["cook_islands", "flag_ck"]),
+    ("🇨🇱", "flag: Chile", &["chile", "flag_cl"]),
+    ("🇨🇲", "flag: Cameroon", &["cameroon", "flag_cm"]),
+    ("🇨🇳", "flag: China", &["china", "flag_cn"]),
+    ("🇨🇴", "flag: Colombia", &["colombia", "flag_co"]),
+    (
+        "🇨🇵",
+        "flag: Clipperton Island",
+        &["clipperton_island", "flag_cp"],
+    ),
+    ("🇨🇶", "flag: Sark", &["flag_cq", "sark"]),
+    ("🇨🇷", "flag: Costa Rica", &["costa_rica", "flag_cr"]),
+    ("🇨🇺", "flag: Cuba", &["cuba", "flag_cu"]),
+    ("🇨🇻", "flag: Cape Verde", &["cape_verde", "flag_cv"]),
+    ("🇨🇼", "flag: Curaçao", &["curacao", "flag_cw"]),
+    (
+        "🇨🇽",
+        "flag: Christmas Island",
+        &["christmas_island", "flag_cx"],
+    ),
+    ("🇨🇾", "flag: Cyprus", &["cyprus", "flag_cy"]),
+    (
+        "🇨🇿",
+        "flag: Czechia",
+        &["czech_republic", "czechia", "flag_cz"],
+    ),
+    ("🇨", "regional indicator C", &["regional_indicator_c"]),
+    ("🇩🇪", "flag: Germany", &["flag_de", "germany"]),
+    ("🇩🇬", "flag: Diego Garcia", &["diego_garcia", "flag_dg"]),
+    ("🇩🇯", "flag: Djibouti", &["djibouti", "flag_dj"]),
+    ("🇩🇰", "flag: Denmark", &["denmark", "flag_dk"]),
+    ("🇩🇲", "flag: Dominica", &["dominica", "flag_dm"]),
+    (
+        "🇩🇴",
+        "flag: Dominican Republic",
+        &["dominican_republic", "flag_do"],
+    ),
+    ("🇩🇿", "flag: Algeria", &["algeria", "flag_dz"]),
+    ("🇩", "regional indicator D", &["regional_indicator_d"]),
+    ("🇪🇦", "flag: Ceuta & Melilla", &["ceuta_melilla", "flag_ea"]),
+    ("🇪🇨", "flag: Ecuador", &["ecuador", "flag_ec"]),
+    ("🇪🇪", "flag: Estonia", &["estonia", "flag_ee"]),
+    ("🇪🇬", "flag: Egypt", &["egypt", "flag_eg"]),
+    ("🇪🇭", "flag: Western Sahara", &["flag_eh", "western_sahara"]),
+    ("🇪🇷", "flag: Eritrea", &["eritrea", "flag_er"]),
+    ("🇪🇸", "flag: Spain", &["flag_es", "spain"]),
+    ("🇪🇹", "flag: Ethiopia", &["ethiopia", "flag_et"]),
+    ("🇪🇺", "flag: European Union", &["european_union", "flag_eu"]),
+    ("🇪", "regional indicator E", &["regional_indicator_e"]),
+    ("🇫🇮", "flag: Finland", &["finland", "flag_fi"]),
+    ("🇫🇯", "flag: Fiji", &["fiji", "flag_fj"]),
+    (
+        "🇫🇰",
+        "flag: Falkland Islands",
+        &["falkland_islands", "flag_fk"],
+    ),
+    ("🇫🇲", "flag: Micronesia", &["flag_fm", "micronesia"]),
+    ("🇫🇴", "flag: Faroe Islands", &["faroe_islands", "flag_fo"]),
+    ("🇫🇷", "flag: France", &["flag_fr", "france"]),
+    ("🇫", "regional indicator F", &["regional_indicator_f"]),
+    ("🇬🇦", "flag: Gabon", &["flag_ga", "gabon"]),
+    (
+        "🇬🇧",
+        "flag: United Kingdom",
+        &["flag_gb", "uk", "united_kingdom"],
+    ),
+    ("🇬🇩", "flag: Grenada", &["flag_gd", "grenada"]),
+    ("🇬🇪", "flag: Georgia", &["flag_ge", "georgia"]),
+    ("🇬🇫", "flag: French Guiana", &["flag_gf", "french_guiana"]),
+    ("🇬🇬", "flag: Guernsey", &["flag_gg", "guernsey"]),
+    ("🇬🇭", "flag: Ghana", &["flag_gh", "ghana"]),
+    ("🇬🇮", "flag: Gibraltar", &["flag_gi", "gibraltar"]),
+    ("🇬🇱", "flag: Greenland", &["flag_gl", "greenland"]),
+    ("🇬🇲", "flag: Gambia", &["flag_gm", "gambia"]),
+    ("🇬🇳", "flag: Guinea", &["flag_gn", "guinea"]),
+    ("🇬🇵", "flag: Guadeloupe", &["flag_gp", "guadeloupe"]),
+    (
+        "🇬🇶",
+        "flag: Equatorial Guinea",
+        &["equatorial_guinea", "flag_gq"],
+    ),
+    ("🇬🇷", "flag: Greece", &["flag_gr", "greece"]),
+    (
+        "🇬🇸",
+        "flag: South Georgia & South Sandwich Islands",
+        &["flag_gs", "south_georgia_south_sandwich_islands"],
+    ),
+    ("🇬🇹", "flag: Guatemala", &["flag_gt", "guatemala"]),
+    ("🇬🇺", "flag: Guam", &["flag_gu", "guam"]),
+    ("🇬🇼", "flag: Guinea-Bissau", &["flag_gw", "guinea_bissau"]),
+    ("🇬🇾", "flag: Guyana", &["flag_gy", "guyana"]),
+    ("🇬", "regional indicator G", &["regional_indicator_g"]),
+    ("🇭🇰", "flag: Hong Kong SAR China", &["flag_hk", "hong_kong"]),
+    (
+        "🇭🇲",
+        "flag: Heard & McDonald Islands",
+        &["flag_hm", "heard_mcdonald_islands"],
+    ),
+    ("🇭🇳", "flag: Honduras", &["flag_hn", "honduras"]),
+    ("🇭🇷", "flag: Croatia", &["croatia", "flag_hr"]),
+    ("🇭🇹", "flag: Haiti", &["flag_ht", "haiti"]),
+    ("🇭🇺", "flag: Hungary", &["flag_hu", "hungary"]),
+    ("🇭", "regional indicator H", &["regional_indicator_h"]),
+    ("🇮🇨", "flag: Canary Islands", &["canary_islands", "flag_ic"]),
+    ("🇮🇩", "flag: Indonesia", &["flag_id", "indonesia"]),
+    ("🇮🇪", "flag: Ireland", &["flag_ie", "ireland"]),
+    ("🇮🇱", "flag: Israel", &["flag_il", "israel"]),
+    ("🇮🇲", "flag: Isle of Man", &["flag_im", "isle_of_man"]),
+    ("🇮🇳", "flag: India", &["flag_in", "india"]),
+    (
+        "🇮🇴",
+        "flag: British Indian Ocean Territory",
+        &["british_indian_ocean_territory", "flag_io"],
+    ),
+    ("🇮🇶", "flag: Iraq", &["flag_iq", "iraq"]),
+    ("🇮🇷", "flag: Iran", &["flag_ir", "iran"]),
+    ("🇮🇸", "flag: Iceland", &["flag_is", "iceland"]),
+    ("🇮🇹", "flag: Italy", &["flag_it", "italy"]),
+    ("🇮", "regional indicator I", &["regional_indicator_i"]),
+    ("🇯🇪", "flag: Jersey", &["flag_je", "jersey"]),
+    ("🇯🇲", "flag: Jamaica", &["flag_jm", "jamaica"]),
+    ("🇯🇴", "flag: Jordan", &["flag_jo", "jordan"]),
+    ("🇯🇵", "flag: Japan", &["flag_jp", "japan"]),
+    ("🇯", "regional indicator J", &["regional_indicator_j"]),
+    ("🇰🇪", "flag: Kenya", &["flag_ke", "kenya"]),
+    ("🇰🇬", "flag: Kyrgyzstan", &["flag_kg", "kyrgyzstan"]),
+    ("🇰🇭", "flag: Cambodia", &["cambodia", "flag_kh"]),
+    ("🇰🇮", "flag: Kiribati", &["flag_ki", "kiribati"]),
+    ("🇰🇲", "flag: Comoros", &["comoros", "flag_km"]),
+    (
+        "🇰🇳",
+        "flag: St. Kitts & Nevis",
+        &["flag_kn", "st_kitts_nevis"],
+    ),
+    ("🇰🇵", "flag: North Korea", &["flag_kp", "north_korea"]),
+    ("🇰🇷", "flag: South Korea", &["flag_kr", "south_korea"]),
+    ("🇰🇼", "flag: Kuwait", &["flag_kw", "kuwait"]),
+    ("🇰🇾", "flag: Cayman Islands", &["cayman_islands", "flag_ky"]),
+    ("🇰🇿", "flag: Kazakhstan", &["flag_kz", "kazakhstan"]),
+    ("🇰", "regional indicator K", &["regional_indicator_k"]),
+    ("🇱🇦", "flag: Laos", &["flag_la", "laos"]),
+    ("🇱🇧", "flag: Lebanon", &["flag_lb", "lebanon"]),
+    ("🇱🇨", "flag: St. Lucia", &["flag_lc", "st_lucia"]),
+    ("🇱🇮", "flag: Liechtenstein", &["flag_li", "liechtenstein"]),
+    ("🇱🇰", "flag: Sri Lanka", &["flag_lk", "sri_lanka"]),
+    ("🇱🇷", "flag: Liberia", &["flag_lr", "liberia"]),
+    ("🇱🇸", "flag: Lesotho", &["flag_ls", "lesotho"]),
+    ("🇱🇹", "flag: Lithuania", &["flag_lt", "lithuania"]),
+    ("🇱🇺", "flag: Luxembourg", &["flag_lu", "luxembourg"]),
+    ("🇱🇻", "flag: Latvia", &["flag_lv", "latvia"]),
+    ("🇱🇾", "flag: Libya", &["flag_ly", "libya"]),
+    ("🇱", "regional indicator L", &["regional_indicator_l"]),
+    ("🇲🇦", "flag: Morocco", &["flag_ma", "morocco"]),
+    ("🇲🇨", "flag: Monaco", &["flag_mc", "monaco"]),
+    ("🇲🇩", "flag: Moldova", &["flag_md", "moldova"]),
+    ("🇲🇪", "flag: Montenegro", &["flag_me", "montenegro"]),
+    ("🇲🇫", "flag: St. Martin", &["flag_mf", "st_martin"]),
+    ("🇲🇬", "flag: Madagascar", &["flag_mg", "madagascar"]),
+    (
+        "🇲🇭",
+        "flag: Marshall Islands",
+        &["flag_mh", "marshall_islands"],
+    ),
+    ("🇲🇰", "flag: North Macedonia", &["flag_mk", "macedonia"]),
+    ("🇲🇱", "flag: Mali", &["flag_ml", "mali"]),
+    (
+        "🇲🇲",
+        "flag: Myanmar (Burma)",
+        &["burma", "flag_mm", "myanmar"],
+    ),
+    ("🇲🇳", "flag: Mongolia", &["flag_mn", "mongolia"]),
+    (
+        "🇲🇴",
+        "flag: Macao SAR China",
+        &["flag_mo", "macao", "macau"],
+    ),
+    (
+        "🇲🇵",
+        "flag: Northern Mariana Islands",
+        &["flag_mp", "northern_mariana_islands"],
+    ),
+    ("🇲🇶", "flag: Martinique", &["flag_mq", "martinique"]),
+    ("🇲🇷", "flag: Mauritania", &["flag_mr", "mauritania"]),
+    ("🇲🇸", "flag: Montserrat", &["flag_ms", "montserrat"]),
+    ("🇲🇹", "flag: Malta", &["flag_mt", "malta"]),
+    ("🇲🇺", "flag: Mauritius", &["flag_mu", "mauritius"]),
+    ("🇲🇻", "flag: Maldives", &["flag_mv", "maldives"]),
+    ("🇲🇼", "flag: Malawi", &["flag_mw", "malawi"]),
+    ("🇲🇽", "flag: Mexico", &["flag_mx", "mexico"]),
+    ("🇲🇾", "flag: Malaysia", &["flag_my", "malaysia"]),
+    ("🇲🇿", "flag: Mozambique", &["flag_mz", "mozambique"]),
+    ("🇲", "regional indicator M", &["regional_indicator_m"]),
+    ("🇳🇦", "flag: Namibia", &["flag_na", "namibia"]),
+    ("🇳🇨", "flag: New Caledonia", &["flag_nc", "new_caledonia"]),
+    ("🇳🇪", "flag: Niger", &["flag_ne", "niger"]),
+    ("🇳🇫", "flag: Norfolk Island", &["flag_nf", "norfolk_island"]),
+    ("🇳🇬", "flag: Nigeria", &["flag_ng", "nigeria"]),
+    ("🇳🇮", "flag: Nicaragua", &["flag_ni", "nicaragua"]),
+    ("🇳🇱", "flag: Netherlands", &["flag_nl", "netherlands"]),
+    ("🇳🇴", "flag: Norway", &["flag_no", "norway"]),
+    ("🇳🇵", "flag: Nepal", &["flag_np", "nepal"]),
+    ("🇳🇷", "flag: Nauru", &["flag_nr", "nauru"]),
+    ("🇳🇺", "flag: Niue", &["flag_nu", "niue"]),
+    ("🇳🇿", "flag: New Zealand", &["flag_nz", "new_zealand"]),
+    ("🇳", "regional indicator N", &["regional_indicator_n"]),
+    ("🇴🇲", "flag: Oman", &["flag_om", "oman"]),
+    ("🇴", "regional indicator O", &["regional_indicator_o"]),
+    ("🇵🇦", "flag: Panama", &["flag_pa", "panama"]),
+    ("🇵🇪", "flag: Peru", &["flag_pe", "peru"]),
+    (
+        "🇵🇫",
+        "flag: French Polynesia",
+        &["flag_pf", "french_polynesia"],
+    ),
+    (
+        "🇵🇬",
+        "flag: Papua New Guinea",
+        &["flag_pg", "papua_new_guinea"],
+    ),
+    ("🇵🇭", "flag: Philippines", &["flag_ph", "philippines"]),
+    ("🇵🇰", "flag: Pakistan", &["flag_pk", "pakistan"]),
+    ("🇵🇱", "flag: Poland", &["flag_pl", "poland"]),
+    (
+        "🇵🇲",
+        "flag: St. Pierre & Miquelon",
+        &["flag_pm", "st_pierre_miquelon"],
+    ),
+    (
+        "🇵🇳",
+        "flag: Pitcairn Islands",
+        &["flag_pn", "pitcairn_islands"],
+    ),
+    ("🇵🇷", "flag: Puerto Rico", &["flag_pr", "puerto_rico"]),
+    (
+        "🇵🇸",
+        "flag: Palestinian Territories",
+        &["flag_ps", "palestinian_territories"],
+    ),
+    ("🇵🇹", "flag: Portugal", &["flag_pt", "portugal"]),
+    ("🇵🇼", "flag: Palau", &["flag_pw", "palau"]),
+    ("🇵🇾", "flag: Paraguay", &["flag_py", "paraguay"]),
+    ("🇵", "regional indicator P", &["regional_indicator_p"]),
+    ("🇶🇦", "flag: Qatar", &["flag_qa", "qatar"]),
+    ("🇶", "regional indicator Q", &["regional_indicator_q"]),
+    ("🇷🇪", "flag: Réunion", &["flag_re", "reunion"]),
+    ("🇷🇴", "flag: Romania", &["flag_ro", "romania"]),
+    ("🇷🇸", "flag: Serbia", &["flag_rs", "serbia"]),
+    ("🇷🇺", "flag: Russia", &["flag_ru", "russia"]),
+    ("🇷🇼", "flag: Rwanda", &["flag_rw", "rwanda"]),
+    ("🇷", "regional indicator R", &["regional_indicator_r"]),
+    ("🇸🇦", "flag: Saudi Arabia", &["flag_sa", "saudi_arabia"]),
+    (
+        "🇸🇧",
+        "flag: Solomon Islands",
+        &["flag_sb", "solomon_islands"],
+    ),
+    ("🇸🇨", "flag: Seychelles", &["flag_sc", "seychelles"]),
+    ("🇸🇩", "flag: Sudan", &["flag_sd", "sudan"]),
+    ("🇸🇪", "flag: Sweden", &["flag_se", "sweden"]),
+    ("🇸🇬", "flag: Singapore", &["flag_sg", "singapore"]),
+    ("🇸🇭", "flag: St. Helena", &["flag_sh", "st_helena"]),
+    ("🇸🇮", "flag: Slovenia", &["flag_si", "slovenia"]),
+    (
+        "🇸🇯",
+        "flag: Svalbard & Jan Mayen",
+        &["flag_sj", "svalbard_jan_mayen"],
+    ),
+    ("🇸🇰", "flag: Slovakia", &["flag_sk", "slovakia"]),
+    ("🇸🇱", "flag: Sierra Leone", &["flag_sl", "sierra_leone"]),
+    ("🇸🇲", "flag: San Marino", &["flag_sm", "san_marino"]),
+    ("🇸🇳", "flag: Senegal", &["flag_sn", "senegal"]),
+    ("🇸🇴", "flag: Somalia", &["flag_so", "somalia"]),
+    ("🇸🇷", "flag: Suriname", &["flag_sr", "suriname"]),
+    ("🇸🇸", "flag: South Sudan", &["flag_ss", "south_sudan"]),
+    (
+        "🇸🇹",
+        "flag: São Tomé & Príncipe",
+        &["flag_st", "sao_tome_principe"],
+    ),
+    ("🇸🇻", "flag: El Salvador", &["el_salvador", "flag_sv"]),
+    ("🇸🇽", "flag: Sint Maarten", &["flag_sx", "sint_maarten"]),
+    ("🇸🇾", "flag: Syria", &["flag_sy", "syria"]),
+    (
+        "🇸🇿",
+        "flag: Eswatini",
+        &["eswatini", "flag_sz", "swaziland"],
+    ),
+    ("🇸", "regional indicator S", &["regional_indicator_s"]),
+    (
+        "🇹🇦",
+        "flag: Tristan da Cunha",
+        &["flag_ta", "tristan_da_cunha"],
+    ),
+    (
+        "🇹🇨",
+        "flag: Turks & Caicos Islands",
+        &["flag_tc", "turks_caicos_islands"],
+    ),
+    ("🇹🇩", "flag: Chad", &["chad", "flag_td"]),
+    (
+        "🇹🇫",
+        "flag: French Southern Territories",
+        &["flag_tf", "french_southern_territories"],
+    ),
+    ("🇹🇬", "flag: Togo", &["flag_tg", "togo"]),
+    ("🇹🇭", "flag: Thailand", &["flag_th", "thailand"]),
+    ("🇹🇯", "flag: Tajikistan", &["flag_tj", "tajikistan"]),
+    ("🇹🇰", "flag: Tokelau", &["flag_tk", "tokelau"]),
+    ("🇹🇱", "flag: Timor-Leste", &["flag_tl", "timor_leste"]),
+    ("🇹🇲", "flag: Turkmenistan", &["flag_tm", "turkmenistan"]),
+    ("🇹🇳", "flag: Tunisia", &["flag_tn", "tunisia"]),
+    ("🇹🇴", "flag: Tonga", &["flag_to", "tonga"]),
+    ("🇹🇷", "flag: Türkiye", &["flag_tr", "turkey_tr"]),
+    (
+        "🇹🇹",
+        "flag: Trinidad & Tobago",
+        &["flag_tt", "trinidad_tobago"],
+    ),
+    ("🇹🇻", "flag: Tuvalu", &["flag_tv", "tuvalu"]),
+    ("🇹🇼", "flag: Taiwan", &["flag_tw", "taiwan"]),
+    ("🇹🇿", "flag: Tanzania", &["flag_tz", "tanzania"]),
+    ("🇹", "regional indicator T", &["regional_indicator_t"]),
+    ("🇺🇦", "flag: Ukraine", &["flag_ua", "ukraine"]),
+    ("🇺🇬", "flag: Uganda", &["flag_ug", "uganda"]),
+    (
+        "🇺🇲",
+        "flag: U.S. Outlying Islands",
+        &["flag_um", "us_outlying_islands"],
+    ),
+    (
+        "🇺🇳",
+        "flag: United Nations",
+        &["flag_un", "un", "united_nations"],
+    ),
+    (
+        "🇺🇸",
+        "flag: United States",
+        &["flag_us", "united_states", "usa"],
+    ),
+    ("🇺🇾", "flag: Uruguay", &["flag_uy", "uruguay"]),
+    ("🇺🇿", "flag: Uzbekistan", &["flag_uz", "uzbekistan"]),
+    ("🇺", "regional indicator U", &["regional_indicator_u"]),
+    ("🇻🇦", "flag: Vatican City", &["flag_va", "vatican_city"]),
+    (
+        "🇻🇨",
+        "flag: St. Vincent & Grenadines",
+        &["flag_vc", "st_vincent_grenadines"],
+    ),
+    ("🇻🇪", "flag: Venezuela", &["flag_ve", "venezuela"]),
+    (
+        "🇻🇬",
+        "flag: British Virgin Islands",
+        &["british_virgin_islands", "flag_vg"],
+    ),
+    (
+        "🇻🇮",
+        "flag: U.S. Virgin Islands",
+        &["flag_vi", "us_virgin_islands"],
+    ),
+    ("🇻🇳", "flag: Vietnam", &["flag_vn", "vietnam"]),
+    ("🇻🇺", "flag: Vanuatu", &["flag_vu", "vanuatu"]),
+    ("🇻", "regional indicator V", &["regional_indicator_v"]),
+    ("🇼🇫", "flag: Wallis & Futuna", &["flag_wf", "wallis_futuna"]),
+    ("🇼🇸", "flag: Samoa", &["flag_ws", "samoa"]),
+    ("🇼", "regional indicator W", &["regional_indicator_w"]),
+    ("🇽🇰", "flag: Kosovo", &["flag_xk", "kosovo"]),
+    ("🇽", "regional indicator X", &["regional_indicator_x"]),
+    ("🇾🇪", "flag: Yemen", &["flag_ye", "yemen"]),
+    ("🇾🇹", "flag: Mayotte", &["flag_yt", "mayotte"]),
+    ("🇾", "regional indicator Y", &["regional_indicator_y"]),
+    ("🇿🇦", "flag: South Africa", &["flag_za", "south_africa"]),
+    ("🇿🇲", "flag: Zambia", &["flag_zm", "zambia"]),
+    ("🇿🇼", "flag: Zimbabwe", &["flag_zw", "zimbabwe"]),
+    ("🇿", "regional indicator Z", &["regional_indicator_z"]),
+    ("🈁", "Japanese “here” button", &["ja_here", "koko"]),
+    (
+        "🈂",
+        "Japanese “service charge” button",
+        &["ja_service_charge"],
+    ),
+    (
+        "🈚",
+        "Japanese “free of charge” button",
+        &["ja_free_of_charge"],
+    ),
+    ("🈯", "Japanese “reserved” button", &["ja_reserved"]),
+    ("🈲", "Japanese “prohibited” button", &["ja_prohibited"]),
+    ("🈳", "Japanese “vacancy” button", &["ja_vacancy"]),
+    (
+        "🈴",
+        "Japanese “passing grade” button",
+        &["ja_passing_grade"],
+    ),
+    ("🈵", "Japanese “no vacancy” button", &["ja_no_vacancy"]),
+    (
+        "🈶",
+        "Japanese “not free of charge” button",
+        &["ja_not_free_of_carge"],
+    ),
+    (
+        "🈷",
+        "Japanese “monthly amount” button",
+        &["ja_monthly_amount"],
+    ),
+    ("🈸", "Japanese “application” button", &["ja_application"]),
+    ("🈹", "Japanese “discount” button", &["ja_discount"]),
+    (
+        "🈺",
+        "Japanese “open for business” button",
+        &["ja_open_for_business"],
+    ),
+    (
+        "🉐",
+        "Japanese “bargain” button",
+        &["ideograph_advantage", "ja_bargain"],
+    ),
+    (
+        "🉑",
+        "Japanese “acceptable” button",
+        &["accept", "ja_acceptable"],
+    ),
+    ("🌀", "cyclone", &["cyclone"]),
+    ("🌁", "foggy", &["foggy"]),
+    ("🌂", "closed umbrella", &["closed_umbrella"]),
+    ("🌃", "night with stars", &["night_with_stars"]),
+    ("🌄", "sunrise over mountains", &["sunrise_over_mountains"]),
+    ("🌅", "sunrise", &["sunrise"]),
+    ("🌆", "cityscape at dusk", &["city_dusk"]),
+    ("🌇", "sunset", &["city_sunrise", "city_sunset"]),
+    ("🌈", "rainbow", &["rainbow"]),
+    ("🌉", "bridge at night", &["bridge_at_night"]),
+    ("🌊", "water wave", &["ocean", "water_wave"]),
+    ("🌋", "volcano", &["volcano"]),
+    ("🌌", "milky way", &["milky_way"]),
+    (
+        "🌍",
+        "globe showing Europe-Africa",
+        &["earth_africa", "earth_europe"],
+    ),
+    ("🌎", "globe showing Americas", &["earth_americas"]),
+    ("🌏", "globe showing Asia-Australia", &["earth_asia"]),
+    ("🌐", "globe with meridians", &["globe_with_meridians"]),
+    ("🌑", "new moon", &["new_moon"]),
+    ("🌒", "waxing crescent moon", &["waxing_crescent_moon"]),
+    ("🌓", "first quarter moon", &["first_quarter_moon"]),
+    ("🌔", "waxing gibbous moon", &["waxing_gibbous_moon"]),
+    ("🌕", "full moon", &["full_moon"]),
+    ("🌖", "waning gibbous moon", &["waning_gibbous_moon"]),
+    ("🌗", "last quarter moon", &["last_quarter_moon"]),
+    ("🌘", "waning crescent moon", &["waning_crescent_moon"]),
+    ("🌙", "crescent moon", &["crescent_moon"]),
+    ("🌚", "new moon face", &["new_moon_with_face"]),
+    (
+        "🌛",
+        "first quarter moon face",
+        &["first_quarter_moon_with_face"],
+    ),
+    (
+        "🌜",
+        "last quarter moon face",
+        &["last_quarter_moon_with_face"],
+    ),
+    ("🌝", "full moon face", &["full_moon_with_face"]),
+    ("🌞", "sun with face", &["sun_with_face"]),
+    ("🌟", "glowing star", &["glowing_star", "star2"]),
+    ("🌠", "shooting star", &["shooting_star", "stars"]),
+    ("🌡", "thermometer", &["thermometer"]),
+    (
+        "🌤",
+        "sun behind small cloud",
+        &["sun_behind_small_cloud", "sunny"],
+    ),
+    (
+        "🌥",
+        "sun behind large cloud",
+        &["cloudy", "sun_behind_large_cloud"],
+    ),
+    (
+        "🌦",
+        "sun behind rain cloud",
+        &["sun_and_rain", "sun_behind_rain_cloud"],
+    ),
+    ("🌧", "cloud with rain", &["cloud_with_rain", "rainy"]),
+    ("🌨", "cloud with snow", &["cloud_with_snow", "snowy"]),
+    (
+        "🌩",
+        "cloud with lightning",
+        &["cloud_with_lightning", "lightning"],
+    ),
+    ("🌪", "tornado", &["tornado"]),
+    ("🌫", "fog", &["fog"]),
+    ("🌬", "wind face", &["wind_blowing_face"]),
+    ("🌭", "hot dog", &["hotdog"]),
+    ("🌮", "taco", &["taco"]),
+    ("🌯", "burrito", &["burrito"]),
+    ("🌰", "chestnut", &["chestnut"]),
+    ("🌱", "seedling", &["seedling"]),
+    ("🌲", "evergreen tree", &["evergreen_tree"]),
+    ("🌳", "deciduous tree", &["deciduous_tree"]),
+    ("🌴", "palm tree", &["palm_tree"]),
+    ("🌵", "cactus", &["cactus"]),
+    ("🌶", "hot pepper", &["hot_pepper"]),
+    ("🌷", "tulip", &["tulip"]),
+    ("🌸", "cherry blossom", &["cherry_blossom"]),
+    ("🌹", "rose", &["rose"]),
+    ("🌺", "hibiscus", &["hibiscus"]),
+    ("🌻", "sunflower", &["sunflower"]),
+    ("🌼", "blossom", &["blossom"]),
+    ("🌽", "ear of corn", &["corn", "ear_of_corn"]),
+    ("🌾", "sheaf of rice", &["ear_of_rice", "sheaf_of_rice"]),
+    ("🌿", "herb", &["herb"]),
+    ("🍀", "four leaf clover", &["four_leaf_clover"]),
+    ("🍁", "maple leaf", &["maple_leaf"]),
+    ("🍂", "fallen leaf", &["fallen_leaf"]),
+    ("🍃", "leaf fluttering in wind", &["leaves"]),
+    ("🍄‍🟫", "brown mushroom", &["brown_mushroom"]),
+    ("🍄", "mushroom", &["mushroom"]),
+    ("🍅", "tomato", &["tomato"]),
+    ("🍆", "eggplant", &["eggplant"]),
+    ("🍇", "grapes", &["grapes"]),
+    ("🍈", "melon", &["melon"]),
+    ("🍉", "watermelon", &["watermelon"]),
+    ("🍊", "tangerine", &["orange", "tangerine"]),
+    ("🍋‍🟩", "lime", &["lime"]),
+    ("🍋", "lemon", &["lemon"]),
+    ("🍌", "banana", &["banana"]),
+    ("🍍", "pineapple", &["pineapple"]),
+    ("🍎", "red apple", &["apple", "red_apple"]),
+    ("🍏", "green apple", &["green_apple"]),
+    ("🍐", "pear", &["pear"]),
+    ("🍑", "peach", &["peach"]),
+    ("🍒", "cherries", &["cherries"]),
+    ("🍓", "strawberry", &["strawberry"]),
+    ("🍔", "hamburger", &["hamburger"]),
+    ("🍕", "pizza", &["pizza"]),
+    ("🍖", "meat on bone", &["meat_on_bone"]),
+    ("🍗", "poultry leg", &["poultry_leg"]),
+    ("🍘", "rice cracker", &["rice_cracker"]),
+    ("🍙", "rice ball", &["rice_ball"]),
+    ("🍚", "cooked rice", &["cooked_rice", "rice"]),
+    ("🍛", "curry rice", &["curry", "curry_rice"]),
+    ("🍜", "steaming bowl", &["ramen", "steaming_bowl"]),
+    ("🍝", "spaghetti", &["spaghetti"]),
+    ("🍞", "bread", &["bread"]),
+    ("🍟", "french fries", &["french_fries", "fries"]),
+    ("🍠", "roasted sweet potato", &["sweet_potato"]),
+    ("🍡", "dango", &["dango"]),
+    ("🍢", "oden", &["oden"]),
+    ("🍣", "sushi", &["sushi"]),
+    ("🍤", "fried shrimp", &["fried_shrimp"]),
+    ("🍥", "fish cake with swirl", &["fish_cake"]),
+    ("🍦", "soft ice cream", &["icecream", "soft_serve"]),
+    ("🍧", "shaved ice", &["shaved_ice"]),
+    ("🍨", "ice cream", &["ice_cream"]),
+    ("🍩", "doughnut", &["doughnut"]),
+    ("🍪", "cookie", &["cookie"]),
+    ("🍫", "chocolate bar", &["chocolate_bar"]),
+    ("🍬", "candy", &["candy"]),
+    ("🍭", "lollipop", &["lollipop"]),
+    ("🍮", "custard", &["custard"]),
+    ("🍯", "honey pot", &["honey_pot"]),
+    ("🍰", "shortcake", &["cake", "shortcake"]),
+    ("🍱", "bento box", &["bento", "bento_box"]),
+    ("🍲", "pot of food", &["pot_of_food", "stew"]),
+    ("🍳", "cooking", &["cooking", "fried_egg"]),
+    ("🍴", "fork and knife", &["fork_and_knife"]),
+    ("🍵", "teacup without handle", &["tea"]),
+    ("🍶", "sake", &["sake"]),
+    ("🍷", "wine glass", &["wine_glass"]),
+    ("🍸", "cocktail glass", &["cocktail"]),
+    ("🍹", "tropical drink", &["tropical_drink"]),
+    ("🍺", "beer mug", &["beer"]),
+    ("🍻", "clinking beer mugs", &["beers"]),
+    ("🍼", "baby bottle", &["baby_bottle"]),
+    ("🍽", "fork and knife with plate", &["fork_knife_plate"]),
+    ("🍾", "bottle with popping cork", &["champagne"]),
+    ("🍿", "popcorn", &["popcorn"]),
+    ("🎀", "ribbon", &["ribbon"]),
+    ("🎁", "wrapped gift", &["gift"]),
+    ("🎂", "birthday cake", &["birthday", "birthday_cake"]),
+    ("🎃", "jack-o-lantern", &["jack_o_lantern"]),
+    ("🎄", "Christmas tree", &["christmas_tree"]),
+    ("🎅🏻", "Santa Claus: light skin tone", &["santa_tone1"]),
+    (
+        "🎅🏼",
+        "Santa Claus: medium-light skin tone",
+        &["santa_tone2"],
+    ),
+    ("🎅🏽", "Santa Claus: medium skin tone", &["santa_tone3"]),
+    ("🎅🏾", "Santa Claus: medium-dark skin tone", &["santa_tone4"]),
+    ("🎅🏿", "Santa Claus: dark skin tone", &["santa_tone5"]),
+    ("🎅", "Santa Claus", &["santa"]),
+    ("🎆", "fireworks", &["fireworks"]),
+    ("🎇", "sparkler", &["sparkler"]),
+    ("🎈", "balloon", &["balloon"]),
+    ("🎉", "party popper", &["party", "party_popper", "tada"]),
+    ("🎊", "confetti ball", &["confetti_ball"]),
+    ("🎋", "tanabata tree", &["tanabata_tree"]),
+    ("🎌", "crossed flags", &["crossed_flags"]),
+    ("🎍", "pine decoration", &["bamboo"]),
+    ("🎎", "Japanese dolls", &["dolls"]),
+    ("🎏", "carp streamer", &["carp_streamer", "flags"]),
+    ("🎐", "wind chime", &["wind_chime"]),
+    (
+        "🎑",
+        "moon viewing ceremony",
+        &["moon_ceremony", "rice_scene"],
+    ),
+    ("🎒", "backpack", &["backpack", "school_satchel"]),
+    ("🎓", "graduation cap", &["graduation_cap", "mortar_board"]),
+    ("🎖", "military medal", &["military_medal"]),
+    ("🎗", "reminder ribbon", &["reminder_ribbon"]),
+    ("🎙", "studio microphone", &["studio_microphone"]),
+    ("🎚", "level slider", &["level_slider"]),
+    ("🎛", "control knobs", &["control_knobs"]),
+    ("🎞", "film frames", &["film_frames"]),
+    ("🎟", "admission tickets", &["admission_tickets", "tickets"]),
+    ("🎠", "carousel horse", &["carousel_horse"]),
+    ("🎡", "ferris wheel", &["ferris_wheel"]),
+    ("🎢", "roller coaster", &["roller_coaster"]),
+    (
+        "🎣",
+        "fishing pole",
+        &["fishing_pole", "fishing_pole_and_fish"],
+    ),
+    ("🎤", "microphone", &["microphone"]),
+    ("🎥", "movie camera", &["movie_camera"]),
+    ("🎦", "cinema", &["cinema"]),
+    ("🎧", "headphone", &["headphones"]),
+    ("🎨", "artist palette", &["art", "palette"]),
+    ("🎩", "top hat", &["top_hat", "tophat"]),
+    ("🎪", "circus tent", &["circus_tent"]),
+    ("🎫", "ticket", &["ticket"]),
+    ("🎬", "clapper board", &["clapper"]),
+    ("🎭", "performing arts", &["performing_arts"]),
+    ("🎮", "video game", &["controller", "video_game"]),
+    ("🎯", "bullseye", &["bullseye", "dart", "direct_hit"]),
+    ("🎰", "slot machine", &["slot_machine"]),
+    ("🎱", "pool 8 ball", &["8ball", "billiards"]),
+    ("🎲", "game die", &["game_die"]),
+    ("🎳", "bowling", &["bowling"]),
+    ("🎴", "flower playing cards", &["flower_playing_cards"]),
+    ("🎵", "musical note", &["musical_note"]),
+    ("🎶", "musical notes", &["musical_notes", "notes"]),
+    ("🎷", "saxophone", &["saxophone"]),
+    ("🎸", "guitar", &["guitar"]),
+    ("🎹", "musical keyboard", &["musical_keyboard"]),
+    ("🎺", "trumpet", &["trumpet"]),
+    ("🎻", "violin", &["violin"]),
+    ("🎼", "musical score", &["musical_score"]),
+    (
+        "🎽",
+        "running shirt",
+        &["running_shirt", "running_shirt_with_sash"],
+    ),
+    ("🎾", "tennis", &["tennis"]),
+    ("🎿", "skis", &["ski"]),
+    ("🏀", "basketball", &["basketball"]),
+    ("🏁", "chequered flag", &["checkered_flag"]),
+    (
+        "🏂🏻",
+        "snowboarder: light skin tone",
+        &[
+            "person_snowboarding_tone1",
+            "snowboarder_tone1",
+            "snowboarding_tone1",
+        ],
+    ),
+    (
+        "🏂🏼",
+        "snowboarder: medium-light skin tone",
+        &[
+            "person_snowboarding_tone2",
+            "snowboarder_tone2",
+            "snowboarding_tone2",
+        ],
+    ),
+    (
+        "🏂🏽",
+        "snowboarder: medium skin tone",
+        &[
+            "person_snowboarding_tone3",
+            "snowboarder_tone3",
+            "snowboarding_tone3",
+        ],
+    ),
+    (
+        "🏂🏾",
+        "snowboarder: medium-dark skin tone",
+        &[
+            "person_snowboarding_tone4",
+            "snowboarder_tone4",
+            "snowboarding_tone4",
+        ],
+    ),
+    (
+        "🏂🏿",
+        "snowboarder: dark skin tone",
+        &[
+            "person_snowboarding_tone5",
+            "snowboarder_tone5",
+            "snowboarding_tone5",
+        ],
+    ),
+    (
+        "🏂",
+        "snowboarder",
+        &["person_snowboarding", "snowboarder", "snowboarding"],
+    ),
+    (
+        "🏃🏻‍♀️‍➡️",
+        "woman running facing right: light skin tone",
+        &["woman_running_right_tone1"],
+    ),
+    (
+        "🏃🏻‍♀️",
+        "woman running: light skin tone",
+        &["woman_running_tone1"],
+    ),
+    (
+        "🏃🏻‍♂️‍➡️",
+        "man running facing right: light skin tone",
+        &["man_running_right_tone1"],
+    ),
+    ("🏃🏻‍♂️", "man running: light skin tone", &["man_running_tone1"]),
+    (
+        "🏃🏻‍➡️",
+        "person running facing right: light skin tone",
+        &["person_running_right_tone1"],
+    ),
+    (
+        "🏃🏻",
+        "person running: light skin tone",
+        &["person_running_tone1", "running_tone1"],
+    ),
+    (
+        "🏃🏼‍♀️‍➡️",
+        "woman running facing right: medium-light skin tone",
+        &["woman_running_right_tone2"],
+    ),
+    (
+        "🏃🏼‍♀️",
+        "woman running: medium-light skin tone",
+        &["woman_running_tone2"],
+    ),
+    (
+        "🏃🏼‍♂️‍➡️",
+        "man running facing right: medium-light skin tone",
+        &["man_running_right_tone2"],
+    ),
+    (
+        "🏃🏼‍♂️",
+        "man running: medium-light skin tone",
+        &["man_running_tone2"],
+    ),
+    (
+        "🏃🏼‍➡️",
+        "person running facing right: medium-light skin tone",
+        &["person_running_right_tone2"],
+    ),
+    (
+        "🏃🏼",
+        "person running: medium-light skin tone",
+        &["person_running_tone2", "running_tone2"],
+    ),
+    (
+        "🏃🏽‍♀️‍➡️",
+        "woman running facing right: medium skin tone",
+        &["woman_running_right_tone3"],
+    ),
+    (
+        "🏃🏽‍♀️",
+        "woman running: medium skin tone",
+        &["woman_running_tone3"],
+    ),
+    (
+        "🏃🏽‍♂️‍➡️",
+        "man running facing right: medium skin tone",
+        &["man_running_right_tone3"],
+    ),
+    (
+        "🏃🏽‍♂️",
+        "man running: medium skin tone",
+        &["man_running_tone3"],
+    ),
+    (
+        "🏃🏽‍➡️",
+        "person running facing right: medium skin tone",
+        &["person_running_right_tone3"],
+    ),
+    (
+        "🏃🏽",
+        "person running: medium skin tone",
+        &["person_running_tone3", "running_tone3"],
+    ),
+    (
+        "🏃🏾‍♀️‍➡️",
+        "woman running facing right: medium-dark skin tone",
+        &["woman_running_right_tone4"],
+    ),
+    (
+        "🏃🏾‍♀️",
+        "woman running: medium-dark skin tone",
+        &["woman_running_tone4"],
+    ),
+    (
+        "🏃🏾‍♂️‍➡️",
+        "man running facing right: medium-dark skin tone",
+        &["man_running_right_tone4"],
+    ),
+    (
+        "🏃🏾‍♂️",
+        "man running: medium-dark skin tone",
+        &["man_running_tone4"],
+    ),
+    (
+        "🏃🏾‍➡️",
+        "person running facing right: medium-dark skin tone",
+        &["person_running_right_tone4"],
+    ),
+    (
+        "🏃🏾",
+        "person running: medium-dark skin tone",
+        &["person_running_tone4", "running_tone4"],
+    ),
+    (
+        "🏃🏿‍♀️‍➡️",
+        "woman running facing right: dark skin tone",
+        &["woman_running_right_tone5"],
+    ),
+    (
+        "🏃🏿‍♀️",
+        "woman running: dark skin tone",
+        &["woman_running_tone5"],
+    ),
+    (
+        "🏃🏿‍♂️‍➡️",
+        "man running facing right: dark skin tone",
+        &["man_running_right_tone5"],
+    ),
+    ("🏃🏿‍♂️", "man running: dark skin tone", &["man_running_tone5"]),
+    (
+        "🏃🏿‍➡️",
+        "person running facing right: dark skin tone",
+        &["person_running_right_tone5"],
+    ),
+    (
+        "🏃🏿",
+        "person running: dark skin tone",
+        &["person_running_tone5", "running_tone5"],
+    ),
+    ("🏃‍♀️‍➡️", "woman running facing right", &["woman_running_right"]),
+    ("🏃‍♀️", "woman running", &["woman_running"]),
+    ("🏃‍♂️‍➡️", "man running facing right", &["man_running_right"]),
+    ("🏃‍♂️", "man running", &["man_running"]),
+    (
+        "🏃‍➡️",
+        "person running facing right",
+        &["person_running_right"],
+    ),
+    ("🏃", "person running", &["person_running", "running"]),
+    (
+        "🏄🏻‍♀️",
+        "woman surfing: light skin tone",
+        &["woman_surfing_tone1"],
+    ),
+    ("🏄🏻‍♂️", "man surfing: light skin tone", &["man_surfing_tone1"]),
+    (
+        "🏄🏻",
+        "person surfing: light skin tone",
+        &["person_surfing_tone1", "surfer_tone1", "surfing_tone1"],
+    ),
+    (
+        "🏄🏼‍♀️",
+        "woman surfing: medium-light skin tone",
+        &["woman_surfing_tone2"],
+    ),
+    (
+        "🏄🏼‍♂️",
+        "man surfing: medium-light skin tone",
+        &["man_surfing_tone2"],
+    ),
+    (
+        "🏄🏼",
+        "person surfing: medium-light skin tone",
+        &["person_surfing_tone2", "surfer_tone2", "surfing_tone2"],
+    ),
+    (
+        "🏄🏽‍♀️",
+        "woman surfing: medium skin tone",
+        &["woman_surfing_tone3"],
+    ),
+    (
+        "🏄🏽‍♂️",
+        "man surfing: medium skin tone",
+        &["man_surfing_tone3"],
+    ),
+    (
+        "🏄🏽",
+        "person surfing: medium skin tone",
+        &["person_surfing_tone3", "surfer_tone3", "surfing_tone3"],
+    ),
+    (
+        "🏄🏾‍♀️",
+        "woman surfing: medium-dark skin tone",
+        &["woman_surfing_tone4"],
+    ),
+    (
+        "🏄🏾‍♂️",
+        "man surfing: medium-dark skin tone",
+        &["man_surfing_tone4"],
+    ),
+    (
+        "🏄🏾",
+        "person surfing: medium-dark skin tone",
+        &["person_surfing_tone4", "surfer_tone4", "surfing_tone4"],
+    ),
+    (
+        "🏄🏿‍♀️",
+        "woman surfing: dark skin tone",
+        &["woman_surfing_tone5"],
+    ),
+    ("🏄🏿‍♂️", "man surfing: dark skin tone", &["man_surfing_tone5"]),
+    (
+        "🏄🏿",
+        "person surfing: dark skin tone",
+        &["person_surfing_tone5", "surfer_tone5", "surfing_tone5"],
+    ),
+    ("🏄‍♀️", "woman surfing", &["woman_surfing"]),
+    ("🏄‍♂️", "man surfing", &["man_surfing"]),
+    (
+        "🏄",
+        "person surfing",
+        &["person_surfing", "surfer", "surfing"],
+    ),
+    ("🏅", "sports medal", &["sports_medal"]),
+    ("🏆", "trophy", &["trophy"]),
+    (
+        "🏇🏻",
+        "horse racing: light skin tone",
+        &["horse_racing_tone1"],
+    ),
+    (
+        "🏇🏼",
+        "horse racing: medium-light skin tone",
+        &["horse_racing_tone2"],
+    ),
+    (
+        "🏇🏽",
+        "horse racing: medium skin tone",
+        &["horse_racing_tone3"],
+    ),
+    (
+        "🏇🏾",
+        "horse racing: medium-dark skin tone",
+        &["horse_racing_tone4"],
+    ),
+    (
+        "🏇🏿",
+        "horse racing: dark skin tone",
+        &["horse_racing_tone5"],
+    ),
+    ("🏇", "horse racing", &["horse_racing"]),
+    ("🏈", "american football", &["football"]),
+    ("🏉", "rugby football", &["rugby_football"]),
+    (
+        "🏊🏻‍♀️",
+        "woman swimming: light skin tone",
+        &["woman_swimming_tone1"],
+    ),
+    (
+        "🏊🏻‍♂️",
+        "man swimming: light skin tone",
+        &["man_swimming_tone1"],
+    ),
+    (
+        "🏊🏻",
+        "person swimming: light skin tone",
+        &["person_swimming_tone1", "swimmer_tone1", "swimming_tone1"],
+    ),
+    (
+        "🏊🏼‍♀️",
+        "woman swimming: medium-light skin tone",
+        &["woman_swimming_tone2"],
+    ),
+    (
+        "🏊🏼‍♂️",
+        "man swimming: medium-light skin tone",
+        &["man_swimming_tone2"],
+    ),
+    (
+        "🏊🏼",
+        "person swimming: medium-light skin tone",
+        &["person_swimming_tone2", "swimmer_tone2", "swimming_tone2"],
+    ),
+    (
+        "🏊🏽‍♀️",
+        "woman swimming: medium skin tone",
+        &["woman_swimming_tone3"],
+    ),
+    (
+        "🏊🏽‍♂️",
+        "man swimming: medium skin tone",
+        &["man_swimming_tone3"],
+    ),
+    (
+        "🏊🏽",
+        "person swimming: medium skin tone",
+        &["person_swimming_tone3", "swimmer_tone3", "swimming_tone3"],
+    ),
+    (
+        "🏊🏾‍♀️",
+        "woman swimming: medium-dark skin tone",
+        &["woman_swimming_tone4"],
+    ),
+    (
+        "🏊🏾‍♂️",
+        "man swimming: medium-dark skin tone",
+        &["man_swimming_tone4"],
+    ),
+    (
+        "🏊🏾",
+        "person swimming: medium-dark skin tone",
+        &["person_swimming_tone4", "swimmer_tone4", "swimming_tone4"],
+    ),
+    (
+        "🏊🏿‍♀️",
+        "woman swimming: dark skin tone",
+        &["woman_swimming_tone5"],
+    ),
+    (
+        "🏊🏿‍♂️",
+        "man swimming: dark skin tone",
+        &["man_swimming_tone5"],
+    ),
+    (
+        "🏊🏿",
+        "person swimming: dark skin tone",
+        &["person_swimming_tone5", "swimmer_tone5", "swimming_tone5"],
+    ),
+    ("🏊‍♀️", "woman swimming", &["woman_swimming"]),
+    ("🏊‍♂️", "man swimming", &["man_swimming"]),
+    (
+        "🏊",
+        "person swimming",
+        &["person_swimming", "swimmer", "swimming"],
+    ),
+    (
+        "🏋🏻‍♀️",
+        "woman lifting weights: light skin tone",
+        &["woman_lifting_weights_tone1"],
+    ),
+    (
+        "🏋🏻‍♂️",
+        "man lifting weights: light skin tone",
+        &["man_lifting_weights_tone1"],
+    ),
+    (
+        "🏋🏻",
+        "person lifting weights: light skin tone",
+        &[
+            "person_lifting_weights_tone1",
+            "weight_lifter_tone1",
+            "weight_lifting_tone1",
+        ],
+    ),
+    (
+        "🏋🏼‍♀️",
+        "woman lifting weights: medium-light skin tone",
+        &["woman_lifting_weights_tone2"],
+    ),
+    (
+        "🏋🏼‍♂️",
+        "man lifting weights: medium-light skin tone",
+        &["man_lifting_weights_tone2"],
+    ),
+    (
+        "🏋🏼",
+        "person lifting weights: medium-light skin tone",
+        &[
+            "person_lifting_weights_tone2",
+            "weight_lifter_tone2",
+            "weight_lifting_tone2",
+        ],
+    ),
+    (
+        "🏋🏽‍♀️",
+        "woman lifting weights: medium skin tone",
+        &["woman_lifting_weights_tone3"],
+    ),
+    (
+        "🏋🏽‍♂️",
+        "man lifting weights: medium skin tone",
+        &["man_lifting_weights_tone3"],
+    ),
+    (
+        "🏋🏽",
+        "person lifting weights: medium skin tone",
+        &[
+            "person_lifting_weights_tone3",
+            "weight_lifter_tone3",
+            "weight_lifting_tone3",
+        ],
+    ),
+    (
+        "🏋🏾‍♀️",
+        "woman lifting weights: medium-dark skin tone",
+        &["woman_lifting_weights_tone4"],
+    ),
+    (
+        "🏋🏾‍♂️",
+        "man lifting weights: medium-dark skin tone",
+        &["man_lifting_weights_tone4"],
+    ),
+    (
+        "🏋🏾",
+        "person lifting weights: medium-dark skin tone",
+        &[
+            "person_lifting_weights_tone4",
+            "weight_lifter_tone4",
+            "weight_lifting_tone4",
+        ],
+    ),
+    (
+        "🏋🏿‍♀️",
+        "woman lifting weights: dark skin tone",
+        &["woman_lifting_weights_tone5"],
+    ),
+    (
+        "🏋🏿‍♂️",
+        "man lifting weights: dark skin tone",
+        &["man_lifting_weights_tone5"],
+    ),
+    (
+        "🏋🏿",
+        "person lifting weights: dark skin tone",
+        &[
+            "person_lifting_weights_tone5",
+            "weight_lifter_tone5",
+            "weight_lifting_tone5",
+        ],
+    ),
+    ("🏋️‍♀️", "woman lifting weights", &["woman_lifting_weights"]),
+    ("🏋️‍♂️", "man lifting weights", &["man_lifting_weights"]),
+    (
+        "🏋",
+        "person lifting weights",
+        &["person_lifting_weights", "weight_lifter", "weight_lifting"],
+    ),
+    (
+        "🏌🏻‍♀️",
+        "woman golfing: light skin tone",
+        &["woman_golfing_tone1"],
+    ),
+    ("🏌🏻‍♂️", "man golfing: light skin tone", &["man_golfing_tone1"]),
+    (
+        "🏌🏻",
+        "person golfing: light skin tone",
+        &["golfer_tone1", "golfing_tone1", "person_golfing_tone1"],
+    ),
+    (
+        "🏌🏼‍♀️",
+        "woman golfing: medium-light skin tone",
+        &["woman_golfing_tone2"],
+    ),
+    (
+        "🏌🏼‍♂️",
+        "man golfing: medium-light skin tone",
+        &["man_golfing_tone2"],
+    ),
+    (
+        "🏌🏼",
+        "person golfing: medium-light skin tone",
+        &["golfer_tone2", "golfing_tone2", "person_golfing_tone2"],
+    ),
+    (
+        "🏌🏽‍♀️",
+        "woman golfing: medium skin tone",
+        &["woman_golfing_tone3"],
+    ),
+    (
+        "🏌🏽‍♂️",
+        "man golfing: medium skin tone",
+        &["man_golfing_tone3"],
+    ),
+    (
+        "🏌🏽",
+        "person golfing: medium skin tone",
+        &["golfer_tone3", "golfing_tone3", "person_golfing_tone3"],
+    ),
+    (
+        "🏌🏾‍♀️",
+        "woman golfing: medium-dark skin tone",
+        &["woman_golfing_tone4"],
+    ),
+    (
+        "🏌🏾‍♂️",
+        "man golfing: medium-dark skin tone",
+        &["man_golfing_tone4"],
+    ),
+    (
+        "🏌🏾",
+        "person golfing: medium-dark skin tone",
+        &["golfer_tone4", "golfing_tone4", "person_golfing_tone4"],
+    ),
+    (
+        "🏌🏿‍♀️",
+        "woman golfing: dark skin tone",
+        &["woman_golfing_tone5"],
+    ),
+    ("🏌🏿‍♂️", "man golfing: dark skin tone", &["man_golfing_tone5"]),
+    (
+        "🏌🏿",
+        "person golfing: dark skin tone",
+        &["golfer_tone5", "golfing_tone5", "person_golfing_tone5"],
+    ),
+    ("🏌️‍♀️", "woman golfing", &["woman_golfing"]),
+    ("🏌️‍♂️", "man golfing", &["man_golfing"]),
+    (
+        "🏌",
+        "person golfing",
+        &["golfer", "golfing", "person_golfing"],
+    ),
+    ("🏍", "motorcycle", &["motorcycle"]),
+    ("🏎", "racing car", &["racing_car"]),
+    ("🏏", "cricket game", &["cricket_game"]),
+    ("🏐", "volleyball", &["volleyball"]),
+    ("🏑", "field hockey", &["field_hockey"]),
+    ("🏒", "ice hockey", &["hockey"]),
+    ("🏓", "ping pong", &["ping_pong"]),
+    ("🏔", "snow-capped mountain", &["mountain_snow"]),
+    ("🏕", "camping", &["camping"]),
+    (
+        "🏖",
+        "beach with umbrella",
+        &["beach", "beach_with_umbrella"],
+    ),
+    (
+        "🏗",
+        "building construction",
+        &["building_construction", "construction_site"],
+    ),
+    ("🏘", "houses", &["homes", "houses"]),
+    ("🏙", "cityscape", &["cityscape"]),
+    (
+        "🏚",
+        "derelict house",
+        &["derelict_house", "house_abandoned"],
+    ),
+    ("🏛", "classical building", &["classical_building"]),
+    ("🏜", "desert", &["desert"]),
+    ("🏝", "desert island", &["desert_island", "island"]),
+    ("🏞", "national park", &["national_park"]),
+    ("🏟", "stadium", &["stadium"]),
+    ("🏠", "house", &["house"]),
+    ("🏡", "house with garden", &["house_with_garden"]),
+    ("🏢", "office building", &["office"]),
+    ("🏣", "Japanese post office", &["post_office"]),
+    ("🏤", "post office", &["european_post_office"]),
+    ("🏥", "hospital", &["hospital"]),
+    ("🏦", "bank", &["bank"]),
+    ("🏧", "ATM sign", &["atm"]),
+    ("🏨", "hotel", &["hotel"]),
+    ("🏩", "love hotel", &["love_hotel"]),
+    ("🏪", "convenience store", &["convenience_store"]),
+    ("🏫", "school", &["school"]),
+    ("🏬", "department store", &["department_store"]),
+    ("🏭", "factory", &["factory"]),
+    (
+        "🏮",
+        "red paper lantern",
+        &["izakaya_lantern", "red_paper_lantern"],
+    ),
+    ("🏯", "Japanese castle", &["japanese_castle"]),
+    ("🏰", "castle", &["castle", "european_castle"]),
+    ("🏳️‍🌈", "rainbow flag", &["rainbow_flag"]),
+    ("🏳️‍⚧️", "transgender flag", &["transgender_flag"]),
+    ("🏳", "white flag", &["white_flag"]),
+    ("🏴‍☠️", "pirate flag", &["jolly_roger", "pirate_flag"]),
+    ("🏴󠁧󠁢󠁥󠁮󠁧󠁿", "flag: England", &["england", "flag_gbeng"]),
+    ("🏴󠁧󠁢󠁳󠁣󠁴󠁿", "flag: Scotland", &["flag_gbsct", "scotland"]),
+    ("🏴󠁧󠁢󠁷󠁬󠁳󠁿", "flag: Wales", &["flag_gbwls", "wales"]),
+    ("🏴", "black flag", &["black_flag"]),
+    ("🏵", "rosette", &["rosette"]),
+    ("🏷", "label", &["label"]),
+    ("🏸", "badminton", &["badminton"]),
+    ("🏹", "bow and arrow", &["bow_and_arrow"]),
+    ("🏺", "amphora", &["amphora"]),
+    ("🏻", "light skin tone", &["tone1", "tone_light"]),
+    (
+        "🏼",
+        "medium-light skin tone",
+        &["tone2", "tone_medium_light"],
+    ),
+    ("🏽", "medium skin tone", &["tone3", "tone_medium"]),
+    (
+        "🏾",
+        "medium-dark skin tone",
+        &["tone4", "tone_medium_dark"],
+    ),
+    ("🏿", "dark skin tone", &["tone5", "tone_dark"]),
+    ("🐀", "rat", &["rat"]),
+    ("🐁", "mouse", &["mouse"]),
+    ("🐂", "ox", &["ox"]),
+    ("🐃", "water buffalo", &["water_buffalo"]),
+    ("🐄", "cow", &["cow"]),
+    ("🐅", "tiger", &["tiger"]),
+    ("🐆", "leopard", &["leopard"]),
+    ("🐇", "rabbit", &["rabbit"]),
+    ("🐈‍⬛", "black cat", &["black_cat"]),
+    ("🐈", "cat", &["cat"]),
+    ("🐉", "dragon", &["dragon"]),
+    ("🐊", "crocodile", &["crocodile"]),
+    ("🐋", "whale", &["whale"]),
+    ("🐌", "snail", &["snail"]),
+    ("🐍", "snake", &["snake"]),
+    ("🐎", "horse", &["horse", "racehorse"]),
+    ("🐏", "ram", &["ram"]),
+    ("🐐", "goat", &["goat"]),
+    ("🐑", "ewe", &["ewe", "sheep"]),
+    ("🐒", "monkey", &["monkey"]),
+    ("🐓", "rooster", &["rooster"]),
+    ("🐔", "chicken", &["chicken", "chicken_face"]),
+    ("🐕‍🦺", "service dog", &["service_dog"]),
+    ("🐕", "dog", &["dog"]),
+    ("🐖", "pig", &["pig"]),
+    ("🐗", "boar", &["boar"]),
+    ("🐘", "elephant", &["elephant"]),
+    ("🐙", "octopus", &["octopus"]),
+    ("🐚", "spiral shell", &["shell"]),
+    ("🐛", "bug", &["bug"]),
+    ("🐜", "ant", &["ant"]),
+    ("🐝", "honeybee", &["bee"]),
+    ("🐞", "lady beetle", &["lady_beetle"]),
+    ("🐟", "fish", &["fish"]),
+    ("🐠", "tropical fish", &["tropical_fish"]),
+    ("🐡", "blowfish", &["blowfish"]),
+    ("🐢", "turtle", &["turtle"]),
+    ("🐣", "hatching chick", &["hatching_chick"]),
+    ("🐤", "baby chick", &["baby_chick"]),
+    ("🐥", "front-facing baby chick", &["hatched_chick"]),
+    ("🐦‍🔥", "phoenix", &["phoenix"]),
+    ("🐦‍⬛", "black bird", &["black_bird"]),
+    ("🐦", "bird", &["bird", "bird_face"]),
+    ("🐧", "penguin", &["penguin", "penguin_face"]),
+    ("🐨", "koala", &["koala", "koala_face"]),
+    ("🐩", "poodle", &["poodle"]),
+    ("🐪", "camel", &["dromedary_camel"]),
+    ("🐫", "two-hump camel", &["camel"]),
+    ("🐬", "dolphin", &["dolphin"]),
+    ("🐭", "mouse face", &["mouse_face"]),
+    ("🐮", "cow face", &["cow_face"]),
+    ("🐯", "tiger face", &["tiger_face"]),
+    ("🐰", "rabbit face", &["rabbit_face"]),
+    ("🐱", "cat face", &["cat_face"]),
+    ("🐲", "dragon face", &["dragon_face"]),
+    ("🐳", "spouting whale", &["spouting_whale"]),
+    ("🐴", "horse face", &["horse_face"]),
+    ("🐵", "monkey face", &["monkey_face"]),
+    ("🐶", "dog face", &["dog_face"]),
+    ("🐷", "pig face", &["pig_face"]),
+    ("🐸", "frog", &["frog", "frog_face"]),
+    ("🐹", "hamster", &["hamster", "hamster_face"]),
+    ("🐺", "wolf", &["wolf", "wolf_face"]),
+    ("🐻‍❄️", "polar bear", &["polar_bear", "polar_bear_face"]),
+    ("🐻", "bear", &["bear", "bear_face"]),
+    ("🐼", "panda", &["panda", "panda_face"]),
+    ("🐽", "pig nose", &["pig_nose"]),
+    ("🐾", "paw prints", &["paw_prints"]),
+    ("🐿", "chipmunk", &["chipmunk"]),
+    ("👀", "eyes", &["eyes"]),
+    ("👁‍🗨", "eye in speech bubble", &["eye_in_speech_bubble"]),
+    ("👁", "eye", &["eye"]),
+    ("👂🏻", "ear: light skin tone", &["ear_tone1"]),
+    ("👂🏼", "ear: medium-light skin tone", &["ear_tone2"]),
+    ("👂🏽", "ear: medium skin tone", &["ear_tone3"]),
+    ("👂🏾", "ear: medium-dark skin tone", &["ear_tone4"]),
+    ("👂🏿", "ear: dark skin tone", &["ear_tone5"]),
+    ("👂", "ear", &["ear"]),
+    ("👃🏻", "nose: light skin tone", &["nose_tone1"]),
+    ("👃🏼", "nose: medium-light skin tone", &["nose_tone2"]),
+    ("👃🏽", "nose: medium skin tone", &["nose_tone3"]),
+    ("👃🏾", "nose: medium-dark skin tone", &["nose_tone4"]),
+    ("👃🏿", "nose: dark skin tone", &["nose_tone5"]),
+    ("👃", "nose", &["nose"]),
+    ("👄", "mouth", &["lips", "mouth"]),
+    ("👅", "tongue", &["tongue"]),
+    (
+        "👆🏻",
+        "backhand index pointing up: light skin tone",
+        &["point_up_tone1"],
+    ),
+    (
+        "👆🏼",
+        "backhand index pointing up: medium-light skin tone",
+        &["point_up_tone2"],
+    ),
+    (
+        "👆🏽",
+        "backhand index pointing up: medium skin tone",
+        &["point_up_tone3"],
+    ),
+    (
+        "👆🏾",
+        "backhand index pointing up: medium-dark skin tone",
+        &["point_up_tone4"],
+    ),
+    (
+        "👆🏿",
+        "backhand index pointing up: dark skin tone",
+        &["point_up_tone5"],
+    ),
+    ("👆", "backhand index pointing up", &["point_up"]),
+    (
+        "👇🏻",
+        "backhand index pointing down: light skin tone",
+        &["point_down_tone1"],
+    ),
+    (
+        "👇🏼",
+        "backhand index pointing down: medium-light skin tone",
+        &["point_down_tone2"],
+    ),
+    (
+        "👇🏽",
+        "backhand index pointing down: medium skin tone",
+        &["point_down_tone3"],
+    ),
+    (
+        "👇🏾",
+        "backhand index pointing down: medium-dark skin tone",
+        &["point_down_tone4"],
+    ),
+    (
+        "👇🏿",
+        "backhand index pointing down: dark skin tone",
+        &["point_down_tone5"],
+    ),
+    ("👇", "backhand index pointing down", &["point_down"]),
+    (
+        "👈🏻",
+        "backhand index pointing left: light skin tone",
+        &["point_left_tone1"],
+    ),
+    (
+        "👈🏼",
+        "backhand index pointing left: medium-light skin tone",
+        &["point_left_tone2"],
+    ),
+    (
+        "👈🏽",
+        "backhand index pointing left: medium skin tone",
+        &["point_left_tone3"],
+    ),
+    (
+        "👈🏾",
+        "backhand index pointing left: medium-dark skin tone",
+        &["point_left_tone4"],
+    ),
+    (
+        "👈🏿",
+        "backhand index pointing left: dark skin tone",
+        &["point_left_tone5"],
+    ),
+    ("👈", "backhand index pointing left", &["point_left"]),
+    (
+        "👉🏻",
+        "backhand index pointing right: light skin tone",
+        &["point_right_tone1"],
+    ),
+    (
+        "👉🏼",
+        "backhand index pointing right: medium-light skin tone",
+        &["point_right_tone2"],
+    ),
+    (
+        "👉🏽",
+        "backhand index pointing right: medium skin tone",
+        &["point_right_tone3"],
+    ),
+    (
+        "👉🏾",
+        "backhand index pointing right: medium-dark skin tone",
+        &["point_right_tone4"],
+    ),
+    (
+        "👉🏿",
+        "backhand index pointing right: dark skin tone",
+        &["point_right_tone5"],
+    ),
+    ("👉", "backhand index pointing right", &["point_right"]),
+    ("👊🏻", "oncoming fist: light skin tone", &["punch_tone1"]),
+    (
+        "👊🏼",
+        "oncoming fist: medium-light skin tone",
+        &["punch_tone2"],
+    ),
+    ("👊🏽", "oncoming fist: medium skin tone", &["punch_tone3"]),
+    (
+        "👊🏾",
+        "oncoming fist: medium-dark skin tone",
+        &["punch_tone4"],
+    ),
+    ("👊🏿", "oncoming fist: dark skin tone", &["punch_tone5"]),
+    ("👊", "oncoming fist", &["punch"]),
+    (
+        "👋🏻",
+        "waving hand: light skin tone",
+        &["wave_tone1", "waving_hand_tone1"],
+    ),
+    (
+        "👋🏼",
+        "waving hand: medium-light skin tone",
+        &["wave_tone2", "waving_hand_tone2"],
+    ),
+    (
+        "👋🏽",
+        "waving hand: medium skin tone",
+        &["wave_tone3", "waving_hand_tone3"],
+    ),
+    (
+        "👋🏾",
+        "waving hand: medium-dark skin tone",
+        &["wave_tone4", "waving_hand_tone4"],
+    ),
+    (
+        "👋🏿",
+        "waving hand: dark skin tone",
+        &["wave_tone5", "waving_hand_tone5"],
+    ),
+    ("👋", "waving hand", &["wave", "waving_hand"]),
+    ("👌🏻", "OK hand: light skin tone", &["ok_hand_tone1"]),
+    ("👌🏼", "OK hand: medium-light skin tone", &["ok_hand_tone2"]),
+    ("👌🏽", "OK hand: medium skin tone", &["ok_hand_tone3"]),
+    ("👌🏾", "OK hand: medium-dark skin tone", &["ok_hand_tone4"]),
+    ("👌🏿", "OK hand: dark skin tone", &["ok_hand_tone5"]),
+    ("👌", "OK hand", &["ok_hand"]),
+    (
+        "👍🏻",
+        "thumbs up: light skin tone",
+        &["+1_tone1", "thumbsup_tone1", "yes_tone1"],
+    ),
+    (
+        "👍🏼",
+        "thumbs up: medium-light skin tone",
+        &["+1_tone2", "thumbsup_tone2", "yes_tone2"],
+    ),
+    (
+        "👍🏽",
+        "thumbs up: medium skin tone",
+        &["+1_tone3", "thumbsup_tone3", "yes_tone3"],
+    ),
+    (
+        "👍🏾",
+        "thumbs up: medium-dark skin tone",
+        &["+1_tone4", "thumbsup_tone4", "yes_tone4"],
+    ),
+    (
+        "👍🏿",
+        "thumbs up: dark skin tone",
+        &["+1_tone5", "thumbsup_tone5", "yes_tone5"],
+    ),
+    ("👍", "thumbs up", &["+1", "thumbsup", "yes"]),
+    (
+        "👎🏻",
+        "thumbs down: light skin tone",
+        &["-1_tone1", "no_tone1", "thumbsdown_tone1"],
+    ),
+    (
+        "👎🏼",
+        "thumbs down: medium-light skin tone",
+        &["-1_tone2", "no_tone2", "thumbsdown_tone2"],
+    ),
+    (
+        "👎🏽",
+        "thumbs down: medium skin tone",
+        &["-1_tone3", "no_tone3", "thumbsdown_tone3"],
+    ),
+    (
+        "👎🏾",
+        "thumbs down: medium-dark skin tone",
+        &["-1_tone4", "no_tone4", "thumbsdown_tone4"],
+    ),
+    (
+        "👎🏿",
+        "thumbs down: dark skin tone",
+        &["-1_tone5", "no_tone5", "thumbsdown_tone5"],
+    ),
+    ("👎", "thumbs down", &["-1", "no", "thumbsdown"]),
+    (
+        "👏🏻",
+        "clapping hands: light skin tone",
+        &["clap_tone1", "clapping_hands_tone1"],
+    ),
+    (
+        "👏🏼",
+        "clapping hands: medium-light skin tone",
+        &["clap_tone2", "clapping_hands_tone2"],
+    ),
+    (
+        "👏🏽",
+        "clapping hands: medium skin tone",
+        &["clap_tone3", "clapping_hands_tone3"],
+    ),
+    (
+        "👏🏾",
+        "clapping hands: medium-dark skin tone",
+        &["clap_tone4", "clapping_hands_tone4"],
+    ),
+    (
+        "👏🏿",
+        "clapping hands: dark skin tone",
+        &["clap_tone5", "clapping_hands_tone5"],
+    ),
+    ("👏", "clapping hands", &["clap", "clapping_hands"]),
+    ("👐🏻", "open hands: light skin tone", &["open_hands_tone1"]),
+    (
+        "👐🏼",
+        "open hands: medium-light skin tone",
+        &["open_hands_tone2"],
+    ),
+    ("👐🏽", "open hands: medium skin tone", &["open_hands_tone3"]),
+    (
+        "👐🏾",
+        "open hands: medium-dark skin tone",
+        &["open_hands_tone4"],
+    ),
+    ("👐🏿", "open hands: dark skin tone", &["open_hands_tone5"]),
+    ("👐", "open hands", &["open_hands"]),
+    ("👑", "crown", &["crown"]),
+    ("👒", "woman’s hat", &["womans_hat"]),
+    ("👓", "glasses", &["eyeglasses", "glasses"]),
+    ("👔", "necktie", &["necktie"]),
+    ("👕", "t-shirt", &["shirt"]),
+    ("👖", "jeans", &["jeans"]),
+    ("👗", "dress", &["dress"]),
+    ("👘", "kimono", &["kimono"]),
+    ("👙", "bikini", &["bikini"]),
+    ("👚", "woman’s clothes", &["womans_clothes"]),
+    ("👛", "purse", &["purse"]),
+    ("👜", "handbag", &["handbag"]),
+    ("👝", "clutch bag", &["clutch_bag", "pouch"]),
+    ("👞", "man’s shoe", &["mans_shoe"]),
+    ("👟", "running shoe", &["athletic_shoe", "sneaker"]),
+    ("👠", "high-heeled shoe", &["high_heel"]),
+    ("👡", "woman’s sandal", &["sandal"]),
+    ("👢", "woman’s boot", &["boot"]),
+    ("👣", "footprints", &["footprints"]),
+    ("👤", "bust in silhouette", &["bust_in_silhouette"]),
+    ("👥", "busts in silhouette", &["busts_in_silhouette"]),
+    ("👦🏻", "boy: light skin tone", &["boy_tone1"]),
+    ("👦🏼", "boy: medium-light skin tone", &["boy_tone2"]),
+    ("👦🏽", "boy: medium skin tone", &["boy_tone3"]),
+    ("👦🏾", "boy: medium-dark skin tone", &["boy_tone4"]),
+    ("👦🏿", "boy: dark skin tone", &["boy_tone5"]),
+    ("👦", "boy", &["boy"]),
+    ("👧🏻", "girl: light skin tone", &["girl_tone1"]),
+    ("👧🏼", "girl: medium-light skin tone", &["girl_tone2"]),
+    ("👧🏽", "girl: medium skin tone", &["girl_tone3"]),
+    ("👧🏾", "girl: medium-dark skin tone", &["girl_tone4"]),
+    ("👧🏿", "girl: dark skin tone", &["girl_tone5"]),
+    ("👧", "girl", &["girl"]),
+    ("👨🏻‍🌾", "man farmer: light skin tone", &["man_farmer_tone1"]),
+    ("👨🏻‍🍳", "man cook: light skin tone", &["man_cook_tone1"]),
+    (
+        "👨🏻‍🍼",
+        "man feeding baby: light skin tone",
+        &["man_feeding_baby_tone1"],
+    ),
+    ("👨🏻‍🎄", "Santa Claus: light skin tone", &[]),
+    ("👨🏻‍🎓", "man student: light skin tone", &["man_student_tone1"]),
+    ("👨🏻‍🎤", "man singer: light skin tone", &["man_singer_tone1"]),
+    ("👨🏻‍🎨", "man artist: light skin tone", &["man_artist_tone1"]),
+    ("👨🏻‍🏫", "man teacher: light skin tone", &["man_teacher_tone1"]),
+    (
+        "👨🏻‍🏭",
+        "man factory worker: light skin tone",
+        &["man_factory_worker_tone1"],
+    ),
+    (
+        "👨🏻‍🐰‍👨🏼",
+        "men with bunny ears: light skin tone, medium-light skin tone",
+        &[],
+    ),
+    (
+        "👨🏻‍🐰‍👨🏽",
+        "men with bunny ears: light skin tone, medium skin tone",
+        &[],
+    ),
+    (
+        "👨🏻‍🐰‍👨🏾",
+        "men with bunny ears: light skin tone, medium-dark skin tone",
+        &[],
+    ),
+    (
+        "👨🏻‍🐰‍👨🏿",
+        "men with bunny ears: light skin tone, dark skin tone",
+        &[],
+    ),
+    (
+        "👨🏻‍💻",
+        "man technologist: light skin tone",
+        &["man_technologist_tone1"],
+    ),
+    (
+        "👨🏻‍💼",
+        "man office worker: light skin tone",
+        &["man_office_worker_tone1"],
+    ),
+    (
+        "👨🏻‍🔧",
+        "man mechanic: light skin tone",
+        &["man_mechanic_tone1"],
+    ),
+    (
+        "👨🏻‍🔬",
+        "man scientist: light skin tone",
+        &["man_scientist_tone1"],
+    ),
+    (
+        "👨🏻‍🚀",
+        "man astronaut: light skin tone",
+        &["man_astronaut_tone1"],
+    ),
+    (
+        "👨🏻‍🚒",
+        "man firefighter: light skin tone",
+        &["man_firefighter_tone1"],
+    ),
+    (
+        "👨🏻‍🤝‍👨🏼",
+        "men holding hands: light skin tone, medium-light skin tone",
+        &["two_men_holding_hands_tone1-2"],
+    ),
+    (
+        "👨🏻‍🤝‍👨🏽",
+        "men holding hands: light skin tone, medium skin tone",
+        &["two_men_holding_hands_tone1-3"],
+    ),
+    (
+        "👨🏻‍🤝‍👨🏾",
+        "men holding hands: light skin tone, medium-dark skin tone",
+        &["two_men_holding_hands_tone1-4"],
+    ),
+    (
+        "👨🏻‍🤝‍👨🏿",
+        "men holding hands: light skin tone, dark skin tone",
+        &["two_men_holding_hands_tone1-5"],
+    ),
+    (
+        "👨🏻‍🦯‍➡️",
+        "man with white cane facing right: light skin tone",
+        &["man_with_white_cane_right_tone1"],
+    ),
+    (
+        "👨🏻‍🦯",
+        "man with white cane: light skin tone",
+        &["man_with_probing_cane_tone1", "man_with_white_cane_tone1"],
+    ),
+    (
+        "👨🏻‍🦰",
+        "man: red hair: light skin tone",
+        &["man_red_haired_tone1"],
+    ),
+    (
+        "👨🏻‍🦱",
+        "man: curly hair: light skin tone",
+        &["man_curly_haired_tone1"],
+    ),
+    ("👨🏻‍🦲", "man: bald: light skin tone", &["man_bald_tone1"]),
+    (
+        "👨🏻‍🦳",
+        "man: white hair: light skin tone",
+        &["man_white_haired_tone1"],
+    ),
+    (
+        "👨🏻‍🦼‍➡️",
+        "man in motorized wheelchair facing right: light skin tone",
+        &["man_in_motorized_wheelchair_right_tone1"],
+    ),
+    (
+        "👨🏻‍🦼",
+        "man in motorized wheelchair: light skin tone",
+        &["man_in_motorized_wheelchair_tone1"],
+    ),
+    (
+        "👨🏻‍🦽‍➡️",
+        "man in manual wheelchair facing right: light skin tone",
+        &["man_in_manual_wheelchair_right_tone1"],
+    ),
+    (
+        "👨🏻‍🦽",
+        "man in manual wheelchair: light skin tone",
+        &["man_in_manual_wheelchair_tone1"],
+    ),
+    (
+        "👨🏻‍🫯‍👨🏼",
+        "men wrestling: light skin tone, medium-light skin tone",
+        &[],
+    ),
+    (
+        "👨🏻‍🫯‍👨🏽",
+        "men wrestling: light skin tone, medium skin tone",
+        &[],
+    ),
+    (
+        "👨🏻‍🫯‍👨🏾",
+        "men wrestling: light skin tone, medium-dark skin tone",
+        &[],
+    ),
+    (
+        "👨🏻‍🫯‍👨🏿",
+        "men wrestling: light skin tone, dark skin tone",
+        &[],
+    ),
+    (
+        "👨🏻‍⚕️",
+        "man health worker: light skin tone",
+        &["man_health_worker_tone1"],
+    ),
+    ("👨🏻‍⚖️", "man judge: light skin tone", &["man_judge_tone1"]),
+    ("👨🏻‍✈️", "man pilot: light skin tone", &["man_pilot_tone1"]),
+    (
+        "👨🏻‍❤️‍👨🏻",
+        "couple with heart: man, man: light skin tone",
+        &["couple_with_heart_mm_tone1"],
+    ),
+    (
+        "👨🏻‍❤️‍👨🏼",
+        "couple with heart: man, man: light skin tone, medium-light skin tone",
+        &["couple_with_heart_mm_tone1-2"],
+    ),
+    (
+        "👨🏻‍❤️‍👨🏽",
+        "couple with heart: man, man: light skin tone, medium skin tone",
+        &["couple_with_heart_mm_tone1-3"],
+    ),
+    (
+        "👨🏻‍❤️‍👨🏾",
+        "couple with heart: man, man: light skin tone, medium-dark skin tone",
+        &["couple_with_heart_mm_tone1-4"],
+    ),
+    (
+        "👨🏻‍❤️‍👨🏿",
+        "couple with heart: man, man: light skin tone, dark skin tone",
+        &["couple_with_heart_mm_tone1-5"],
+    ),
+    ("👨🏻‍❤️‍💋‍👨🏻", "kiss: man, man: light skin tone", &["kiss_mm_tone1"]),
+    (
+        "👨🏻‍❤️‍💋‍👨🏼",
+        "kiss: man, man: light skin tone, medium-light skin tone",
+        &["kiss_mm_tone1-2"],
+    ),
+    (
+        "👨🏻‍❤️‍💋‍👨🏽",
+        "kiss: man, man: light skin tone, medium skin tone",
+        &["kiss_mm_tone1-3"],
+    ),
+    (
+        "👨🏻‍❤️‍💋‍👨🏾",
+        "kiss: man, man: light skin tone, medium-dark skin tone",
+        &["kiss_mm_tone1-4"],
+    ),
+    (
+        "👨🏻‍❤️‍💋‍👨🏿",
+        "kiss: man, man: light skin tone, dark skin tone",
+        &["kiss_mm_tone1-5"],
+    ),
+    ("👨🏻", "man: light skin tone", &["man_tone1"]),
+    (
+        "👨🏼‍🌾",
+        "man farmer: medium-light skin tone",
+        &["man_farmer_tone2"],
+    ),
+    (
+        "👨🏼‍🍳",
+        "man cook: medium-light skin tone",
+        &["man_cook_tone2"],
+    ),
+    (
+        "👨🏼‍🍼",
+        "man feeding baby: medium-light skin tone",
+        &["man_feeding_baby_tone2"],
+    ),
+    ("👨🏼‍🎄", "Santa Claus: medium-light skin tone", &[]),
+    (
+        "👨🏼‍🎓",
+        "man student: medium-light skin tone",
+        &["man_student_tone2"],
+    ),
+    (
+        "👨🏼‍🎤",
+        "man singer: medium-light skin tone",
+        &["man_singer_tone2"],
+    ),
+    (
+        "👨🏼‍🎨",
+        "man artist: medium-light skin tone",
+        &["man_artist_tone2"],
+    ),
+    (
+        "👨🏼‍🏫",
+        "man teacher: medium-light skin tone",
+        &["man_teacher_tone2"],
+    ),
+    (
+        "👨🏼‍🏭",
+        "man factory worker: medium-light skin tone",
+        &["man_factory_worker_tone2"],
+    ),
+    (
+        "👨🏼‍🐰‍👨🏻",
+        "men with bunny ears: medium-light skin tone, light skin tone",
+        &[],
+    ),
+    (
+        "👨🏼‍🐰‍👨🏽",
+        "men with bunny ears: medium-light skin tone, medium skin tone",
+        &[],
+    ),
+    (
+        "👨🏼‍🐰‍👨🏾",
+        "men with bunny ears: medium-light skin tone, medium-dark skin tone",
+        &[],
+    ),
+    (
+        "👨🏼‍🐰‍👨🏿",
+        "men with bunny ears: medium-light skin tone, dark skin tone",
+        &[],
+    ),
+    (
+        "👨🏼‍💻",
+        "man technologist: medium-light skin tone",
+        &["man_technologist_tone2"],
+    ),
+    (
+        "👨🏼‍💼",
+        "man office worker: medium-light skin tone",
+        &["man_office_worker_tone2"],
+    ),
+    (
+        "👨🏼‍🔧",
+        "man mechanic: medium-light skin tone",
+        &["man_mechanic_tone2"],
+    ),
+    (
+        "👨🏼‍🔬",
+        "man scientist: medium-light skin tone",
+        &["man_scientist_tone2"],
+    ),
+    (
+        "👨🏼‍🚀",
+        "man astronaut: medium-light skin tone",
+        &["man_astronaut_tone2"],
+    ),
+    (
+        "👨🏼‍🚒",
+        "man firefighter: medium-light skin tone",
+        &["man_firefighter_tone2"],
+    ),
+    (
+        "👨🏼‍🤝‍👨🏻",
+        "men holding hands: medium-light skin tone, light skin tone",
+        &["two_men_holding_hands_tone2-1"],
+    ),
+    (
+        "👨🏼‍🤝‍👨🏽",
+        "men holding hands: medium-light skin tone, medium skin tone",
+        &["two_men_holding_hands_tone2-3"],
+    ),
+    (
+        "👨🏼‍🤝‍👨🏾",
+        "men holding hands: medium-light skin tone, medium-dark skin tone",
+        &["two_men_holding_hands_tone2-4"],
+    ),
+    (
+        "👨🏼‍🤝‍👨🏿",
+        "men holding hands: medium-light skin tone, dark skin tone",
+        &["two_men_holding_hands_tone2-5"],
+    ),
+    (
+        "👨🏼‍🦯‍➡️",
+        "man with white cane facing right: medium-light skin tone",
+        &["man_with_white_cane_right_tone2"],
+    ),
+    (
+        "👨🏼‍🦯",
+        "man with white cane: medium-light skin tone",
+        &["man_with_probing_cane_tone2", "man_with_white_cane_tone2"],
+    ),
+    (
+        "👨🏼‍🦰",
+        "man: red hair: medium-light skin tone",
+        &["man_red_haired_tone2"],
+    ),
+    (
+        "👨🏼‍🦱",
+        "man: curly hair: medium-light skin tone",
+        &["man_curly_haired_tone2"],
+    ),
+    (
+        "👨🏼‍🦲",
+        "man: bald: medium-light skin tone",
+        &["man_bald_tone2"],
+    ),
+    (
+        "👨🏼‍🦳",
+        "man: white hair: medium-light skin tone",
+        &["man_white_haired_tone2"],
+    ),
+    (
+        "👨🏼‍🦼‍➡️",
+        "man in motorized wheelchair facing right: medium-light skin tone",
+        &["man_in_motorized_wheelchair_right_tone2"],
+    ),
+    (
+        "👨🏼‍🦼",
+        "man in motorized wheelchair: medium-light skin tone",
+        &["man_in_motorized_wheelchair_tone2"],
+    ),
+    (
+        "👨🏼‍🦽‍➡️",
+        "man in manual wheelchair facing right: medium-light skin tone",
+        &["man_in_manual_wheelchair_right_tone2"],
+    ),
+    (
+        "👨🏼‍🦽",
+        "man in manual wheelchair: medium-light skin tone",
+        &["man_in_manual_wheelchair_tone2"],
+    ),
+    (
+        "👨🏼‍🫯‍👨🏻",
+        "men wrestling: medium-light skin tone, light skin tone",
+        &[],
+    ),
+    (
+        "👨🏼‍🫯‍👨🏽",
+        "men wrestling: medium-light skin tone, medium skin tone",
+        &[],
+    ),
+    (
+        "👨🏼‍🫯‍👨🏾",
+        "men wrestling: medium-light skin tone, medium-dark skin tone",
+        &[],
+    ),
+    (
+        "👨🏼‍🫯‍👨🏿",
+        "men wrestling: medium-light skin tone, dark skin tone",
+        &[],
+    ),
+    (
+        "👨🏼‍⚕️",
+        "man health worker: medium-light skin tone",
+        &["man_health_worker_tone2"],
+    ),
+    (
+        "👨🏼‍⚖️",
+        "man judge: medium-light skin tone",
+        &["man_judge_tone2"],
+    ),
+    (
+        "👨🏼‍✈️",
+        "man pilot: medium-light skin tone",
+        &["man_pilot_tone2"],
+    ),
+    (
+        "👨🏼‍❤️‍👨🏻",
+        "couple with heart: man, man: medium-light skin tone, light skin tone",
+        &["couple_with_heart_mm_tone2-1"],
+    ),
+    (
+        "👨🏼‍❤️‍👨🏼",
+        "couple with heart: man, man: medium-light skin tone",
+        &["couple_with_heart_mm_tone2"],
+    ),
+    (
+        "👨🏼‍❤️‍👨🏽",
+        "couple with heart: man, man: medium-light skin tone, medium skin tone",
+        &["couple_with_heart_mm_tone2-3"],
+    ),
+    (
+        "👨🏼‍❤️‍👨🏾",
+        "couple with heart: man, man: medium-light skin tone, medium-dark skin tone",
+        &["couple_with_heart_mm_tone2-4"],
+    ),
+    (
+        "👨🏼‍❤️‍👨🏿",
+        "couple with heart: man, man: medium-light skin tone, dark skin tone",
+        &["couple_with_heart_mm_tone2-5"],
+    ),
+    (
+        "👨🏼‍❤️‍💋‍👨🏻",
+        "kiss: man, man: medium-light skin tone, light skin tone",
+        &["kiss_mm_tone2-1"],
+    ),
+    (
+        "👨🏼‍❤️‍💋‍👨🏼",
+        "kiss: man, man: medium-light skin tone",
+        &["kiss_mm_tone2"],
+    ),
+    (
+        "👨🏼‍❤️‍💋‍👨🏽",
+        "kiss: man, man: medium-light skin tone, medium skin tone",
+        &["kiss_mm_tone2-3"],
+    ),
+    (
+        "👨🏼‍❤️‍💋‍👨🏾",
+        "kiss: man, man: medium-light skin tone, medium-dark skin tone",
+        &["kiss_mm_tone2-4"],
+    ),
+    (
+        "👨🏼‍❤️‍💋‍👨🏿",
+        "kiss: man, man: medium-light skin tone, dark skin tone",
+        &["kiss_mm_tone2-5"],
+    ),
+    ("👨🏼", "man: medium-light skin tone", &["man_tone2"]),
+    ("👨🏽‍🌾", "man farmer: medium skin tone", &["man_farmer_tone3"]),
+    ("👨🏽‍🍳", "man cook: medium skin tone", &["man_cook_tone3"]),
+    (
+        "👨🏽‍🍼",
+        "man feeding baby: medium skin tone",
+        &["man_feeding_baby_tone3"],
+    ),
+    ("👨🏽‍🎄", "Santa Claus: medium skin tone", &[]),
+    (
+        "👨🏽‍🎓",
+        "man student: medium skin tone",
+        &["man_student_tone3"],
+    ),
+    ("👨🏽‍🎤", "man singer: medium skin tone", &["man_singer_tone3"]),
+    ("👨🏽‍🎨", "man artist: medium skin tone", &["man_artist_tone3"]),
+    (
+        "👨🏽‍🏫",
+        "man teacher: medium skin tone",
+        &["man_teacher_tone3"],
+    ),
+    (
+        "👨🏽‍🏭",
+        "man factory worker: medium skin tone",
+        &["man_factory_worker_tone3"],
+    ),
+    (
+        "👨🏽‍🐰‍👨🏻",
+        "men with bunny ears: medium skin tone, light skin tone",
+        &[],
+    ),
+    (
+        "👨🏽‍🐰‍👨🏼",
+        "men with bunny ears: medium skin tone, medium-light skin tone",
+        &[],
+    ),
+    (
+        "👨🏽‍🐰‍👨🏾",
+        "men with bunny ears: medium skin tone, medium-dark skin tone",
+        &[],
+    ),
+    (
+        "👨🏽‍🐰‍👨🏿",
+        "men with bunny ears: medium skin tone, dark skin tone",
+        &[],
+    ),
+    (
+        "👨🏽‍💻",
+        "man technologist: medium skin tone",
+        &["man_technologist_tone3"],
+    ),
+    (
+        "👨🏽‍💼",
+        "man office worker: medium skin tone",
+        &["man_office_worker_tone3"],
+    ),
+    (
+        "👨🏽‍🔧",
+        "man mechanic: medium skin tone",
+        &["man_mechanic_tone3"],
+    ),
+    (
+        "👨🏽‍🔬",
+        "man scientist: medium skin tone",
+        &["man_scientist_tone3"],
+    ),
+    (
+        "👨🏽‍🚀",
+        "man astronaut: medium skin tone",
+        &["man_astronaut_tone3"],
+    ),
+    (
+        "👨🏽‍🚒",
+        "man firefighter: medium skin tone",
+        &["man_firefighter_tone3"],
+    ),
+    (
+        "👨🏽‍🤝‍👨🏻",
+        "men holding hands: medium skin tone, light skin tone",
+        &["two_men_holding_hands_tone3-1"],
+    ),
+    (
+        "👨🏽‍🤝‍👨🏼",
+        "men holding hands: medium skin tone, medium-light skin tone",
+        &["two_men_holding_hands_tone3-2"],
+    ),
+    (
+        "👨🏽‍🤝‍👨🏾",
+        "men holding hands: medium skin tone, medium-dark skin tone",
+        &["two_men_holding_hands_tone3-4"],
+    ),
+    (
+        "👨🏽‍🤝‍👨🏿",
+        "men holding hands: medium skin tone, dark skin tone",
+        &["two_men_holding_hands_tone3-5"],
+    ),
+    (
+        "👨🏽‍🦯‍➡️",
+        "man with white cane facing right: medium skin tone",
+        &["man_with_white_cane_right_tone3"],
+    ),
+    (
+        "👨🏽‍🦯",
+        "man with white cane: medium skin tone",
+        &["man_with_probing_cane_tone3", "man_with_white_cane_tone3"],
+    ),
+    (
+        "👨🏽‍🦰",
+        "man: red hair: medium skin tone",
+        &["man_red_haired_tone3"],
+    ),
+    (
+        "👨🏽‍🦱",
+        "man: curly hair: medium skin tone",
+        &["man_curly_haired_tone3"],
+    ),
+    ("👨🏽‍🦲", "man: bald: medium skin tone", &["man_bald_tone3"]),
+    (
+        "👨🏽‍🦳",
+        "man: white hair: medium skin tone",
+        &["man_white_haired_tone3"],
+    ),
+    (
+        "👨🏽‍🦼‍➡️",
+        "man in motorized wheelchair facing right: medium skin tone",
+        &["man_in_motorized_wheelchair_right_tone3"],
+    ),
+    (
+        "👨🏽‍🦼",
+        "man in motorized wheelchair: medium skin tone",
+        &["man_in_motorized_wheelchair_tone3"],
+    ),
+    (
+        "👨🏽‍🦽‍➡️",
+        "man in manual wheelchair facing right: medium skin tone",
+        &["man_in_manual_wheelchair_right_tone3"],
+    ),
+    (
+        "👨🏽‍🦽",
+        "man in manual wheelchair: medium skin tone",
+        &["man_in_manual_wheelchair_tone3"],
+    ),
+    (
+        "👨🏽‍🫯‍👨🏻",
+        "men wrestling: medium skin tone, light skin tone",
+        &[],
+    ),
+    (
+        "👨🏽‍🫯‍👨🏼",
+        "men wrestling: medium skin tone, medium-light skin tone",
+        &[],
+    ),
+    (
+        "👨🏽‍🫯‍👨🏾",
+        "men wrestling: medium skin tone, medium-dark skin tone",
+        &[],
+    ),
+    (
+        "👨🏽‍🫯‍👨🏿",
+        "men wrestling: medium skin tone, dark skin tone",
+        &[],
+    ),
+    (
+        "👨🏽‍⚕️",
+        "man health worker: medium skin tone",
+        &["man_health_worker_tone3"],
+    ),
+    ("👨🏽‍⚖️", "man judge: medium skin tone", &["man_judge_tone3"]),
+    ("👨🏽‍✈️", "man pilot: medium skin tone", &["man_pilot_tone3"]),
+    (
+        "👨🏽‍❤️‍👨🏻",
+        "couple with heart: man, man: medium skin tone, light skin tone",
+        &["couple_with_heart_mm_tone3-1"],
+    ),
+    (
+        "👨🏽‍❤️‍👨🏼",
+        "couple with heart: man, man: medium skin tone, medium-light skin tone",
+        &["couple_with_heart_mm_tone3-2"],
+    ),
+    (
+        "👨🏽‍❤️‍👨🏽",
+        "couple with heart: man, man: medium skin tone",
+        &["couple_with_heart_mm_tone3"],
+    ),
+    (
+        "👨🏽‍❤️‍👨🏾",
+        "couple with heart: man, man: medium skin tone, medium-dark skin tone",
+        &["couple_with_heart_mm_tone3-4"],
+    ),
+    (
+        "👨🏽‍❤️‍👨🏿",
+        "couple with heart: man, man: medium skin tone, dark skin tone",
+        &["couple_with_heart_mm_tone3-5"],
+    ),
+    (
+        "👨🏽‍❤️‍💋‍👨🏻",
+        "kiss: man, man: medium skin tone, light skin tone",
+        &["kiss_mm_tone3-1"],
+    ),
+    (
+        "👨🏽‍❤️‍💋‍👨🏼",
+        "kiss: man, man: medium skin tone, medium-light skin tone",
+        &["kiss_mm_tone3-2"],
+    ),
+    ("👨🏽‍❤️‍💋‍👨🏽", "kiss: man, man: medium skin tone", &["kiss_mm_tone3"]),
+    (
+        "👨🏽‍❤️‍💋‍👨🏾",
+        "kiss: man, man: medium skin tone, medium-dark skin tone",
+        &["kiss_mm_tone3-4"],
+    ),
+    (
+        "👨🏽‍❤️‍💋‍👨🏿",
+        "kiss: man, man: medium skin tone, dark skin tone",
+        &["kiss_mm_tone3-5"],
+    ),
+    ("👨🏽", "man: medium skin tone", &["man_tone3"]),
+    (
+        "👨🏾‍🌾",
+        "man farmer: medium-dark skin tone",
+        &["man_farmer_tone4"],
+    ),
+    ("👨🏾‍🍳", "man cook: medium-dark skin tone", &["man_cook_tone4"]),
+    (
+        "👨🏾‍🍼",
+        "man feeding baby: medium-dark skin tone",
+        &["man_feeding_baby_tone4"],
+    ),
+    ("👨🏾‍🎄", "Santa Claus: medium-dark skin tone", &[]),
+    (
+        "👨🏾‍🎓",
+        "man student: medium-dark skin tone",
+        &["man_student_tone4"],
+    ),
+    (
+        "👨🏾‍🎤",
+        "man singer: medium-dark skin tone",
+        &["man_singer_tone4"],
+    ),
+    (
+        "👨🏾‍🎨",
+        "man artist: medium-dark skin tone",
+        &["man_artist_tone4"],
+    ),
+    (
+        "👨🏾‍🏫",
+        "man teacher: medium-dark skin tone",
+        &["man_teacher_tone4"],
+    ),
+    (
+        "👨🏾‍🏭",
+        "man factory worker: medium-dark skin tone",
+        &["man_factory_worker_tone4"],
+    ),
+    (
+        "👨🏾‍🐰‍👨🏻",
+        "men with bunny ears: medium-dark skin tone, light skin tone",
+        &[],
+    ),
+    (
+        "👨🏾‍🐰‍👨🏼",
+        "men with bunny ears: medium-dark skin tone, medium-light skin tone",
+        &[],
+    ),
+    (
+        "👨🏾‍🐰‍👨🏽",
+        "men with bunny ears: medium-dark skin tone, medium skin tone",
+        &[],
+    ),
+    (
+        "👨🏾‍🐰‍👨🏿",
+        "men with bunny ears: medium-dark skin tone, dark skin tone",
+        &[],
+    ),
+    (
+        "👨🏾‍💻",
+        "man technologist: medium-dark skin tone",
+        &["man_technologist_tone4"],
+    ),
+    (
+        "👨🏾‍💼",
+        "man office worker: medium-dark skin tone",
+        &["man_office_worker_tone4"],
+    ),
+    (
+        "👨🏾‍🔧",
+        "man mechanic: medium-dark skin tone",
+        &["man_mechanic_tone4"],
+    ),
+    (
+        "👨🏾‍🔬",
+        "man scientist: medium-dark skin tone",
+        &["man_scientist_tone4"],
+    ),
+    (
+        "👨🏾‍🚀",
+        "man astronaut: medium-dark skin tone",
+        &["man_astronaut_tone4"],
+    ),
+    (
+        "👨🏾‍🚒",
+        "man firefighter: medium-dark skin tone",
+        &["man_firefighter_tone4"],
+    ),
+    (
+        "👨🏾‍🤝‍👨🏻",
+        "men holding hands: medium-dark skin tone, light skin tone",
+        &["two_men_holding_hands_tone4-1"],
+    ),
+    (
+        "👨🏾‍🤝‍👨🏼",
+        "men holding hands: medium-dark skin tone, medium-light skin tone",
+        &["two_men_holding_hands_tone4-2"],
+    ),
+    (
+        "👨🏾‍🤝‍👨🏽",
+        "men holding hands: medium-dark skin tone, medium skin tone",
+        &["two_men_holding_hands_tone4-3"],
+    ),
+    (
+        "👨🏾‍🤝‍👨🏿",
+        "men holding hands: medium-dark skin tone, dark skin tone",
+        &["two_men_holding_hands_tone4-5"],
+    ),
+    (
+        "👨🏾‍🦯‍➡️",
+        "man with white cane facing right: medium-dark skin tone",
+        &["man_with_white_cane_right_tone4"],
+    ),
+    (
+        "👨🏾‍🦯",
+        "man with white cane: medium-dark skin tone",
+        &["man_with_probing_cane_tone4", "man_with_white_cane_tone4"],
+    ),
+    (
+        "👨🏾‍🦰",
+        "man: red hair: medium-dark skin tone",
+        &["man_red_haired_tone4"],
+    ),
+    (
+        "👨🏾‍🦱",
+        "man: curly hair: medium-dark skin tone",
+        &["man_curly_haired_tone4"],
+    ),
+    (
+        "👨🏾‍🦲",
+        "man: bald: medium-dark skin tone",
+        &["man_bald_tone4"],
+    ),
+    (
+        "👨🏾‍🦳",
+        "man: white hair: medium-dark skin tone",
+        &["man_white_haired_tone4"],
+    ),
+    (
+        "👨🏾‍🦼‍➡️",
+        "man in motorized wheelchair facing right: medium-dark skin tone",
+        &["man_in_motorized_wheelchair_right_tone4"],
+    ),
+    (
+        "👨🏾‍🦼",
+        "man in motorized wheelchair: medium-dark skin tone",
+        &["man_in_motorized_wheelchair_tone4"],
+    ),
+    (
+        "👨🏾‍🦽‍➡️",
+        "man in manual wheelchair facing right: medium-dark skin tone",
+        &["man_in_manual_wheelchair_right_tone4"],
+    ),
+    (
+        "👨🏾‍🦽",
+        "man in manual wheelchair: medium-dark skin tone",
+        &["man_in_manual_wheelchair_tone4"],
+    ),
+    (
+        "👨🏾‍🫯‍👨🏻",
+        "men wrestling: medium-dark skin tone, light skin tone",
+        &[],
+    ),
+    (
+        "👨🏾‍🫯‍👨🏼",
+        "men wrestling: medium-dark skin tone, medium-light skin tone",
+        &[],
+    ),
+    (
+        "👨🏾‍🫯‍👨🏽",
+        "men wrestling: medium-dark skin tone, medium skin tone",
+        &[],
+    ),
+    (
+        "👨🏾‍🫯‍👨🏿",
+        "men wrestling: medium-dark skin tone, dark skin tone",
+        &[],
+    ),
+    (
+        "👨🏾‍⚕️",
+        "man health worker: medium-dark skin tone",
+        &["man_health_worker_tone4"],
+    ),
+    (
+        "👨🏾‍⚖️",
+        "man judge: medium-dark skin tone",
+        &["man_judge_tone4"],
+    ),
+    (
+        "👨🏾‍✈️",
+        "man pilot: medium-dark skin tone",
+        &["man_pilot_tone4"],
+    ),
+    (
+        "👨🏾‍❤️‍👨🏻",
+        "couple with heart: man, man: medium-dark skin tone, light skin tone",
+        &["couple_with_heart_mm_tone4-1"],
+    ),
+    (
+        "👨🏾‍❤️‍👨🏼",
+        "couple with heart: man, man: medium-dark skin tone, medium-light skin tone",
+        &["couple_with_heart_mm_tone4-2"],
+    ),
+    (
+        "👨🏾‍❤️‍👨🏽",
+        "couple with heart: man, man: medium-dark skin tone, medium skin tone",
+        &["couple_with_heart_mm_tone4-3"],
+    ),
+    (
+        "👨🏾‍❤️‍👨🏾",
+        "couple with heart: man, man: medium-dark skin tone",
+        &["couple_with_heart_mm_tone4"],
+    ),
+    (
+        "👨🏾‍❤️‍👨🏿",
+        "couple with heart: man, man: medium-dark skin tone, dark skin tone",
+        &["couple_with_heart_mm_tone4-5"],
+    ),
+    (
+        "👨🏾‍❤️‍💋‍👨🏻",
+        "kiss: man, man: medium-dark skin tone, light skin tone",
+        &["kiss_mm_tone4-1"],
+    ),
+    (
+        "👨🏾‍❤️‍💋‍👨🏼",
+        "kiss: man, man: medium-dark skin tone, medium-light skin tone",
+        &["kiss_mm_tone4-2"],
+    ),
+    (
+        "👨🏾‍❤️‍💋‍👨🏽",
+        "kiss: man, man: medium-dark skin tone, medium skin tone",
+        &["kiss_mm_tone4-3"],
+    ),
+    (
+        "👨🏾‍❤️‍💋‍👨🏾",
+        "kiss: man, man: medium-dark skin tone",
+        &["kiss_mm_tone4"],
+    ),
+    (
+        "👨🏾‍❤️‍💋‍👨🏿",
+        "kiss: man, man: medium-dark skin tone, dark skin tone",
+        &["kiss_mm_tone4-5"],
+    ),
+    ("👨🏾", "man: medium-dark skin tone", &["man_tone4"]),
+    ("👨🏿‍🌾", "man farmer: dark skin tone", &["man_farmer_tone5"]),
+    ("👨🏿‍🍳", "man cook: dark skin tone", &["man_cook_tone5"]),
+    (
+        "👨🏿‍🍼",
+        "man feeding baby: dark skin tone",
+        &["man_feeding_baby_tone5"],
+    ),
+    ("👨🏿‍🎄", "Santa Claus: dark skin tone", &[]),
+    ("👨🏿‍🎓", "man student: dark skin tone", &["man_student_tone5"]),
+    ("👨🏿‍🎤", "man singer: dark skin tone", &["man_singer_tone5"]),
+    ("👨🏿‍🎨", "man artist: dark skin tone", &["man_artist_tone5"]),
+    ("👨🏿‍🏫", "man teacher: dark skin tone", &["man_teacher_tone5"]),
+    (
+        "👨🏿‍🏭",
+        "man factory worker: dark skin tone",
+        &["man_factory_worker_tone5"],
+    ),
+    (
+        "👨🏿‍🐰‍👨🏻",
+        "men with bunny ears: dark skin tone, light skin tone",
+        &[],
+    ),
+    (
+        "👨🏿‍🐰‍👨🏼",
+        "men with bunny ears: dark skin tone, medium-light skin tone",
+        &[],
+    ),
+    (
+        "👨🏿‍🐰‍👨🏽",
+        "men with bunny ears: dark skin tone, medium skin tone",
+        &[],
+    ),
+    (
+        "👨🏿‍🐰‍👨🏾",
+        "men with bunny ears: dark skin tone, medium-dark skin tone",
+        &[],
+    ),
+    (
+        "👨🏿‍💻",
+        "man technologist: dark skin tone",
+        &["man_technologist_tone5"],
+    ),
+    (
+        "👨🏿‍💼",
+        "man office worker: dark skin tone",
+        &["man_office_worker_tone5"],
+    ),
+    (
+        "👨🏿‍🔧",
+        "man mechanic: dark skin tone",
+        &["man_mechanic_tone5"],
+    ),
+    (
+        "👨🏿‍🔬",
+        "man scientist: dark skin tone",
+        &["man_scientist_tone5"],
+    ),
+    (
+        "👨🏿‍🚀",
+        "man astronaut: dark skin tone",
+        &["man_astronaut_tone5"],
+    ),
+    (
+        "👨🏿‍🚒",
+        "man firefighter: dark skin tone",
+        &["man_firefighter_tone5"],
+    ),
+    (
+        "👨🏿‍🤝‍👨🏻",
+        "men holding hands: dark skin tone, light skin tone",
+        &["two_men_holding_hands_tone5-1"],
+    ),
+    (
+        "👨🏿‍🤝‍👨🏼",
+        "men holding hands: dark skin tone, medium-light skin tone",
+        &["two_men_holding_hands_tone5-2"],
+    ),
+    (
+        "👨🏿‍🤝‍👨🏽",
+        "men holding hands: dark skin tone, medium skin tone",
+        &["two_men_holding_hands_tone5-3"],
+    ),
+    (
+        "👨🏿‍🤝‍👨🏾",
+        "men holding hands: dark skin tone, medium-dark skin tone",
+        &["two_men_holding_hands_tone5-4"],
+    ),
+    (
+        "👨🏿‍🦯‍➡️",
+        "man with white cane facing right: dark skin tone",
+        &["man_with_white_cane_right_tone5"],
+    ),
+    (
+        "👨🏿‍🦯",
+        "man with white cane: dark skin tone",
+        &["man_with_probing_cane_tone5", "man_with_white_cane_tone5"],
+    ),
+    (
+        "👨🏿‍🦰",
+        "man: red hair: dark skin tone",
+        &["man_red_haired_tone5"],
+    ),
+    (
+        "👨🏿‍🦱",
+        "man: curly hair: dark skin tone",
+        &["man_curly_haired_tone5"],
+    ),
+    ("👨🏿‍🦲", "man: bald: dark skin tone", &["man_bald_tone5"]),
+    (
+        "👨🏿‍🦳",
+        "man: white hair: dark skin tone",
+        &["man_white_haired_tone5"],
+    ),
+    (
+        "👨🏿‍🦼‍➡️",
+        "man in motorized wheelchair facing right: dark skin tone",
+        &["man_in_motorized_wheelchair_right_tone5"],
+    ),
+    (
+        "👨🏿‍🦼",
+        "man in motorized wheelchair: dark skin tone",
+        &["man_in_motorized_wheelchair_tone5"],
+    ),
+    (
+        "👨🏿‍🦽‍➡️",
+        "man in manual wheelchair facing right: dark skin tone",
+        &["man_in_manual_wheelchair_right_tone5"],
+    ),
+    (
+        "👨🏿‍🦽",
+        "man in manual wheelchair: dark skin tone",
+        &["man_in_manual_wheelchair_tone5"],
+    ),
+    (
+        "👨🏿‍🫯‍👨🏻",
+        "men wrestling: dark skin tone, light skin tone",
+        &[],
+    ),
+    (
+        "👨🏿‍🫯‍👨🏼",
+        "men wrestling: dark skin tone, medium-light skin tone",
+        &[],
+    ),
+    (
+        "👨🏿‍🫯‍👨🏽",
+        "men wrestling: dark skin tone, medium skin tone",
+        &[],
+    ),
+    (
+        "👨🏿‍🫯‍👨🏾",
+        "men wrestling: dark skin tone, medium-dark skin tone",
+        &[],
+    ),
+    (
+        "👨🏿‍⚕️",
+        "man health worker: dark skin tone",
+        &["man_health_worker_tone5"],
+    ),
+    ("👨🏿‍⚖️", "man judge: dark skin tone", &["man_judge_tone5"]),
+    ("👨🏿‍✈️", "man pilot: dark skin tone", &["man_pilot_tone5"]),
+    (
+        "👨🏿‍❤️‍👨🏻",
+        "couple with heart: man, man: dark skin tone, light skin tone",
+        &["couple_with_heart_mm_tone5-1"],
+    ),
+    (
+        "👨🏿‍❤️‍👨🏼",
+        "couple with heart: man, man: dark skin tone, medium-light skin tone",
+        &["couple_with_heart_mm_tone5-2"],
+    ),
+    (
+        "👨🏿‍❤️‍👨🏽",
+        "couple with heart: man, man: dark skin tone, medium skin tone",
+        &["couple_with_heart_mm_tone5-3"],
+    ),
+    (
+        "👨🏿‍❤️‍👨🏾",
+        "couple with heart: man, man: dark skin tone, medium-dark skin tone",
+        &["couple_with_heart_mm_tone5-4"],
+    ),
+    (
+        "👨🏿‍❤️‍👨🏿",
+        "couple with heart: man, man: dark skin tone",
+        &["couple_with_heart_mm_tone5"],
+    ),
+    (
+        "👨🏿‍❤️‍💋‍👨🏻",
+        "kiss: man, man: dark skin tone, light skin tone",
+        &["kiss_mm_tone5-1"],
+    ),
+    (
+        "👨🏿‍❤️‍💋‍👨🏼",
+        "kiss: man, man: dark skin tone, medium-light skin tone",
+        &["kiss_mm_tone5-2"],
+    ),
+    (
+        "👨🏿‍❤️‍💋‍👨🏽",
+        "kiss: man, man: dark skin tone, medium skin tone",
+        &["kiss_mm_tone5-3"],
+    ),
+    (
+        "👨🏿‍❤️‍💋‍👨🏾",
+        "kiss: man, man: dark skin tone, medium-dark skin tone",
+        &["kiss_mm_tone5-4"],
+    ),
+    ("👨🏿‍❤️‍💋‍👨🏿", "kiss: man, man: dark skin tone", &["kiss_mm_tone5"]),
+    ("👨🏿", "man: dark skin tone", &["man_tone5"]),
+    ("👨‍🌾", "man farmer", &["man_farmer"]),
+    ("👨‍🍳", "man cook", &["man_cook"]),
+    ("👨‍🍼", "man feeding baby", &["man_feeding_baby"]),
+    ("👨‍🎄", "Santa Claus", &[]),
+    ("👨‍🎓", "man student", &["man_student"]),
+    ("👨‍🎤", "man singer", &["man_singer"]),
+    ("👨‍🎨", "man artist", &["man_artist"]),
+    ("👨‍🏫", "man teacher", &["man_teacher"]),
+    ("👨‍🏭", "man factory worker", &["man_factory_worker"]),
+    ("👨‍👦‍👦", "family: man, boy, boy", &["family_mbb"]),
+    ("👨‍👦", "family: man, boy", &["family_mb"]),
+    ("👨‍👧‍👦", "family: man, girl, boy", &["family_mgb"]),
+    ("👨‍👧‍👧", "family: man, girl, girl", &["family_mgg"]),
+    ("👨‍👧", "family: man, girl", &["family_mg"]),
+    ("👨‍👨‍👦‍👦", "family: man, man, boy, boy", &["family_mmbb"]),
+    ("👨‍👨‍👦", "family: man, man, boy", &["family_mmb"]),
+    ("👨‍👨‍👧‍👦", "family: man, man, girl, boy", &["family_mmgb"]),
+    ("👨‍👨‍👧‍👧", "family: man, man, girl, girl", &["family_mmgg"]),
+    ("👨‍👨‍👧", "family: man, man, girl", &["family_mmg"]),
+    ("👨‍👩‍👦‍👦", "family: man, woman, boy, boy", &["family_mwbb"]),
+    ("👨‍👩‍👦", "family: man, woman, boy", &["family_mwb"]),
+    ("👨‍👩‍👧‍👦", "family: man, woman, girl, boy", &["family_mwgb"]),
+    ("👨‍👩‍👧‍👧", "family: man, woman, girl, girl", &["family_mwgg"]),
+    ("👨‍👩‍👧", "family: man, woman, girl", &["family_mwg"]),
+    ("👨‍💻", "man technologist", &["man_technologist"]),
+    ("👨‍💼", "man office worker", &["man_office_worker"]),
+    ("👨‍🔧", "man mechanic", &["man_mechanic"]),
+    ("👨‍🔬", "man scientist", &["man_scientist"]),
+    ("👨‍🚀", "man astronaut", &["man_astronaut"]),
+    ("👨‍🚒", "man firefighter", &["man_firefighter"]),
+    (
+        "👨‍🦯‍➡️",
+        "man with white cane facing right",
+        &["man_with_white_cane_right"],
+    ),
+    (
+        "👨‍🦯",
+        "man with white cane",
+        &["man_with_probing_cane", "man_with_white_cane"],
+    ),
+    ("👨‍🦰", "man: red hair", &["man_red_haired"]),
+    ("👨‍🦱", "man: curly hair", &["man_curly_haired"]),
+    ("👨‍🦲", "man: bald", &["man_bald"]),
+    ("👨‍🦳", "man: white hair", &["man_white_haired"]),
+    (
+        "👨‍🦼‍➡️",
+        "man in motorized wheelchair facing right",
+        &["man_in_motorized_wheelchair_right"],
+    ),
+    (
+        "👨‍🦼",
+        "man in motorized wheelchair",
+        &["man_in_motorized_wheelchair"],
+    ),
+    (
+        "👨‍🦽‍➡️",
+        "man in manual wheelchair facing right",
+        &["man_in_manual_wheelchair_right"],
+    ),
+    (
+        "👨‍🦽",
+        "man in manual wheelchair",
+        &["man_in_manual_wheelchair"],
+    ),
+    ("👨‍⚕️", "man health worker", &["man_health_worker"]),
+    ("👨‍⚖️", "man judge", &["man_judge"]),
+    ("👨‍✈️", "man pilot", &["man_pilot"]),
+    (
+        "👨‍❤️‍👨",
+        "couple with heart: man, man",
+        &["couple_with_heart_mm"],
+    ),
+    ("👨‍❤️‍💋‍👨", "kiss: man, man", &["kiss_mm"]),
+    ("👨", "man", &["man"]),
+    (
+        "👩🏻‍🌾",
+        "woman farmer: light skin tone",
+        &["woman_farmer_tone1"],
+    ),
+    ("👩🏻‍🍳", "woman cook: light skin tone", &["woman_cook_tone1"]),
+    (
+        "👩🏻‍🍼",
+        "woman feeding baby: light skin tone",
+        &["woman_feeding_baby_tone1"],
+    ),
+    ("👩🏻‍🎄", "Mrs. Claus: light skin tone", &[]),
+    (
+        "👩🏻‍🎓",
+        "woman student: light skin tone",
+        &["woman_student_tone1"],
+    ),
+    (
+        "👩🏻‍🎤",
+        "woman singer: light skin tone",
+        &["woman_singer_tone1"],
+    ),
+    (
+        "👩🏻‍🎨",
+        "woman artist: light skin tone",
+        &["woman_artist_tone1"],
+    ),
+    (
+        "👩🏻‍🏫",
+        "woman teacher: light skin tone",
+        &["woman_teacher_tone1"],
+    ),
+    (
+        "👩🏻‍🏭",
+        "woman factory worker: light skin tone",
+        &["woman_factory_worker_tone1"],
+    ),
+    (
+        "👩🏻‍🐰‍👩🏼",
+        "women with bunny ears: light skin tone, medium-light skin tone",
+        &[],
+    ),
+    (
+        "👩🏻‍🐰‍👩🏽",
+        "women with bunny ears: light skin tone, medium skin tone",
+        &[],
+    ),
+    (
+        "👩🏻‍🐰‍👩🏾",
+        "women with bunny ears: light skin tone, medium-dark skin tone",
+        &[],
+    ),
+    (
+        "👩🏻‍🐰‍👩🏿",
+        "women with bunny ears: light skin tone, dark skin tone",
+        &[],
+    ),
+    (
+        "👩🏻‍💻",
+        "woman technologist: light skin tone",
+        &["woman_technologist_tone1"],
+    ),
+    (
+        "👩🏻‍💼",
+        "woman office worker: light skin tone",
+        &["woman_office_worker_tone1"],
+    ),
+    (
+        "👩🏻‍🔧",
+        "woman mechanic: light skin tone",
+        &["woman_mechanic_tone1"],
+    ),
+    (
+        "👩🏻‍🔬",
+        "woman scientist: light skin tone",
+        &["woman_scientist_tone1"],
+    ),
+    (
+        "👩🏻‍🚀",
+        "woman astronaut: light skin tone",
+        &["woman_astronaut_tone1"],
+    ),
+    (
+        "👩🏻‍🚒",
+        "woman firefighter: light skin tone",
+        &["woman_firefighter_tone1"],
+    ),
+    (
+        "👩🏻‍🤝‍👨🏼",
+        "woman and man holding hands: light skin tone, medium-light skin tone",
+        &["couple_tone1-2"],
+    ),
+    (
+        "👩🏻‍🤝‍👨🏽",
+        "woman and man holding hands: light skin tone, medium skin tone",
+        &["couple_tone1-3"],
+    ),
+    (
+        "👩🏻‍🤝‍👨🏾",
+        "woman and man holding hands: light skin tone, medium-dark skin tone",
+        &["couple_tone1-4"],
+    ),
+    (
+        "👩🏻‍🤝‍👨🏿",
+        "woman and man holding hands: light skin tone, dark skin tone",
+        &["couple_tone1-5"],
+    ),
+    (
+        "👩🏻‍🤝‍👩🏼",
+        "women holding hands: light skin tone, medium-light skin tone",
+        &["two_women_holding_hands_tone1-2"],
+    ),
+    (
+        "👩🏻‍🤝‍👩🏽",
+        "women holding hands: light skin tone, medium skin tone",
+        &["two_women_holding_hands_tone1-3"],
+    ),
+    (
+        "👩🏻‍🤝‍👩🏾",
+        "women holding hands: light skin tone, medium-dark skin tone",
+        &["two_women_holding_hands_tone1-4"],
+    ),
+    (
+        "👩🏻‍🤝‍👩🏿",
+        "women holding hands: light skin tone, dark skin tone",
+        &["two_women_holding_hands_tone1-5"],
+    ),
+    (
+        "👩🏻‍🦯‍➡️",
+        "woman with white cane facing right: light skin tone",
+        &["woman_with_white_cane_right_tone1"],
+    ),
+    (
+        "👩🏻‍🦯",
+        "woman with white cane: light skin tone",
+        &[
+            "woman_with_probing_cane_tone1",
+            "woman_with_white_cane_tone1",
+        ],
+    ),
+    (
+        "👩🏻‍🦰",
+        "woman: red hair: light skin tone",
+        &["woman_red_haired_tone1"],
+    ),
+    (
+        "👩🏻‍🦱",
+        "woman: curly hair: light skin tone",
+        &["woman_curly_haired_tone1"],
+    ),
+    ("👩🏻‍🦲", "woman: bald: light skin tone", &["woman_bald_tone1"]),
+    (
+        "👩🏻‍🦳",
+        "woman: white hair: light skin tone",
+        &["woman_white_haired_tone1"],
+    ),
+    (
+        "👩🏻‍🦼‍➡️",
+        "woman in motorized wheelchair facing right: light skin tone",
+        &["woman_in_motorized_wheelchair_right_tone1"],
+    ),
+    (
+        "👩🏻‍🦼",
+        "woman in motorized wheelchair: light skin tone",
+        &["woman_in_motorized_wheelchair_tone1"],
+    ),
+    (
+        "👩🏻‍🦽‍➡️",
+        "woman in manual wheelchair facing right: light skin tone",
+        &["woman_in_manual_wheelchair_right_tone1"],
+    ),
+    (
+        "👩🏻‍🦽",
+        "woman in manual wheelchair: light skin tone",
+        &["woman_in_manual_wheelchair_tone1"],
+    ),
+    (
+        "👩🏻‍🫯‍👩🏼",
+        "women wrestling: light skin tone, medium-light skin tone",
+        &[],
+    ),
+    (
+        "👩🏻‍🫯‍👩🏽",
+        "women wrestling: light skin tone, medium skin tone",
+        &[],
+    ),
+    (
+        "👩🏻‍🫯‍👩🏾",
+        "women wrestling: light skin tone, medium-dark skin tone",
+        &[],
+    ),
+    (
+        "👩🏻‍🫯‍👩🏿",
+        "women wrestling: light skin tone, dark skin tone",
+        &[],
+    ),
+    (
+        "👩🏻‍⚕️",
+        "woman health worker: light skin tone",
+        &["woman_health_worker_tone1"],
+    ),
+    ("👩🏻‍⚖️", "woman judge: light skin tone", &["woman_judge_tone1"]),
+    ("👩🏻‍✈️", "woman pilot: light skin tone", &["woman_pilot_tone1"]),
+    (
+        "👩🏻‍❤️‍👨🏻",
+        "couple with heart: woman, man: light skin tone",
+        &["couple_with_heart_mw_tone1", "couple_with_heart_wm_tone1"],
+    ),
+    (
+        "👩🏻‍❤️‍👨🏼",
+        "couple with heart: woman, man: light skin tone, medium-light skin tone",
+        &[
+            "couple_with_heart_mw_tone1-2",
+            "couple_with_heart_wm_tone1-2",
+        ],
+    ),
+    (
+        "👩🏻‍❤️‍👨🏽",
+        "couple with heart: woman, man: light skin tone, medium skin tone",
+        &[
+            "couple_with_heart_mw_tone1-3",
+            "couple_with_heart_wm_tone1-3",
+        ],
+    ),
+    (
+        "👩🏻‍❤️‍👨🏾",
+        "couple with heart: woman, man: light skin tone, medium-dark skin tone",
+        &[
+            "couple_with_heart_mw_tone1-4",
+            "couple_with_heart_wm_tone1-4",
+        ],
+    ),
+    (
+        "👩🏻‍❤️‍👨🏿",
+        "couple with heart: woman, man: light skin tone, dark skin tone",
+        &[
+            "couple_with_heart_mw_tone1-5",
+            "couple_with_heart_wm_tone1-5",
+        ],
+    ),
+    (
+        "👩🏻‍❤️‍👩🏻",
+        "couple with heart: woman, woman: light skin tone",
+        &["couple_with_heart_ww_tone1"],
+    ),
+    (
+        "👩🏻‍❤️‍👩🏼",
+        "couple with heart: woman, woman: light skin tone, medium-light skin tone",
+        &["couple_with_heart_ww_tone1-2"],
+    ),
+    (
+        "👩🏻‍❤️‍👩🏽",
+        "couple with heart: woman, woman: light skin tone, medium skin tone",
+        &["couple_with_heart_ww_tone1-3"],
+    ),
+    (
+        "👩🏻‍❤️‍👩🏾",
+        "couple with heart: woman, woman: light skin tone, medium-dark skin tone",
+        &["couple_with_heart_ww_tone1-4"],
+    ),
+    (
+        "👩🏻‍❤️‍👩🏿",
+        "couple with heart: woman, woman: light skin tone, dark skin tone",
+        &["couple_with_heart_ww_tone1-5"],
+    ),
+    (
+        "👩🏻‍❤️‍💋‍👨🏻",
+        "kiss: woman, man: light skin tone",
+        &["kiss_mw_tone1", "kiss_wm_tone1"],
+    ),
+    (
+        "👩🏻‍❤️‍💋‍👨🏼",
+        "kiss: woman, man: light skin tone, medium-light skin tone",
+        &["kiss_mw_tone1-2", "kiss_wm_tone1-2"],
+    ),
+    (
+        "👩🏻‍❤️‍💋‍👨🏽",
+        "kiss: woman, man: light skin tone, medium skin tone",
+        &["kiss_mw_tone1-3", "kiss_wm_tone1-3"],
+    ),
+    (
+        "👩🏻‍❤️‍💋‍👨🏾",
+        "kiss: woman, man: light skin tone, medium-dark skin tone",
+        &["kiss_mw_tone1-4", "kiss_wm_tone1-4"],
+    ),
+    (
+        "👩🏻‍❤️‍💋‍👨🏿",
+        "kiss: woman, man: light skin tone, dark skin tone",
+        &["kiss_mw_tone1-5", "kiss_wm_tone1-5"],
+    ),
+    (
+        "👩🏻‍❤️‍💋‍👩🏻",
+        "kiss: woman, woman: light skin tone",
+        &["kiss_ww_tone1"],
+    ),
+    (
+        "👩🏻‍❤️‍💋‍👩🏼",
+        "kiss: woman, woman: light skin tone, medium-light skin tone",
+        &["kiss_ww_tone1-2"],
+    ),
+    (
+        "👩🏻‍❤️‍💋‍👩🏽",
+        "kiss: woman, woman: light skin tone, medium skin tone",
+        &["kiss_ww_tone1-3"],
+    ),
+    (
+        "👩🏻‍❤️‍💋‍👩🏾",
+        "kiss: woman, woman: light skin tone, medium-dark skin tone",
+        &["kiss_ww_tone1-4"],
+    ),
+    (
+        "👩🏻‍❤️‍💋‍👩🏿",
+        "kiss: woman, woman: light skin tone, dark skin tone",
+        &["kiss_ww_tone1-5"],
+    ),
+    ("👩🏻", "woman: light skin tone", &["woman_tone1"]),
+    (
+        "👩🏼‍🌾",
+        "woman farmer: medium-light skin tone",
+        &["woman_farmer_tone2"],
+    ),
+    (
+        "👩🏼‍🍳",
+        "woman cook: medium-light skin tone",
+        &["woman_cook_tone2"],
+    ),
+    (
+        "👩🏼‍🍼",
+        "woman feeding baby: medium-light skin tone",
+        &["woman_feeding_baby_tone2"],
+    ),
+    ("👩🏼‍🎄", "Mrs. Claus: medium-light skin tone", &[]),
+    (
+        "👩🏼‍🎓",
+        "woman student: medium-light skin tone",
+        &["woman_student_tone2"],
+    ),
+    (
+        "👩🏼‍🎤",
+        "woman singer: medium-light skin tone",
+        &["woman_singer_tone2"],
+    ),
+    (
+        "👩🏼‍🎨",
+        "woman artist: medium-light skin tone",
+        &["woman_artist_tone2"],
+    ),
+    (
+        "👩🏼‍🏫",
+        "woman teacher: medium-light skin tone",
+        &["woman_teacher_tone2"],
+    ),
+    (
+        "👩🏼‍🏭",
+        "woman factory worker: medium-light skin tone",
+        &["woman_factory_worker_tone2"],
+    ),
+    (
+        "👩🏼‍🐰‍👩🏻",
+        "women with bunny ears: medium-light skin tone, light skin tone",
+        &[],
+    ),
+    (
+        "👩🏼‍🐰‍👩🏽",
+        "women with bunny ears: medium-light skin tone, medium skin tone",
+        &[],
+    ),
+    (
+        "👩🏼‍🐰‍👩🏾",
+        "women with bunny ears: medium-light skin tone, medium-dark skin tone",
+        &[],
+    ),
+    (
+        "👩🏼‍🐰‍👩🏿",
+        "women with bunny ears: medium-light skin tone, dark skin tone",
+        &[],
+    ),
+    (
+        "👩🏼‍💻",
+        "woman technologist: medium-light skin tone",
+        &["woman_technologist_tone2"],
+    ),
+    (
+        "👩🏼‍💼",
+        "woman office worker: medium-light skin tone",
+        &["woman_office_worker_tone2"],
+    ),
+    (
+        "👩🏼‍🔧",
+        "woman mechanic: medium-light skin tone",
+        &["woman_mechanic_tone2"],
+    ),
+    (
+        "👩🏼‍🔬",
+        "woman scientist: medium-light skin tone",
+        &["woman_scientist_tone2"],
+    ),
+    (
+        "👩🏼‍🚀",
+        "woman astronaut: medium-light skin tone",
+        &["woman_astronaut_tone2"],
+    ),
+    (
+        "👩🏼‍🚒",
+        "woman firefighter: medium-light skin tone",
+        &["woman_firefighter_tone2"],
+    ),
+    (
+        "👩🏼‍🤝‍👨🏻",
+        "woman and man holding hands: medium-light skin tone, light skin tone",
+        &["couple_tone2-1"],
+    ),
+    (
+        "👩🏼‍🤝‍👨🏽",
+        "woman and man holding hands: medium-light skin tone, medium skin tone",
+        &["couple_tone2-3"],
+    ),
+    (
+        "👩🏼‍🤝‍👨🏾",
+        "woman and man holding hands: medium-light skin tone, medium-dark skin tone",
+        &["couple_tone2-4"],
+    ),
+    (
+        "👩🏼‍🤝‍👨🏿",
+        "woman and man holding hands: medium-light skin tone, dark skin tone",
+        &["couple_tone2-5"],
+    ),
+    (
+        "👩🏼‍🤝‍👩🏻",
+        "women holding hands: medium-light skin tone, light skin tone",
+        &["two_women_holding_hands_tone2-1"],
+    ),
+    (
+        "👩🏼‍🤝‍👩🏽",
+        "women holding hands: medium-light skin tone, medium skin tone",
+        &["two_women_holding_hands_tone2-3"],
+    ),
+    (
+        "👩🏼‍🤝‍👩🏾",
+        "women holding hands: medium-light skin tone, medium-dark skin tone",
+        &["two_women_holding_hands_tone2-4"],
+    ),
+    (
+        "👩🏼‍🤝‍👩🏿",
+        "women holding hands: medium-light skin tone, dark skin tone",
+        &["two_women_holding_hands_tone2-5"],
+    ),
+    (
+        "👩🏼‍🦯‍➡️",
+        "woman with white cane facing right: medium-light skin tone",
+        &["woman_with_white_cane_right_tone2"],
+    ),
+    (
+        "👩🏼‍🦯",
+        "woman with white cane: medium-light skin tone",
+        &[
+            "woman_with_probing_cane_tone2",
+            "woman_with_white_cane_tone2",
+        ],
+    ),
+    (
+        "👩🏼‍🦰",
+        "woman: red hair: medium-light skin tone",
+        &["woman_red_haired_tone2"],
+    ),
+    (
+        "👩🏼‍🦱",
+        "woman: curly hair: medium-light skin tone",
+        &["woman_curly_haired_tone2"],
+    ),
+    (
+        "👩🏼‍🦲",
+        "woman: bald: medium-light skin tone",
+        &["woman_bald_tone2"],
+    ),
+    (
+        "👩🏼‍🦳",
+        "woman: white hair: medium-light skin tone",
+        &["woman_white_haired_tone2"],
+    ),
+    (
+        "👩🏼‍🦼‍➡️",
+        "woman in motorized wheelchair facing right: medium-light skin tone",
+        &["woman_in_motorized_wheelchair_right_tone2"],
+    ),
+    (
+        "👩🏼‍🦼",
+        "woman in motorized wheelchair: medium-light skin tone",
+        &["woman_in_motorized_wheelchair_tone2"],
+    ),
+    (
+        "👩🏼‍🦽‍➡️",
+        "woman in manual wheelchair facing right: medium-light skin tone",
+        &["woman_in_manual_wheelchair_right_tone2"],
+    ),
+    (
+        "👩🏼‍🦽",
+        "woman in manual wheelchair: medium-light skin tone",
+        &["woman_in_manual_wheelchair_tone2"],
+    ),
+    (
+        "👩🏼‍🫯‍👩🏻",
+        "women wrestling: medium-light skin tone, light skin tone",
+        &[],
+    ),
+    (
+        "👩🏼‍🫯‍👩🏽",
+        "women wrestling: medium-light skin tone, medium skin tone",
+        &[],
+    ),
+    (
+        "👩🏼‍🫯‍👩🏾",
+        "women wrestling: medium-light skin tone, medium-dark skin tone",
+        &[],
+    ),
+    (
+        "👩🏼‍🫯‍👩🏿",
+        "women wrestling: medium-light skin tone, dark skin tone",
+        &[],
+    ),
+    (
+        "👩🏼‍⚕️",
+        "woman health worker: medium-light skin tone",
+        &["woman_health_worker_tone2"],
+    ),
+    (
+        "👩🏼‍⚖️",
+        "woman judge: medium-light skin tone",
+        &["woman_judge_tone2"],
+    ),
+    (
+        "👩🏼‍✈️",
+        "woman pilot: medium-light skin tone",
+        &["woman_pilot_tone2"],
+    ),
+    (
+        "👩🏼‍❤️‍👨🏻",
+        "couple with heart: woman, man: medium-light skin tone, light skin tone",
+        &[
+            "couple_with_heart_mw_tone2-1",
+            "couple_with_heart_wm_tone2-1",
+        ],
+    ),
+    (
+        "👩🏼‍❤️‍👨🏼",
+        "couple with heart: woman, man: medium-light skin tone",
+        &["couple_with_heart_mw_tone2", "couple_with_heart_wm_tone2"],
+    ),
+    (
+        "👩🏼‍❤️‍👨🏽",
+        "couple with heart: woman, man: medium-light skin tone, medium skin tone",
+        &[
+            "couple_with_heart_mw_tone2-3",
+            "couple_with_heart_wm_tone2-3",
+        ],
+    ),
+    (
+        "👩🏼‍❤️‍👨🏾",
+        "couple with heart: woman, man: medium-light skin tone, medium-dark skin tone",
+        &[
+            "couple_with_heart_mw_tone2-4",
+            "couple_with_heart_wm_tone2-4",
+        ],
+    ),
+    (
+        "👩🏼‍❤️‍👨🏿",
+        "couple with heart: woman, man: medium-light skin tone, dark skin tone",
+        &[
+            "couple_with_heart_mw_tone2-5",
+            "couple_with_heart_wm_tone2-5",
+        ],
+    ),
+    (
+        "👩🏼‍❤️‍👩🏻",
+        "couple with heart: woman, woman: medium-light skin tone, light skin tone",
+        &["couple_with_heart_ww_tone2-1"],
+    ),
+    (
+        "👩🏼‍❤️‍👩🏼",
+        "couple with heart: woman, woman: medium-light skin tone",
+        &["couple_with_heart_ww_tone2"],
+    ),
+    (
+        "👩🏼‍❤️‍👩🏽",
+        "couple with heart: woman, woman: medium-light skin tone, medium skin tone",
+        &["couple_with_heart_ww_tone2-3"],
+    ),
+    (
+        "👩🏼‍❤️‍👩🏾",
+        "couple with heart: woman, woman: medium-light skin tone, medium-dark skin tone",
+        &["couple_with_heart_ww_tone2-4"],
+    ),
+    (
+        "👩🏼‍❤️‍👩🏿",
+        "couple with heart: woman, woman: medium-light skin tone, dark skin tone",
+        &["couple_with_heart_ww_tone2-5"],
+    ),
+    (
+        "👩🏼‍❤️‍💋‍👨🏻",
+        "kiss: woman, man: medium-light skin tone, light skin tone",
+        &["kiss_mw_tone2-1", "kiss_wm_tone2-1"],
+    ),
+    (
+        "👩🏼‍❤️‍💋‍👨🏼",
+        "kiss: woman, man: medium-light skin tone",
+        &["kiss_mw_tone2", "kiss_wm_tone2"],
+    ),
+    (
+        "👩🏼‍❤️‍💋‍👨🏽",
+        "kiss: woman, man: medium-light skin tone, medium skin tone",
+        &["kiss_mw_tone2-3", "kiss_wm_tone2-3"],
+    ),
+    (
+        "👩🏼‍❤️‍💋‍👨🏾",
+        "kiss: woman, man: medium-light skin tone, medium-dark skin tone",
+        &["kiss_mw_tone2-4", "kiss_wm_tone2-4"],
+    ),
+    (
+        "👩🏼‍❤️‍💋‍👨🏿",
+        "kiss: woman, man: medium-light skin tone, dark skin tone",
+        &["kiss_mw_tone2-5", "kiss_wm_tone2-5"],
+    ),
+    (
+        "👩🏼‍❤️‍💋‍👩🏻",
+        "kiss: woman, woman: medium-light skin tone, light skin tone",
+        &["kiss_ww_tone2-1"],
+    ),
+    (
+        "👩🏼‍❤️‍💋‍👩🏼",
+        "kiss: woman, woman: medium-light skin tone",
+        &["kiss_ww_tone2"],
+    ),
+    (
+        "👩🏼‍❤️‍💋‍👩🏽",
+        "kiss: woman, woman: medium-light skin tone, medium skin tone",
+        &["kiss_ww_tone2-3"],
+    ),
+    (
+        "👩🏼‍❤️‍💋‍👩🏾",
+        "kiss: woman, woman: medium-light skin tone, medium-dark skin tone",
+        &["kiss_ww_tone2-4"],
+    ),
+    (
+        "👩🏼‍❤️‍💋‍👩🏿",
+        "kiss: woman, woman: medium-light skin tone, dark skin tone",
+        &["kiss_ww_tone2-5"],
+    ),
+    ("👩🏼", "woman: medium-light skin tone", &["woman_tone2"]),
+    (
+        "👩🏽‍🌾",
+        "woman farmer: medium skin tone",
+        &["woman_farmer_tone3"],
+    ),
+    ("👩🏽‍🍳", "woman cook: medium skin tone", &["woman_cook_tone3"]),
+    (
+        "👩🏽‍🍼",
+        "woman feeding baby: medium skin tone",
+        &["woman_feeding_baby_tone3"],
+    ),
+    ("👩🏽‍🎄", "Mrs. Claus: medium skin tone", &[]),
+    (
+        "👩🏽‍🎓",
+        "woman student: medium skin tone",
+        &["woman_student_tone3"],
+    ),
+    (
+        "👩🏽‍🎤",
+        "woman singer: medium skin tone",
+        &["woman_singer_tone3"],
+    ),
+    (
+        "👩🏽‍🎨",
+        "woman artist: medium skin tone",
+        &["woman_artist_tone3"],
+    ),
+    (
+        "👩🏽‍🏫",
+        "woman teacher: medium skin tone",
+        &["woman_teacher_tone3"],
+    ),
+    (
+        "👩🏽‍🏭",
+        "woman factory worker: medium skin tone",
+        &["woman_factory_worker_tone3"],
+    ),
+    (
+        "👩🏽‍🐰‍👩🏻",
+        "women with bunny ears: medium skin tone, light skin tone",
+        &[],
+    ),
+    (
+        "👩🏽‍🐰‍👩🏼",
+        "women with bunny ears: medium skin tone, medium-light skin tone",
+        &[],
+    ),
+    (
+        "👩🏽‍🐰‍👩🏾",
+        "women with bunny ears: medium skin tone, medium-dark skin tone",
+        &[],
+    ),
+    (
+        "👩🏽‍🐰‍👩🏿",
+        "women with bunny ears: medium skin tone, dark skin tone",
+        &[],
+    ),
+    (
+        "👩🏽‍💻",
+        "woman technologist: medium skin tone",
+        &["woman_technologist_tone3"],
+    ),
+    (
+        "👩🏽‍💼",
+        "woman office worker: medium skin tone",
+        &["woman_office_worker_tone3"],
+    ),
+    (
+        "👩🏽‍🔧",
+        "woman mechanic: medium skin tone",
+        &["woman_mechanic_tone3"],
+    ),
+    (
+        "👩🏽‍🔬",
+        "woman scientist: medium skin tone",
+        &["woman_scientist_tone3"],
+    ),
+    (
+        "👩🏽‍🚀",
+        "woman astronaut: medium skin tone",
+        &["woman_astronaut_tone3"],
+    ),
+    (
+        "👩🏽‍🚒",
+        "woman firefighter: medium skin tone",
+        &["woman_firefighter_tone3"],
+    ),
+    (
+        "👩🏽‍🤝‍👨🏻",
+        "woman and man holding hands: medium skin tone, light skin tone",
+        &["couple_tone3-1"],
+    ),
+    (
+        "👩🏽‍🤝‍👨🏼",
+        "woman and man holding hands: medium skin tone, medium-light skin tone",
+        &["couple_tone3-2"],
+    ),
+    (
+        "👩🏽‍🤝‍👨🏾",
+        "woman and man holding hands: medium skin tone, medium-dark skin tone",
+        &["couple_tone3-4"],
+    ),
+    (
+        "👩🏽‍🤝‍👨🏿",
+        "woman and man holding hands: medium skin tone, dark skin tone",
+        &["couple_tone3-5"],
+    ),
+    (
+        "👩🏽‍🤝‍👩🏻",
+        "women holding hands: medium skin tone, light skin tone",
+        &["two_women_holding_hands_tone3-1"],
+    ),
+    (
+        "👩🏽‍🤝‍👩🏼",
+        "women holding hands: medium skin tone, medium-light skin tone",
+        &["two_women_holding_hands_tone3-2"],
+    ),
+    (
+        "👩🏽‍🤝‍👩🏾",
+        "women holding hands: medium skin tone, medium-dark skin tone",
+        &["two_women_holding_hands_tone3-4"],
+    ),
+    (
+        "👩🏽‍🤝‍👩🏿",
+        "women holding hands: medium skin tone, dark skin tone",
+        &["two_women_holding_hands_tone3-5"],
+    ),
+    (
+        "👩🏽‍🦯‍➡️",
+        "woman with white cane facing right: medium skin tone",
+        &["woman_with_white_cane_right_tone3"],
+    ),
+    (
+        "👩🏽‍🦯",
+        "woman with white cane: medium skin tone",
+        &[
+            "woman_with_probing_cane_tone3",
+            "woman_with_white_cane_tone3",
+        ],
+    ),
+    (
+        "👩🏽‍🦰",
+        "woman: red hair: medium skin tone",
+        &["woman_red_haired_tone3"],
+    ),
+    (
+        "👩🏽‍🦱",
+        "woman: curly hair: medium skin tone",
+        &["woman_curly_haired_tone3"],
+    ),
+    ("👩🏽‍🦲", "woman: bald: medium skin tone", &["woman_bald_tone3"]),
+    (
+        "👩🏽‍🦳",
+        "woman: white hair: medium skin tone",
+        &["woman_white_haired_tone3"],
+    ),
+    (
+        "👩🏽‍🦼‍➡️",
+        "woman in motorized wheelchair facing right: medium skin tone",
+        &["woman_in_motorized_wheelchair_right_tone3"],
+    ),
+    (
+        "👩🏽‍🦼",
+        "woman in motorized wheelchair: medium skin tone",
+        &["woman_in_motorized_wheelchair_tone3"],
+    ),
+    (
+        "👩🏽‍🦽‍➡️",
+        "woman in manual wheelchair facing right: medium skin tone",
+        &["woman_in_manual_wheelchair_right_tone3"],
+    ),
+    (
+        "👩🏽‍🦽",
+        "woman in manual wheelchair: medium skin tone",
+        &["woman_in_manual_wheelchair_tone3"],
+    ),
+    (
+        "👩🏽‍🫯‍👩🏻",
+        "women wrestling: medium skin tone, light skin tone",
+        &[],
+    ),
+    (
+        "👩🏽‍🫯‍👩🏼",
+        "women wrestling: medium skin tone, medium-light skin tone",
+        &[],
+    ),
+    (
+        "👩🏽‍🫯‍👩🏾",
+        "women wrestling: medium skin tone, medium-dark skin tone",
+        &[],
+    ),
+    (
+        "👩🏽‍🫯‍👩🏿",
+        "women wrestling: medium skin tone, dark skin tone",
+        &[],
+    ),
+    (
+        "👩🏽‍⚕️",
+        "woman health worker: medium skin tone",
+        &["woman_health_worker_tone3"],
+    ),
+    (
+        "👩🏽‍⚖️",
+        "woman judge: medium skin tone",
+        &["woman_judge_tone3"],
+    ),
+    (
+        "👩🏽‍✈️",
+        "woman pilot: medium skin tone",
+        &["woman_pilot_tone3"],
+    ),
+    (
+        "👩🏽‍❤️‍👨🏻",
+        "couple with heart: woman, man: medium skin tone, light skin tone",
+        &[
+            "couple_with_heart_mw_tone3-1",
+            "couple_with_heart_wm_tone3-1",
+        ],
+    ),
+    (
+        "👩🏽‍❤️‍👨🏼",
+        "couple with heart: woman, man: medium skin tone, medium-light skin tone",
+        &[
+            "couple_with_heart_mw_tone3-2",
+            "couple_with_heart_wm_tone3-2",
+        ],
+    ),
+    (
+        "👩🏽‍❤️‍👨🏽",
+        "couple with heart: woman, man: medium skin tone",
+        &["couple_with_heart_mw_tone3", "couple_with_heart_wm_tone3"],
+    ),
+    (
+        "👩🏽‍❤️‍👨🏾",
+        "couple with heart: woman, man: medium skin tone, medium-dark skin tone",
+        &[
+            "couple_with_heart_mw_tone3-4",
+            "couple_with_heart_wm_tone3-4",
+        ],
+    ),
+    (
+        "👩🏽‍❤️‍👨🏿",
+        "couple with heart: woman, man: medium skin tone, dark skin tone",
+        &[
+            "couple_with_heart_mw_tone3-5",
+            "couple_with_heart_wm_tone3-5",
+        ],
+    ),
+    (
+        "👩🏽‍❤️‍👩🏻",
+        "couple with heart: woman, woman: medium skin tone, light skin tone",
+        &["couple_with_heart_ww_tone3-1"],
+    ),
+    (
+        "👩🏽‍❤️‍👩🏼",
+        "couple with heart: woman, woman: medium skin tone, medium-light skin tone",
+        &["couple_with_heart_ww_tone3-2"],
+    ),
+    (
+        "👩🏽‍❤️‍👩🏽",
+        "couple with heart: woman, woman: medium skin tone",
+        &["couple_with_heart_ww_tone3"],
+    ),
+    (
+        "👩🏽‍❤️‍👩🏾",
+        "couple with heart: woman, woman: medium skin tone, medium-dark skin tone",
+        &["couple_with_heart_ww_tone3-4"],
+    ),
+    (
+        "👩🏽‍❤️‍👩🏿",
+        "couple with heart: woman, woman: medium skin tone, dark skin tone",
+        &["couple_with_heart_ww_tone3-5"],
+    ),
+    (
+        "👩🏽‍❤️‍💋‍👨🏻",
+        "kiss: woman, man: medium skin tone, light skin tone",
+        &["kiss_mw_tone3-1", "kiss_wm_tone3-1"],
+    ),
+    (
+        "👩🏽‍❤️‍💋‍👨🏼",
+        "kiss: woman, man: medium skin tone, medium-light skin tone",
+        &["kiss_mw_tone3-2", "kiss_wm_tone3-2"],
+    ),
+    (
+        "👩🏽‍❤️‍💋‍👨🏽",
+        "kiss: woman, man: medium skin tone",
+        &["kiss_mw_tone3", "kiss_wm_tone3"],
+    ),
+    (
+        "👩🏽‍❤️‍💋‍👨🏾",
+        "kiss: woman, man: medium skin tone, medium-dark skin tone",
+        &["kiss_mw_tone3-4", "kiss_wm_tone3-4"],
+    ),
+    (
+        "👩🏽‍❤️‍💋‍👨🏿",
+        "kiss: woman, man: medium skin tone, dark skin tone",
+        &["kiss_mw_tone3-5", "kiss_wm_tone3-5"],
+    ),
+    (
+        "👩🏽‍❤️‍💋‍👩🏻",
+        "kiss: woman, woman: medium skin tone, light skin tone",
+        &["kiss_ww_tone3-1"],
+    ),
+    (
+        "👩🏽‍❤️‍💋‍👩🏼",
+        "kiss: woman, woman: medium skin tone, medium-light skin tone",
+        &["kiss_ww_tone3-2"],
+    ),
+    (
+        "👩🏽‍❤️‍💋‍👩🏽",
+        "kiss: woman, woman: medium skin tone",
+        &["kiss_ww_tone3"],
+    ),
+    (
+        "👩🏽‍❤️‍💋‍👩🏾",
+        "kiss: woman, woman: medium skin tone, medium-dark skin tone",
+        &["kiss_ww_tone3-4"],
+    ),
+    (
+        "👩🏽‍❤️‍💋‍👩🏿",
+        "kiss: woman, woman: medium skin tone, dark skin tone",
+        &["kiss_ww_tone3-5"],
+    ),
+    ("👩🏽", "woman: medium skin tone", &["woman_tone3"]),
+    (
+        "👩🏾‍🌾",
+        "woman farmer: medium-dark skin tone",
+        &["woman_farmer_tone4"],
+    ),
+    (
+        "👩🏾‍🍳",
+        "woman cook: medium-dark skin tone",
+        &["woman_cook_tone4"],
+    ),
+    (
+        "👩🏾‍🍼",
+        "woman feeding baby: medium-dark skin tone",
+        &["woman_feeding_baby_tone4"],
+    ),
+    ("👩🏾‍🎄", "Mrs. Claus: medium-dark skin tone", &[]),
+    (
+        "👩🏾‍🎓",
+        "woman student: medium-dark skin tone",
+        &["woman_student_tone4"],
+    ),
+    (
+        "👩🏾‍🎤",
+        "woman singer: medium-dark skin tone",
+        &["woman_singer_tone4"],
+    ),
+    (
+        "👩🏾‍🎨",
+        "woman artist: medium-dark skin tone",
+        &["woman_artist_tone4"],
+    ),
+    (
+        "👩🏾‍🏫",
+        "woman teacher: medium-dark skin tone",
+        &["woman_teacher_tone4"],
+    ),
+    (
+        "👩🏾‍🏭",
+        "woman factory worker: medium-dark skin tone",
+        &["woman_factory_worker_tone4"],
+    ),
+    (
+        "👩🏾‍🐰‍👩🏻",
+        "women with bunny ears: medium-dark skin tone, light skin tone",
+        &[],
+    ),
+    (
+        "👩🏾‍🐰‍👩🏼",
+        "women with bunny ears: medium-dark skin tone, medium-light skin tone",
+        &[],
+    ),
+    (
+        "👩🏾‍🐰‍👩🏽",
+        "women with bunny ears: medium-dark skin tone, medium skin tone",
+        &[],
+    ),
+    (
+        "👩🏾‍🐰‍👩🏿",
+        "women with bunny ears: medium-dark skin tone, dark skin tone",
+        &[],
+    ),
+    (
+        "👩🏾‍💻",
+        "woman technologist: medium-dark skin tone",
+        &["woman_technologist_tone4"],
+    ),
+    (
+        "👩🏾‍💼",
+        "woman office worker: medium-dark skin tone",
+        &["woman_office_worker_tone4"],
+    ),
+    (
+        "👩🏾‍🔧",
+        "woman mechanic: medium-dark skin tone",
+        &["woman_mechanic_tone4"],
+    ),
+    (
+        "👩🏾‍🔬",
+        "woman scientist: medium-dark skin tone",
+        &["woman_scientist_tone4"],
+    ),
+    (
+        "👩🏾‍🚀",
+        "woman astronaut: medium-dark skin tone",
+        &["woman_astronaut_tone4"],
+    ),
+    (
+        "👩🏾‍🚒",
+        "woman firefighter: medium-dark skin tone",
+        &["woman_firefighter_tone4"],
+    ),
+    (
+        "👩🏾‍🤝‍👨🏻",
+        "woman and man holding hands: medium-dark skin tone, light skin tone",
+        &["couple_tone4-1"],
+    ),
+    (
+        "👩🏾‍🤝‍👨🏼",
+        "woman and man holding hands: medium-dark skin tone, medium-light skin tone",
+        &["couple_tone4-2"],
+    ),
+    (
+        "👩🏾‍🤝‍👨🏽",
+        "woman and man holding hands: medium-dark skin tone, medium skin tone",
+        &["couple_tone4-3"],
+    ),
+    (
+        "👩🏾‍🤝‍👨🏿",
+        "woman and man holding hands: medium-dark skin tone, dark skin tone",
+        &["couple_tone4-5"],
+    ),
+    (
+        "👩🏾‍🤝‍👩🏻",
+        "women holding hands: medium-dark skin tone, light skin tone",
+        &["two_women_holding_hands_tone4-1"],
+    ),
+    (
+        "👩🏾‍🤝‍👩🏼",
+        "women holding hands: medium-dark skin tone, medium-light skin tone",
+        &["two_women_holding_hands_tone4-2"],
+    ),
+    (
+        "👩🏾‍🤝‍👩🏽",
+        "women holding hands: medium-dark skin tone, medium skin tone",
+        &["two_women_holding_hands_tone4-3"],
+    ),
+    (
+        "👩🏾‍🤝‍👩🏿",
+        "women holding hands: medium-dark skin tone, dark skin tone",
+        &["two_women_holding_hands_tone4-5"],
+    ),
+    (
+        "👩🏾‍🦯‍➡️",
+        "woman with white cane facing right: medium-dark skin tone",
+        &["woman_with_white_cane_right_tone4"],
+    ),
+    (
+        "👩🏾‍🦯",
+        "woman with white cane: medium-dark skin tone",
+        &[
+            "woman_with_probing_cane_tone4",
+            "woman_with_white_cane_tone4",
+        ],
+    ),
+    (
+        "👩🏾‍🦰",
+        "woman: red hair: medium-dark skin tone",
+        &["woman_red_haired_tone4"],
+    ),
+    (
+        "👩🏾‍🦱",
+        "woman: curly hair: medium-dark skin tone",
+        &["woman_curly_haired_tone4"],
+    ),
+    (
+        "👩🏾‍🦲",
+        "woman: bald: medium-dark skin tone",
+        &["woman_bald_tone4"],
+    ),
+    (
+        "👩🏾‍🦳",
+        "woman: white hair: medium-dark skin tone",
+        &["woman_white_haired_tone4"],
+    ),
+    (
+        "👩🏾‍🦼‍➡️",
+        "woman in motorized wheelchair facing right: medium-dark skin tone",
+        &["woman_in_motorized_wheelchair_right_tone4"],
+    ),
+    (
+        "👩🏾‍🦼",
+        "woman in motorized wheelchair: medium-dark skin tone",
+        &["woman_in_motorized_wheelchair_tone4"],
+    ),
+    (
+        "👩🏾‍🦽‍➡️",
+        "woman in manual wheelchair facing right: medium-dark skin tone",
+        &["woman_in_manual_wheelchair_right_tone4"],
+    ),
+    (
+        "👩🏾‍🦽",
+        "woman in manual wheelchair: medium-dark skin tone",
+        &["woman_in_manual_wheelchair_tone4"],
+    ),
+    (
+        "👩🏾‍🫯‍👩🏻",
+        "women wrestling: medium-dark skin tone, light skin tone",
+        &[],
+    ),
+    (
+        "👩🏾‍🫯‍👩🏼",
+        "women wrestling: medium-dark skin tone, medium-light skin tone",
+        &[],
+    ),
+    (
+        "👩🏾‍🫯‍👩🏽",
+        "women wrestling: medium-dark skin tone, medium skin tone",
+        &[],
+    ),
+    (
+        "👩🏾‍🫯‍👩🏿",
+        "women wrestling: medium-dark skin tone, dark skin tone",
+        &[],
+    ),
+    (
+        "👩🏾‍⚕️",
+        "woman health worker: medium-dark skin tone",
+        &["woman_health_worker_tone4"],
+    ),
+    (
+        "👩🏾‍⚖️",
+        "woman judge: medium-dark skin tone",
+        &["woman_judge_tone4"],
+    ),
+    (
+        "👩🏾‍✈️",
+        "woman pilot: medium-dark skin tone",
+        &["woman_pilot_tone4"],
+    ),
+    (
+        "👩🏾‍❤️‍👨🏻",
+        "couple with heart: woman, man: medium-dark skin tone, light skin tone",
+        &[
+            "couple_with_heart_mw_tone4-1",
+            "couple_with_heart_wm_tone4-1",
+        ],
+    ),
+    (
+        "👩🏾‍❤️‍👨🏼",
+        "couple with heart: woman, man: medium-dark skin tone, medium-light skin tone",
+        &[
+            "couple_with_heart_mw_tone4-2",
+            "couple_with_heart_wm_tone4-2",
+        ],
+    ),
+    (
+        "👩🏾‍❤️‍👨🏽",
+        "couple with heart: woman, man: medium-dark skin tone, medium skin tone",
+        &[
+            "couple_with_heart_mw_tone4-3",
+            "couple_with_heart_wm_tone4-3",
+        ],
+    ),
+    (
+        "👩🏾‍❤️‍👨🏾",
+        "couple with heart: woman, man: medium-dark skin tone",
+        &["couple_with_heart_mw_tone4", "couple_with_heart_wm_tone4"],
+    ),
+    (
+        "👩🏾‍❤️‍👨🏿",
+        "couple with heart: woman, man: medium-dark skin tone, dark skin tone",
+        &[
+            "couple_with_heart_mw_tone4-5",
+            "couple_with_heart_wm_tone4-5",
+        ],
+    ),
+    (
+        "👩🏾‍❤️‍👩🏻",
+        "couple with heart: woman, woman: medium-dark skin tone, light skin tone",
+        &["couple_with_heart_ww_tone4-1"],
+    ),
+    (
+        "👩🏾‍❤️‍👩🏼",
+        "couple with heart: woman, woman: medium-dark skin tone, medium-light skin tone",
+        &["couple_with_heart_ww_tone4-2"],
+    ),
+    (
+        "👩🏾‍❤️‍👩🏽",
+        "couple with heart: woman, woman: medium-dark skin tone, medium skin tone",
+        &["couple_with_heart_ww_tone4-3"],
+    ),
+    (
+        "👩🏾‍❤️‍👩🏾",
+        "couple with heart: woman, woman: medium-dark skin tone",
+        &["couple_with_heart_ww_tone4"],
+    ),
+    (
+        "👩🏾‍❤️‍👩🏿",
+        "couple with heart: woman, woman: medium-dark skin tone, dark skin tone",
+        &["couple_with_heart_ww_tone4-5"],
+    ),
+    (
+        "👩🏾‍❤️‍💋‍👨🏻",
+        "kiss: woman, man: medium-dark skin tone, light skin tone",
+        &["kiss_mw_tone4-1", "kiss_wm_tone4-1"],
+    ),
+    (
+        "👩🏾‍❤️‍💋‍👨🏼",
+        "kiss: woman, man: medium-dark skin tone, medium-light skin tone",
+        &["kiss_mw_tone4-2", "kiss_wm_tone4-2"],
+    ),
+    (
+        "👩🏾‍❤️‍💋‍👨🏽",
+        "kiss: woman, man: medium-dark skin tone, medium skin tone",
+        &["kiss_mw_tone4-3", "kiss_wm_tone4-3"],
+    ),
+    (
+        "👩🏾‍❤️‍💋‍👨🏾",
+        "kiss: woman, man: medium-dark skin tone",
+        &["kiss_mw_tone4", "kiss_wm_tone4"],
+    ),
+    (
+        "👩🏾‍❤️‍💋‍👨🏿",
+        "kiss: woman, man: medium-dark skin tone, dark skin tone",
+        &["kiss_mw_tone4-5", "kiss_wm_tone4-5"],
+    ),
+    (
+        "👩🏾‍❤️‍💋‍👩🏻",
+        "kiss: woman, woman: medium-dark skin tone, light skin tone",
+        &["kiss_ww_tone4-1"],
+    ),
+    (
+        "👩🏾‍❤️‍💋‍👩🏼",
+        "kiss: woman, woman: medium-dark skin tone, medium-light skin tone",
+        &["kiss_ww_tone4-2"],
+    ),
+    (
+        "👩🏾‍❤️‍💋‍👩🏽",
+        "kiss: woman, woman: medium-dark skin tone, medium skin tone",
+        &["kiss_ww_tone4-3"],
+    ),
+    (
+        "👩🏾‍❤️‍💋‍👩🏾",
+        "kiss: woman, woman: medium-dark skin tone",
+        &["kiss_ww_tone4"],
+    ),
+    (
+        "👩🏾‍❤️‍💋‍👩🏿",
+        "kiss: woman, woman: medium-dark skin tone, dark skin tone",
+        &["kiss_ww_tone4-5"],
+    ),
+    ("👩🏾", "woman: medium-dark skin tone", &["woman_tone4"]),
+    (
+        "👩🏿‍🌾",
+        "woman farmer: dark skin tone",
+        &["woman_farmer_tone5"],
+    ),
+    ("👩🏿‍🍳", "woman cook: dark skin tone", &["woman_cook_tone5"]),
+    (
+        "👩🏿‍🍼",
+        "woman feeding baby: dark skin tone",
+        &["woman_feeding_baby_tone5"],
+    ),
+    ("👩🏿‍🎄", "Mrs. Claus: dark skin tone", &[]),
+    (
+        "👩🏿‍🎓",
+        "woman student: dark skin tone",
+        &["woman_student_tone5"],
+    ),
+    (
+        "👩🏿‍🎤",
+        "woman singer: dark skin tone",
+        &["woman_singer_tone5"],
+    ),
+    (
+        "👩🏿‍🎨",
+        "woman artist: dark skin tone",
+        &["woman_artist_tone5"],
+    ),
+    (
+        "👩🏿‍🏫",
+        "woman teacher: dark skin tone",
+        &["woman_teacher_tone5"],
+    ),
+    (
+        "👩🏿‍🏭",
+        "woman factory worker: dark skin tone",
+        &["woman_factory_worker_tone5"],
+    ),
+    (
+        "👩🏿‍🐰‍👩🏻",
+        "women with bunny ears: dark skin tone, light skin tone",
+        &[],
+    ),
+    (
+        "👩🏿‍🐰‍👩🏼",
+        "women with bunny ears: dark skin tone, medium-light skin tone",
+        &[],
+    ),
+    (
+        "👩🏿‍🐰‍👩🏽",
+        "women with bunny ears: dark skin tone, medium skin tone",
+        &[],
+    ),
+    (
+        "👩🏿‍🐰‍👩🏾",
+        "women with bunny ears: dark skin tone, medium-dark skin tone",
+        &[],
+    ),
+    (
+        "👩🏿‍💻",
+        "woman technologist: dark skin tone",
+        &["woman_technologist_tone5"],
+    ),
+    (
+        "👩🏿‍💼",
+        "woman office worker: dark skin tone",
+        &["woman_office_worker_tone5"],
+    ),
+    (
+        "👩🏿‍🔧",
+        "woman mechanic: dark skin tone",
+        &["woman_mechanic_tone5"],
+    ),
+    (
+        "👩🏿‍🔬",
+        "woman scientist: dark skin tone",
+        &["woman_scientist_tone5"],
+    ),
+    (
+        "👩🏿‍🚀",
+        "woman astronaut: dark skin tone",
+        &["woman_astronaut_tone5"],
+    ),
+    (
+        "👩🏿‍🚒",
+        "woman firefighter: dark skin tone",
+        &["woman_firefighter_tone5"],
+    ),
+    (
+        "👩🏿‍🤝‍👨🏻",
+        "woman and man holding hands: dark skin tone, light skin tone",
+        &["couple_tone5-1"],
+    ),
+    (
+        "👩🏿‍🤝‍👨🏼",
+        "woman and man holding hands: dark skin tone, medium-light skin tone",
+        &["couple_tone5-2"],
+    ),
+    (
+        "👩🏿‍🤝‍👨🏽",
+        "woman and man holding hands: dark skin tone, medium skin tone",
+        &["couple_tone5-3"],
+    ),
+    (
+        "👩🏿‍🤝‍👨🏾",
+        "woman and man holding hands: dark skin tone, medium-dark skin tone",
+        &["couple_tone5-4"],
+    ),
+    (
+        "👩🏿‍🤝‍👩🏻",
+        "women holding hands: dark skin tone, light skin tone",
+        &["two_women_holding_hands_tone5-1"],
+    ),
+    (
+        "👩🏿‍🤝‍👩🏼",
+        "women holding hands: dark skin tone, medium-light skin tone",
+        &["two_women_holding_hands_tone5-2"],
+    ),
+    (
+        "👩🏿‍🤝‍👩🏽",
+        "women holding hands: dark skin tone, medium skin tone",
+        &["two_women_holding_hands_tone5-3"],
+    ),
+    (
+        "👩🏿‍🤝‍👩🏾",
+        "women holding hands: dark skin tone, medium-dark skin tone",
+        &["two_women_holding_hands_tone5-4"],
+    ),
+    (
+        "👩🏿‍🦯‍➡️",
+        "woman with white cane facing right: dark skin tone",
+        &["woman_with_white_cane_right_tone5"],
+    ),
+    (
+        "👩🏿‍🦯",
+        "woman with white cane: dark skin tone",
+        &[
+            "woman_with_probing_cane_tone5",
+            "woman_with_white_cane_tone5",
+        ],
+    ),
+    (
+        "👩🏿‍🦰",
+        "woman: red hair: dark skin tone",
+        &["woman_red_haired_tone5"],
+    ),
+    (
+        "👩🏿‍🦱",
+        "woman: curly hair: dark skin tone",
+        &["woman_curly_haired_tone5"],
+    ),
+    ("👩🏿‍🦲", "woman: bald: dark skin tone", &["woman_bald_tone5"]),
+    (
+        "👩🏿‍🦳",
+        "woman: white hair: dark skin tone",
+        &["woman_white_haired_tone5"],
+    ),
+    (
+        "👩🏿‍🦼‍➡️",
+        "woman in motorized wheelchair facing right: dark skin tone",
+        &["woman_in_motorized_wheelchair_right_tone5"],
+    ),
+    (
+        "👩🏿‍🦼",
+        "woman in motorized wheelchair: dark skin tone",
+        &["woman_in_motorized_wheelchair_tone5"],
+    ),
+    (
+        "👩🏿‍🦽‍➡️",
+        "woman in manual wheelchair facing right: dark skin tone",
+        &["woman_in_manual_wheelchair_right_tone5"],
+    ),
+    (
+        "👩🏿‍🦽",
+        "woman in manual wheelchair: dark skin tone",
+        &["woman_in_manual_wheelchair_tone5"],
+    ),
+    (
+        "👩🏿‍🫯‍👩🏻",
+        "women wrestling: dark skin tone, light skin tone",
+        &[],
+    ),
+    (
+        "👩🏿‍🫯‍👩🏼",
+        "women wrestling: dark skin tone, medium-light skin tone",
+        &[],
+    ),
+    (
+        "👩🏿‍🫯‍👩🏽",
+        "women wrestling: dark skin tone, medium skin tone",
+        &[],
+    ),
+    (
+        "👩🏿‍🫯‍👩🏾",
+        "women wrestling: dark skin tone, medium-dark skin tone",
+        &[],
+    ),
+    (
+        "👩🏿‍⚕️",
+        "woman health worker: dark skin tone",
+        &["woman_health_worker_tone5"],
+    ),
+    ("👩🏿‍⚖️", "woman judge: dark skin tone", &["woman_judge_tone5"]),
+    ("👩🏿‍✈️", "woman pilot: dark skin tone", &["woman_pilot_tone5"]),
+    (
+        "👩🏿‍❤️‍👨🏻",
+        "couple with heart: woman, man: dark skin tone, light skin tone",
+        &[
+            "couple_with_heart_mw_tone5-1",
+            "couple_with_heart_wm_tone5-1",
+        ],
+    ),
+    (
+        "👩🏿‍❤️‍👨🏼",
+        "couple with heart: woman, man: dark skin tone, medium-light skin tone",
+        &[
+            "couple_with_heart_mw_tone5-2",
+            "couple_with_heart_wm_tone5-2",
+        ],
+    ),
+    (
+        "👩🏿‍❤️‍👨🏽",
+        "couple with heart: woman, man: dark skin tone, medium skin tone",
+        &[
+            "couple_with_heart_mw_tone5-3",
+            "couple_with_heart_wm_tone5-3",
+        ],
+    ),
+    (
+        "👩🏿‍❤️‍👨🏾",
+        "couple with heart: woman, man: dark skin tone, medium-dark skin tone",
+        &[
+            "couple_with_heart_mw_tone5-4",
+            "couple_with_heart_wm_tone5-4",
+        ],
+    ),
+    (
+        "👩🏿‍❤️‍👨🏿",
+        "couple with heart: woman, man: dark skin tone",
+        &["couple_with_heart_mw_tone5", "couple_with_heart_wm_tone5"],
+    ),
+    (
+        "👩🏿‍❤️‍👩🏻",
+        "couple with heart: woman, woman: dark skin tone, light skin tone",
+        &["couple_with_heart_ww_tone5-1"],
+    ),
+    (
+        "👩🏿‍❤️‍👩🏼",
+        "couple with heart: woman, woman: dark skin tone, medium-light skin tone",
+        &["couple_with_heart_ww_tone5-2"],
+    ),
+    (
+        "👩🏿‍❤️‍👩🏽",
+        "couple with heart: woman, woman: dark skin tone, medium skin tone",
+        &["couple_with_heart_ww_tone5-3"],
+    ),
+    (
+        "👩🏿‍❤️‍👩🏾",
+        "couple with heart: woman, woman: dark skin tone, medium-dark skin tone",
+        &["couple_with_heart_ww_tone5-4"],
+    ),
+    (
+        "👩🏿‍❤️‍👩🏿",
+        "couple with heart: woman, woman: dark skin tone",
+        &["couple_with_heart_ww_tone5"],
+    ),
+    (
+        "👩🏿‍❤️‍💋‍👨🏻",
+        "kiss: woman, man: dark skin tone, light skin tone",
+        &["kiss_mw_tone5-1", "kiss_wm_tone5-1"],
+    ),
+    (
+        "👩🏿‍❤️‍💋‍👨🏼",
+        "kiss: woman, man: dark skin tone, medium-light skin tone",
+        &["kiss_mw_tone5-2", "kiss_wm_tone5-2"],
+    ),
+    (
+        "👩🏿‍❤️‍💋‍👨🏽",
+        "kiss: woman, man: dark skin tone, medium skin tone",
+        &["kiss_mw_tone5-3", "kiss_wm_tone5-3"],
+    ),
+    (
+        "👩🏿‍❤️‍💋‍👨🏾",
+        "kiss: woman, man: dark skin tone, medium-dark skin tone",
+        &["kiss_mw_tone5-4", "kiss_wm_tone5-4"],
+    ),
+    (
+        "👩🏿‍❤️‍💋‍👨🏿",
+        "kiss: woman, man: dark skin tone",
+        &["kiss_mw_tone5", "kiss_wm_tone5"],
+    ),
+    (
+        "👩🏿‍❤️‍💋‍👩🏻",
+        "kiss: woman, woman: dark skin tone, light skin tone",
+        &["kiss_ww_tone5-1"],
+    ),
+    (
+        "👩🏿‍❤️‍💋‍👩🏼",
+        "kiss: woman, woman: dark skin tone, medium-light skin tone",
+        &["kiss_ww_tone5-2"],
+    ),
+    (
+        "👩🏿‍❤️‍💋‍👩🏽",
+        "kiss: woman, woman: dark skin tone, medium skin tone",
+        &["kiss_ww_tone5-3"],
+    ),
+    (
+        "👩🏿‍❤️‍💋‍👩🏾",
+        "kiss: woman, woman: dark skin tone, medium-dark skin tone",
+        &["kiss_ww_tone5-4"],
+    ),
+    (
+        "👩🏿‍❤️‍💋‍👩🏿",
+        "kiss: woman, woman: dark skin tone",
+        &["kiss_ww_tone5"],
+    ),
+    ("👩🏿", "woman: dark skin tone", &["woman_tone5"]),
+    ("👩‍🌾", "woman farmer", &["woman_farmer"]),
+    ("👩‍🍳", "woman cook", &["woman_cook"]),
+    ("👩‍🍼", "woman feeding baby", &["woman_feeding_baby"]),
+    ("👩‍🎄", "Mrs. Claus", &[]),
+    ("👩‍🎓", "woman student", &["woman_student"]),
+    ("👩‍🎤", "woman singer", &["woman_singer"]),
+    ("👩‍🎨", "woman artist", &["woman_artist"]),
+    ("👩‍🏫", "woman teacher", &["woman_teacher"]),
+    ("👩‍🏭", "woman factory worker", &["woman_factory_worker"]),
+    ("👩‍👦‍👦", "family: woman, boy, boy", &["family_wbb"]),
+    ("👩‍👦", "family: woman, boy", &["family_wb"]),
+    ("👩‍👧‍👦", "family: woman, girl, boy", &["family_wgb"]),
+    ("👩‍👧‍👧", "family: woman, girl, girl", &["family_wgg"]),
+    ("👩‍👧", "family: woman, girl", &["family_wg"]),
+    ("👩‍👩‍👦‍👦", "family: woman, woman, boy, boy", &["family_wwbb"]),
+    ("👩‍👩‍👦", "family: woman, woman, boy", &["family_wwb"]),
+    ("👩‍👩‍👧‍👦", "family: woman, woman, girl, boy", &["family_wwgb"]),
+    ("👩‍👩‍👧‍👧", "family: woman, woman, girl, girl", &["family_wwgg"]),
+    ("👩‍👩‍👧", "family: woman, woman, girl", &["family_wwg"]),
+    ("👩‍💻", "woman technologist", &["woman_technologist"]),
+    ("👩‍💼", "woman office worker", &["woman_office_worker"]),
+    ("👩‍🔧", "woman mechanic", &["woman_mechanic"]),
+    ("👩‍🔬", "woman scientist", &["woman_scientist"]),
+    ("👩‍🚀", "woman astronaut", &["woman_astronaut"]),
+    ("👩‍🚒", "woman firefighter", &["woman_firefighter"]),
+    (
+        "👩‍🦯‍➡️",
+        "woman with white cane facing right",
+        &["woman_with_white_cane_right"],
+    ),
+    (
+        "👩‍🦯",
+        "woman with white cane",
+        &["woman_with_probing_cane", "woman_with_white_cane"],
+    ),
+    ("👩‍🦰", "woman: red hair", &["woman_red_haired"]),
+    ("👩‍🦱", "woman: curly hair", &["woman_curly_haired"]),
+    ("👩‍🦲", "woman: bald", &["woman_bald"]),
+    ("👩‍🦳", "woman: white hair", &["woman_white_haired"]),
+    (
+        "👩‍🦼‍➡️",
+        "woman in motorized wheelchair facing right",
+        &["woman_in_motorized_wheelchair_right"],
+    ),
+    (
+        "👩‍🦼",
+        "woman in motorized wheelchair",
+        &["woman_in_motorized_wheelchair"],
+    ),
+    (
+        "👩‍🦽‍➡️",
+        "woman in manual wheelchair facing right",
+        &["woman_in_manual_wheelchair_right"],
+    ),
+    (
+        "👩‍🦽",
+        "woman in manual wheelchair",
+        &["woman_in_manual_wheelchair"],
+    ),
+    ("👩‍⚕️", "woman health worker", &["woman_health_worker"]),
+    ("👩‍⚖️", "woman judge", &["woman_judge"]),
+    ("👩‍✈️", "woman pilot", &["woman_pilot"]),
+    (
+        "👩‍❤️‍👨",
+        "couple with heart: woman, man",
+        &["couple_with_heart_mw", "couple_with_heart_wm"],
+    ),
+    (
+        "👩‍❤️‍👩",
+        "couple with heart: woman, woman",
+        &["couple_with_heart_ww"],
+    ),
+    ("👩‍❤️‍💋‍👨", "kiss: woman, man", &["kiss_mw", "kiss_wm"]),
+    ("👩‍❤️‍💋‍👩", "kiss: woman, woman", &["kiss_ww"]),
+    ("👩", "woman", &["woman"]),
+    ("👪", "family", &["family"]),
+    (
+        "👫🏻",
+        "woman and man holding hands: light skin tone",
+        &["couple_tone1"],
+    ),
+    (
+        "👫🏼",
+        "woman and man holding hands: medium-light skin tone",
+        &["couple_tone2"],
+    ),
+    (
+        "👫🏽",
+        "woman and man holding hands: medium skin tone",
+        &["couple_tone3"],
+    ),
+    (
+        "👫🏾",
+        "woman and man holding hands: medium-dark skin tone",
+        &["couple_tone4"],
+    ),
+    (
+        "👫🏿",
+        "woman and man holding hands: dark skin tone",
+        &["couple_tone5"],
+    ),
+    ("👫", "woman and man holding hands", &["couple"]),
+    (
+        "👬🏻",
+        "men holding hands: light skin tone",
+        &["two_men_holding_hands_tone1"],
+    ),
+    (
+        "👬🏼",
+        "men holding hands: medium-light skin tone",
+        &["two_men_holding_hands_tone2"],
+    ),
+    (
+        "👬🏽",
+        "men holding hands: medium skin tone",
+        &["two_men_holding_hands_tone3"],
+    ),
+    (
+        "👬🏾",
+        "men holding hands: medium-dark skin tone",
+        &["two_men_holding_hands_tone4"],
+    ),
+    (
+        "👬🏿",
+        "men holding hands: dark skin tone",
+        &["two_men_holding_hands_tone5"],
+    ),
+    ("👬", "men holding hands", &["two_men_holding_hands"]),
+    (
+        "👭🏻",
+        "women holding hands: light skin tone",
+        &["two_women_holding_hands_tone1"],
+    ),
+    (
+        "👭🏼",
+        "women holding hands: medium-light skin tone",
+        &["two_women_holding_hands_tone2"],
+    ),
+    (
+        "👭🏽",
+        "women holding hands: medium skin tone",
+        &["two_women_holding_hands_tone3"],
+    ),
+    (
+        "👭🏾",
+        "women holding hands: medium-dark skin tone",
+        &["two_women_holding_hands_tone4"],
+    ),
+    (
+        "👭🏿",
+        "women holding hands: dark skin tone",
+        &["two_women_holding_hands_tone5"],
+    ),
+    ("👭", "women holding hands", &["two_women_holding_hands"]),
+    (
+        "👮🏻‍♀️",
+        "woman police officer: light skin tone",
+        &["woman_police_officer_tone1"],
+    ),
+    (
+        "👮🏻‍♂️",
+        "man police officer: light skin tone",
+        &["man_police_officer_tone1"],
+    ),
+    (
+        "👮🏻",
+        "police officer: light skin tone",
+        &["cop_tone1", "police_officer_tone1"],
+    ),
+    (
+        "👮🏼‍♀️",
+        "woman police officer: medium-light skin tone",
+        &["woman_police_officer_tone2"],
+    ),
+    (
+        "👮🏼‍♂️",
+        "man police officer: medium-light skin tone",
+        &["man_police_officer_tone2"],
+    ),
+    (
+        "👮🏼",
+        "police officer: medium-light skin tone",
+        &["cop_tone2", "police_officer_tone2"],
+    ),
+    (
+        "👮🏽‍♀️",
+        "woman police officer: medium skin tone",
+        &["woman_police_officer_tone3"],
+    ),
+    (
+        "👮🏽‍♂️",
+        "man police officer: medium skin tone",
+        &["man_police_officer_tone3"],
+    ),
+    (
+        "👮🏽",
+        "police officer: medium skin tone",
+        &["cop_tone3", "police_officer_tone3"],
+    ),
+    (
+        "👮🏾‍♀️",
+        "woman police officer: medium-dark skin tone",
+        &["woman_police_officer_tone4"],
+    ),
+    (
+        "👮🏾‍♂️",
+        "man police officer: medium-dark skin tone",
+        &["man_police_officer_tone4"],
+    ),
+    (
+        "👮🏾",
+        "police officer: medium-dark skin tone",
+        &["cop_tone4", "police_officer_tone4"],
+    ),
+    (
+        "👮🏿‍♀️",
+        "woman police officer: dark skin tone",
+        &["woman_police_officer_tone5"],
+    ),
+    (
+        "👮🏿‍♂️",
+        "man police officer: dark skin tone",
+        &["man_police_officer_tone5"],
+    ),
+    (
+        "👮🏿",
+        "police officer: dark skin tone",
+        &["cop_tone5", "police_officer_tone5"],
+    ),
+    ("👮‍♀️", "woman police officer", &["woman_police_officer"]),
+    ("👮‍♂️", "man police officer", &["man_police_officer"]),
+    ("👮", "police officer", &["cop", "police_officer"]),
+    ("👯🏻‍♀️", "women with bunny ears: light skin tone", &[]),
+    ("👯🏻‍♂️", "men with bunny ears: light skin tone", &[]),
+    ("👯🏻", "people with bunny ears: light skin tone", &[]),
+    ("👯🏼‍♀️", "women with bunny ears: medium-light skin tone", &[]),
+    ("👯🏼‍♂️", "men with bunny ears: medium-light skin tone", &[]),
+    ("👯🏼", "people with bunny ears: medium-light skin tone", &[]),
+    ("👯🏽‍♀️", "women with bunny ears: medium skin tone", &[]),
+    ("👯🏽‍♂️", "men with bunny ears: medium skin tone", &[]),
+    ("👯🏽", "people with bunny ears: medium skin tone", &[]),
+    ("👯🏾‍♀️", "women with bunny ears: medium-dark skin tone", &[]),
+    ("👯🏾‍♂️", "men with bunny ears: medium-dark skin tone", &[]),
+    ("👯🏾", "people with bunny ears: medium-dark skin tone", &[]),
+    ("👯🏿‍♀️", "women with bunny ears: dark skin tone", &[]),
+    ("👯🏿‍♂️", "men with bunny ears: dark skin tone", &[]),
+    ("👯🏿", "people with bunny ears: dark skin tone", &[]),
+    (
+        "👯‍♀️",
+        "women with bunny ears",
+        &["women_with_bunny_ears_partying"],
+    ),
+    (
+        "👯‍♂️",
+        "men with bunny ears",
+        &["men_with_bunny_ears_partying"],
+    ),
+    (
+        "👯",
+        "people with bunny ears",
+        &["dancers", "people_with_bunny_ears_partying"],
+    ),
+    (
+        "👰🏻‍♀️",
+        "woman with veil: light skin tone",
+        &["woman_with_veil_tone1"],
+    ),
+    (
+        "👰🏻‍♂️",
+        "man with veil: light skin tone",
+        &["man_with_veil_tone1"],
+    ),
+    (
+        "👰🏻",
+        "person with veil: light skin tone",
+        &["person_with_veil_tone1"],
+    ),
+    (
+        "👰🏼‍♀️",
+        "woman with veil: medium-light skin tone",
+        &["woman_with_veil_tone2"],
+    ),
+    (
+        "👰🏼‍♂️",
+        "man with veil: medium-light skin tone",
+        &["man_with_veil_tone2"],
+    ),
+    (
+        "👰🏼",
+        "person with veil: medium-light skin tone",
+        &["person_with_veil_tone2"],
+    ),
+    (
+        "👰🏽‍♀️",
+        "woman with veil: medium skin tone",
+        &["woman_with_veil_tone3"],
+    ),
+    (
+        "👰🏽‍♂️",
+        "man with veil: medium skin tone",
+        &["man_with_veil_tone3"],
+    ),
+    (
+        "👰🏽",
+        "person with veil: medium skin tone",
+        &["person_with_veil_tone3"],
+    ),
+    (
+        "👰🏾‍♀️",
+        "woman with veil: medium-dark skin tone",
+        &["woman_with_veil_tone4"],
+    ),
+    (
+        "👰🏾‍♂️",
+        "man with veil: medium-dark skin tone",
+        &["man_with_veil_tone4"],
+    ),
+    (
+        "👰🏾",
+        "person with veil: medium-dark skin tone",
+        &["person_with_veil_tone4"],
+    ),
+    (
+        "👰🏿‍♀️",
+        "woman with veil: dark skin tone",
+        &["woman_with_veil_tone5"],
+    ),
+    (
+        "👰🏿‍♂️",
+        "man with veil: dark skin tone",
+        &["man_with_veil_tone5"],
+    ),
+    (
+        "👰🏿",
+        "person with veil: dark skin tone",
+        &["person_with_veil_tone5"],
+    ),
+    ("👰‍♀️", "woman with veil", &["woman_with_veil"]),
+    ("👰‍♂️", "man with veil", &["man_with_veil"]),
+    ("👰", "person with veil", &["person_with_veil"]),
+    (
+        "👱🏻‍♀️",
+        "woman: blond hair: light skin tone",
+        &["woman_blond_haired_tone1"],
+    ),
+    (
+        "👱🏻‍♂️",
+        "man: blond hair: light skin tone",
+        &["man_blond_haired_tone1"],
+    ),
+    (
+        "👱🏻",
+        "person: blond hair: light skin tone",
+        &["blond_haired_tone1"],
+    ),
+    (
+        "👱🏼‍♀️",
+        "woman: blond hair: medium-light skin tone",
+        &["woman_blond_haired_tone2"],
+    ),
+    (
+        "👱🏼‍♂️",
+        "man: blond hair: medium-light skin tone",
+        &["man_blond_haired_tone2"],
+    ),
+    (
+        "👱🏼",
+        "person: blond hair: medium-light skin tone",
+        &["blond_haired_tone2"],
+    ),
+    (
+        "👱🏽‍♀️",
+        "woman: blond hair: medium skin tone",
+        &["woman_blond_haired_tone3"],
+    ),
+    (
+        "👱🏽‍♂️",
+        "man: blond hair: medium skin tone",
+        &["man_blond_haired_tone3"],
+    ),
+    (
+        "👱🏽",
+        "person: blond hair: medium skin tone",
+        &["blond_haired_tone3"],
+    ),
+    (
+        "👱🏾‍♀️",
+        "woman: blond hair: medium-dark skin tone",
+        &["woman_blond_haired_tone4"],
+    ),
+    (
+        "👱🏾‍♂️",
+        "man: blond hair: medium-dark skin tone",
+        &["man_blond_haired_tone4"],
+    ),
+    (
+        "👱🏾",
+        "person: blond hair: medium-dark skin tone",
+        &["blond_haired_tone4"],
+    ),
+    (
+        "👱🏿‍♀️",
+        "woman: blond hair: dark skin tone",
+        &["woman_blond_haired_tone5"],
+    ),
+    (
+        "👱🏿‍♂️",
+        "man: blond hair: dark skin tone",
+        &["man_blond_haired_tone5"],
+    ),
+    (
+        "👱🏿",
+        "person: blond hair: dark skin tone",
+        &["blond_haired_tone5"],
+    ),
+    ("👱‍♀️", "woman: blond hair", &["woman_blond_haired"]),
+    ("👱‍♂️", "man: blond hair", &["man_blond_haired"]),
+    ("👱", "person: blond hair", &["blond_haired"]),
+    (
+        "👲🏻",
+        "person with skullcap: light skin tone",
+        &["person_with_skullcap_tone1"],
+    ),
+    (
+        "👲🏼",
+        "person with skullcap: medium-light skin tone",
+        &["person_with_skullcap_tone2"],
+    ),
+    (
+        "👲🏽",
+        "person with skullcap: medium skin tone",
+        &["person_with_skullcap_tone3"],
+    ),
+    (
+        "👲🏾",
+        "person with skullcap: medium-dark skin tone",
+        &["person_with_skullcap_tone4"],
+    ),
+    (
+        "👲🏿",
+        "person with skullcap: dark skin tone",
+        &["person_with_skullcap_tone5"],
+    ),
+    ("👲", "person with skullcap", &["person_with_skullcap"]),
+    (
+        "👳🏻‍♀️",
+        "woman wearing turban: light skin tone",
+        &["woman_wearing_turban_tone1"],
+    ),
+    (
+        "👳🏻‍♂️",
+        "man wearing turban: light skin tone",
+        &["man_wearing_turban_tone1"],
+    ),
+    (
+        "👳🏻",
+        "person wearing turban: light skin tone",
+        &["person_wearing_turban_tone1"],
+    ),
+    (
+        "👳🏼‍♀️",
+        "woman wearing turban: medium-light skin tone",
+        &["woman_wearing_turban_tone2"],
+    ),
+    (
+        "👳🏼‍♂️",
+        "man wearing turban: medium-light skin tone",
+        &["man_wearing_turban_tone2"],
+    ),
+    (
+        "👳🏼",
+        "person wearing turban: medium-light skin tone",
+        &["person_wearing_turban_tone2"],
+    ),
+    (
+        "👳🏽‍♀️",
+        "woman wearing turban: medium skin tone",
+        &["woman_wearing_turban_tone3"],
+    ),
+    (
+        "👳🏽‍♂️",
+        "man wearing turban: medium skin tone",
+        &["man_wearing_turban_tone3"],
+    ),
+    (
+        "👳🏽",
+        "person wearing turban: medium skin tone",
+        &["person_wearing_turban_tone3"],
+    ),
+    (
+        "👳🏾‍♀️",
+        "woman wearing turban: medium-dark skin tone",
+        &["woman_wearing_turban_tone4"],
+    ),
+    (
+        "👳🏾‍♂️",
+        "man wearing turban: medium-dark skin tone",
+        &["man_wearing_turban_tone4"],
+    ),
+    (
+        "👳🏾",
+        "person wearing turban: medium-dark skin tone",
+        &["person_wearing_turban_tone4"],
+    ),
+    (
+        "👳🏿‍♀️",
+        "woman wearing turban: dark skin tone",
+        &["woman_wearing_turban_tone5"],
+    ),
+    (
+        "👳🏿‍♂️",
+        "man wearing turban: dark skin tone",
+        &["man_wearing_turban_tone5"],
+    ),
+    (
+        "👳🏿",
+        "person wearing turban: dark skin tone",
+        &["person_wearing_turban_tone5"],
+    ),
+    ("👳‍♀️", "woman wearing turban", &["woman_wearing_turban"]),
+    ("👳‍♂️", "man wearing turban", &["man_wearing_turban"]),
+    ("👳", "person wearing turban", &["person_wearing_turban"]),
+    ("👴🏻", "old man: light skin tone", &["older_man_tone1"]),
+    (
+        "👴🏼",
+        "old man: medium-light skin tone",
+        &["older_man_tone2"],
+    ),
+    ("👴🏽", "old man: medium skin tone", &["older_man_tone3"]),
+    ("👴🏾", "old man: medium-dark skin tone", &["older_man_tone4"]),
+    ("👴🏿", "old man: dark skin tone", &["older_man_tone5"]),
+    ("👴", "old man", &["older_man"]),
+    ("👵🏻", "old woman: light skin tone", &["older_woman_tone1"]),
+    (
+        "👵🏼",
+        "old woman: medium-light skin tone",
+        &["older_woman_tone2"],
+    ),
+    ("👵🏽", "old woman: medium skin tone", &["older_woman_tone3"]),
+    (
+        "👵🏾",
+        "old woman: medium-dark skin tone",
+        &["older_woman_tone4"],
+    ),
+    ("👵🏿", "old woman: dark skin tone", &["older_woman_tone5"]),
+    ("👵", "old woman", &["older_woman"]),
+    ("👶🏻", "baby: light skin tone", &["baby_tone1"]),
+    ("👶🏼", "baby: medium-light skin tone", &["baby_tone2"]),
+    ("👶🏽", "baby: medium skin tone", &["baby_tone3"]),
+    ("👶🏾", "baby: medium-dark skin tone", &["baby_tone4"]),
+    ("👶🏿", "baby: dark skin tone", &["baby_tone5"]),
+    ("👶", "baby", &["baby"]),
+    (
+        "👷🏻‍♀️",
+        "woman construction worker: light skin tone",
+        &["woman_construction_worker_tone1"],
+    ),
+    (
+        "👷🏻‍♂️",
+        "man construction worker: light skin tone",
+        &["man_construction_worker_tone1"],
+    ),
+    (
+        "👷🏻",
+        "construction worker: light skin tone",
+        &["construction_worker_tone1"],
+    ),
+    (
+        "👷🏼‍♀️",
+        "woman construction worker: medium-light skin tone",
+        &["woman_construction_worker_tone2"],
+    ),
+    (
+        "👷🏼‍♂️",
+        "man construction worker: medium-light skin tone",
+        &["man_construction_worker_tone2"],
+    ),
+    (
+        "👷🏼",
+        "construction worker: medium-light skin tone",
+        &["construction_worker_tone2"],
+    ),
+    (
+        "👷🏽‍♀️",
+        "woman construction worker: medium skin tone",
+        &["woman_construction_worker_tone3"],
+    ),
+    (
+        "👷🏽‍♂️",
+        "man construction worker: medium skin tone",
+        &["man_construction_worker_tone3"],
+    ),
+    (
+        "👷🏽",
+        "construction worker: medium skin tone",
+        &["construction_worker_tone3"],
+    ),
+    (
+        "👷🏾‍♀️",
+        "woman construction worker: medium-dark skin tone",
+        &["woman_construction_worker_tone4"],
+    ),
+    (
+        "👷🏾‍♂️",
+        "man construction worker: medium-dark skin tone",
+        &["man_construction_worker_tone4"],
+    ),
+    (
+        "👷🏾",
+        "construction worker: medium-dark skin tone",
+        &["construction_worker_tone4"],
+    ),
+    (
+        "👷🏿‍♀️",
+        "woman construction worker: dark skin tone",
+        &["woman_construction_worker_tone5"],
+    ),
+    (
+        "👷🏿‍♂️",
+        "man construction worker: dark skin tone",
+        &["man_construction_worker_tone5"],
+    ),
+    (
+        "👷🏿",
+        "construction worker: dark skin tone",
+        &["construction_worker_tone5"],
+    ),
+    (
+        "👷‍♀️",
+        "woman construction worker",
+        &["woman_construction_worker"],
+    ),
+    (
+        "👷‍♂️",
+        "man construction worker",
+        &["man_construction_worker"],
+    ),
+    ("👷", "construction worker", &["construction_worker"]),
+    ("👸🏻", "princess: light skin tone", &["princess_tone1"]),
+    (
+        "👸🏼",
+        "princess: medium-light skin tone",
+        &["princess_tone2"],
+    ),
+    ("👸🏽", "princess: medium skin tone", &["princess_tone3"]),
+    ("👸🏾", "princess: medium-dark skin tone", &["princess_tone4"]),
+    ("👸🏿", "princess: dark skin tone", &["princess_tone5"]),
+    ("👸", "princess", &["princess"]),
+    ("👹", "ogre", &["japanese_ogre", "ogre"]),
+    ("👺", "goblin", &["goblin", "japanese_goblin"]),
+    ("👻", "ghost", &["ghost"]),
+    ("👼🏻", "baby angel: light skin tone", &["angel_tone1"]),
+    ("👼🏼", "baby angel: medium-light skin tone", &["angel_tone2"]),
+    ("👼🏽", "baby angel: medium skin tone", &["angel_tone3"]),
+    ("👼🏾", "baby angel: medium-dark skin tone", &["angel_tone4"]),
+    ("👼🏿", "baby angel: dark skin tone", &["angel_tone5"]),
+    ("👼", "baby angel", &["angel"]),
+    ("👽", "alien", &["alien"]),
+    ("👾", "alien monster", &["alien_monster", "space_invader"]),
+    ("👿", "angry face with horns", &["angry_imp", "imp"]),
+    ("💀", "skull", &["skull"]),
+    (
+        "💁🏻‍♀️",
+        "woman tipping hand: light skin tone",
+        &["woman_tipping_hand_tone1"],
+    ),
+    (
+        "💁🏻‍♂️",
+        "man tipping hand: light skin tone",
+        &["man_tipping_hand_tone1"],
+    ),
+    (
+        "💁🏻",
+        "person tipping hand: light skin tone",
+        &["person_tipping_hand_tone1"],
+    ),
+    (
+        "💁🏼‍♀️",
+        "woman tipping hand: medium-light skin tone",
+        &["woman_tipping_hand_tone2"],
+    ),
+    (
+        "💁🏼‍♂️",
+        "man tipping hand: medium-light skin tone",
+        &["man_tipping_hand_tone2"],
+    ),
+    (
+        "💁🏼",
+        "person tipping hand: medium-light skin tone",
+        &["person_tipping_hand_tone2"],
+    ),
+    (
+        "💁🏽‍♀️",
+        "woman tipping hand: medium skin tone",
+        &["woman_tipping_hand_tone3"],
+    ),
+    (
+        "💁🏽‍♂️",
+        "man tipping hand: medium skin tone",
+        &["man_tipping_hand_tone3"],
+    ),
+    (
+        "💁🏽",
+        "person tipping hand: medium skin tone",
+        &["person_tipping_hand_tone3"],
+    ),
+    (
+        "💁🏾‍♀️",
+        "woman tipping hand: medium-dark skin tone",
+        &["woman_tipping_hand_tone4"],
+    ),
+    (
+        "💁🏾‍♂️",
+        "man tipping hand: medium-dark skin tone",
+        &["man_tipping_hand_tone4"],
+    ),
+    (
+        "💁🏾",
+        "person tipping hand: medium-dark skin tone",
+        &["person_tipping_hand_tone4"],
+    ),
+    (
+        "💁🏿‍♀️",
+        "woman tipping hand: dark skin tone",
+        &["woman_tipping_hand_tone5"],
+    ),
+    (
+        "💁🏿‍♂️",
+        "man tipping hand: dark skin tone",
+        &["man_tipping_hand_tone5"],
+    ),
+    (
+        "💁🏿",
+        "person tipping hand: dark skin tone",
+        &["person_tipping_hand_tone5"],
+    ),
+    ("💁‍♀️", "woman tipping hand", &["woman_tipping_hand"]),
+    ("💁‍♂️", "man tipping hand", &["man_tipping_hand"]),
+    ("💁", "person tipping hand", &["person_tipping_hand"]),
+    ("💂🏻‍♀️", "woman guard: light skin tone", &["woman_guard_tone1"]),
+    ("💂🏻‍♂️", "man guard: light skin tone", &["man_guard_tone1"]),
+    ("💂🏻", "guard: light skin tone", &["guard_tone1"]),
+    (
+        "💂🏼‍♀️",
+        "woman guard: medium-light skin tone",
+        &["woman_guard_tone2"],
+    ),
+    (
+        "💂🏼‍♂️",
+        "man guard: medium-light skin tone",
+        &["man_guard_tone2"],
+    ),
+    ("💂🏼", "guard: medium-light skin tone", &["guard_tone2"]),
+    (
+        "💂🏽‍♀️",
+        "woman guard: medium skin tone",
+        &["woman_guard_tone3"],
+    ),
+    ("💂🏽‍♂️", "man guard: medium skin tone", &["man_guard_tone3"]),
+    ("💂🏽", "guard: medium skin tone", &["guard_tone3"]),
+    (
+        "💂🏾‍♀️",
+        "woman guard: medium-dark skin tone",
+        &["woman_guard_tone4"],
+    ),
+    (
+        "💂🏾‍♂️",
+        "man guard: medium-dark skin tone",
+        &["man_guard_tone4"],
+    ),
+    ("💂🏾", "guard: medium-dark skin tone", &["guard_tone4"]),
+    ("💂🏿‍♀️", "woman guard: dark skin tone", &["woman_guard_tone5"]),
+    ("💂🏿‍♂️", "man guard: dark skin tone", &["man_guard_tone5"]),
+    ("💂🏿", "guard: dark skin tone", &["guard_tone5"]),
+    ("💂‍♀️", "woman guard", &["woman_guard"]),
+    ("💂‍♂️", "man guard", &["man_guard"]),
+    ("💂", "guard", &["guard"]),
+    (
+        "💃🏻",
+        "woman dancing: light skin tone",
+        &["dancer_tone1", "woman_dancing_tone1"],
+    ),
+    (
+        "💃🏼",
+        "woman dancing: medium-light skin tone",
+        &["dancer_tone2", "woman_dancing_tone2"],
+    ),
+    (
+        "💃🏽",
+        "woman dancing: medium skin tone",
+        &["dancer_tone3", "woman_dancing_tone3"],
+    ),
+    (
+        "💃🏾",
+        "woman dancing: medium-dark skin tone",
+        &["dancer_tone4", "woman_dancing_tone4"],
+    ),
+    (
+        "💃🏿",
+        "woman dancing: dark skin tone",
+        &["dancer_tone5", "woman_dancing_tone5"],
+    ),
+    ("💃", "woman dancing", &["dancer", "woman_dancing"]),
+    ("💄", "lipstick", &["lipstick"]),
+    (
+        "💅🏻",
+        "nail polish: light skin tone",
+        &["nail_care_tone1", "nail_polish_tone1"],
+    ),
+    (
+        "💅🏼",
+        "nail polish: medium-light skin tone",
+        &["nail_care_tone2", "nail_polish_tone2"],
+    ),
+    (
+        "💅🏽",
+        "nail polish: medium skin tone",
+        &["nail_care_tone3", "nail_polish_tone3"],
+    ),
+    (
+        "💅🏾",
+        "nail polish: medium-dark skin tone",
+        &["nail_care_tone4", "nail_polish_tone4"],
+    ),
+    (
+        "💅🏿",
+        "nail polish: dark skin tone",
+        &["nail_care_tone5", "nail_polish_tone5"],
+    ),
+    ("💅", "nail polish", &["nail_care", "nail_polish"]),
+    (
+        "💆🏻‍♀️",
+        "woman getting massage: light skin tone",
+        &["woman_getting_massage_tone1"],
+    ),
+    (
+        "💆🏻‍♂️",
+        "man getting massage: light skin tone",
+        &["man_getting_massage_tone1"],
+    ),
+    (
+        "💆🏻",
+        "person getting massage: light skin tone",
+        &["massage_tone1", "person_getting_massage_tone1"],
+    ),
+    (
+        "💆🏼‍♀️",
+        "woman getting massage: medium-light skin tone",
+        &["woman_getting_massage_tone2"],
+    ),
+    (
+        "💆🏼‍♂️",
+        "man getting massage: medium-light skin tone",
+        &["man_getting_massage_tone2"],
+    ),
+    (
+        "💆🏼",
+        "person getting massage: medium-light skin tone",
+        &["massage_tone2", "person_getting_massage_tone2"],
+    ),
+    (
+        "💆🏽‍♀️",
+        "woman getting massage: medium skin tone",
+        &["woman_getting_massage_tone3"],
+    ),
+    (
+        "💆🏽‍♂️",
+        "man getting massage: medium skin tone",
+        &["man_getting_massage_tone3"],
+    ),
+    (
+        "💆🏽",
+        "person getting massage: medium skin tone",
+        &["massage_tone3", "person_getting_massage_tone3"],
+    ),
+    (
+        "💆🏾‍♀️",
+        "woman getting massage: medium-dark skin tone",
+        &["woman_getting_massage_tone4"],
+    ),
+    (
+        "💆🏾‍♂️",
+        "man getting massage: medium-dark skin tone",
+        &["man_getting_massage_tone4"],
+    ),
+    (
+        "💆🏾",
+        "person getting massage: medium-dark skin tone",
+        &["massage_tone4", "person_getting_massage_tone4"],
+    ),
+    (
+        "💆🏿‍♀️",
+        "woman getting massage: dark skin tone",
+        &["woman_getting_massage_tone5"],
+    ),
+    (
+        "💆🏿‍♂️",
+        "man getting massage: dark skin tone",
+        &["man_getting_massage_tone5"],
+    ),
+    (
+        "💆🏿",
+        "person getting massage: dark skin tone",
+        &["massage_tone5", "person_getting_massage_tone5"],
+    ),
+    ("💆‍♀️", "woman getting massage", &["woman_getting_massage"]),
+    ("💆‍♂️", "man getting massage", &["man_getting_massage"]),
+    (
+        "💆",
+        "person getting massage",
+        &["massage", "person_getting_massage"],
+    ),
+    (
+        "💇🏻‍♀️",
+        "woman getting haircut: light skin tone",
+        &["woman_getting_haircut_tone1"],
+    ),
+    (
+        "💇🏻‍♂️",
+        "man getting haircut: light skin tone",
+        &["man_getting_haircut_tone1"],
+    ),
+    (
+        "💇🏻",
+        "person getting haircut: light skin tone",
+        &["haircut_tone1", "person_getting_haircut_tone1"],
+    ),
+    (
+        "💇🏼‍♀️",
+        "woman getting haircut: medium-light skin tone",
+        &["woman_getting_haircut_tone2"],
+    ),
+    (
+        "💇🏼‍♂️",
+        "man getting haircut: medium-light skin tone",
+        &["man_getting_haircut_tone2"],
+    ),
+    (
+        "💇🏼",
+        "person getting haircut: medium-light skin tone",
+        &["haircut_tone2", "person_getting_haircut_tone2"],
+    ),
+    (
+        "💇🏽‍♀️",
+        "woman getting haircut: medium skin tone",
+        &["woman_getting_haircut_tone3"],
+    ),
+    (
+        "💇🏽‍♂️",
+        "man getting haircut: medium skin tone",
+        &["man_getting_haircut_tone3"],
+    ),
+    (
+        "💇🏽",
+        "person getting haircut: medium skin tone",
+        &["haircut_tone3", "person_getting_haircut_tone3"],
+    ),
+    (
+        "💇🏾‍♀️",
+        "woman getting haircut: medium-dark skin tone",
+        &["woman_getting_haircut_tone4"],
+    ),
+    (
+        "💇🏾‍♂️",
+        "man getting haircut: medium-dark skin tone",
+        &["man_getting_haircut_tone4"],
+    ),
+    (
+        "💇🏾",
+        "person getting haircut: medium-dark skin tone",
+        &["haircut_tone4", "person_getting_haircut_tone4"],
+    ),
+    (
+        "💇🏿‍♀️",
+        "woman getting haircut: dark skin tone",
+        &["woman_getting_haircut_tone5"],
+    ),
+    (
+        "💇🏿‍♂️",
+        "man getting haircut: dark skin tone",
+        &["man_getting_haircut_tone5"],
+    ),
+    (
+        "💇🏿",
+        "person getting haircut: dark skin tone",
+        &["haircut_tone5", "person_getting_haircut_tone5"],
+    ),
+    ("💇‍♀️", "woman getting haircut", &["woman_getting_haircut"]),
+    ("💇‍♂️", "man getting haircut", &["man_getting_haircut"]),
+    (
+        "💇",
+        "person getting haircut",
+        &["haircut", "person_getting_haircut"],
+    ),
+    ("💈", "barber pole", &["barber", "barber_pole"]),
+    ("💉", "syringe", &["syringe"]),
+    ("💊", "pill", &["pill"]),
+    ("💋", "kiss mark", &["kiss"]),
+    ("💌", "love letter", &["love_letter"]),
+    ("💍", "ring", &["ring"]),
+    ("💎", "gem stone", &["gem"]),
+    (
+        "💏🏻",
+        "kiss: light skin tone",
+        &["couple_kiss_tone1", "couplekiss_tone1"],
+    ),
+    (
+        "💏🏼",
+        "kiss: medium-light skin tone",
+        &["couple_kiss_tone2", "couplekiss_tone2"],
+    ),
+    (
+        "💏🏽",
+        "kiss: medium skin tone",
+        &["couple_kiss_tone3", "couplekiss_tone3"],
+    ),
+    (
+        "💏🏾",
+        "kiss: medium-dark skin tone",
+        &["couple_kiss_tone4", "couplekiss_tone4"],
+    ),
+    (
+        "💏🏿",
+        "kiss: dark skin tone",
+        &["couple_kiss_tone5", "couplekiss_tone5"],
+    ),
+    ("💏", "kiss", &["couple_kiss", "couplekiss"]),
+    ("💐", "bouquet", &["bouquet"]),
+    (
+        "💑🏻",
+        "couple with heart: light skin tone",
+        &["couple_with_heart_tone1"],
+    ),
+    (
+        "💑🏼",
+        "couple with heart: medium-light skin tone",
+        &["couple_with_heart_tone2"],
+    ),
+    (
+        "💑🏽",
+        "couple with heart: medium skin tone",
+        &["couple_with_heart_tone3"],
+    ),
+    (
+        "💑🏾",
+        "couple with heart: medium-dark skin tone",
+        &["couple_with_heart_tone4"],
+    ),
+    (
+        "💑🏿",
+        "couple with heart: dark skin tone",
+        &["couple_with_heart_tone5"],
+    ),
+    ("💑", "couple with heart", &["couple_with_heart"]),
+    ("💒", "wedding", &["wedding"]),
+    ("💓", "beating heart", &["beating_heart", "heartbeat"]),
+    ("💔", "broken heart", &["broken_heart"]),
+    ("💕", "two hearts", &["two_hearts"]),
+    ("💖", "sparkling heart", &["sparkling_heart"]),
+    ("💗", "growing heart", &["growing_heart", "heartpulse"]),
+    ("💘", "heart with arrow", &["cupid", "heart_with_arrow"]),
+    ("💙", "blue heart", &["blue_heart"]),
+    ("💚", "green heart", &["green_heart"]),
+    ("💛", "yellow heart", &["yellow_heart"]),
+    ("💜", "purple heart", &["purple_heart"]),
+    (
+        "💝",
+        "heart with ribbon",
+        &["gift_heart", "heart_with_ribbon"],
+    ),
+    ("💞", "revolving hearts", &["revolving_hearts"]),
+    ("💟", "heart decoration", &["heart_decoration"]),
+    (
+        "💠",
+        "diamond with a dot",
+        &["diamond_shape_with_a_dot_inside", "diamond_with_a_dot"],
+    ),
+    ("💡", "light bulb", &["bulb", "light_bulb"]),
+    ("💢", "anger symbol", &["anger"]),
+    ("💣", "bomb", &["bomb"]),
+    ("💤", "ZZZ", &["zzz"]),
+    ("💥", "collision", &["boom", "collision"]),
+    ("💦", "sweat droplets", &["sweat_drops"]),
+    ("💧", "droplet", &["droplet"]),
+    ("💨", "dashing away", &["dash", "dashing_away"]),
+    ("💩", "pile of poo", &["poop", "shit"]),
+    (
+        "💪🏻",
+        "flexed biceps: light skin tone",
+        &["muscle_tone1", "right_bicep_tone1"],
+    ),
+    (
+        "💪🏼",
+        "flexed biceps: medium-light skin tone",
+        &["muscle_tone2", "right_bicep_tone2"],
+    ),
+    (
+        "💪🏽",
+        "flexed biceps: medium skin tone",
+        &["muscle_tone3", "right_bicep_tone3"],
+    ),
+    (
+        "💪🏾",
+        "flexed biceps: medium-dark skin tone",
+        &["muscle_tone4", "right_bicep_tone4"],
+    ),
+    (
+        "💪🏿",
+        "flexed biceps: dark skin tone",
+        &["muscle_tone5", "right_bicep_tone5"],
+    ),
+    ("💪", "flexed biceps", &["muscle", "right_bicep"]),
+    ("💫", "dizzy", &["dizzy"]),
+    ("💬", "speech balloon", &["speech_balloon"]),
+    ("💭", "thought balloon", &["thought_balloon"]),
+    ("💮", "white flower", &["white_flower"]),
+    ("💯", "hundred points", &["100"]),
+    ("💰", "money bag", &["moneybag"]),
+    ("💱", "currency exchange", &["currency_exchange"]),
+    ("💲", "heavy dollar sign", &["heavy_dollar_sign"]),
+    ("💳", "credit card", &["credit_card"]),
+    ("💴", "yen banknote", &["yen"]),
+    ("💵", "dollar banknote", &["dollar"]),
+    ("💶", "euro banknote", &["euro"]),
+    ("💷", "pound banknote", &["pound"]),
+    ("💸", "money with wings", &["money_with_wings"]),
+    ("💹", "chart increasing with yen", &["chart"]),
+    ("💺", "seat", &["seat"]),
+    ("💻", "laptop", &["laptop"]),
+    ("💼", "briefcase", &["briefcase"]),
+    ("💽", "computer disk", &["computer_disk", "minidisc"]),
+    ("💾", "floppy disk", &["floppy_disk"]),
+    ("💿", "optical disk", &["cd", "optical_disk"]),
+    ("📀", "dvd", &["dvd"]),
+    ("📁", "file folder", &["file_folder"]),
+    ("📂", "open file folder", &["open_file_folder"]),
+    ("📃", "page with curl", &["page_with_curl"]),
+    ("📄", "page facing up", &["page_facing_up"]),
+    ("📅", "calendar", &["date"]),
+    ("📆", "tear-off calendar", &["calendar"]),
+    ("📇", "card index", &["card_index"]),
+    (
+        "📈",
+        "chart increasing",
+        &["chart_increasing", "chart_with_upwards_trend"],
+    ),
+    (
+        "📉",
+        "chart decreasing",
+        &["chart_decreasing", "chart_with_downwards_trend"],
+    ),
+    ("📊", "bar chart", &["bar_chart"]),
+    ("📋", "clipboard", &["clipboard"]),
+    ("📌", "pushpin", &["pushpin"]),
+    ("📍", "round pushpin", &["round_pushpin"]),
+    ("📎", "paperclip", &["paperclip"]),
+    ("📏", "straight ruler", &["straight_ruler"]),
+    ("📐", "triangular ruler", &["triangular_ruler"]),
+    ("📑", "bookmark tabs", &["bookmark_tabs"]),
+    ("📒", "ledger", &["ledger"]),
+    ("📓", "notebook", &["notebook"]),
+    (
+        "📔",
+        "notebook with decorative cover",
+        &["notebook_with_decorative_cover"],
+    ),
+    ("📕", "closed book", &["closed_book"]),
+    ("📖", "open book", &["book", "open_book"]),
+    ("📗", "green book", &["green_book"]),
+    ("📘", "blue book", &["blue_book"]),
+    ("📙", "orange book", &["orange_book"]),
+    ("📚", "books", &["books"]),
+    ("📛", "name badge", &["name_badge"]),
+    ("📜", "scroll", &["scroll"]),
+    ("📝", "memo", &["memo"]),
+    ("📞", "telephone receiver", &["telephone_receiver"]),
+    ("📟", "pager", &["pager"]),
+    ("📠", "fax machine", &["fax", "fax_machine"]),
+    ("📡", "satellite antenna", &["satellite_antenna"]),
+    ("📢", "loudspeaker", &["loudspeaker"]),
+    ("📣", "megaphone", &["mega", "megaphone"]),
+    ("📤", "outbox tray", &["outbox_tray"]),
+    ("📥", "inbox tray", &["inbox_tray"]),
+    ("📦", "package", &["package"]),
+    ("📧", "e-mail", &["e-mail", "email"]),
+    ("📨", "incoming envelope", &["incoming_envelope"]),
+    ("📩", "envelope with arrow", &["envelope_with_arrow"]),
+    (
+        "📪",
+        "closed mailbox with lowered flag",
+        &["mailbox_closed"],
+    ),
+    ("📫", "closed mailbox with raised flag", &["mailbox"]),
+    (
+        "📬",
+        "open mailbox with raised flag",
+        &["mailbox_with_mail"],
+    ),
+    (
+        "📭",
+        "open mailbox with lowered flag",
+        &["mailbox_with_no_mail"],
+    ),
+    ("📮", "postbox", &["postbox"]),
+    ("📯", "postal horn", &["postal_horn"]),
+    ("📰", "newspaper", &["newspaper"]),
+    ("📱", "mobile phone", &["android", "iphone", "mobile_phone"]),
+    (
+        "📲",
+        "mobile phone with arrow",
+        &["calling", "mobile_phone_arrow"],
+    ),
+    ("📳", "vibration mode", &["vibration_mode"]),
+    ("📴", "mobile phone off", &["mobile_phone_off"]),
+    ("📵", "no mobile phones", &["no_mobile_phones"]),
+    ("📶", "antenna bars", &["antenna_bars", "signal_strength"]),
+    ("📷", "camera", &["camera"]),
+    ("📸", "camera with flash", &["camera_with_flash"]),
+    ("📹", "video camera", &["video_camera"]),
+    ("📺", "television", &["tv"]),
+    ("📻", "radio", &["radio"]),
+    ("📼", "videocassette", &["vhs", "videocassette"]),
+    ("📽", "film projector", &["film_projector"]),
+    ("📿", "prayer beads", &["prayer_beads"]),
+    (
+        "🔀",
+        "shuffle tracks button",
+        &["shuffle", "twisted_rightwards_arrows"],
+    ),
+    ("🔁", "repeat button", &["repeat"]),
+    ("🔂", "repeat single button", &["repeat_one"]),
+    (
+        "🔃",
+        "clockwise vertical arrows",
+        &["arrows_clockwise", "clockwise"],
+    ),
+    (
+        "🔄",
+        "counterclockwise arrows button",
+        &["arrows_counterclockwise", "counterclockwise"],
+    ),
+    ("🔅", "dim button", &["dim_button", "low_brightness"]),
+    ("🔆", "bright button", &["bright_button", "high_brightness"]),
+    ("🔇", "muted speaker", &["mute", "no_sound"]),
+    (
+        "🔈",
+        "speaker low volume",
+        &["low_volume", "quiet_sound", "speaker"],
+    ),
+    ("🔉", "speaker medium volume", &["medium_volumne", "sound"]),
+    ("🔊", "speaker high volume", &["high_volume", "loud_sound"]),
+    ("🔋", "battery", &["battery"]),
+    ("🔌", "electric plug", &["electric_plug"]),
+    ("🔍", "magnifying glass tilted left", &["mag"]),
+    ("🔎", "magnifying glass tilted right", &["mag_right"]),
+    (
+        "🔏",
+        "locked with pen",
+        &["lock_with_ink_pen", "locked_with_pen"],
+    ),
+    (
+        "🔐",
+        "locked with key",
+        &["closed_lock_with_key", "locked_with_key"],
+    ),
+    ("🔑", "key", &["key"]),
+    ("🔒", "locked", &["lock", "locked"]),
+    ("🔓", "unlocked", &["unlock", "unlocked"]),
+    ("🔔", "bell", &["bell"]),
+    ("🔕", "bell with slash", &["no_bell"]),
+    ("🔖", "bookmark", &["bookmark"]),
+    ("🔗", "link", &["link"]),
+    ("🔘", "radio button", &["radio_button"]),
+    ("🔙", "BACK arrow", &["back"]),
+    ("🔚", "END arrow", &["end"]),
+    ("🔛", "ON! arrow", &["on"]),
+    ("🔜", "SOON arrow", &["soon"]),
+    ("🔝", "TOP arrow", &["top"]),
+    (
+        "🔞",
+        "no one under eighteen",
+        &["no_one_under_18", "underage"],
+    ),
+    ("🔟", "keycap: 10", &["ten"]),
+    ("🔠", "input latin uppercase", &["capital_abcd"]),
+    ("🔡", "input latin lowercase", &["abcd"]),
+    ("🔢", "input numbers", &["1234"]),
+    ("🔣", "input symbols", &["symbols"]),
+    ("🔤", "input latin letters", &["abc"]),
+    ("🔥", "fire", &["fire"]),
+    ("🔦", "flashlight", &["flashlight"]),
+    ("🔧", "wrench", &["wrench"]),
+    ("🔨", "hammer", &["hammer"]),
+    ("🔩", "nut and bolt", &["nut_and_bolt"]),
+    ("🔪", "kitchen knife", &["knife"]),
+    ("🔫", "water pistol", &["gun", "pistol"]),
+    ("🔬", "microscope", &["microscope"]),
+    ("🔭", "telescope", &["telescope"]),
+    ("🔮", "crystal ball", &["crystal_ball"]),
+    ("🔯", "dotted six-pointed star", &["six_pointed_star"]),
+    ("🔰", "Japanese symbol for beginner", &["beginner"]),
+    ("🔱", "trident emblem", &["trident"]),
+    ("🔲", "black square button", &["black_square_button"]),
+    ("🔳", "white square button", &["white_square_button"]),
+    ("🔴", "red circle", &["red_circle"]),
+    ("🔵", "blue circle", &["blue_circle"]),
+    ("🔶", "large orange diamond", &["large_orange_diamond"]),
+    ("🔷", "large blue diamond", &["large_blue_diamond"]),
+    ("🔸", "small orange diamond", &["small_orange_diamond"]),
+    ("🔹", "small blue diamond", &["small_blue_diamond"]),
+    ("🔺", "red triangle pointed up", &["small_red_triangle"]),
+    (
+        "🔻",
+        "red triangle pointed down",
+        &["small_red_triangle_down"],
+    ),
+    ("🔼", "upwards button", &["arrow_up_small", "up"]),
+    ("🔽", "downwards button", &["arrow_down_small", "down"]),
+    ("🕉", "om", &["om"]),
+    ("🕊", "dove", &["dove"]),
+    ("🕋", "kaaba", &["kaaba"]),
+    ("🕌", "mosque", &["mosque"]),
+    ("🕍", "synagogue", &["synagogue"]),
+    ("🕎", "menorah", &["menorah"]),
+    ("🕐", "one o’clock", &["clock1"]),
+    ("🕑", "two o’clock", &["clock2"]),
+    ("🕒", "three o’clock", &["clock3"]),
+    ("🕓", "four o’clock", &["clock4"]),
+    ("🕔", "five o’clock", &["clock5"]),
+    ("🕕", "six o’clock", &["clock6"]),
+    ("🕖", "seven o’clock", &["clock7"]),
+    ("🕗", "eight o’clock", &["clock8"]),
+    ("🕘", "nine o’clock", &["clock9"]),
+    ("🕙", "ten o’clock", &["clock10"]),
+    ("🕚", "eleven o’clock", &["clock11"]),
+    ("🕛", "twelve o’clock", &["clock12"]),
+    ("🕜", "one-thirty", &["clock130"]),
+    ("🕝", "two-thirty", &["clock230"]),
+    ("🕞", "three-thirty", &["clock330"]),
+    ("🕟", "four-thirty", &["clock430"]),
+    ("🕠", "five-thirty", &["clock530"]),
+    ("🕡", "six-thirty", &["clock630"]),
+    ("🕢", "seven-thirty", &["clock730"]),
+    ("🕣", "eight-thirty", &["clock830"]),
+    ("🕤", "nine-thirty", &["clock930"]),
+    ("🕥", "ten-thirty", &["clock1030"]),
+    ("🕦", "eleven-thirty", &["clock1130"]),
+    ("🕧", "twelve-thirty", &["clock1230"]),
+    ("🕯", "candle", &["candle"]),
+    ("🕰", "mantelpiece clock", &["clock"]),
+    ("🕳", "hole", &["hole"]),
+    ("🕴🏻‍♀️", "", &[]),
+    ("🕴🏻‍♂️", "", &[]),
+    (
+        "🕴🏻",
+        "person in suit levitating: light skin tone",
+        &[
+            "levitate_tone1",
+            "levitating_tone1",
+            "person_in_suit_levitating_tone1",
+        ],
+    ),
+    ("🕴🏼‍♀️", "", &[]),
+    ("🕴🏼‍♂️", "", &[]),
+    (
+        "🕴🏼",
+        "person in suit levitating: medium-light skin tone",
+        &[
+            "levitate_tone2",
+            "levitating_tone2",
+            "person_in_suit_levitating_tone2",
+        ],
+    ),
+    ("🕴🏽‍♀️", "", &[]),
+    ("🕴🏽‍♂️", "", &[]),
+    (
+        "🕴🏽",
+        "person in suit levitating: medium skin tone",
+        &[
+            "levitate_tone3",
+            "levitating_tone3",
+            "person_in_suit_levitating_tone3",
+        ],
+    ),
+    ("🕴🏾‍♀️", "", &[]),
+    ("🕴🏾‍♂️", "", &[]),
+    (
+        "🕴🏾",
+        "person in suit levitating: medium-dark skin tone",
+        &[
+            "levitate_tone4",
+            "levitating_tone4",
+            "person_in_suit_levitating_tone4",
+        ],
+    ),
+    ("🕴🏿‍♀️", "", &[]),
+    ("🕴🏿‍♂️", "", &[]),
+    (
+        "🕴🏿",
+        "person in suit levitating: dark skin tone",
+        &[
+            "levitate_tone5",
+            "levitating_tone5",
+            "person_in_suit_levitating_tone5",
+        ],
+    ),
+    ("🕴️‍♀️", "", &[]),
+    ("🕴️‍♂️", "", &[]),
+    (
+        "🕴",
+        "person in suit levitating",
+        &["levitate", "levitating", "person_in_suit_levitating"],
+    ),
+    (
+        "🕵🏻‍♀️",
+        "woman detective: light skin tone",
+        &["woman_detective_tone1"],
+    ),
+    (
+        "🕵🏻‍♂️",
+        "man detective: light skin tone",
+        &["man_detective_tone1"],
+    ),
+    ("🕵🏻", "detective: light skin tone", &["detective_tone1"]),
+    (
+        "🕵🏼‍♀️",
+        "woman detective: medium-light skin tone",
+        &["woman_detective_tone2"],
+    ),
+    (
+        "🕵🏼‍♂️",
+        "man detective: medium-light skin tone",
+        &["man_detective_tone2"],
+    ),
+    (
+        "🕵🏼",
+        "detective: medium-light skin tone",
+        &["detective_tone2"],
+    ),
+    (
+        "🕵🏽‍♀️",
+        "woman detective: medium skin tone",
+        &["woman_detective_tone3"],
+    ),
+    (
+        "🕵🏽‍♂️",
+        "man detective: medium skin tone",
+        &["man_detective_tone3"],
+    ),
+    ("🕵🏽", "detective: medium skin tone", &["detective_tone3"]),
+    (
+        "🕵🏾‍♀️",
+        "woman detective: medium-dark skin tone",
+        &["woman_detective_tone4"],
+    ),
+    (
+        "🕵🏾‍♂️",
+        "man detective: medium-dark skin tone",
+        &["man_detective_tone4"],
+    ),
+    (
+        "🕵🏾",
+        "detective: medium-dark skin tone",
+        &["detective_tone4"],
+    ),
+    (
+        "🕵🏿‍♀️",
+        "woman detective: dark skin tone",
+        &["woman_detective_tone5"],
+    ),
+    (
+        "🕵🏿‍♂️",
+        "man detective: dark skin tone",
+        &["man_detective_tone5"],
+    ),
+    ("🕵🏿", "detective: dark skin tone", &["detective_tone5"]),
+    ("🕵️‍♀️", "woman detective", &["woman_detective"]),
+    ("🕵️‍♂️", "man detective", &["man_detective"]),
+    ("🕵", "detective", &["detective"]),
+    ("🕶", "sunglasses", &["sunglasses"]),
+    ("🕷", "spider", &["spider"]),
+    ("🕸", "spider web", &["spider_web"]),
+    ("🕹", "joystick", &["joystick"]),
+    ("🕺🏻", "man dancing: light skin tone", &["man_dancing_tone1"]),
+    (
+        "🕺🏼",
+        "man dancing: medium-light skin tone",
+        &["man_dancing_tone2"],
+    ),
+    (
+        "🕺🏽",
+        "man dancing: medium skin tone",
+        &["man_dancing_tone3"],
+    ),
+    (
+        "🕺🏾",
+        "man dancing: medium-dark skin tone",
+        &["man_dancing_tone4"],
+    ),
+    ("🕺🏿", "man dancing: dark skin tone", &["man_dancing_tone5"]),
+    ("🕺", "man dancing", &["man_dancing"]),
+    ("🖇", "linked paperclips", &["paperclips"]),
+    ("🖊", "pen", &["pen"]),
+    ("🖋", "fountain pen", &["fountain_pen"]),
+    ("🖌", "paintbrush", &["paintbrush"]),
+    ("🖍", "crayon", &["crayon"]),
+    (
+        "🖐🏻",
+        "hand with fingers splayed: light skin tone",
+        &["raised_hand_with_fingers_splayed_tone1"],
+    ),
+    (
+        "🖐🏼",
+        "hand with fingers splayed: medium-light skin tone",
+        &["raised_hand_with_fingers_splayed_tone2"],
+    ),
+    (
+        "🖐🏽",
+        "hand with fingers splayed: medium skin tone",
+        &["raised_hand_with_fingers_splayed_tone3"],
+    ),
+    (
+        "🖐🏾",
+        "hand with fingers splayed: medium-dark skin tone",
+        &["raised_hand_with_fingers_splayed_tone4"],
+    ),
+    (
+        "🖐🏿",
+        "hand with fingers splayed: dark skin tone",
+        &["raised_hand_with_fingers_splayed_tone5"],
+    ),
+    (
+        "🖐",
+        "hand with fingers splayed",
+        &["raised_hand_with_fingers_splayed"],
+    ),
+    (
+        "🖕🏻",
+        "middle finger: light skin tone",
+        &["middle_finger_tone1"],
+    ),
+    (
+        "🖕🏼",
+        "middle finger: medium-light skin tone",
+        &["middle_finger_tone2"],
+    ),
+    (
+        "🖕🏽",
+        "middle finger: medium skin tone",
+        &["middle_finger_tone3"],
+    ),
+    (
+        "🖕🏾",
+        "middle finger: medium-dark skin tone",
+        &["middle_finger_tone4"],
+    ),
+    (
+        "🖕🏿",
+        "middle finger: dark skin tone",
+        &["middle_finger_tone5"],
+    ),
+    ("🖕", "middle finger", &["middle_finger"]),
+    ("🖖🏻", "vulcan salute: light skin tone", &["vulcan_tone1"]),
+    (
+        "🖖🏼",
+        "vulcan salute: medium-light skin tone",
+        &["vulcan_tone2"],
+    ),
+    ("🖖🏽", "vulcan salute: medium skin tone", &["vulcan_tone3"]),
+    (
+        "🖖🏾",
+        "vulcan salute: medium-dark skin tone",
+        &["vulcan_tone4"],
+    ),
+    ("🖖🏿", "vulcan salute: dark skin tone", &["vulcan_tone5"]),
+    ("🖖", "vulcan salute", &["vulcan"]),
+    ("🖤", "black heart", &["black_heart"]),
+    ("🖥", "desktop computer", &["computer", "desktop_computer"]),
+    ("🖨", "printer", &["printer"]),
+    ("🖱", "computer mouse", &["computer_mouse"]),
+    ("🖲", "trackball", &["trackball"]),
+    (
+        "🖼",
+        "framed picture",
+        &["frame_with_picture", "framed_picture"],
+    ),
+    ("🗂", "card index dividers", &["card_index_dividers"]),
+    ("🗃", "card file box", &["card_file_box"]),
+    ("🗄", "file cabinet", &["file_cabinet"]),
+    ("🗑", "wastebasket", &["trashcan", "wastebasket"]),
+    ("🗒", "spiral notepad", &["notepad_spiral"]),
+    ("🗓", "spiral calendar", &["calendar_spiral"]),
+    ("🗜", "clamp", &["clamp", "compression"]),
+    ("🗝", "old key", &["old_key"]),
+    ("🗞", "rolled-up newspaper", &["rolled_up_newspaper"]),
+    ("🗡", "dagger", &["dagger"]),
+    ("🗣", "speaking head", &["speaking_head"]),
+    ("🗨", "left speech bubble", &["left_speech_bubble"]),
+    ("🗯", "right anger bubble", &["right_anger_bubble"]),
+    ("🗳", "ballot box with ballot", &["ballot_box"]),
+    ("🗺", "world map", &["world_map"]),
+    ("🗻", "mount fuji", &["mount_fuji"]),
+    ("🗼", "Tokyo tower", &["tokyo_tower"]),
+    ("🗽", "Statue of Liberty", &["statue_of_liberty"]),
+    ("🗾", "map of Japan", &["japan_map"]),
+    ("🗿", "moai", &["moai", "moyai"]),
+    ("😀", "grinning face", &["grinning", "grinning_face"]),
+    (
+        "😁",
+        "beaming face with smiling eyes",
+        &["beaming_face", "grin"],
+    ),
+    (
+        "😂",
+        "face with tears of joy",
+        &["joy", "lmao", "tears_of_joy"],
+    ),
+    (
+        "😃",
+        "grinning face with big eyes",
+        &["grinning_face_with_big_eyes", "smiley"],
+    ),
+    (
+        "😄",
+        "grinning face with smiling eyes",
+        &["grinning_face_with_closed_eyes", "smile"],
+    ),
+    (
+        "😅",
+        "grinning face with sweat",
+        &["grinning_face_with_sweat", "sweat_smile"],
+    ),
+    (
+        "😆",
+        "grinning squinting face",
+        &["laughing", "lol", "satisfied", "squinting_face"],
+    ),
+    ("😇", "smiling face with halo", &["halo", "innocent"]),
+    ("😈", "smiling face with horns", &["smiling_imp"]),
+    ("😉", "winking face", &["wink", "winking_face"]),
+    (
+        "😊",
+        "smiling face with smiling eyes",
+        &["blush", "smiling_face_with_closed_eyes"],
+    ),
+    ("😋", "face savoring food", &["savoring_food", "yum"]),
+    ("😌", "relieved face", &["relieved", "relieved_face"]),
+    (
+        "😍",
+        "smiling face with heart-eyes",
+        &["heart_eyes", "smiling_face_with_heart_eyes"],
+    ),
+    (
+        "😎",
+        "smiling face with sunglasses",
+        &[
+            "smiling_face_with_sunglasses",
+            "sunglasses_cool",
+            "too_cool",
+        ],
+    ),
+    (
+        "😏",
+        "smirking face",
+        &["smirk", "smirking", "smirking_face"],
+    ),
+    ("😐", "neutral face", &["neutral", "neutral_face"]),
+    (
+        "😑",
+        "expressionless face",
+        &["expressionless", "expressionless_face"],
+    ),
+    ("😒", "unamused face", &["unamused", "unamused_face"]),
+    (
+        "😓",
+        "downcast face with sweat",
+        &["downcast_face", "sweat"],
+    ),
+    ("😔", "pensive face", &["pensive", "pensive_face"]),
+    ("😕", "confused face", &["confused", "confused_face"]),
+    ("😖", "confounded face", &["confounded", "confounded_face"]),
+    ("😗", "kissing face", &["kissing", "kissing_face"]),
+    (
+        "😘",
+        "face blowing a kiss",
+        &["blowing_a_kiss", "kissing_heart"],
+    ),
+    (
+        "😙",
+        "kissing face with smiling eyes",
+        &["kissing_face_with_smiling_eyes", "kissing_smiling_eyes"],
+    ),
+    (
+        "😚",
+        "kissing face with closed eyes",
+        &["kissing_closed_eyes", "kissing_face_with_closed_eyes"],
+    ),
+    (
+        "😛",
+        "face with tongue",
+        &["face_with_tongue", "stuck_out_tongue"],
+    ),
+    (
+        "😜",
+        "winking face with tongue",
+        &["stuck_out_tongue_winking_eye"],
+    ),
+    (
+        "😝",
+        "squinting face with tongue",
+        &["stuck_out_tongue_closed_eyes"],
+    ),
+    (
+        "😞",
+        "disappointed face",
+        &["disappointed", "disappointed_face"],
+    ),
+    ("😟", "worried face", &["worried", "worried_face"]),
+    ("😠", "angry face", &["angry", "angry_face"]),
+    ("😡", "enraged face", &["pout", "pouting_face", "rage"]),
+    ("😢", "crying face", &["cry", "crying_face"]),
+    ("😣", "persevering face", &["persevere", "persevering_face"]),
+    (
+        "😤",
+        "face with steam from nose",
+        &["nose_steam", "triumph"],
+    ),
+    (
+        "😥",
+        "sad but relieved face",
+        &["disappointed_relieved", "sad_relieved_face"],
+    ),
+    (
+        "😦",
+        "frowning face with open mouth",
+        &["frowning", "frowning_face"],
+    ),
+    ("😧", "anguished face", &["anguished", "anguished_face"]),
+    ("😨", "fearful face", &["fearful", "fearful_face"]),
+    ("😩", "weary face", &["weary", "weary_face"]),
+    ("😪", "sleepy face", &["sleepy", "sleepy_face"]),
+    ("😫", "tired face", &["tired", "tired_face"]),
+    ("😬", "grimacing face", &["grimacing", "grimacing_face"]),
+    ("😭", "loudly crying face", &["loudly_crying_face", "sob"]),
+    ("😮‍💨", "face exhaling", &["exhale", "exhaling"]),
+    (
+        "😮",
+        "face with open mouth",
+        &["face_with_open_mouth", "open_mouth"],
+    ),
+    ("😯", "hushed face", &["hushed", "hushed_face"]),
+    (
+        "😰",
+        "anxious face with sweat",
+        &["anxious", "anxious_face", "cold_sweat"],
+    ),
+    (
+        "😱",
+        "face screaming in fear",
+        &["scream", "screaming_in_fear"],
+    ),
+    ("😲", "astonished face", &["astonished", "astonished_face"]),
+    ("😳", "flushed face", &["flushed", "flushed_face"]),
+    ("😴", "sleeping face", &["sleeping", "sleeping_face"]),
+    ("😵‍💫", "face with spiral eyes", &["dizzy_eyes"]),
+    (
+        "😵",
+        "face with crossed-out eyes",
+        &["dizzy_face", "knocked_out"],
+    ),
+    ("😶‍🌫️", "face in clouds", &["in_clouds"]),
+    ("😶", "face without mouth", &["no_mouth"]),
+    ("😷", "face with medical mask", &["mask", "medical_mask"]),
+    (
+        "😸",
+        "grinning cat with smiling eyes",
+        &["grinning_cat_with_closed_eyes", "smile_cat"],
+    ),
+    (
+        "😹",
+        "cat with tears of joy",
+        &["joy_cat", "tears_of_joy_cat"],
+    ),
+    ("😺", "grinning cat", &["grinning_cat", "smiley_cat"]),
+    (
+        "😻",
+        "smiling cat with heart-eyes",
+        &["heart_eyes_cat", "smiling_cat_with_heart_eyes"],
+    ),
+    ("😼", "cat with wry smile", &["smirk_cat", "wry_smile_cat"]),
+    ("😽", "kissing cat", &["kissing_cat"]),
+    ("😾", "pouting cat", &["pouting_cat"]),
+    ("😿", "crying cat", &["crying_cat"]),
+    ("🙀", "weary cat", &["scream_cat", "weary_cat"]),
+    ("🙁", "slightly frowning face", &["slightly_frowning_face"]),
+    (
+        "🙂‍↔️",
+        "head shaking horizontally",
+        &["head_shaking_horizontally"],
+    ),
+    (
+        "🙂‍↕️",
+        "head shaking vertically",
+        &["head_shaking_vertically"],
+    ),
+    ("🙂", "slightly smiling face", &["slightly_smiling_face"]),
+    ("🙃", "upside-down face", &["upside_down_face"]),
+    ("🙄", "face with rolling eyes", &["rolling_eyes"]),
+    (
+        "🙅🏻‍♀️",
+        "woman gesturing NO: light skin tone",
+        &["woman_gesturing_no_tone1"],
+    ),
+    (
+        "🙅🏻‍♂️",
+        "man gesturing NO: light skin tone",
+        &["man_gesturing_no_tone1"],
+    ),
+    (
+        "🙅🏻",
+        "person gesturing NO: light skin tone",
+        &["no_good_tone1", "person_gesturing_no_tone1"],
+    ),
+    (
+        "🙅🏼‍♀️",
+        "woman gesturing NO: medium-light skin tone",
+        &["woman_gesturing_no_tone2"],
+    ),
+    (
+        "🙅🏼‍♂️",
+        "man gesturing NO: medium-light skin tone",
+        &["man_gesturing_no_tone2"],
+    ),
+    (
+        "🙅🏼",
+        "person gesturing NO: medium-light skin tone",
+        &["no_good_tone2", "person_gesturing_no_tone2"],
+    ),
+    (
+        "🙅🏽‍♀️",
+        "woman gesturing NO: medium skin tone",
+        &["woman_gesturing_no_tone3"],
+    ),
+    (
+        "🙅🏽‍♂️",
+        "man gesturing NO: medium skin tone",
+        &["man_gesturing_no_tone3"],
+    ),
+    (
+        "🙅🏽",
+        "person gesturing NO: medium skin tone",
+        &["no_good_tone3", "person_gesturing_no_tone3"],
+    ),
+    (
+        "🙅🏾‍♀️",
+        "woman gesturing NO: medium-dark skin tone",
+        &["woman_gesturing_no_tone4"],
+    ),
+    (
+        "🙅🏾‍♂️",
+        "man gesturing NO: medium-dark skin tone",
+        &["man_gesturing_no_tone4"],
+    ),
+    (
+        "🙅🏾",
+        "person gesturing NO: medium-dark skin tone",
+        &["no_good_tone4", "person_gesturing_no_tone4"],
+    ),
+    (
+        "🙅🏿‍♀️",
+        "woman gesturing NO: dark skin tone",
+        &["woman_gesturing_no_tone5"],
+    ),
+    (
+        "🙅🏿‍♂️",
+        "man gesturing NO: dark skin tone",
+        &["man_gesturing_no_tone5"],
+    ),
+    (
+        "🙅🏿",
+        "person gesturing NO: dark skin tone",
+        &["no_good_tone5", "person_gesturing_no_tone5"],
+    ),
+    ("🙅‍♀️", "woman gesturing NO", &["woman_gesturing_no"]),
+    ("🙅‍♂️", "man gesturing NO", &["man_gesturing_no"]),
+    (
+        "🙅",
+        "person gesturing NO",
+        &["no_good", "person_gesturing_no"],
+    ),
+    (
+        "🙆🏻‍♀️",
+        "woman gesturing OK: light skin tone",
+        &["woman_gesturing_ok_tone1"],
+    ),
+    (
+        "🙆🏻‍♂️",
+        "man gesturing OK: light skin tone",
+        &["man_gesturing_ok_tone1"],
+    ),
+    (
+        "🙆🏻",
+        "person gesturing OK: light skin tone",
+        &["all_good_tone1", "person_gesturing_ok_tone1"],
+    ),
+    (
+        "🙆🏼‍♀️",
+        "woman gesturing OK: medium-light skin tone",
+        &["woman_gesturing_ok_tone2"],
+    ),
+    (
+        "🙆🏼‍♂️",
+        "man gesturing OK: medium-light skin tone",
+        &["man_gesturing_ok_tone2"],
+    ),
+    (
+        "🙆🏼",
+        "person gesturing OK: medium-light skin tone",
+        &["all_good_tone2", "person_gesturing_ok_tone2"],
+    ),
+    (
+        "🙆🏽‍♀️",
+        "woman gesturing OK: medium skin tone",
+        &["woman_gesturing_ok_tone3"],
+    ),
+    (
+        "🙆🏽‍♂️",
+        "man gesturing OK: medium skin tone",
+        &["man_gesturing_ok_tone3"],
+    ),
+    (
+        "🙆🏽",
+        "person gesturing OK: medium skin tone",
+        &["all_good_tone3", "person_gesturing_ok_tone3"],
+    ),
+    (
+        "🙆🏾‍♀️",
+        "woman gesturing OK: medium-dark skin tone",
+        &["woman_gesturing_ok_tone4"],
+    ),
+    (
+        "🙆🏾‍♂️",
+        "man gesturing OK: medium-dark skin tone",
+        &["man_gesturing_ok_tone4"],
+    ),
+    (
+        "🙆🏾",
+        "person gesturing OK: medium-dark skin tone",
+        &["all_good_tone4", "person_gesturing_ok_tone4"],
+    ),
+    (
+        "🙆🏿‍♀️",
+        "woman gesturing OK: dark skin tone",
+        &["woman_gesturing_ok_tone5"],
+    ),
+    (
+        "🙆🏿‍♂️",
+        "man gesturing OK: dark skin tone",
+        &["man_gesturing_ok_tone5"],
+    ),
+    (
+        "🙆🏿",
+        "person gesturing OK: dark skin tone",
+        &["all_good_tone5", "person_gesturing_ok_tone5"],
+    ),
+    ("🙆‍♀️", "woman gesturing OK", &["woman_gesturing_ok"]),
+    ("🙆‍♂️", "man gesturing OK", &["man_gesturing_ok"]),
+    (
+        "🙆",
+        "person gesturing OK",
+        &["all_good", "person_gesturing_ok"],
+    ),
+    (
+        "🙇🏻‍♀️",
+        "woman bowing: light skin tone",
+        &["woman_bowing_tone1"],
+    ),
+    ("🙇🏻‍♂️", "man bowing: light skin tone", &["man_bowing_tone1"]),
+    (
+        "🙇🏻",
+        "person bowing: light skin tone",
+        &["bow_tone1", "person_bowing_tone1"],
+    ),
+    (
+        "🙇🏼‍♀️",
+        "woman bowing: medium-light skin tone",
+        &["woman_bowing_tone2"],
+    ),
+    (
+        "🙇🏼‍♂️",
+        "man bowing: medium-light skin tone",
+        &["man_bowing_tone2"],
+    ),
+    (
+        "🙇🏼",
+        "person bowing: medium-light skin tone",
+        &["bow_tone2", "person_bowing_tone2"],
+    ),
+    (
+        "🙇🏽‍♀️",
+        "woman bowing: medium skin tone",
+        &["woman_bowing_tone3"],
+    ),
+    ("🙇🏽‍♂️", "man bowing: medium skin tone", &["man_bowing_tone3"]),
+    (
+        "🙇🏽",
+        "person bowing: medium skin tone",
+        &["bow_tone3", "person_bowing_tone3"],
+    ),
+    (
+        "🙇🏾‍♀️",
+        "woman bowing: medium-dark skin tone",
+        &["woman_bowing_tone4"],
+    ),
+    (
+        "🙇🏾‍♂️",
+        "man bowing: medium-dark skin tone",
+        &["man_bowing_tone4"],
+    ),
+    (
+        "🙇🏾",
+        "person bowing: medium-dark skin tone",
+        &["bow_tone4", "person_bowing_tone4"],
+    ),
+    (
+        "🙇🏿‍♀️",
+        "woman bowing: dark skin tone",
+        &["woman_bowing_tone5"],
+    ),
+    ("🙇🏿‍♂️", "man bowing: dark skin tone", &["man_bowing_tone5"]),
+    (
+        "🙇🏿",
+        "person bowing: dark skin tone",
+        &["bow_tone5", "person_bowing_tone5"],
+    ),
+    ("🙇‍♀️", "woman bowing", &["woman_bowing"]),
+    ("🙇‍♂️", "man bowing", &["man_bowing"]),
+    ("🙇", "person bowing", &["bow", "person_bowing"]),
+    ("🙈", "see-no-evil monkey", &["see_no_evil"]),
+    ("🙉", "hear-no-evil monkey", &["hear_no_evil"]),
+    ("🙊", "speak-no-evil monkey", &["speak_no_evil"]),
+    (
+        "🙋🏻‍♀️",
+        "woman raising hand: light skin tone",
+        &["woman_raising_hand_tone1"],
+    ),
+    (
+        "🙋🏻‍♂️",
+        "man raising hand: light skin tone",
+        &["man_raising_hand_tone1"],
+    ),
+    (
+        "🙋🏻",
+        "person raising hand: light skin tone",
+        &["person_raising_hand_tone1"],
+    ),
+    (
+        "🙋🏼‍♀️",
+        "woman raising hand: medium-light skin tone",
+        &["woman_raising_hand_tone2"],
+    ),
+    (
+        "🙋🏼‍♂️",
+        "man raising hand: medium-light skin tone",
+        &["man_raising_hand_tone2"],
+    ),
+    (
+        "🙋🏼",
+        "person raising hand: medium-light skin tone",
+        &["person_raising_hand_tone2"],
+    ),
+    (
+        "🙋🏽‍♀️",
+        "woman raising hand: medium skin tone",
+        &["woman_raising_hand_tone3"],
+    ),
+    (
+        "🙋🏽‍♂️",
+        "man raising hand: medium skin tone",
+        &["man_raising_hand_tone3"],
+    ),
+    (
+        "🙋🏽",
+        "person raising hand: medium skin tone",
+        &["person_raising_hand_tone3"],
+    ),
+    (
+        "🙋🏾‍♀️",
+        "woman raising hand: medium-dark skin tone",
+        &["woman_raising_hand_tone4"],
+    ),
+    (
+        "🙋🏾‍♂️",
+        "man raising hand: medium-dark skin tone",
+        &["man_raising_hand_tone4"],
+    ),
+    (
+        "🙋🏾",
+        "person raising hand: medium-dark skin tone",
+        &["person_raising_hand_tone4"],
+    ),
+    (
+        "🙋🏿‍♀️",
+        "woman raising hand: dark skin tone",
+        &["woman_raising_hand_tone5"],
+    ),
+    (
+        "🙋🏿‍♂️",
+        "man raising hand: dark skin tone",
+        &["man_raising_hand_tone5"],
+    ),
+    (
+        "🙋🏿",
+        "person raising hand: dark skin tone",
+        &["person_raising_hand_tone5"],
+    ),
+    ("🙋‍♀️", "woman raising hand", &["woman_raising_hand"]),
+    ("🙋‍♂️", "man raising hand", &["man_raising_hand"]),
+    ("🙋", "person raising hand", &["person_raising_hand"]),
+    (
+        "🙌🏻",
+        "raising hands: light skin tone",
+        &["raised_hands_tone1"],
+    ),
+    (
+        "🙌🏼",
+        "raising hands: medium-light skin tone",
+        &["raised_hands_tone2"],
+    ),
+    (
+        "🙌🏽",
+        "raising hands: medium skin tone",
+        &["raised_hands_tone3"],
+    ),
+    (
+        "🙌🏾",
+        "raising hands: medium-dark skin tone",
+        &["raised_hands_tone4"],
+    ),
+    (
+        "🙌🏿",
+        "raising hands: dark skin tone",
+        &["raised_hands_tone5"],
+    ),
+    ("🙌", "raising hands", &["raised_hands"]),
+    (
+        "🙍🏻‍♀️",
+        "woman frowning: light skin tone",
+        &["woman_frowning_tone1"],
+    ),
+    (
+        "🙍🏻‍♂️",
+        "man frowning: light skin tone",
+        &["man_frowning_tone1"],
+    ),
+    (
+        "🙍🏻",
+        "person frowning: light skin tone",
+        &["person_frowning_tone1"],
+    ),
+    (
+        "🙍🏼‍♀️",
+        "woman frowning: medium-light skin tone",
+        &["woman_frowning_tone2"],
+    ),
+    (
+        "🙍🏼‍♂️",
+        "man frowning: medium-light skin tone",
+        &["man_frowning_tone2"],
+    ),
+    (
+        "🙍🏼",
+        "person frowning: medium-light skin tone",
+        &["person_frowning_tone2"],
+    ),
+    (
+        "🙍🏽‍♀️",
+        "woman frowning: medium skin tone",
+        &["woman_frowning_tone3"],
+    ),
+    (
+        "🙍🏽‍♂️",
+        "man frowning: medium skin tone",
+        &["man_frowning_tone3"],
+    ),
+    (
+        "🙍🏽",
+        "person frowning: medium skin tone",
+        &["person_frowning_tone3"],
+    ),
+    (
+        "🙍🏾‍♀️",
+        "woman frowning: medium-dark skin tone",
+        &["woman_frowning_tone4"],
+    ),
+    (
+        "🙍🏾‍♂️",
+        "man frowning: medium-dark skin tone",
+        &["man_frowning_tone4"],
+    ),
+    (
+        "🙍🏾",
+        "person frowning: medium-dark skin tone",
+        &["person_frowning_tone4"],
+    ),
+    (
+        "🙍🏿‍♀️",
+        "woman frowning: dark skin tone",
+        &["woman_frowning_tone5"],
+    ),
+    (
+        "🙍🏿‍♂️",
+        "man frowning: dark skin tone",
+        &["man_frowning_tone5"],
+    ),
+    (
+        "🙍🏿",
+        "person frowning: dark skin tone",
+        &["person_frowning_tone5"],
+    ),
+    ("🙍‍♀️", "woman frowning", &["woman_frowning"]),
+    ("🙍‍♂️", "man frowning", &["man_frowning"]),
+    ("🙍", "person frowning", &["person_frowning"]),
+    (
+        "🙎🏻‍♀️",
+        "woman pouting: light skin tone",
+        &["woman_pouting_tone1"],
+    ),
+    ("🙎🏻‍♂️", "man pouting: light skin tone", &["man_pouting_tone1"]),
+    (
+        "🙎🏻",
+        "person pouting: light skin tone",
+        &["person_pouting_tone1", "pouting_tone1"],
+    ),
+    (
+        "🙎🏼‍♀️",
+        "woman pouting: medium-light skin tone",
+        &["woman_pouting_tone2"],
+    ),
+    (
+        "🙎🏼‍♂️",
+        "man pouting: medium-light skin tone",
+        &["man_pouting_tone2"],
+    ),
+    (
+        "🙎🏼",
+        "person pouting: medium-light skin tone",
+        &["person_pouting_tone2", "pouting_tone2"],
+    ),
+    (
+        "🙎🏽‍♀️",
+        "woman pouting: medium skin tone",
+        &["woman_pouting_tone3"],
+    ),
+    (
+        "🙎🏽‍♂️",
+        "man pouting: medium skin tone",
+        &["man_pouting_tone3"],
+    ),
+    (
+        "🙎🏽",
+        "person pouting: medium skin tone",
+        &["person_pouting_tone3", "pouting_tone3"],
+    ),
+    (
+        "🙎🏾‍♀️",
+        "woman pouting: medium-dark skin tone",
+        &["woman_pouting_tone4"],
+    ),
+    (
+        "🙎🏾‍♂️",
+        "man pouting: medium-dark skin tone",
+        &["man_pouting_tone4"],
+    ),
+    (
+        "🙎🏾",
+        "person pouting: medium-dark skin tone",
+        &["person_pouting_tone4", "pouting_tone4"],
+    ),
+    (
+        "🙎🏿‍♀️",
+        "woman pouting: dark skin tone",
+        &["woman_pouting_tone5"],
+    ),
+    ("🙎🏿‍♂️", "man pouting: dark skin tone", &["man_pouting_tone5"]),
+    (
+        "🙎🏿",
+        "person pouting: dark skin tone",
+        &["person_pouting_tone5", "pouting_tone5"],
+    ),
+    ("🙎‍♀️", "woman pouting", &["woman_pouting"]),
+    ("🙎‍♂️", "man pouting", &["man_pouting"]),
+    ("🙎", "person pouting", &["person_pouting", "pouting"]),
+    (
+        "🙏🏻",
+        "folded hands: light skin tone",
+        &["folded_hands_tone1", "pray_tone1"],
+    ),
+    (
+        "🙏🏼",
+        "folded hands: medium-light skin tone",
+        &["folded_hands_tone2", "pray_tone2"],
+    ),
+    (
+        "🙏🏽",
+        "folded hands: medium skin tone",
+        &["folded_hands_tone3", "pray_tone3"],
+    ),
+    (
+        "🙏🏾",
+        "folded hands: medium-dark skin tone",
+        &["folded_hands_tone4", "pray_tone4"],
+    ),
+    (
+        "🙏🏿",
+        "folded hands: dark skin tone",
+        &["folded_hands_tone5", "pray_tone5"],
+    ),
+    ("🙏", "folded hands", &["folded_hands", "pray"]),
+    ("🚀", "rocket", &["rocket"]),
+    ("🚁", "helicopter", &["helicopter"]),
+    ("🚂", "locomotive", &["steam_locomotive"]),
+    ("🚃", "railway car", &["railway_car"]),
+    ("🚄", "high-speed train", &["bullettrain_side"]),
+    ("🚅", "bullet train", &["bullettrain_front"]),
+    ("🚆", "train", &["train"]),
+    ("🚇", "metro", &["metro"]),
+    ("🚈", "light rail", &["light_rail"]),
+    ("🚉", "station", &["station"]),
+    ("🚊", "tram", &["tram"]),
+    ("🚋", "tram car", &["tram_car"]),
+    ("🚌", "bus", &["bus"]),
+    ("🚍", "oncoming bus", &["oncoming_bus"]),
+    ("🚎", "trolleybus", &["trolleybus"]),
+    ("🚏", "bus stop", &["busstop"]),
+    ("🚐", "minibus", &["minibus"]),
+    ("🚑", "ambulance", &["ambulance"]),
+    ("🚒", "fire engine", &["fire_engine"]),
+    ("🚓", "police car", &["police_car"]),
+    ("🚔", "oncoming police car", &["oncoming_police_car"]),
+    ("🚕", "taxi", &["taxi"]),
+    ("🚖", "oncoming taxi", &["oncoming_taxi"]),
+    ("🚗", "automobile", &["car", "red_car"]),
+    ("🚘", "oncoming automobile", &["oncoming_automobile"]),
+    ("🚙", "sport utility vehicle", &["blue_car", "suv"]),
+    ("🚚", "delivery truck", &["delivery_truck", "truck"]),
+    ("🚛", "articulated lorry", &["articulated_lorry"]),
+    ("🚜", "tractor", &["tractor"]),
+    ("🚝", "monorail", &["monorail"]),
+    ("🚞", "mountain railway", &["mountain_railway"]),
+    ("🚟", "suspension railway", &["suspension_railway"]),
+    ("🚠", "mountain cableway", &["mountain_cableway"]),
+    ("🚡", "aerial tramway", &["aerial_tramway"]),
+    ("🚢", "ship", &["ship"]),
+    (
+        "🚣🏻‍♀️",
+        "woman rowing boat: light skin tone",
+        &["woman_rowing_boat_tone1"],
+    ),
+    (
+        "🚣🏻‍♂️",
+        "man rowing boat: light skin tone",
+        &["man_rowing_boat_tone1"],
+    ),
+    (
+        "🚣🏻",
+        "person rowing boat: light skin tone",
+        &["person_rowing_boat_tone1", "rowboat_tone1"],
+    ),
+    (
+        "🚣🏼‍♀️",
+        "woman rowing boat: medium-light skin tone",
+        &["woman_rowing_boat_tone2"],
+    ),
+    (
+        "🚣🏼‍♂️",
+        "man rowing boat: medium-light skin tone",
+        &["man_rowing_boat_tone2"],
+    ),
+    (
+        "🚣🏼",
+        "person rowing boat: medium-light skin tone",
+        &["person_rowing_boat_tone2", "rowboat_tone2"],
+    ),
+    (
+        "🚣🏽‍♀️",
+        "woman rowing boat: medium skin tone",
+        &["woman_rowing_boat_tone3"],
+    ),
+    (
+        "🚣🏽‍♂️",
+        "man rowing boat: medium skin tone",
+        &["man_rowing_boat_tone3"],
+    ),
+    (
+        "🚣🏽",
+        "person rowing boat: medium skin tone",
+        &["person_rowing_boat_tone3", "rowboat_tone3"],
+    ),
+    (
+        "🚣🏾‍♀️",
+        "woman rowing boat: medium-dark skin tone",
+        &["woman_rowing_boat_tone4"],
+    ),
+    (
+        "🚣🏾‍♂️",
+        "man rowing boat: medium-dark skin tone",
+        &["man_rowing_boat_tone4"],
+    ),
+    (
+        "🚣🏾",
+        "person rowing boat: medium-dark skin tone",
+        &["person_rowing_boat_tone4", "rowboat_tone4"],
+    ),
+    (
+        "🚣🏿‍♀️",
+        "woman rowing boat: dark skin tone",
+        &["woman_rowing_boat_tone5"],
+    ),
+    (
+        "🚣🏿‍♂️",
+        "man rowing boat: dark skin tone",
+        &["man_rowing_boat_tone5"],
+    ),
+    (
+        "🚣🏿",
+        "person rowing boat: dark skin tone",
+        &["person_rowing_boat_tone5", "rowboat_tone5"],
+    ),
+    ("🚣‍♀️", "woman rowing boat", &["woman_rowing_boat"]),
+    ("🚣‍♂️", "man rowing boat", &["man_rowing_boat"]),
+    (
+        "🚣",
+        "person rowing boat",
+        &["person_rowing_boat", "rowboat"],
+    ),
+    ("🚤", "speedboat", &["speedboat"]),
+    ("🚥", "horizontal traffic light", &["traffic_light"]),
+    ("🚦", "vertical traffic light", &["vertical_traffic_light"]),
+    ("🚧", "construction", &["construction"]),
+    ("🚨", "police car light", &["rotating_light"]),
+    (
+        "🚩",
+        "triangular flag",
+        &["triangular_flag", "triangular_flag_on_post"],
+    ),
+    ("🚪", "door", &["door"]),
+    ("🚫", "prohibited", &["no_entry_sign"]),
+    ("🚬", "cigarette", &["cigarette", "smoking"]),
+    ("🚭", "no smoking", &["no_smoking"]),
+    (
+        "🚮",
+        "litter in bin sign",
+        &["litter_bin", "put_litter_in_its_place"],
+    ),
+    ("🚯", "no littering", &["do_not_litter", "no_littering"]),
+    ("🚰", "potable water", &["potable_water"]),
+    ("🚱", "non-potable water", &["non-potable_water"]),
+    ("🚲", "bicycle", &["bicycle", "bike"]),
+    ("🚳", "no bicycles", &["no_bicycles"]),
+    (
+        "🚴🏻‍♀️",
+        "woman biking: light skin tone",
+        &["woman_biking_tone1"],
+    ),
+    ("🚴🏻‍♂️", "man biking: light skin tone", &["man_biking_tone1"]),
+    (
+        "🚴🏻",
+        "person biking: light skin tone",
+        &["bicyclist_tone1", "biking_tone1", "person_biking_tone1"],
+    ),
+    (
+        "🚴🏼‍♀️",
+        "woman biking: medium-light skin tone",
+        &["woman_biking_tone2"],
+    ),
+    (
+        "🚴🏼‍♂️",
+        "man biking: medium-light skin tone",
+        &["man_biking_tone2"],
+    ),
+    (
+        "🚴🏼",
+        "person biking: medium-light skin tone",
+        &["bicyclist_tone2", "biking_tone2", "person_biking_tone2"],
+    ),
+    (
+        "🚴🏽‍♀️",
+        "woman biking: medium skin tone",
+        &["woman_biking_tone3"],
+    ),
+    ("🚴🏽‍♂️", "man biking: medium skin tone", &["man_biking_tone3"]),
+    (
+        "🚴🏽",
+        "person biking: medium skin tone",
+        &["bicyclist_tone3", "biking_tone3", "person_biking_tone3"],
+    ),
+    (
+        "🚴🏾‍♀️",
+        "woman biking: medium-dark skin tone",
+        &["woman_biking_tone4"],
+    ),
+    (
+        "🚴🏾‍♂️",
+        "man biking: medium-dark skin tone",
+        &["man_biking_tone4"],
+    ),
+    (
+        "🚴🏾",
+        "person biking: medium-dark skin tone",
+        &["bicyclist_tone4", "biking_tone4", "person_biking_tone4"],
+    ),
+    (
+        "🚴🏿‍♀️",
+        "woman biking: dark skin tone",
+        &["woman_biking_tone5"],
+    ),
+    ("🚴🏿‍♂️", "man biking: dark skin tone", &["man_biking_tone5"]),
+    (
+        "🚴🏿",
+        "person biking: dark skin tone",
+        &["bicyclist_tone5", "biking_tone5", "person_biking_tone5"],
+    ),
+    ("🚴‍♀️", "woman biking", &["woman_biking"]),
+    ("🚴‍♂️", "man biking", &["man_biking"]),
+    (
+        "🚴",
+        "person biking",
+        &["bicyclist", "biking", "person_biking"],
+    ),
+    (
+        "🚵🏻‍♀️",
+        "woman mountain biking: light skin tone",
+        &["woman_mountain_biking_tone1"],
+    ),
+    (
+        "🚵🏻‍♂️",
+        "man mountain biking: light skin tone",
+        &["man_mountain_biking_tone1"],
+    ),
+    (
+        "🚵🏻",
+        "person mountain biking: light skin tone",
+        &[
+            "mountain_bicyclist_tone1",
+            "mountain_biking_tone1",
+            "person_mountain_biking_tone1",
+        ],
+    ),
+    (
+        "🚵🏼‍♀️",
+        "woman mountain biking: medium-light skin tone",
+        &["woman_mountain_biking_tone2"],
+    ),
+    (
+        "🚵🏼‍♂️",
+        "man mountain biking: medium-light skin tone",
+        &["man_mountain_biking_tone2"],
+    ),
+    (
+        "🚵🏼",
+        "person mountain biking: medium-light skin tone",
+        &[
+            "mountain_bicyclist_tone2",
+            "mountain_biking_tone2",
+            "person_mountain_biking_tone2",
+        ],
+    ),
+    (
+        "🚵🏽‍♀️",
+        "woman mountain biking: medium skin tone",
+        &["woman_mountain_biking_tone3"],
+    ),
+    (
+        "🚵🏽‍♂️",
+        "man mountain biking: medium skin tone",
+        &["man_mountain_biking_tone3"],
+    ),
+    (
+        "🚵🏽",
+        "person mountain biking: medium skin tone",
+        &[
+            "mountain_bicyclist_tone3",
+            "mountain_biking_tone3",
+            "person_mountain_biking_tone3",
+        ],
+    ),
+    (
+        "🚵🏾‍♀️",
+        "woman mountain biking: medium-dark skin tone",
+        &["woman_mountain_biking_tone4"],
+    ),
+    (
+        "🚵🏾‍♂️",
+        "man mountain biking: medium-dark skin tone",
+        &["man_mountain_biking_tone4"],
+    ),
+    (
+        "🚵🏾",
+        "person mountain biking: medium-dark skin tone",
+        &[
+            "mountain_bicyclist_tone4",
+            "mountain_biking_tone4",
+            "person_mountain_biking_tone4",
+        ],
+    ),
+    (
+        "🚵🏿‍♀️",
+        "woman mountain biking: dark skin tone",
+        &["woman_mountain_biking_tone5"],
+    ),
+    (
+        "🚵🏿‍♂️",
+        "man mountain biking: dark skin tone",
+        &["man_mountain_biking_tone5"],
+    ),
+    (
+        "🚵🏿",
+        "person mountain biking: dark skin tone",
+        &[
+            "mountain_bicyclist_tone5",
+            "mountain_biking_tone5",
+            "person_mountain_biking_tone5",
+        ],
+    ),
+    ("🚵‍♀️", "woman mountain biking", &["woman_mountain_biking"]),
+    ("🚵‍♂️", "man mountain biking", &["man_mountain_biking"]),
+    (
+        "🚵",
+        "person mountain biking",
+        &[
+            "mountain_bicyclist",
+            "mountain_biking",
+            "person_mountain_biking",
+        ],
+    ),
+    (
+        "🚶🏻‍♀️‍➡️",
+        "woman walking facing right: light skin tone",
+        &["woman_walking_right_tone1"],
+    ),
+    (
+        "🚶🏻‍♀️",
+        "woman walking: light skin tone",
+        &["woman_walking_tone1"],
+    ),
+    (
+        "🚶🏻‍♂️‍➡️",
+        "man walking facing right: light skin tone",
+        &["man_walking_right_tone1"],
+    ),
+    ("🚶🏻‍♂️", "man walking: light skin tone", &["man_walking_tone1"]),
+    (
+        "🚶🏻‍➡️",
+        "person walking facing right: light skin tone",
+        &["person_walking_right_tone1"],
+    ),
+    (
+        "🚶🏻",
+        "person walking: light skin tone",
+        &["person_walking_tone1", "walking_tone1"],
+    ),
+    (
+        "🚶🏼‍♀️‍➡️",
+        "woman walking facing right: medium-light skin tone",
+        &["woman_walking_right_tone2"],
+    ),
+    (
+        "🚶🏼‍♀️",
+        "woman walking: medium-light skin tone",
+        &["woman_walking_tone2"],
+    ),
+    (
+        "🚶🏼‍♂️‍➡️",
+        "man walking facing right: medium-light skin tone",
+        &["man_walking_right_tone2"],
+    ),
+    (
+        "🚶🏼‍♂️",
+        "man walking: medium-light skin tone",
+        &["man_walking_tone2"],
+    ),
+    (
+        "🚶🏼‍➡️",
+        "person walking facing right: medium-light skin tone",
+        &["person_walking_right_tone2"],
+    ),
+    (
+        "🚶🏼",
+        "person walking: medium-light skin tone",
+        &["person_walking_tone2", "walking_tone2"],
+    ),
+    (
+        "🚶🏽‍♀️‍➡️",
+        "woman walking facing right: medium skin tone",
+        &["woman_walking_right_tone3"],
+    ),
+    (
+        "🚶🏽‍♀️",
+        "woman walking: medium skin tone",
+        &["woman_walking_tone3"],
+    ),
+    (
+        "🚶🏽‍♂️‍➡️",
+        "man walking facing right: medium skin tone",
+        &["man_walking_right_tone3"],
+    ),
+    (
+        "🚶🏽‍♂️",
+        "man walking: medium skin tone",
+        &["man_walking_tone3"],
+    ),
+    (
+        "🚶🏽‍➡️",
+        "person walking facing right: medium skin tone",
+        &["person_walking_right_tone3"],
+    ),
+    (
+        "🚶🏽",
+        "person walking: medium skin tone",
+        &["person_walking_tone3", "walking_tone3"],
+    ),
+    (
+        "🚶🏾‍♀️‍➡️",
+        "woman walking facing right: medium-dark skin tone",
+        &["woman_walking_right_tone4"],
+    ),
+    (
+        "🚶🏾‍♀️",
+        "woman walking: medium-dark skin tone",
+        &["woman_walking_tone4"],
+    ),
+    (
+        "🚶🏾‍♂️‍➡️",
+        "man walking facing right: medium-dark skin tone",
+        &["man_walking_right_tone4"],
+    ),
+    (
+        "🚶🏾‍♂️",
+        "man walking: medium-dark skin tone",
+        &["man_walking_tone4"],
+    ),
+    (
+        "🚶🏾‍➡️",
+        "person walking facing right: medium-dark skin tone",
+        &["person_walking_right_tone4"],
+    ),
+    (
+        "🚶🏾",
+        "person walking: medium-dark skin tone",
+        &["person_walking_tone4", "walking_tone4"],
+    ),
+    (
+        "🚶🏿‍♀️‍➡️",
+        "woman walking facing right: dark skin tone",
+        &["woman_walking_right_tone5"],
+    ),
+    (
+        "🚶🏿‍♀️",
+        "woman walking: dark skin tone",
+        &["woman_walking_tone5"],
+    ),
+    (
+        "🚶🏿‍♂️‍➡️",
+        "man walking facing right: dark skin tone",
+        &["man_walking_right_tone5"],
+    ),
+    ("🚶🏿‍♂️", "man walking: dark skin tone", &["man_walking_tone5"]),
+    (
+        "🚶🏿‍➡️",
+        "person walking facing right: dark skin tone",
+        &["person_walking_right_tone5"],
+    ),
+    (
+        "🚶🏿",
+        "person walking: dark skin tone",
+        &["person_walking_tone5", "walking_tone5"],
+    ),
+    ("🚶‍♀️‍➡️", "woman walking facing right", &["woman_walking_right"]),
+    ("🚶‍♀️", "woman walking", &["woman_walking"]),
+    ("🚶‍♂️‍➡️", "man walking facing right", &["man_walking_right"]),
+    ("🚶‍♂️", "man walking", &["man_walking"]),
+    (
+        "🚶‍➡️",
+        "person walking facing right",
+        &["person_walking_right"],
+    ),
+    ("🚶", "person walking", &["person_walking", "walking"]),
+    ("🚷", "no pedestrians", &["no_pedestrians"]),
+    ("🚸", "children crossing", &["children_crossing"]),
+    ("🚹", "men’s room", &["mens"]),
+    ("🚺", "women’s room", &["womens"]),
+    ("🚻", "restroom", &["bathroom", "restroom"]),
+    ("🚼", "baby symbol", &["baby_symbol"]),
+    ("🚽", "toilet", &["toilet"]),
+    ("🚾", "water closet", &["water_closet", "wc"]),
+    ("🚿", "shower", &["shower"]),
+    (
+        "🛀🏻",
+        "person taking bath: light skin tone",
+        &["bath_tone1", "person_taking_bath_tone1"],
+    ),
+    (
+        "🛀🏼",
+        "person taking bath: medium-light skin tone",
+        &["bath_tone2", "person_taking_bath_tone2"],
+    ),
+    (
+        "🛀🏽",
+        "person taking bath: medium skin tone",
+        &["bath_tone3", "person_taking_bath_tone3"],
+    ),
+    (
+        "🛀🏾",
+        "person taking bath: medium-dark skin tone",
+        &["bath_tone4", "person_taking_bath_tone4"],
+    ),
+    (
+        "🛀🏿",
+        "person taking bath: dark skin tone",
+        &["bath_tone5", "person_taking_bath_tone5"],
+    ),
+    ("🛀", "person taking bath", &["bath", "person_taking_bath"]),
+    ("🛁", "bathtub", &["bathtub"]),
+    ("🛂", "passport control", &["passport_control"]),
+    ("🛃", "customs", &["customs"]),
+    ("🛄", "baggage claim", &["baggage_claim"]),
+    ("🛅", "left luggage", &["left_luggage"]),
+    ("🛋", "couch and lamp", &["couch_and_lamp"]),
+    (
+        "🛌🏻",
+        "person in bed: light skin tone",
+        &["person_in_bed_tone1", "sleeping_accommodation_tone1"],
+    ),
+    (
+        "🛌🏼",
+        "person in bed: medium-light skin tone",
+        &["person_in_bed_tone2", "sleeping_accommodation_tone2"],
+    ),
+    (
+        "🛌🏽",
+        "person in bed: medium skin tone",
+        &["person_in_bed_tone3", "sleeping_accommodation_tone3"],
+    ),
+    (
+        "🛌🏾",
+        "person in bed: medium-dark skin tone",
+        &["person_in_bed_tone4", "sleeping_accommodation_tone4"],
+    ),
+    (
+        "🛌🏿",
+        "person in bed: dark skin tone",
+        &["person_in_bed_tone5", "sleeping_accommodation_tone5"],
+    ),
+    (
+        "🛌",
+        "person in bed",
+        &["person_in_bed", "sleeping_accommodation"],
+    ),
+    ("🛍", "shopping bags", &["shopping_bags"]),
+    ("🛎", "bellhop bell", &["bellhop"]),
+    ("🛏", "bed", &["bed"]),
+    ("🛐", "place of worship", &["place_of_worship"]),
+    ("🛑", "stop sign", &["octagonal_sign", "stop_sign"]),
+    ("🛒", "shopping cart", &["shopping_cart"]),
+    ("🛕", "hindu temple", &["hindu_temple"]),
+    ("🛖", "hut", &["hut"]),
+    ("🛗", "elevator", &["elevator"]),
+    ("🛘", "landslide", &[]),
+    ("🛜", "wireless", &["wireless"]),
+    ("🛝", "playground slide", &["playground_slide", "slide"]),
+    ("🛞", "wheel", &["wheel"]),
+    ("🛟", "ring buoy", &["lifebuoy", "ring_buoy"]),
+    ("🛠", "hammer and wrench", &["hammer_and_wrench"]),
+    ("🛡", "shield", &["shield"]),
+    ("🛢", "oil drum", &["oil_drum"]),
+    ("🛣", "motorway", &["motorway"]),
+    ("🛤", "railway track", &["railway_track"]),
+    ("🛥", "motor boat", &["motorboat"]),
+    ("🛩", "small airplane", &["small_airplane"]),
+    ("🛫", "airplane departure", &["airplane_departure"]),
+    ("🛬", "airplane arrival", &["airplane_arriving"]),
+    ("🛰", "satellite", &["satellite"]),
+    ("🛳", "passenger ship", &["cruise_ship", "passenger_ship"]),
+    ("🛴", "kick scooter", &["scooter"]),
+    ("🛵", "motor scooter", &["motor_scooter"]),
+    ("🛶", "canoe", &["canoe"]),
+    ("🛷", "sled", &["sled"]),
+    ("🛸", "flying saucer", &["flying_saucer"]),
+    ("🛹", "skateboard", &["skateboard"]),
+    ("🛺", "auto rickshaw", &["auto_rickshaw"]),
+    ("🛻", "pickup truck", &["pickup_truck"]),
+    ("🛼", "roller skate", &["roller_skate"]),
+    ("🟠", "orange circle", &["orange_circle"]),
+    ("🟡", "yellow circle", &["yellow_circle"]),
+    ("🟢", "green circle", &["green_circle"]),
+    ("🟣", "purple circle", &["purple_circle"]),
+    ("🟤", "brown circle", &["brown_circle"]),
+    ("🟥", "red square", &["red_square"]),
+    ("🟦", "blue square", &["blue_square"]),
+    ("🟧", "orange square", &["orange_square"]),
+    ("🟨", "yellow square", &["yellow_square"]),
+    ("🟩", "green square", &["green_square"]),
+    ("🟪", "purple square", &["purple_square"]),
+    ("🟫", "brown square", &["brown_square"]),
+    ("🟰", "heavy equals sign", &["heavy_equals_sign"]),
+    (
+        "🤌🏻",
+        "pinched fingers: light skin tone",
+        &["pinch_tone1", "pinched_fingers_tone1"],
+    ),
+    (
+        "🤌🏼",
+        "pinched fingers: medium-light skin tone",
+        &["pinch_tone2", "pinched_fingers_tone2"],
+    ),
+    (
+        "🤌🏽",
+        "pinched fingers: medium skin tone",
+        &["pinch_tone3", "pinched_fingers_tone3"],
+    ),
+    (
+        "🤌🏾",
+        "pinched fingers: medium-dark skin tone",
+        &["pinch_tone4", "pinched_fingers_tone4"],
+    ),
+    (
+        "🤌🏿",
+        "pinched fingers: dark skin tone",
+        &["pinch_tone5", "pinched_fingers_tone5"],
+    ),
+    ("🤌", "pinched fingers", &["pinch", "pinched_fingers"]),
+    ("🤍", "white heart", &["white_heart"]),
+    ("🤎", "brown heart", &["brown_heart"]),
+    (
+        "🤏🏻",
+        "pinching hand: light skin tone",
+        &["pinching_hand_tone1"],
+    ),
+    (
+        "🤏🏼",
+        "pinching hand: medium-light skin tone",
+        &["pinching_hand_tone2"],
+    ),
+    (
+        "🤏🏽",
+        "pinching hand: medium skin tone",
+        &["pinching_hand_tone3"],
+    ),
+    (
+        "🤏🏾",
+        "pinching hand: medium-dark skin tone",
+        &["pinching_hand_tone4"],
+    ),
+    (
+        "🤏🏿",
+        "pinching hand: dark skin tone",
+        &["pinching_hand_tone5"],
+    ),
+    ("🤏", "pinching hand", &["pinching_hand"]),
+    (
+        "🤐",
+        "zipper-mouth face",
+        &["zipper_mouth", "zipper_mouth_face"],
+    ),
+    ("🤑", "money-mouth face", &["money_mouth_face"]),
+    ("🤒", "face with thermometer", &["face_with_thermometer"]),
+    ("🤓", "nerd face", &["nerd", "nerd_face"]),
+    ("🤔", "thinking face", &["thinking", "thinking_face", "wtf"]),
+    ("🤕", "face with head-bandage", &["face_with_head_bandage"]),
+    ("🤖", "robot", &["robot", "robot_face"]),
+    (
+        "🤗",
+        "smiling face with open hands",
+        &["hug", "hugging", "hugging_face"],
+    ),
+    (
+        "🤘🏻",
+        "sign of the horns: light skin tone",
+        &["metal_tone1", "sign_of_the_horns_tone1"],
+    ),
+    (
+        "🤘🏼",
+        "sign of the horns: medium-light skin tone",
+        &["metal_tone2", "sign_of_the_horns_tone2"],
+    ),
+    (
+        "🤘🏽",
+        "sign of the horns: medium skin tone",
+        &["metal_tone3", "sign_of_the_horns_tone3"],
+    ),
+    (
+        "🤘🏾",
+        "sign of the horns: medium-dark skin tone",
+        &["metal_tone4", "sign_of_the_horns_tone4"],
+    ),
+    (
+        "🤘🏿",
+        "sign of the horns: dark skin tone",
+        &["metal_tone5", "sign_of_the_horns_tone5"],
+    ),
+    ("🤘", "sign of the horns", &["metal", "sign_of_the_horns"]),
+    (
+        "🤙🏻",
+        "call me hand: light skin tone",
+        &["call_me_hand_tone1"],
+    ),
+    (
+        "🤙🏼",
+        "call me hand: medium-light skin tone",
+        &["call_me_hand_tone2"],
+    ),
+    (
+        "🤙🏽",
+        "call me hand: medium skin tone",
+        &["call_me_hand_tone3"],
+    ),
+    (
+        "🤙🏾",
+        "call me hand: medium-dark skin tone",
+        &["call_me_hand_tone4"],
+    ),
+    (
+        "🤙🏿",
+        "call me hand: dark skin tone",
+        &["call_me_hand_tone5"],
+    ),
+    ("🤙", "call me hand", &["call_me_hand"]),
+    (
+        "🤚🏻",
+        "raised back of hand: light skin tone",
+        &["raised_back_of_hand_tone1"],
+    ),
+    (
+        "🤚🏼",
+        "raised back of hand: medium-light skin tone",
+        &["raised_back_of_hand_tone2"],
+    ),
+    (
+        "🤚🏽",
+        "raised back of hand: medium skin tone",
+        &["raised_back_of_hand_tone3"],
+    ),
+    (
+        "🤚🏾",
+        "raised back of hand: medium-dark skin tone",
+        &["raised_back_of_hand_tone4"],
+    ),
+    (
+        "🤚🏿",
+        "raised back of hand: dark skin tone",
+        &["raised_back_of_hand_tone5"],
+    ),
+    ("🤚", "raised back of hand", &["raised_back_of_hand"]),
+    (
+        "🤛🏻",
+        "left-facing fist: light skin tone",
+        &["left_facing_fist_tone1"],
+    ),
+    (
+        "🤛🏼",
+        "left-facing fist: medium-light skin tone",
+        &["left_facing_fist_tone2"],
+    ),
+    (
+        "🤛🏽",
+        "left-facing fist: medium skin tone",
+        &["left_facing_fist_tone3"],
+    ),
+    (
+        "🤛🏾",
+        "left-facing fist: medium-dark skin tone",
+        &["left_facing_fist_tone4"],
+    ),
+    (
+        "🤛🏿",
+        "left-facing fist: dark skin tone",
+        &["left_facing_fist_tone5"],
+    ),
+    ("🤛", "left-facing fist", &["left_facing_fist"]),
+    (
+        "🤜🏻",
+        "right-facing fist: light skin tone",
+        &["right_facing_fist_tone1"],
+    ),
+    (
+        "🤜🏼",
+        "right-facing fist: medium-light skin tone",
+        &["right_facing_fist_tone2"],
+    ),
+    (
+        "🤜🏽",
+        "right-facing fist: medium skin tone",
+        &["right_facing_fist_tone3"],
+    ),
+    (
+        "🤜🏾",
+        "right-facing fist: medium-dark skin tone",
+        &["right_facing_fist_tone4"],
+    ),
+    (
+        "🤜🏿",
+        "right-facing fist: dark skin tone",
+        &["right_facing_fist_tone5"],
+    ),
+    ("🤜", "right-facing fist", &["right_facing_fist"]),
+    ("🤝🏻", "handshake: light skin tone", &["handshake_tone1"]),
+    (
+        "🤝🏼",
+        "handshake: medium-light skin tone",
+        &["handshake_tone2"],
+    ),
+    ("🤝🏽", "handshake: medium skin tone", &["handshake_tone3"]),
+    (
+        "🤝🏾",
+        "handshake: medium-dark skin tone",
+        &["handshake_tone4"],
+    ),
+    ("🤝🏿", "handshake: dark skin tone", &["handshake_tone5"]),
+    ("🤝", "handshake", &["handshake"]),
+    (
+        "🤞🏻",
+        "crossed fingers: light skin tone",
+        &["fingers_crossed_tone1"],
+    ),
+    (
+        "🤞🏼",
+        "crossed fingers: medium-light skin tone",
+        &["fingers_crossed_tone2"],
+    ),
+    (
+        "🤞🏽",
+        "crossed fingers: medium skin tone",
+        &["fingers_crossed_tone3"],
+    ),
+    (
+        "🤞🏾",
+        "crossed fingers: medium-dark skin tone",
+        &["fingers_crossed_tone4"],
+    ),
+    (
+        "🤞🏿",
+        "crossed fingers: dark skin tone",
+        &["fingers_crossed_tone5"],
+    ),
+    ("🤞", "crossed fingers", &["fingers_crossed"]),
+    (
+        "🤟🏻",
+        "love-you gesture: light skin tone",
+        &["love_you_gesture_tone1"],
+    ),
+    (
+        "🤟🏼",
+        "love-you gesture: medium-light skin tone",
+        &["love_you_gesture_tone2"],
+    ),
+    (
+        "🤟🏽",
+        "love-you gesture: medium skin tone",
+        &["love_you_gesture_tone3"],
+    ),
+    (
+        "🤟🏾",
+        "love-you gesture: medium-dark skin tone",
+        &["love_you_gesture_tone4"],
+    ),
+    (
+        "🤟🏿",
+        "love-you gesture: dark skin tone",
+        &["love_you_gesture_tone5"],
+    ),
+    ("🤟", "love-you gesture", &["love_you_gesture"]),
+    ("🤠", "cowboy hat face", &["cowboy", "cowboy_face"]),
+    ("🤡", "clown face", &["clown", "clown_face"]),
+    ("🤢", "nauseated face", &["nauseated", "nauseated_face"]),
+    ("🤣", "rolling on the floor laughing", &["rofl"]),
+    ("🤤", "drooling face", &["drooling", "drooling_face"]),
+    ("🤥", "lying face", &["lying", "lying_face"]),
+    (
+        "🤦🏻‍♀️",
+        "woman facepalming: light skin tone",
+        &["woman_facepalming_tone1"],
+    ),
+    (
+        "🤦🏻‍♂️",
+        "man facepalming: light skin tone",
+        &["man_facepalming_tone1"],
+    ),
+    (
+        "🤦🏻",
+        "person facepalming: light skin tone",
+        &["facepalm_tone1", "person_facepalming_tone1"],
+    ),
+    (
+        "🤦🏼‍♀️",
+        "woman facepalming: medium-light skin tone",
+        &["woman_facepalming_tone2"],
+    ),
+    (
+        "🤦🏼‍♂️",
+        "man facepalming: medium-light skin tone",
+        &["man_facepalming_tone2"],
+    ),
+    (
+        "🤦🏼",
+        "person facepalming: medium-light skin tone",
+        &["facepalm_tone2", "person_facepalming_tone2"],
+    ),
+    (
+        "🤦🏽‍♀️",
+        "woman facepalming: medium skin tone",
+        &["woman_facepalming_tone3"],
+    ),
+    (
+        "🤦🏽‍♂️",
+        "man facepalming: medium skin tone",
+        &["man_facepalming_tone3"],
+    ),
+    (
+        "🤦🏽",
+        "person facepalming: medium skin tone",
+        &["facepalm_tone3", "person_facepalming_tone3"],
+    ),
+    (
+        "🤦🏾‍♀️",
+        "woman facepalming: medium-dark skin tone",
+        &["woman_facepalming_tone4"],
+    ),
+    (
+        "🤦🏾‍♂️",
+        "man facepalming: medium-dark skin tone",
+        &["man_facepalming_tone4"],
+    ),
+    (
+        "🤦🏾",
+        "person facepalming: medium-dark skin tone",
+        &["facepalm_tone4", "person_facepalming_tone4"],
+    ),
+    (
+        "🤦🏿‍♀️",
+        "woman facepalming: dark skin tone",
+        &["woman_facepalming_tone5"],
+    ),
+    (
+        "🤦🏿‍♂️",
+        "man facepalming: dark skin tone",
+        &["man_facepalming_tone5"],
+    ),
+    (
+        "🤦🏿",
+        "person facepalming: dark skin tone",
+        &["facepalm_tone5", "person_facepalming_tone5"],
+    ),
+    ("🤦‍♀️", "woman facepalming", &["woman_facepalming"]),
+    ("🤦‍♂️", "man facepalming", &["man_facepalming"]),
+    (
+        "🤦",
+        "person facepalming",
+        &["facepalm", "person_facepalming"],
+    ),
+    ("🤧", "sneezing face", &["sneezing", "sneezing_face"]),
+    (
+        "🤨",
+        "face with raised eyebrow",
+        &["face_with_raised_eyebrow", "raised_eyebrow"],
+    ),
+    ("🤩", "star-struck", &["star_struck"]),
+    ("🤪", "zany face", &["zany", "zany_face"]),
+    ("🤫", "shushing face", &["shush", "shushing_face"]),
+    (
+        "🤬",
+        "face with symbols on mouth",
+        &["censored", "face_with_symbols_on_mouth"],
+    ),
+    (
+        "🤭",
+        "face with hand over mouth",
+        &["face_with_hand_over_mouth", "hand_over_mouth"],
+    ),
+    ("🤮", "face vomiting", &["face_vomiting", "vomiting"]),
+    ("🤯", "exploding head", &["exploding_head"]),
+    (
+        "🤰🏻",
+        "pregnant woman: light skin tone",
+        &["pregnant_woman_tone1"],
+    ),
+    (
+        "🤰🏼",
+        "pregnant woman: medium-light skin tone",
+        &["pregnant_woman_tone2"],
+    ),
+    (
+        "🤰🏽",
+        "pregnant woman: medium skin tone",
+        &["pregnant_woman_tone3"],
+    ),
+    (
+        "🤰🏾",
+        "pregnant woman: medium-dark skin tone",
+        &["pregnant_woman_tone4"],
+    ),
+    (
+        "🤰🏿",
+        "pregnant woman: dark skin tone",
+        &["pregnant_woman_tone5"],
+    ),
+    ("🤰", "pregnant woman", &["pregnant_woman"]),
+    (
+        "🤱🏻",
+        "breast-feeding: light skin tone",
+        &["breast_feeding_tone1"],
+    ),
+    (
+        "🤱🏼",
+        "breast-feeding: medium-light skin tone",
+        &["breast_feeding_tone2"],
+    ),
+    (
+        "🤱🏽",
+        "breast-feeding: medium skin tone",
+        &["breast_feeding_tone3"],
+    ),
+    (
+        "🤱🏾",
+        "breast-feeding: medium-dark skin tone",
+        &["breast_feeding_tone4"],
+    ),
+    (
+        "🤱🏿",
+        "breast-feeding: dark skin tone",
+        &["breast_feeding_tone5"],
+    ),
+    ("🤱", "breast-feeding", &["breast_feeding"]),
+    (
+        "🤲🏻",
+        "palms up together: light skin tone",
+        &["palms_up_together_tone1"],
+    ),
+    (
+        "🤲🏼",
+        "palms up together: medium-light skin tone",
+        &["palms_up_together_tone2"],
+    ),
+    (
+        "🤲🏽",
+        "palms up together: medium skin tone",
+        &["palms_up_together_tone3"],
+    ),
+    (
+        "🤲🏾",
+        "palms up together: medium-dark skin tone",
+        &["palms_up_together_tone4"],
+    ),
+    (
+        "🤲🏿",
+        "palms up together: dark skin tone",
+        &["palms_up_together_tone5"],
+    ),
+    ("🤲", "palms up together", &["palms_up_together"]),
+    ("🤳🏻", "selfie: light skin tone", &["selfie_tone1"]),
+    ("🤳🏼", "selfie: medium-light skin tone", &["selfie_tone2"]),
+    ("🤳🏽", "selfie: medium skin tone", &["selfie_tone3"]),
+    ("🤳🏾", "selfie: medium-dark skin tone", &["selfie_tone4"]),
+    ("🤳🏿", "selfie: dark skin tone", &["selfie_tone5"]),
+    ("🤳", "selfie", &["selfie"]),
+    ("🤴🏻", "prince: light skin tone", &["prince_tone1"]),
+    ("🤴🏼", "prince: medium-light skin tone", &["prince_tone2"]),
+    ("🤴🏽", "prince: medium skin tone", &["prince_tone3"]),
+    ("🤴🏾", "prince: medium-dark skin tone", &["prince_tone4"]),
+    ("🤴🏿", "prince: dark skin tone", &["prince_tone5"]),
+    ("🤴", "prince", &["prince"]),
+    (
+        "🤵🏻‍♀️",
+        "woman in tuxedo: light skin tone",
+        &["woman_in_tuxedo_tone1"],
+    ),
+    (
+        "🤵🏻‍♂️",
+        "man in tuxedo: light skin tone",
+        &["man_in_tuxedo_tone1"],
+    ),
+    (
+        "🤵🏻",
+        "person in tuxedo: light skin tone",
+        &["person_in_tuxedo_tone1"],
+    ),
+    (
+        "🤵🏼‍♀️",
+        "woman in tuxedo: medium-light skin tone",
+        &["woman_in_tuxedo_tone2"],
+    ),
+    (
+        "🤵🏼‍♂️",
+        "man in tuxedo: medium-light skin tone",
+        &["man_in_tuxedo_tone2"],
+    ),
+    (
+        "🤵🏼",
+        "person in tuxedo: medium-light skin tone",
+        &["person_in_tuxedo_tone2"],
+    ),
+    (
+        "🤵🏽‍♀️",
+        "woman in tuxedo: medium skin tone",
+        &["woman_in_tuxedo_tone3"],
+    ),
+    (
+        "🤵🏽‍♂️",
+        "man in tuxedo: medium skin tone",
+        &["man_in_tuxedo_tone3"],
+    ),
+    (
+        "🤵🏽",
+        "person in tuxedo: medium skin tone",
+        &["person_in_tuxedo_tone3"],
+    ),
+    (
+        "🤵🏾‍♀️",
+        "woman in tuxedo: medium-dark skin tone",
+        &["woman_in_tuxedo_tone4"],
+    ),
+    (
+        "🤵🏾‍♂️",
+        "man in tuxedo: medium-dark skin tone",
+        &["man_in_tuxedo_tone4"],
+    ),
+    (
+        "🤵🏾",
+        "person in tuxedo: medium-dark skin tone",
+        &["person_in_tuxedo_tone4"],
+    ),
+    (
+        "🤵🏿‍♀️",
+        "woman in tuxedo: dark skin tone",
+        &["woman_in_tuxedo_tone5"],
+    ),
+    (
+        "🤵🏿‍♂️",
+        "man in tuxedo: dark skin tone",
+        &["man_in_tuxedo_tone5"],
+    ),
+    (
+        "🤵🏿",
+        "person in tuxedo: dark skin tone",
+        &["person_in_tuxedo_tone5"],
+    ),
+    ("🤵‍♀️", "woman in tuxedo", &["woman_in_tuxedo"]),
+    ("🤵‍♂️", "man in tuxedo", &["man_in_tuxedo"]),
+    ("🤵", "person in tuxedo", &["person_in_tuxedo"]),
+    ("🤶🏻", "Mrs. Claus: light skin tone", &["mrs_claus_tone1"]),
+    (
+        "🤶🏼",
+        "Mrs. Claus: medium-light skin tone",
+        &["mrs_claus_tone2"],
+    ),
+    ("🤶🏽", "Mrs. Claus: medium skin tone", &["mrs_claus_tone3"]),
+    (
+        "🤶🏾",
+        "Mrs. Claus: medium-dark skin tone",
+        &["mrs_claus_tone4"],
+    ),
+    ("🤶🏿", "Mrs. Claus: dark skin tone", &["mrs_claus_tone5"]),
+    ("🤶", "Mrs. Claus", &["mrs_claus"]),
+    (
+        "🤷🏻‍♀️",
+        "woman shrugging: light skin tone",
+        &["woman_shrugging_tone1"],
+    ),
+    (
+        "🤷🏻‍♂️",
+        "man shrugging: light skin tone",
+        &["man_shrugging_tone1"],
+    ),
+    (
+        "🤷🏻",
+        "person shrugging: light skin tone",
+        &["person_shrugging_tone1", "shrug_tone1"],
+    ),
+    (
+        "🤷🏼‍♀️",
+        "woman shrugging: medium-light skin tone",
+        &["woman_shrugging_tone2"],
+    ),
+    (
+        "🤷🏼‍♂️",
+        "man shrugging: medium-light skin tone",
+        &["man_shrugging_tone2"],
+    ),
+    (
+        "🤷🏼",
+        "person shrugging: medium-light skin tone",
+        &["person_shrugging_tone2", "shrug_tone2"],
+    ),
+    (
+        "🤷🏽‍♀️",
+        "woman shrugging: medium skin tone",
+        &["woman_shrugging_tone3"],
+    ),
+    (
+        "🤷🏽‍♂️",
+        "man shrugging: medium skin tone",
+        &["man_shrugging_tone3"],
+    ),
+    (
+        "🤷🏽",
+        "person shrugging: medium skin tone",
+        &["person_shrugging_tone3", "shrug_tone3"],
+    ),
+    (
+        "🤷🏾‍♀️",
+        "woman shrugging: medium-dark skin tone",
+        &["woman_shrugging_tone4"],
+    ),
+    (
+        "🤷🏾‍♂️",
+        "man shrugging: medium-dark skin tone",
+        &["man_shrugging_tone4"],
+    ),
+    (
+        "🤷🏾",
+        "person shrugging: medium-dark skin tone",
+        &["person_shrugging_tone4", "shrug_tone4"],
+    ),
+    (
+        "🤷🏿‍♀️",
+        "woman shrugging: dark skin tone",
+        &["woman_shrugging_tone5"],
+    ),
+    (
+        "🤷🏿‍♂️",
+        "man shrugging: dark skin tone",
+        &["man_shrugging_tone5"],
+    ),
+    (
+        "🤷🏿",
+        "person shrugging: dark skin tone",
+        &["person_shrugging_tone5", "shrug_tone5"],
+    ),
+    ("🤷‍♀️", "woman shrugging", &["woman_shrugging"]),
+    ("🤷‍♂️", "man shrugging", &["man_shrugging"]),
+    ("🤷", "person shrugging", &["person_shrugging", "shrug"]),
+    (
+        "🤸🏻‍♀️",
+        "woman cartwheeling: light skin tone",
+        &["woman_cartwheeling_tone1"],
+    ),
+    (
+        "🤸🏻‍♂️",
+        "man cartwheeling: light skin tone",
+        &["man_cartwheeling_tone1"],
+    ),
+    (
+        "🤸🏻",
+        "person cartwheeling: light skin tone",
+        &["cartwheeling_tone1", "person_cartwheel_tone1"],
+    ),
+    (
+        "🤸🏼‍♀️",
+        "woman cartwheeling: medium-light skin tone",
+        &["woman_cartwheeling_tone2"],
+    ),
+    (
+        "🤸🏼‍♂️",
+        "man cartwheeling: medium-light skin tone",
+        &["man_cartwheeling_tone2"],
+    ),
+    (
+        "🤸🏼",
+        "person cartwheeling: medium-light skin tone",
+        &["cartwheeling_tone2", "person_cartwheel_tone2"],
+    ),
+    (
+        "🤸🏽‍♀️",
+        "woman cartwheeling: medium skin tone",
+        &["woman_cartwheeling_tone3"],
+    ),
+    (
+        "🤸🏽‍♂️",
+        "man cartwheeling: medium skin tone",
+        &["man_cartwheeling_tone3"],
+    ),
+    (
+        "🤸🏽",
+        "person cartwheeling: medium skin tone",
+        &["cartwheeling_tone3", "person_cartwheel_tone3"],
+    ),
+    (
+        "🤸🏾‍♀️",
+        "woman cartwheeling: medium-dark skin tone",
+        &["woman_cartwheeling_tone4"],
+    ),
+    (
+        "🤸🏾‍♂️",
+        "man cartwheeling: medium-dark skin tone",
+        &["man_cartwheeling_tone4"],
+    ),
+    (
+        "🤸🏾",
+        "person cartwheeling: medium-dark skin tone",
+        &["cartwheeling_tone4", "person_cartwheel_tone4"],
+    ),
+    (
+        "🤸🏿‍♀️",
+        "woman cartwheeling: dark skin tone",
+        &["woman_cartwheeling_tone5"],
+    ),
+    (
+        "🤸🏿‍♂️",
+        "man cartwheeling: dark skin tone",
+        &["man_cartwheeling_tone5"],
+    ),
+    (
+        "🤸🏿",
+        "person cartwheeling: dark skin tone",
+        &["cartwheeling_tone5", "person_cartwheel_tone5"],
+    ),
+    ("🤸‍♀️", "woman cartwheeling", &["woman_cartwheeling"]),
+    ("🤸‍♂️", "man cartwheeling", &["man_cartwheeling"]),
+    (
+        "🤸",
+        "person cartwheeling",
+        &["cartwheeling", "person_cartwheel"],
+    ),
+    (
+        "🤹🏻‍♀️",
+        "woman juggling: light skin tone",
+        &["woman_juggling_tone1"],
+    ),
+    (
+        "🤹🏻‍♂️",
+        "man juggling: light skin tone",
+        &["man_juggling_tone1"],
+    ),
+    (
+        "🤹🏻",
+        "person juggling: light skin tone",
+        &["juggler_tone1", "juggling_tone1", "person_juggling_tone1"],
+    ),
+    (
+        "🤹🏼‍♀️",
+        "woman juggling: medium-light skin tone",
+        &["woman_juggling_tone2"],
+    ),
+    (
+        "🤹🏼‍♂️",
+        "man juggling: medium-light skin tone",
+        &["man_juggling_tone2"],
+    ),
+    (
+        "🤹🏼",
+        "person juggling: medium-light skin tone",
+        &["juggler_tone2", "juggling_tone2", "person_juggling_tone2"],
+    ),
+    (
+        "🤹🏽‍♀️",
+        "woman juggling: medium skin tone",
+        &["woman_juggling_tone3"],
+    ),
+    (
+        "🤹🏽‍♂️",
+        "man juggling: medium skin tone",
+        &["man_juggling_tone3"],
+    ),
+    (
+        "🤹🏽",
+        "person juggling: medium skin tone",
+        &["juggler_tone3", "juggling_tone3", "person_juggling_tone3"],
+    ),
+    (
+        "🤹🏾‍♀️",
+        "woman juggling: medium-dark skin tone",
+        &["woman_juggling_tone4"],
+    ),
+    (
+        "🤹🏾‍♂️",
+        "man juggling: medium-dark skin tone",
+        &["man_juggling_tone4"],
+    ),
+    (
+        "🤹🏾",
+        "person juggling: medium-dark skin tone",
+        &["juggler_tone4", "juggling_tone4", "person_juggling_tone4"],
+    ),
+    (
+        "🤹🏿‍♀️",
+        "woman juggling: dark skin tone",
+        &["woman_juggling_tone5"],
+    ),
+    (
+        "🤹🏿‍♂️",
+        "man juggling: dark skin tone",
+        &["man_juggling_tone5"],
+    ),
+    (
+        "🤹🏿",
+        "person juggling: dark skin tone",
+        &["juggler_tone5", "juggling_tone5", "person_juggling_tone5"],
+    ),
+    ("🤹‍♀️", "woman juggling", &["woman_juggling"]),
+    ("🤹‍♂️", "man juggling", &["man_juggling"]),
+    (
+        "🤹",
+        "person juggling",
+        &["juggler", "juggling", "person_juggling"],
+    ),
+    (
+        "🤺",
+        "person fencing",
+        &["fencer", "fencing", "person_fencing"],
+    ),
+    ("🤼🏻‍♀️", "women wrestling: light skin tone", &[]),
+    ("🤼🏻‍♂️", "men wrestling: light skin tone", &[]),
+    ("🤼🏻", "people wrestling: light skin tone", &[]),
+    ("🤼🏼‍♀️", "women wrestling: medium-light skin tone", &[]),
+    ("🤼🏼‍♂️", "men wrestling: medium-light skin tone", &[]),
+    ("🤼🏼", "people wrestling: medium-light skin tone", &[]),
+    ("🤼🏽‍♀️", "women wrestling: medium skin tone", &[]),
+    ("🤼🏽‍♂️", "men wrestling: medium skin tone", &[]),
+    ("🤼🏽", "people wrestling: medium skin tone", &[]),
+    ("🤼🏾‍♀️", "women wrestling: medium-dark skin tone", &[]),
+    ("🤼🏾‍♂️", "men wrestling: medium-dark skin tone", &[]),
+    ("🤼🏾", "people wrestling: medium-dark skin tone", &[]),
+    ("🤼🏿‍♀️", "women wrestling: dark skin tone", &[]),
+    ("🤼🏿‍♂️", "men wrestling: dark skin tone", &[]),
+    ("🤼🏿", "people wrestling: dark skin tone", &[]),
+    ("🤼‍♀️", "women wrestling", &["women_wrestling"]),
+    ("🤼‍♂️", "men wrestling", &["men_wrestling"]),
+    (
+        "🤼",
+        "people wrestling",
+        &["people_wrestling", "wrestlers", "wrestling"],
+    ),
+    (
+        "🤽🏻‍♀️",
+        "woman playing water polo: light skin tone",
+        &["woman_playing_water_polo_tone1"],
+    ),
+    (
+        "🤽🏻‍♂️",
+        "man playing water polo: light skin tone",
+        &["man_playing_water_polo_tone1"],
+    ),
+    (
+        "🤽🏻",
+        "person playing water polo: light skin tone",
+        &["person_playing_water_polo_tone1", "water_polo_tone1"],
+    ),
+    (
+        "🤽🏼‍♀️",
+        "woman playing water polo: medium-light skin tone",
+        &["woman_playing_water_polo_tone2"],
+    ),
+    (
+        "🤽🏼‍♂️",
+        "man playing water polo: medium-light skin tone",
+        &["man_playing_water_polo_tone2"],
+    ),
+    (
+        "🤽🏼",
+        "person playing water polo: medium-light skin tone",
+        &["person_playing_water_polo_tone2", "water_polo_tone2"],
+    ),
+    (
+        "🤽🏽‍♀️",
+        "woman playing water polo: medium skin tone",
+        &["woman_playing_water_polo_tone3"],
+    ),
+    (
+        "🤽🏽‍♂️",
+        "man playing water polo: medium skin tone",
+        &["man_playing_water_polo_tone3"],
+    ),
+    (
+        "🤽🏽",
+        "person playing water polo: medium skin tone",
+        &["person_playing_water_polo_tone3", "water_polo_tone3"],
+    ),
+    (
+        "🤽🏾‍♀️",
+        "woman playing water polo: medium-dark skin tone",
+        &["woman_playing_water_polo_tone4"],
+    ),
+    (
+        "🤽🏾‍♂️",
+        "man playing water polo: medium-dark skin tone",
+        &["man_playing_water_polo_tone4"],
+    ),
+    (
+        "🤽🏾",
+        "person playing water polo: medium-dark skin tone",
+        &["person_playing_water_polo_tone4", "water_polo_tone4"],
+    ),
+    (
+        "🤽🏿‍♀️",
+        "woman playing water polo: dark skin tone",
+        &["woman_playing_water_polo_tone5"],
+    ),
+    (
+        "🤽🏿‍♂️",
+        "man playing water polo: dark skin tone",
+        &["man_playing_water_polo_tone5"],
+    ),
+    (
+        "🤽🏿",
+        "person playing water polo: dark skin tone",
+        &["person_playing_water_polo_tone5", "water_polo_tone5"],
+    ),
+    (
+        "🤽‍♀️",
+        "woman playing water polo",
+        &["woman_playing_water_polo"],
+    ),
+    ("🤽‍♂️", "man playing water polo", &["man_playing_water_polo"]),
+    (
+        "🤽",
+        "person playing water polo",
+        &["person_playing_water_polo", "water_polo"],
+    ),
+    (
+        "🤾🏻‍♀️",
+        "woman playing handball: light skin tone",
+        &["woman_playing_handball_tone1"],
+    ),
+    (
+        "🤾🏻‍♂️",
+        "man playing handball: light skin tone",
+        &["man_playing_handball_tone1"],
+    ),
+    (
+        "🤾🏻",
+        "person playing handball: light skin tone",
+        &["handball_tone1", "person_playing_handball_tone1"],
+    ),
+    (
+        "🤾🏼‍♀️",
+        "woman playing handball: medium-light skin tone",
+        &["woman_playing_handball_tone2"],
+    ),
+    (
+        "🤾🏼‍♂️",
+        "man playing handball: medium-light skin tone",
+        &["man_playing_handball_tone2"],
+    ),
+    (
+        "🤾🏼",
+        "person playing handball: medium-light skin tone",
+        &["handball_tone2", "person_playing_handball_tone2"],
+    ),
+    (
+        "🤾🏽‍♀️",
+        "woman playing handball: medium skin tone",
+        &["woman_playing_handball_tone3"],
+    ),
+    (
+        "🤾🏽‍♂️",
+        "man playing handball: medium skin tone",
+        &["man_playing_handball_tone3"],
+    ),
+    (
+        "🤾🏽",
+        "person playing handball: medium skin tone",
+        &["handball_tone3", "person_playing_handball_tone3"],
+    ),
+    (
+        "🤾🏾‍♀️",
+        "woman playing handball: medium-dark skin tone",
+        &["woman_playing_handball_tone4"],
+    ),
+    (
+        "🤾🏾‍♂️",
+        "man playing handball: medium-dark skin tone",
+        &["man_playing_handball_tone4"],
+    ),
+    (
+        "🤾🏾",
+        "person playing handball: medium-dark skin tone",
+        &["handball_tone4", "person_playing_handball_tone4"],
+    ),
+    (
+        "🤾🏿‍♀️",
+        "woman playing handball: dark skin tone",
+        &["woman_playing_handball_tone5"],
+    ),
+    (
+        "🤾🏿‍♂️",
+        "man playing handball: dark skin tone",
+        &["man_playing_handball_tone5"],
+    ),
+    (
+        "🤾🏿",
+        "person playing handball: dark skin tone",
+        &["handball_tone5", "person_playing_handball_tone5"],
+    ),
+    ("🤾‍♀️", "woman playing handball", &["woman_playing_handball"]),
+    ("🤾‍♂️", "man playing handball", &["man_playing_handball"]),
+    (
+        "🤾",
+        "person playing handball",
+        &["handball", "person_playing_handball"],
+    ),
+    ("🤿", "diving mask", &["diving_mask"]),
+    ("🥀", "wilted flower", &["wilted_flower"]),
+    ("🥁", "drum", &["drum"]),
+    ("🥂", "clinking glasses", &["clinking_glasses"]),
+    ("🥃", "tumbler glass", &["tumbler_glass", "whisky"]),
+    ("🥄", "spoon", &["spoon"]),
+    ("🥅", "goal net", &["goal_net"]),
+    ("🥇", "1st place medal", &["1st", "first_place_medal"]),
+    ("🥈", "2nd place medal", &["2nd", "second_place_medal"]),
+    ("🥉", "3rd place medal", &["3rd", "third_place_medal"]),
+    ("🥊", "boxing glove", &["boxing_glove"]),
+    ("🥋", "martial arts uniform", &["martial_arts_uniform"]),
+    ("🥌", "curling stone", &["curling_stone"]),
+    ("🥍", "lacrosse", &["lacrosse"]),
+    ("🥎", "softball", &["softball"]),
+    ("🥏", "flying disc", &["flying_disc"]),
+    ("🥐", "croissant", &["croissant"]),
+    ("🥑", "avocado", &["avocado"]),
+    ("🥒", "cucumber", &["cucumber"]),
+    ("🥓", "bacon", &["bacon"]),
+    ("🥔", "potato", &["potato"]),
+    ("🥕", "carrot", &["carrot"]),
+    ("🥖", "baguette bread", &["baguette_bread"]),
+    ("🥗", "green salad", &["green_salad", "salad"]),
+    ("🥘", "shallow pan of food", &["shallow_pan_of_food"]),
+    ("🥙", "stuffed flatbread", &["stuffed_flatbread"]),
+    ("🥚", "egg", &["egg"]),
+    ("🥛", "glass of milk", &["glass_of_milk", "milk"]),
+    ("🥜", "peanuts", &["peanuts"]),
+    ("🥝", "kiwi fruit", &["kiwi"]),
+    ("🥞", "pancakes", &["pancakes"]),
+    ("🥟", "dumpling", &["dumpling"]),
+    ("🥠", "fortune cookie", &["fortune_cookie"]),
+    ("🥡", "takeout box", &["takeout_box"]),
+    ("🥢", "chopsticks", &["chopsticks"]),
+    ("🥣", "bowl with spoon", &["bowl_with_spoon"]),
+    ("🥤", "cup with straw", &["cup_with_straw"]),
+    ("🥥", "coconut", &["coconut"]),
+    ("🥦", "broccoli", &["broccoli"]),
+    ("🥧", "pie", &["pie"]),
+    ("🥨", "pretzel", &["pretzel"]),
+    ("🥩", "cut of meat", &["cut_of_meat"]),
+    ("🥪", "sandwich", &["sandwich"]),
+    ("🥫", "canned food", &["canned_food"]),
+    ("🥬", "leafy green", &["leafy_green"]),
+    ("🥭", "mango", &["mango"]),
+    ("🥮", "moon cake", &["moon_cake"]),
+    ("🥯", "bagel", &["bagel"]),
+    (
+        "🥰",
+        "smiling face with hearts",
+        &["smiling_face_with_3_hearts"],
+    ),
+    ("🥱", "yawning face", &["yawn", "yawning", "yawning_face"]),
+    ("🥲", "smiling face with tear", &["smiling_face_with_tear"]),
+    (
+        "🥳",
+        "partying face",
+        &["hooray", "partying", "partying_face"],
+    ),
+    ("🥴", "woozy face", &["woozy", "woozy_face"]),
+    ("🥵", "hot face", &["hot", "hot_face"]),
+    ("🥶", "cold face", &["cold", "cold_face"]),
+    ("🥷🏻", "ninja: light skin tone", &["ninja_tone1"]),
+    ("🥷🏼", "ninja: medium-light skin tone", &["ninja_tone2"]),
+    ("🥷🏽", "ninja: medium skin tone", &["ninja_tone3"]),
+    ("🥷🏾", "ninja: medium-dark skin tone", &["ninja_tone4"]),
+    ("🥷🏿", "ninja: dark skin tone", &["ninja_tone5"]),
+    ("🥷", "ninja", &["ninja"]),
+    ("🥸", "disguised face", &["disguised", "disguised_face"]),
+    (
+        "🥹",
+        "face holding back tears",
+        &["face_holding_back_tears", "watery_eyes"],
+    ),
+    ("🥺", "pleading face", &["pleading", "pleading_face"]),
+    ("🥻", "sari", &["sari"]),
+    ("🥼", "lab coat", &["lab_coat"]),
+    ("🥽", "goggles", &["goggles"]),
+    ("🥾", "hiking boot", &["hiking_boot"]),
+    ("🥿", "flat shoe", &["flat_shoe", "womans_flat_shoe"]),
+    ("🦀", "crab", &["crab"]),
+    ("🦁", "lion", &["lion", "lion_face"]),
+    ("🦂", "scorpion", &["scorpion"]),
+    ("🦃", "turkey", &["turkey"]),
+    ("🦄", "unicorn", &["unicorn", "unicorn_face"]),
+    ("🦅", "eagle", &["eagle"]),
+    ("🦆", "duck", &["duck"]),
+    ("🦇", "bat", &["bat"]),
+    ("🦈", "shark", &["shark"]),
+    ("🦉", "owl", &["owl"]),
+    ("🦊", "fox", &["fox", "fox_face"]),
+    ("🦋", "butterfly", &["butterfly"]),
+    ("🦌", "deer", &["deer"]),
+    ("🦍", "gorilla", &["gorilla"]),
+    ("🦎", "lizard", &["lizard"]),
+    ("🦏", "rhinoceros", &["rhino", "rhinoceros"]),
+    ("🦐", "shrimp", &["shrimp"]),
+    ("🦑", "squid", &["squid"]),
+    ("🦒", "giraffe", &["giraffe"]),
+    ("🦓", "zebra", &["zebra"]),
+    ("🦔", "hedgehog", &["hedgehog"]),
+    ("🦕", "sauropod", &["sauropod"]),
+    ("🦖", "T-Rex", &["t-rex", "trex"]),
+    ("🦗", "cricket", &["cricket"]),
+    ("🦘", "kangaroo", &["kangaroo"]),
+    ("🦙", "llama", &["llama"]),
+    ("🦚", "peacock", &["peacock"]),
+    ("🦛", "hippopotamus", &["hippo"]),
+    ("🦜", "parrot", &["parrot"]),
+    ("🦝", "raccoon", &["raccoon"]),
+    ("🦞", "lobster", &["lobster"]),
+    ("🦟", "mosquito", &["mosquito"]),
+    ("🦠", "microbe", &["microbe"]),
+    ("🦡", "badger", &["badger"]),
+    ("🦢", "swan", &["swan"]),
+    ("🦣", "mammoth", &["mammoth"]),
+    ("🦤", "dodo", &["dodo"]),
+    ("🦥", "sloth", &["sloth"]),
+    ("🦦", "otter", &["otter"]),
+    ("🦧", "orangutan", &["orangutan"]),
+    ("🦨", "skunk", &["skunk"]),
+    ("🦩", "flamingo", &["flamingo"]),
+    ("🦪", "oyster", &["oyster"]),
+    ("🦫", "beaver", &["beaver"]),
+    ("🦬", "bison", &["bison"]),
+    ("🦭", "seal", &["seal"]),
+    ("🦮", "guide dog", &["guide_dog"]),
+    ("🦯", "white cane", &["probing_cane", "white_cane"]),
+    ("🦰", "red hair", &["red_hair"]),
+    ("🦱", "curly hair", &["curly_hair"]),
+    ("🦲", "bald", &["no_hair"]),
+    ("🦳", "white hair", &["white_hair"]),
+    ("🦴", "bone", &["bone"]),
+    ("🦵🏻", "leg: light skin tone", &["leg_tone1"]),
+    ("🦵🏼", "leg: medium-light skin tone", &["leg_tone2"]),
+    ("🦵🏽", "leg: medium skin tone", &["leg_tone3"]),
+    ("🦵🏾", "leg: medium-dark skin tone", &["leg_tone4"]),
+    ("🦵🏿", "leg: dark skin tone", &["leg_tone5"]),
+    ("🦵", "leg", &["leg"]),
+    ("🦶🏻", "foot: light skin tone", &["foot_tone1"]),
+    ("🦶🏼", "foot: medium-light skin tone", &["foot_tone2"]),
+    ("🦶🏽", "foot: medium skin tone", &["foot_tone3"]),
+    ("🦶🏾", "foot: medium-dark skin tone", &["foot_tone4"]),
+    ("🦶🏿", "foot: dark skin tone", &["foot_tone5"]),
+    ("🦶", "foot", &["foot"]),
+    ("🦷", "tooth", &["tooth"]),
+    (
+        "🦸🏻‍♀️",
+        "woman superhero: light skin tone",
+        &["woman_superhero_tone1"],
+    ),
+    (
+        "🦸🏻‍♂️",
+        "man superhero: light skin tone",
+        &["man_superhero_tone1"],
+    ),
+    ("🦸🏻", "superhero: light skin tone", &["superhero_tone1"]),
+    (
+        "🦸🏼‍♀️",
+        "woman superhero: medium-light skin tone",
+        &["woman_superhero_tone2"],
+    ),
+    (
+        "🦸🏼‍♂️",
+        "man superhero: medium-light skin tone",
+        &["man_superhero_tone2"],
+    ),
+    (
+        "🦸🏼",
+        "superhero: medium-light skin tone",
+        &["superhero_tone2"],
+    ),
+    (
+        "🦸🏽‍♀️",
+        "woman superhero: medium skin tone",
+        &["woman_superhero_tone3"],
+    ),
+    (
+        "🦸🏽‍♂️",
+        "man superhero: medium skin tone",
+        &["man_superhero_tone3"],
+    ),
+    ("🦸🏽", "superhero: medium skin tone", &["superhero_tone3"]),
+    (
+        "🦸🏾‍♀️",
+        "woman superhero: medium-dark skin tone",
+        &["woman_superhero_tone4"],
+    ),
+    (
+        "🦸🏾‍♂️",
+        "man superhero: medium-dark skin tone",
+        &["man_superhero_tone4"],
+    ),
+    (
+        "🦸🏾",
+        "superhero: medium-dark skin tone",
+        &["superhero_tone4"],
+    ),
+    (
+        "🦸🏿‍♀️",
+        "woman superhero: dark skin tone",
+        &["woman_superhero_tone5"],
+    ),
+    (
+        "🦸🏿‍♂️",
+        "man superhero: dark skin tone",
+        &["man_superhero_tone5"],
+    ),
+    ("🦸🏿", "superhero: dark skin tone", &["superhero_tone5"]),
+    ("🦸‍♀️", "woman superhero", &["woman_superhero"]),
+    ("🦸‍♂️", "man superhero", &["man_superhero"]),
+    ("🦸", "superhero", &["superhero"]),
+    (
+        "🦹🏻‍♀️",
+        "woman supervillain: light skin tone",
+        &["woman_supervillain_tone1"],
+    ),
+    (
+        "🦹🏻‍♂️",
+        "man supervillain: light skin tone",
+        &["man_supervillain_tone1"],
+    ),
+    (
+        "🦹🏻",
+        "supervillain: light skin tone",
+        &["supervillain_tone1"],
+    ),
+    (
+        "🦹🏼‍♀️",
+        "woman supervillain: medium-light skin tone",
+        &["woman_supervillain_tone2"],
+    ),
+    (
+        "🦹🏼‍♂️",
+        "man supervillain: medium-light skin tone",
+        &["man_supervillain_tone2"],
+    ),
+    (
+        "🦹🏼",
+        "supervillain: medium-light skin tone",
+        &["supervillain_tone2"],
+    ),
+    (
+        "🦹🏽‍♀️",
+        "woman supervillain: medium skin tone",
+        &["woman_supervillain_tone3"],
+    ),
+    (
+        "🦹🏽‍♂️",
+        "man supervillain: medium skin tone",
+        &["man_supervillain_tone3"],
+    ),
+    (
+        "🦹🏽",
+        "supervillain: medium skin tone",
+        &["supervillain_tone3"],
+    ),
+    (
+        "🦹🏾‍♀️",
+        "woman supervillain: medium-dark skin tone",
+        &["woman_supervillain_tone4"],
+    ),
+    (
+        "🦹🏾‍♂️",
+        "man supervillain: medium-dark skin tone",
+        &["man_supervillain_tone4"],
+    ),
+    (
+        "🦹🏾",
+        "supervillain: medium-dark skin tone",
+        &["supervillain_tone4"],
+    ),
+    (
+        "🦹🏿‍♀️",
+        "woman supervillain: dark skin tone",
+        &["woman_supervillain_tone5"],
+    ),
+    (
+        "🦹🏿‍♂️",
+        "man supervillain: dark skin tone",
+        &["man_supervillain_tone5"],
+    ),
+    (
+        "🦹🏿",
+        "supervillain: dark skin tone",
+        &["supervillain_tone5"],
+    ),
+    ("🦹‍♀️", "woman supervillain", &["woman_supervillain"]),
+    ("🦹‍♂️", "man supervillain", &["man_supervillain"]),
+    ("🦹", "supervillain", &["supervillain"]),
+    ("🦺", "safety vest", &["safety_vest"]),
+    (
+        "🦻🏻",
+        "ear with hearing aid: light skin tone",
+        &["ear_with_hearing_aid_tone1", "hearing_aid_tone1"],
+    ),
+    (
+        "🦻🏼",
+        "ear with hearing aid: medium-light skin tone",
+        &["ear_with_hearing_aid_tone2", "hearing_aid_tone2"],
+    ),
+    (
+        "🦻🏽",
+        "ear with hearing aid: medium skin tone",
+        &["ear_with_hearing_aid_tone3", "hearing_aid_tone3"],
+    ),
+    (
+        "🦻🏾",
+        "ear with hearing aid: medium-dark skin tone",
+        &["ear_with_hearing_aid_tone4", "hearing_aid_tone4"],
+    ),
+    (
+        "🦻🏿",
+        "ear with hearing aid: dark skin tone",
+        &["ear_with_hearing_aid_tone5", "hearing_aid_tone5"],
+    ),
+    (
+        "🦻",
+        "ear with hearing aid",
+        &["ear_with_hearing_aid", "hearing_aid"],
+    ),
+    ("🦼", "motorized wheelchair", &["motorized_wheelchair"]),
+    ("🦽", "manual wheelchair", &["manual_wheelchair"]),
+    ("🦾", "mechanical arm", &["mechanical_arm"]),
+    ("🦿", "mechanical leg", &["mechanical_leg"]),
+    ("🧀", "cheese wedge", &["cheese"]),
+    ("🧁", "cupcake", &["cupcake"]),
+    ("🧂", "salt", &["salt"]),
+    ("🧃", "beverage box", &["beverage_box", "juice_box"]),
+    ("🧄", "garlic", &["garlic"]),
+    ("🧅", "onion", &["onion"]),
+    ("🧆", "falafel", &["falafel"]),
+    ("🧇", "waffle", &["waffle"]),
+    ("🧈", "butter", &["butter"]),
+    ("🧉", "mate", &["mate"]),
+    ("🧊", "ice", &["ice", "ice_cube"]),
+    ("🧋", "bubble tea", &["boba_drink", "bubble_tea"]),
+    ("🧌", "troll", &["troll"]),
+    (
+        "🧍🏻‍♀️",
+        "woman standing: light skin tone",
+        &["woman_standing_tone1"],
+    ),
+    (
+        "🧍🏻‍♂️",
+        "man standing: light skin tone",
+        &["man_standing_tone1"],
+    ),
+    (
+        "🧍🏻",
+        "person standing: light skin tone",
+        &["person_standing_tone1", "standing_tone1"],
+    ),
+    (
+        "🧍🏼‍♀️",
+        "woman standing: medium-light skin tone",
+        &["woman_standing_tone2"],
+    ),
+    (
+        "🧍🏼‍♂️",
+        "man standing: medium-light skin tone",
+        &["man_standing_tone2"],
+    ),
+    (
+        "🧍🏼",
+        "person standing: medium-light skin tone",
+        &["person_standing_tone2", "standing_tone2"],
+    ),
+    (
+        "🧍🏽‍♀️",
+        "woman standing: medium skin tone",
+        &["woman_standing_tone3"],
+    ),
+    (
+        "🧍🏽‍♂️",
+        "man standing: medium skin tone",
+        &["man_standing_tone3"],
+    ),
+    (
+        "🧍🏽",
+        "person standing: medium skin tone",
+        &["person_standing_tone3", "standing_tone3"],
+    ),
+    (
+        "🧍🏾‍♀️",
+        "woman standing: medium-dark skin tone",
+        &["woman_standing_tone4"],
+    ),
+    (
+        "🧍🏾‍♂️",
+        "man standing: medium-dark skin tone",
+        &["man_standing_tone4"],
+    ),
+    (
+        "🧍🏾",
+        "person standing: medium-dark skin tone",
+        &["person_standing_tone4", "standing_tone4"],
+    ),
+    (
+        "🧍🏿‍♀️",
+        "woman standing: dark skin tone",
+        &["woman_standing_tone5"],
+    ),
+    (
+        "🧍🏿‍♂️",
+        "man standing: dark skin tone",
+        &["man_standing_tone5"],
+    ),
+    (
+        "🧍🏿",
+        "person standing: dark skin tone",
+        &["person_standing_tone5", "standing_tone5"],
+    ),
+    ("🧍‍♀️", "woman standing", &["woman_standing"]),
+    ("🧍‍♂️", "man standing", &["man_standing"]),
+    ("🧍", "person standing", &["person_standing", "standing"]),
+    (
+        "🧎🏻‍♀️‍➡️",
+        "woman kneeling facing right: light skin tone",
+        &["woman_kneeling_right_tone1"],
+    ),
+    (
+        "🧎🏻‍♀️",
+        "woman kneeling: light skin tone",
+        &["woman_kneeling_tone1"],
+    ),
+    (
+        "🧎🏻‍♂️‍➡️",
+        "man kneeling facing right: light skin tone",
+        &["man_kneeling_right_tone1"],
+    ),
+    (
+        "🧎🏻‍♂️",
+        "man kneeling: light skin tone",
+        &["man_kneeling_tone1"],
+    ),
+    (
+        "🧎🏻‍➡️",
+        "person kneeling facing right: light skin tone",
+        &["person_kneeling_right_tone1"],
+    ),
+    (
+        "🧎🏻",
+        "person kneeling: light skin tone",
+        &["kneeling_tone1", "person_kneeling_tone1"],
+    ),
+    (
+        "🧎🏼‍♀️‍➡️",
+        "woman kneeling facing right: medium-light skin tone",
+        &["woman_kneeling_right_tone2"],
+    ),
+    (
+        "🧎🏼‍♀️",
+        "woman kneeling: medium-light skin tone",
+        &["woman_kneeling_tone2"],
+    ),
+    (
+        "🧎🏼‍♂️‍➡️",
+        "man kneeling facing right: medium-light skin tone",
+        &["man_kneeling_right_tone2"],
+    ),
+    (
+        "🧎🏼‍♂️",
+        "man kneeling: medium-light skin tone",
+        &["man_kneeling_tone2"],
+    ),
+    (
+        "🧎🏼‍➡️",
+        "person kneeling facing right: medium-light skin tone",
+        &["person_kneeling_right_tone2"],
+    ),
+    (
+        "🧎🏼",
+        "person kneeling: medium-light skin tone",
+        &["kneeling_tone2", "person_kneeling_tone2"],
+    ),
+    (
+        "🧎🏽‍♀️‍➡️",
+        "woman kneeling facing right: medium skin tone",
+        &["woman_kneeling_right_tone3"],
+    ),
+    (
+        "🧎🏽‍♀️",
+        "woman kneeling: medium skin tone",
+        &["woman_kneeling_tone3"],
+    ),
+    (
+        "🧎🏽‍♂️‍➡️",
+        "man kneeling facing right: medium skin tone",
+        &["man_kneeling_right_tone3"],
+    ),
+    (
+        "🧎🏽‍♂️",
+        "man kneeling: medium skin tone",
+        &["man_kneeling_tone3"],
+    ),
+    (
+        "🧎🏽‍➡️",
+        "person kneeling facing right: medium skin tone",
+        &["person_kneeling_right_tone3"],
+    ),
+    (
+        "🧎🏽",
+        "person kneeling: medium skin tone",
+        &["kneeling_tone3", "person_kneeling_tone3"],
+    ),
+    (
+        "🧎🏾‍♀️‍➡️",
+        "woman kneeling facing right: medium-dark skin tone",
+        &["woman_kneeling_right_tone4"],
+    ),
+    (
+        "🧎🏾‍♀️",
+        "woman kneeling: medium-dark skin tone",
+        &["woman_kneeling_tone4"],
+    ),
+    (
+        "🧎🏾‍♂️‍➡️",
+        "man kneeling facing right: medium-dark skin tone",
+        &["man_kneeling_right_tone4"],
+    ),
+    (
+        "🧎🏾‍♂️",
+        "man kneeling: medium-dark skin tone",
+        &["man_kneeling_tone4"],
+    ),
+    (
+        "🧎🏾‍➡️",
+        "person kneeling facing right: medium-dark skin tone",
+        &["person_kneeling_right_tone4"],
+    ),
+    (
+        "🧎🏾",
+        "person kneeling: medium-dark skin tone",
+        &["kneeling_tone4", "person_kneeling_tone4"],
+    ),
+    (
+        "🧎🏿‍♀️‍➡️",
+        "woman kneeling facing right: dark skin tone",
+        &["woman_kneeling_right_tone5"],
+    ),
+    (
+        "🧎🏿‍♀️",
+        "woman kneeling: dark skin tone",
+        &["woman_kneeling_tone5"],
+    ),
+    (
+        "🧎🏿‍♂️‍➡️",
+        "man kneeling facing right: dark skin tone",
+        &["man_kneeling_right_tone5"],
+    ),
+    (
+        "🧎🏿‍♂️",
+        "man kneeling: dark skin tone",
+        &["man_kneeling_tone5"],
+    ),
+    (
+        "🧎🏿‍➡️",
+        "person kneeling facing right: dark skin tone",
+        &["person_kneeling_right_tone5"],
+    ),
+    (
+        "🧎🏿",
+        "person kneeling: dark skin tone",
+        &["kneeling_tone5", "person_kneeling_tone5"],
+    ),
+    (
+        "🧎‍♀️‍➡️",
+        "woman kneeling facing right",
+        &["woman_kneeling_right"],
+    ),
+    ("🧎‍♀️", "woman kneeling", &["woman_kneeling"]),
+    ("🧎‍♂️‍➡️", "man kneeling facing right", &["man_kneeling_right"]),
+    ("🧎‍♂️", "man kneeling", &["man_kneeling"]),
+    (
+        "🧎‍➡️",
+        "person kneeling facing right",
+        &["person_kneeling_right"],
+    ),
+    ("🧎", "person kneeling", &["kneeling", "person_kneeling"]),
+    ("🧏🏻‍♀️", "deaf woman: light skin tone", &["deaf_woman_tone1"]),
+    ("🧏🏻‍♂️", "deaf man: light skin tone", &["deaf_man_tone1"]),
+    ("🧏🏻", "deaf person: light skin tone", &["deaf_person_tone1"]),
+    (
+        "🧏🏼‍♀️",
+        "deaf woman: medium-light skin tone",
+        &["deaf_woman_tone2"],
+    ),
+    (
+        "🧏🏼‍♂️",
+        "deaf man: medium-light skin tone",
+        &["deaf_man_tone2"],
+    ),
+    (
+        "🧏🏼",
+        "deaf person: medium-light skin tone",
+        &["deaf_person_tone2"],
+    ),
+    ("🧏🏽‍♀️", "deaf woman: medium skin tone", &["deaf_woman_tone3"]),
+    ("🧏🏽‍♂️", "deaf man: medium skin tone", &["deaf_man_tone3"]),
+    (
+        "🧏🏽",
+        "deaf person: medium skin tone",
+        &["deaf_person_tone3"],
+    ),
+    (
+        "🧏🏾‍♀️",
+        "deaf woman: medium-dark skin tone",
+        &["deaf_woman_tone4"],
+    ),
+    ("🧏🏾‍♂️", "deaf man: medium-dark skin tone", &["deaf_man_tone4"]),
+    (
+        "🧏🏾",
+        "deaf person: medium-dark skin tone",
+        &["deaf_person_tone4"],
+    ),
+    ("🧏🏿‍♀️", "deaf woman: dark skin tone", &["deaf_woman_tone5"]),
+    ("🧏🏿‍♂️", "deaf man: dark skin tone", &["deaf_man_tone5"]),
+    ("🧏🏿", "deaf person: dark skin tone", &["deaf_person_tone5"]),
+    ("🧏‍♀️", "deaf woman", &["deaf_woman"]),
+    ("🧏‍♂️", "deaf man", &["deaf_man"]),
+    ("🧏", "deaf person", &["deaf_person"]),
+    ("🧐", "face with monocle", &["face_with_monocle"]),
+    ("🧑🏻‍🌾", "farmer: light skin tone", &["farmer_tone1"]),
+    ("🧑🏻‍🍳", "cook: light skin tone", &["cook_tone1"]),
+    (
+        "🧑🏻‍🍼",
+        "person feeding baby: light skin tone",
+        &["person_feeding_baby_tone1"],
+    ),
+    ("🧑🏻‍🎄", "Mx Claus: light skin tone", &["mx_claus_tone1"]),
+    ("🧑🏻‍🎓", "student: light skin tone", &["student_tone1"]),
+    ("🧑🏻‍🎤", "singer: light skin tone", &["singer_tone1"]),
+    ("🧑🏻‍🎨", "artist: light skin tone", &["artist_tone1"]),
+    ("🧑🏻‍🏫", "teacher: light skin tone", &["teacher_tone1"]),
+    (
+        "🧑🏻‍🏭",
+        "factory worker: light skin tone",
+        &["factory_worker_tone1"],
+    ),
+    (
+        "🧑🏻‍🐰‍🧑🏼",
+        "people with bunny ears: light skin tone, medium-light skin tone",
+        &[],
+    ),
+    (
+        "🧑🏻‍🐰‍🧑🏽",
+        "people with bunny ears: light skin tone, medium skin tone",
+        &[],
+    ),
+    (
+        "🧑🏻‍🐰‍🧑🏾",
+        "people with bunny ears: light skin tone, medium-dark skin tone",
+        &[],
+    ),
+    (
+        "🧑🏻‍🐰‍🧑🏿",
+        "people with bunny ears: light skin tone, dark skin tone",
+        &[],
+    ),
+    (
+        "🧑🏻‍💻",
+        "technologist: light skin tone",
+        &["technologist_tone1"],
+    ),
+    (
+        "🧑🏻‍💼",
+        "office worker: light skin tone",
+        &["office_worker_tone1"],
+    ),
+    ("🧑🏻‍🔧", "mechanic: light skin tone", &["mechanic_tone1"]),
+    ("🧑🏻‍🔬", "scientist: light skin tone", &["scientist_tone1"]),
+    ("🧑🏻‍🚀", "astronaut: light skin tone", &["astronaut_tone1"]),
+    ("🧑🏻‍🚒", "firefighter: light skin tone", &["firefighter_tone1"]),
+    (
+        "🧑🏻‍🤝‍🧑🏻",
+        "people holding hands: light skin tone",
+        &["people_holding_hands_tone1"],
+    ),
+    (
+        "🧑🏻‍🤝‍🧑🏼",
+        "people holding hands: light skin tone, medium-light skin tone",
+        &["people_holding_hands_tone1-2"],
+    ),
+    (
+        "🧑🏻‍🤝‍🧑🏽",
+        "people holding hands: light skin tone, medium skin tone",
+        &["people_holding_hands_tone1-3"],
+    ),
+    (
+        "🧑🏻‍🤝‍🧑🏾",
+        "people holding hands: light skin tone, medium-dark skin tone",
+        &["people_holding_hands_tone1-4"],
+    ),
+    (
+        "🧑🏻‍🤝‍🧑🏿",
+        "people holding hands: light skin tone, dark skin tone",
+        &["people_holding_hands_tone1-5"],
+    ),
+    (
+        "🧑🏻‍🦯‍➡️",
+        "person with white cane facing right: light skin tone",
+        &["person_with_white_cane_right_tone1"],
+    ),
+    (
+        "🧑🏻‍🦯",
+        "person with white cane: light skin tone",
+        &[
+            "person_with_probing_cane_tone1",
+            "person_with_white_cane_tone1",
+        ],
+    ),
+    (
+        "🧑🏻‍🦰",
+        "person: red hair: light skin tone",
+        &["red_haired_tone1"],
+    ),
+    (
+        "🧑🏻‍🦱",
+        "person: curly hair: light skin tone",
+        &["curly_haired_tone1"],
+    ),
+    ("🧑🏻‍🦲", "person: bald: light skin tone", &["bald_tone1"]),
+    (
+        "🧑🏻‍🦳",
+        "person: white hair: light skin tone",
+        &["white_haired_tone1"],
+    ),
+    (
+        "🧑🏻‍🦼‍➡️",
+        "person in motorized wheelchair facing right: light skin tone",
+        &["person_in_motorized_wheelchair_right_tone1"],
+    ),
+    (
+        "🧑🏻‍🦼",
+        "person in motorized wheelchair: light skin tone",
+        &["person_in_motorized_wheelchair_tone1"],
+    ),
+    (
+        "🧑🏻‍🦽‍➡️",
+        "person in manual wheelchair facing right: light skin tone",
+        &["person_in_manual_wheelchair_right_tone1"],
+    ),
+    (
+        "🧑🏻‍🦽",
+        "person in manual wheelchair: light skin tone",
+        &["person_in_manual_wheelchair_tone1"],
+    ),
+    ("🧑🏻‍🩰", "ballet dancers: light skin tone", &[]),
+    (
+        "🧑🏻‍🫯‍🧑🏼",
+        "people wrestling: light skin tone, medium-light skin tone",
+        &[],
+    ),
+    (
+        "🧑🏻‍🫯‍🧑🏽",
+        "people wrestling: light skin tone, medium skin tone",
+        &[],
+    ),
+    (
+        "🧑🏻‍🫯‍🧑🏾",
+        "people wrestling: light skin tone, medium-dark skin tone",
+        &[],
+    ),
+    (
+        "🧑🏻‍🫯‍🧑🏿",
+        "people wrestling: light skin tone, dark skin tone",
+        &[],
+    ),
+    (
+        "🧑🏻‍⚕️",
+        "health worker: light skin tone",
+        &["health_worker_tone1"],
+    ),
+    ("🧑🏻‍⚖️", "judge: light skin tone", &["judge_tone1"]),
+    ("🧑🏻‍✈️", "pilot: light skin tone", &["pilot_tone1"]),
+    (
+        "🧑🏻‍❤️‍💋‍🧑🏼",
+        "kiss: light skin tone, medium-light skin tone",
+        &["couple_kiss_tone1-2", "couplekiss_tone1-2"],
+    ),
+    (
+        "🧑🏻‍❤️‍💋‍🧑🏽",
+        "kiss: light skin tone, medium skin tone",
+        &["couple_kiss_tone1-3", "couplekiss_tone1-3"],
+    ),
+    (
+        "🧑🏻‍❤️‍💋‍🧑🏾",
+        "kiss: light skin tone, medium-dark skin tone",
+        &["couple_kiss_tone1-4", "couplekiss_tone1-4"],
+    ),
+    (
+        "🧑🏻‍❤️‍💋‍🧑🏿",
+        "kiss: light skin tone, dark skin tone",
+        &["couple_kiss_tone1-5", "couplekiss_tone1-5"],
+    ),
+    (
+        "🧑🏻‍❤️‍🧑🏼",
+        "couple with heart: light skin tone, medium-light skin tone",
+        &["couple_with_heart_tone1-2"],
+    ),
+    (
+        "🧑🏻‍❤️‍🧑🏽",
+        "couple with heart: light skin tone, medium skin tone",
+        &["couple_with_heart_tone1-3"],
+    ),
+    (
+        "🧑🏻‍❤️‍🧑🏾",
+        "couple with heart: light skin tone, medium-dark skin tone",
+        &["couple_with_heart_tone1-4"],
+    ),
+    (
+        "🧑🏻‍❤️‍🧑🏿",
+        "couple with heart: light skin tone, dark skin tone",
+        &["couple_with_heart_tone1-5"],
+    ),
+    ("🧑🏻", "person: light skin tone", &["adult_tone1"]),
+    ("🧑🏼‍🌾", "farmer: medium-light skin tone", &["farmer_tone2"]),
+    ("🧑🏼‍🍳", "cook: medium-light skin tone", &["cook_tone2"]),
+    (
+        "🧑🏼‍🍼",
+        "person feeding baby: medium-light skin tone",
+        &["person_feeding_baby_tone2"],
+    ),
+    (
+        "🧑🏼‍🎄",
+        "Mx Claus: medium-light skin tone",
+        &["mx_claus_tone2"],
+    ),
+    ("🧑🏼‍🎓", "student: medium-light skin tone", &["student_tone2"]),
+    ("🧑🏼‍🎤", "singer: medium-light skin tone", &["singer_tone2"]),
+    ("🧑🏼‍🎨", "artist: medium-light skin tone", &["artist_tone2"]),
+    ("🧑🏼‍🏫", "teacher: medium-light skin tone", &["teacher_tone2"]),
+    (
+        "🧑🏼‍🏭",
+        "factory worker: medium-light skin tone",
+        &["factory_worker_tone2"],
+    ),
+    (
+        "🧑🏼‍🐰‍🧑🏻",
+        "people with bunny ears: medium-light skin tone, light skin tone",
+        &[],
+    ),
+    (
+        "🧑🏼‍🐰‍🧑🏽",
+        "people with bunny ears: medium-light skin tone, medium skin tone",
+        &[],
+    ),
+    (
+        "🧑🏼‍🐰‍🧑🏾",
+        "people with bunny ears: medium-light skin tone, medium-dark skin tone",
+        &[],
+    ),
+    (
+        "🧑🏼‍🐰‍🧑🏿",
+        "people with bunny ears: medium-light skin tone, dark skin tone",
+        &[],
+    ),
+    (
+        "🧑🏼‍💻",
+        "technologist: medium-light skin tone",
+        &["technologist_tone2"],
+    ),
+    (
+        "🧑🏼‍💼",
+        "office worker: medium-light skin tone",
+        &["office_worker_tone2"],
+    ),
+    (
+        "🧑🏼‍🔧",
+        "mechanic: medium-light skin tone",
+        &["mechanic_tone2"],
+    ),
+    (
+        "🧑🏼‍🔬",
+        "scientist: medium-light skin tone",
+        &["scientist_tone2"],
+    ),
+    (
+        "🧑🏼‍🚀",
+        "astronaut: medium-light skin tone",
+        &["astronaut_tone2"],
+    ),
+    (
+        "🧑🏼‍🚒",
+        "firefighter: medium-light skin tone",
+        &["firefighter_tone2"],
+    ),
+    (
+        "🧑🏼‍🤝‍🧑🏻",
+        "people holding hands: medium-light skin tone, light skin tone",
+        &["people_holding_hands_tone2-1"],
+    ),
+    (
+        "🧑🏼‍🤝‍🧑🏼",
+        "people holding hands: medium-light skin tone",
+        &["people_holding_hands_tone2"],
+    ),
+    (
+        "🧑🏼‍🤝‍🧑🏽",
+        "people holding hands: medium-light skin tone, medium skin tone",
+        &["people_holding_hands_tone2-3"],
+    ),
+    (
+        "🧑🏼‍🤝‍🧑🏾",
+        "people holding hands: medium-light skin tone, medium-dark skin tone",
+        &["people_holding_hands_tone2-4"],
+    ),
+    (
+        "🧑🏼‍🤝‍🧑🏿",
+        "people holding hands: medium-light skin tone, dark skin tone",
+        &["people_holding_hands_tone2-5"],
+    ),
+    (
+        "🧑🏼‍🦯‍➡️",
+        "person with white cane facing right: medium-light skin tone",
+        &["person_with_white_cane_right_tone2"],
+    ),
+    (
+        "🧑🏼‍🦯",
+        "person with white cane: medium-light skin tone",
+        &[
+            "person_with_probing_cane_tone2",
+            "person_with_white_cane_tone2",
+        ],
+    ),
+    (
+        "🧑🏼‍🦰",
+        "person: red hair: medium-light skin tone",
+        &["red_haired_tone2"],
+    ),
+    (
+        "🧑🏼‍🦱",
+        "person: curly hair: medium-light skin tone",
+        &["curly_haired_tone2"],
+    ),
+    (
+        "🧑🏼‍🦲",
+        "person: bald: medium-light skin tone",
+        &["bald_tone2"],
+    ),
+    (
+        "🧑🏼‍🦳",
+        "person: white hair: medium-light skin tone",
+        &["white_haired_tone2"],
+    ),
+    (
+        "🧑🏼‍🦼‍➡️",
+        "person in motorized wheelchair facing right: medium-light skin tone",
+        &["person_in_motorized_wheelchair_right_tone2"],
+    ),
+    (
+        "🧑🏼‍🦼",
+        "person in motorized wheelchair: medium-light skin tone",
+        &["person_in_motorized_wheelchair_tone2"],
+    ),
+    (
+        "🧑🏼‍🦽‍➡️",
+        "person in manual wheelchair facing right: medium-light skin tone",
+        &["person_in_manual_wheelchair_right_tone2"],
+    ),
+    (
+        "🧑🏼‍🦽",
+        "person in manual wheelchair: medium-light skin tone",
+        &["person_in_manual_wheelchair_tone2"],
+    ),
+    ("🧑🏼‍🩰", "ballet dancers: medium-light skin tone", &[]),
+    (
+        "🧑🏼‍🫯‍🧑🏻",
+        "people wrestling: medium-light skin tone, light skin tone",
+        &[],
+    ),
+    (
+        "🧑🏼‍🫯‍🧑🏽",
+        "people wrestling: medium-light skin tone, medium skin tone",
+        &[],
+    ),
+    (
+        "🧑🏼‍🫯‍🧑🏾",
+        "people wrestling: medium-light skin tone, medium-dark skin tone",
+        &[],
+    ),
+    (
+        "🧑🏼‍🫯‍🧑🏿",
+        "people wrestling: medium-light skin tone, dark skin tone",
+        &[],
+    ),
+    (
+        "🧑🏼‍⚕️",
+        "health worker: medium-light skin tone",
+        &["health_worker_tone2"],
+    ),
+    ("🧑🏼‍⚖️", "judge: medium-light skin tone", &["judge_tone2"]),
+    ("🧑🏼‍✈️", "pilot: medium-light skin tone", &["pilot_tone2"]),
+    (
+        "🧑🏼‍❤️‍💋‍🧑🏻",
+        "kiss: medium-light skin tone, light skin tone",
+        &["couple_kiss_tone2-1", "couplekiss_tone2-1"],
+    ),
+    (
+        "🧑🏼‍❤️‍💋‍🧑🏽",
+        "kiss: medium-light skin tone, medium skin tone",
+        &["couple_kiss_tone2-3", "couplekiss_tone2-3"],
+    ),
+    (
+        "🧑🏼‍❤️‍💋‍🧑🏾",
+        "kiss: medium-light skin tone, medium-dark skin tone",
+        &["couple_kiss_tone2-4", "couplekiss_tone2-4"],
+    ),
+    (
+        "🧑🏼‍❤️‍💋‍🧑🏿",
+        "kiss: medium-light skin tone, dark skin tone",
+        &["couple_kiss_tone2-5", "couplekiss_tone2-5"],
+    ),
+    (
+        "🧑🏼‍❤️‍🧑🏻",
+        "couple with heart: medium-light skin tone, light skin tone",
+        &["couple_with_heart_tone2-1"],
+    ),
+    (
+        "🧑🏼‍❤️‍🧑🏽",
+        "couple with heart: medium-light skin tone, medium skin tone",
+        &["couple_with_heart_tone2-3"],
+    ),
+    (
+        "🧑🏼‍❤️‍🧑🏾",
+        "couple with heart: medium-light skin tone, medium-dark skin tone",
+        &["couple_with_heart_tone2-4"],
+    ),
+    (
+        "🧑🏼‍❤️‍🧑🏿",
+        "couple with heart: medium-light skin tone, dark skin tone",
+        &["couple_with_heart_tone2-5"],
+    ),
+    ("🧑🏼", "person: medium-light skin tone", &["adult_tone2"]),
+    ("🧑🏽‍🌾", "farmer: medium skin tone", &["farmer_tone3"]),
+    ("🧑🏽‍🍳", "cook: medium skin tone", &["cook_tone3"]),
+    (
+        "🧑🏽‍🍼",
+        "person feeding baby: medium skin tone",
+        &["person_feeding_baby_tone3"],
+    ),
+    ("🧑🏽‍🎄", "Mx Claus: medium skin tone", &["mx_claus_tone3"]),
+    ("🧑🏽‍🎓", "student: medium skin tone", &["student_tone3"]),
+    ("🧑🏽‍🎤", "singer: medium skin tone", &["singer_tone3"]),
+    ("🧑🏽‍🎨", "artist: medium skin tone", &["artist_tone3"]),
+    ("🧑🏽‍🏫", "teacher: medium skin tone", &["teacher_tone3"]),
+    (
+        "🧑🏽‍🏭",
+        "factory worker: medium skin tone",
+        &["factory_worker_tone3"],
+    ),
+    (
+        "🧑🏽‍🐰‍🧑🏻",
+        "people with bunny ears: medium skin tone, light skin tone",
+        &[],
+    ),
+    (
+        "🧑🏽‍🐰‍🧑🏼",
+        "people with bunny ears: medium skin tone, medium-light skin tone",
+        &[],
+    ),
+    (
+        "🧑🏽‍🐰‍🧑🏾",
+        "people with bunny ears: medium skin tone, medium-dark skin tone",
+        &[],
+    ),
+    (
+        "🧑🏽‍🐰‍🧑🏿",
+        "people with bunny ears: medium skin tone, dark skin tone",
+        &[],
+    ),
+    (
+        "🧑🏽‍💻",
+        "technologist: medium skin tone",
+        &["technologist_tone3"],
+    ),
+    (
+        "🧑🏽‍💼",
+        "office worker: medium skin tone",
+        &["office_worker_tone3"],
+    ),
+    ("🧑🏽‍🔧", "mechanic: medium skin tone", &["mechanic_tone3"]),
+    ("🧑🏽‍🔬", "scientist: medium skin tone", &["scientist_tone3"]),
+    ("🧑🏽‍🚀", "astronaut: medium skin tone", &["astronaut_tone3"]),
+    (
+        "🧑🏽‍🚒",
+        "firefighter: medium skin tone",
+        &["firefighter_tone3"],
+    ),
+    (
+        "🧑🏽‍🤝‍🧑🏻",
+        "people holding hands: medium skin tone, light skin tone",
+        &["people_holding_hands_tone3-1"],
+    ),
+    (
+        "🧑🏽‍🤝‍🧑🏼",
+        "people holding hands: medium skin tone, medium-light skin tone",
+        &["people_holding_hands_tone3-2"],
+    ),
+    (
+        "🧑🏽‍🤝‍🧑🏽",
+        "people holding hands: medium skin tone",
+        &["people_holding_hands_tone3"],
+    ),
+    (
+        "🧑🏽‍🤝‍🧑🏾",
+        "people holding hands: medium skin tone, medium-dark skin tone",
+        &["people_holding_hands_tone3-4"],
+    ),
+    (
+        "🧑🏽‍🤝‍🧑🏿",
+        "people holding hands: medium skin tone, dark skin tone",
+        &["people_holding_hands_tone3-5"],
+    ),
+    (
+        "🧑🏽‍🦯‍➡️",
+        "person with white cane facing right: medium skin tone",
+        &["person_with_white_cane_right_tone3"],
+    ),
+    (
+        "🧑🏽‍🦯",
+        "person with white cane: medium skin tone",
+        &[
+            "person_with_probing_cane_tone3",
+            "person_with_white_cane_tone3",
+        ],
+    ),
+    (
+        "🧑🏽‍🦰",
+        "person: red hair: medium skin tone",
+        &["red_haired_tone3"],
+    ),
+    (
+        "🧑🏽‍🦱",
+        "person: curly hair: medium skin tone",
+        &["curly_haired_tone3"],
+    ),
+    ("🧑🏽‍🦲", "person: bald: medium skin tone", &["bald_tone3"]),
+    (
+        "🧑🏽‍🦳",
+        "person: white hair: medium skin tone",
+        &["white_haired_tone3"],
+    ),
+    (
+        "🧑🏽‍🦼‍➡️",
+        "person in motorized wheelchair facing right: medium skin tone",
+        &["person_in_motorized_wheelchair_right_tone3"],
+    ),
+    (
+        "🧑🏽‍🦼",
+        "person in motorized wheelchair: medium skin tone",
+        &["person_in_motorized_wheelchair_tone3"],
+    ),
+    (
+        "🧑🏽‍🦽‍➡️",
+        "person in manual wheelchair facing right: medium skin tone",
+        &["person_in_manual_wheelchair_right_tone3"],
+    ),
+    (
+        "🧑🏽‍🦽",
+        "person in manual wheelchair: medium skin tone",
+        &["person_in_manual_wheelchair_tone3"],
+    ),
+    ("🧑🏽‍🩰", "ballet dancers: medium skin tone", &[]),
+    (
+        "🧑🏽‍🫯‍🧑🏻",
+        "people wrestling: medium skin tone, light skin tone",
+        &[],
+    ),
+    (
+        "🧑🏽‍🫯‍🧑🏼",
+        "people wrestling: medium skin tone, medium-light skin tone",
+        &[],
+    ),
+    (
+        "🧑🏽‍🫯‍🧑🏾",
+        "people wrestling: medium skin tone, medium-dark skin tone",
+        &[],
+    ),
+    (
+        "🧑🏽‍🫯‍🧑🏿",
+        "people wrestling: medium skin tone, dark skin tone",
+        &[],
+    ),
+    (
+        "🧑🏽‍⚕️",
+        "health worker: medium skin tone",
+        &["health_worker_tone3"],
+    ),
+    ("🧑🏽‍⚖️", "judge: medium skin tone", &["judge_tone3"]),
+    ("🧑🏽‍✈️", "pilot: medium skin tone", &["pilot_tone3"]),
+    (
+        "🧑🏽‍❤️‍💋‍🧑🏻",
+        "kiss: medium skin tone, light skin tone",
+        &["couple_kiss_tone3-1", "couplekiss_tone3-1"],
+    ),
+    (
+        "🧑🏽‍❤️‍💋‍🧑🏼",
+        "kiss: medium skin tone, medium-light skin tone",
+        &["couple_kiss_tone3-2", "couplekiss_tone3-2"],
+    ),
+    (
+        "🧑🏽‍❤️‍💋‍🧑🏾",
+        "kiss: medium skin tone, medium-dark skin tone",
+        &["couple_kiss_tone3-4", "couplekiss_tone3-4"],
+    ),
+    (
+        "🧑🏽‍❤️‍💋‍🧑🏿",
+        "kiss: medium skin tone, dark skin tone",
+        &["couple_kiss_tone3-5", "couplekiss_tone3-5"],
+    ),
+    (
+        "🧑🏽‍❤️‍🧑🏻",
+        "couple with heart: medium skin tone, light skin tone",
+        &["couple_with_heart_tone3-1"],
+    ),
+    (
+        "🧑🏽‍❤️‍🧑🏼",
+        "couple with heart: medium skin tone, medium-light skin tone",
+        &["couple_with_heart_tone3-2"],
+    ),
+    (
+        "🧑🏽‍❤️‍🧑🏾",
+        "couple with heart: medium skin tone, medium-dark skin tone",
+        &["couple_with_heart_tone3-4"],
+    ),
+    (
+        "🧑🏽‍❤️‍🧑🏿",
+        "couple with heart: medium skin tone, dark skin tone",
+        &["couple_with_heart_tone3-5"],
+    ),
+    ("🧑🏽", "person: medium skin tone", &["adult_tone3"]),
+    ("🧑🏾‍🌾", "farmer: medium-dark skin tone", &["farmer_tone4"]),
+    ("🧑🏾‍🍳", "cook: medium-dark skin tone", &["cook_tone4"]),
+    (
+        "🧑🏾‍🍼",
+        "person feeding baby: medium-dark skin tone",
+        &["person_feeding_baby_tone4"],
+    ),
+    ("🧑🏾‍🎄", "Mx Claus: medium-dark skin tone", &["mx_claus_tone4"]),
+    ("🧑🏾‍🎓", "student: medium-dark skin tone", &["student_tone4"]),
+    ("🧑🏾‍🎤", "singer: medium-dark skin tone", &["singer_tone4"]),
+    ("🧑🏾‍🎨", "artist: medium-dark skin tone", &["artist_tone4"]),
+    ("🧑🏾‍🏫", "teacher: medium-dark skin tone", &["teacher_tone4"]),
+    (
+        "🧑🏾‍🏭",
+        "factory worker: medium-dark skin tone",
+        &["factory_worker_tone4"],
+    ),
+    (
+        "🧑🏾‍🐰‍🧑🏻",
+        "people with bunny ears: medium-dark skin tone, light skin tone",
+        &[],
+    ),
+    (
+        "🧑🏾‍🐰‍🧑🏼",
+        "people with bunny ears: medium-dark skin tone, medium-light skin tone",
+        &[],
+    ),
+    (
+        "🧑🏾‍🐰‍🧑🏽",
+        "people with bunny ears: medium-dark skin tone, medium skin tone",
+        &[],
+    ),
+    (
+        "🧑🏾‍🐰‍🧑🏿",
+        "people with bunny ears: medium-dark skin tone, dark skin tone",
+        &[],
+    ),
+    (
+        "🧑🏾‍💻",
+        "technologist: medium-dark skin tone",
+        &["technologist_tone4"],
+    ),
+    (
+        "🧑🏾‍💼",
+        "office worker: medium-dark skin tone",
+        &["office_worker_tone4"],
+    ),
+    ("🧑🏾‍🔧", "mechanic: medium-dark skin tone", &["mechanic_tone4"]),
+    (
+        "🧑🏾‍🔬",
+        "scientist: medium-dark skin tone",
+        &["scientist_tone4"],
+    ),
+    (
+        "🧑🏾‍🚀",
+        "astronaut: medium-dark skin tone",
+        &["astronaut_tone4"],
+    ),
+    (
+        "🧑🏾‍🚒",
+        "firefighter: medium-dark skin tone",
+        &["firefighter_tone4"],
+    ),
+    (
+        "🧑🏾‍🤝‍🧑🏻",
+        "people holding hands: medium-dark skin tone, light skin tone",
+        &["people_holding_hands_tone4-1"],
+    ),
+    (
+        "🧑🏾‍🤝‍🧑🏼",
+        "people holding hands: medium-dark skin tone, medium-light skin tone",
+        &["people_holding_hands_tone4-2"],
+    ),
+    (
+        "🧑🏾‍🤝‍🧑🏽",
+        "people holding hands: medium-dark skin tone, medium skin tone",
+        &["people_holding_hands_tone4-3"],
+    ),
+    (
+        "🧑🏾‍🤝‍🧑🏾",
+        "people holding hands: medium-dark skin tone",
+        &["people_holding_hands_tone4"],
+    ),
+    (
+        "🧑🏾‍🤝‍🧑🏿",
+        "people holding hands: medium-dark skin tone, dark skin tone",
+        &["people_holding_hands_tone4-5"],
+    ),
+    (
+        "🧑🏾‍🦯‍➡️",
+        "person with white cane facing right: medium-dark skin tone",
+        &["person_with_white_cane_right_tone4"],
+    ),
+    (
+        "🧑🏾‍🦯",
+        "person with white cane: medium-dark skin tone",
+        &[
+            "person_with_probing_cane_tone4",
+            "person_with_white_cane_tone4",
+        ],
+    ),
+    (
+        "🧑🏾‍🦰",
+        "person: red hair: medium-dark skin tone",
+        &["red_haired_tone4"],
+    ),
+    (
+        "🧑🏾‍🦱",
+        "person: curly hair: medium-dark skin tone",
+        &["curly_haired_tone4"],
+    ),
+    ("🧑🏾‍🦲", "person: bald: medium-dark skin tone", &["bald_tone4"]),
+    (
+        "🧑🏾‍🦳",
+        "person: white hair: medium-dark skin tone",
+        &["white_haired_tone4"],
+    ),
+    (
+        "🧑🏾‍🦼‍➡️",
+        "person in motorized wheelchair facing right: medium-dark skin tone",
+        &["person_in_motorized_wheelchair_right_tone4"],
+    ),
+    (
+        "🧑🏾‍🦼",
+        "person in motorized wheelchair: medium-dark skin tone",
+        &["person_in_motorized_wheelchair_tone4"],
+    ),
+    (
+        "🧑🏾‍🦽‍➡️",
+        "person in manual wheelchair facing right: medium-dark skin tone",
+        &["person_in_manual_wheelchair_right_tone4"],
+    ),
+    (
+        "🧑🏾‍🦽",
+        "person in manual wheelchair: medium-dark skin tone",
+        &["person_in_manual_wheelchair_tone4"],
+    ),
+    ("🧑🏾‍🩰", "ballet dancers: medium-dark skin tone", &[]),
+    (
+        "🧑🏾‍🫯‍🧑🏻",
+        "people wrestling: medium-dark skin tone, light skin tone",
+        &[],
+    ),
+    (
+        "🧑🏾‍🫯‍🧑🏼",
+        "people wrestling: medium-dark skin tone, medium-light skin tone",
+        &[],
+    ),
+    (
+        "🧑🏾‍🫯‍🧑🏽",
+        "people wrestling: medium-dark skin tone, medium skin tone",
+        &[],
+    ),
+    (
+        "🧑🏾‍🫯‍🧑🏿",
+        "people wrestling: medium-dark skin tone, dark skin tone",
+        &[],
+    ),
+    (
+        "🧑🏾‍⚕️",
+        "health worker: medium-dark skin tone",
+        &["health_worker_tone4"],
+    ),
+    ("🧑🏾‍⚖️", "judge: medium-dark skin tone", &["judge_tone4"]),
+    ("🧑🏾‍✈️", "pilot: medium-dark skin tone", &["pilot_tone4"]),
+    (
+        "🧑🏾‍❤️‍💋‍🧑🏻",
+        "kiss: medium-dark skin tone, light skin tone",
+        &["couple_kiss_tone4-1", "couplekiss_tone4-1"],
+    ),
+    (
+        "🧑🏾‍❤️‍💋‍🧑🏼",
+        "kiss: medium-dark skin tone, medium-light skin tone",
+        &["couple_kiss_tone4-2", "couplekiss_tone4-2"],
+    ),
+    (
+        "🧑🏾‍❤️‍💋‍🧑🏽",
+        "kiss: medium-dark skin tone, medium skin tone",
+        &["couple_kiss_tone4-3", "couplekiss_tone4-3"],
+    ),
+    (
+        "🧑🏾‍❤️‍💋‍🧑🏿",
+        "kiss: medium-dark skin tone, dark skin tone",
+        &["couple_kiss_tone4-5", "couplekiss_tone4-5"],
+    ),
+    (
+        "🧑🏾‍❤️‍🧑🏻",
+        "couple with heart: medium-dark skin tone, light skin tone",
+        &["couple_with_heart_tone4-1"],
+    ),
+    (
+        "🧑🏾‍❤️‍🧑🏼",
+        "couple with heart: medium-dark skin tone, medium-light skin tone",
+        &["couple_with_heart_tone4-2"],
+    ),
+    (
+        "🧑🏾‍❤️‍🧑🏽",
+        "couple with heart: medium-dark skin tone, medium skin tone",
+        &["couple_with_heart_tone4-3"],
+    ),
+    (
+        "🧑🏾‍❤️‍🧑🏿",
+        "couple with heart: medium-dark skin tone, dark skin tone",
+        &["couple_with_heart_tone4-5"],
+    ),
+    ("🧑🏾", "person: medium-dark skin tone", &["adult_tone4"]),
+    ("🧑🏿‍🌾", "farmer: dark skin tone", &["farmer_tone5"]),
+    ("🧑🏿‍🍳", "cook: dark skin tone", &["cook_tone5"]),
+    (
+        "🧑🏿‍🍼",
+        "person feeding baby: dark skin tone",
+        &["person_feeding_baby_tone5"],
+    ),
+    ("🧑🏿‍🎄", "Mx Claus: dark skin tone", &["mx_claus_tone5"]),
+    ("🧑🏿‍🎓", "student: dark skin tone", &["student_tone5"]),
+    ("🧑🏿‍🎤", "singer: dark skin tone", &["singer_tone5"]),
+    ("🧑🏿‍🎨", "artist: dark skin tone", &["artist_tone5"]),
+    ("🧑🏿‍🏫", "teacher: dark skin tone", &["teacher_tone5"]),
+    (
+        "🧑🏿‍🏭",
+        "factory worker: dark skin tone",
+        &["factory_worker_tone5"],
+    ),
+    (
+        "🧑🏿‍🐰‍🧑🏻",
+        "people with bunny ears: dark skin tone, light skin tone",
+        &[],
+    ),
+    (
+        "🧑🏿‍🐰‍🧑🏼",
+        "people with bunny ears: dark skin tone, medium-light skin tone",
+        &[],
+    ),
+    (
+        "🧑🏿‍🐰‍🧑🏽",
+        "people with bunny ears: dark skin tone, medium skin tone",
+        &[],
+    ),
+    (
+        "🧑🏿‍🐰‍🧑🏾",
+        "people with bunny ears: dark skin tone, medium-dark skin tone",
+        &[],
+    ),
+    (
+        "🧑🏿‍💻",
+        "technologist: dark skin tone",
+        &["technologist_tone5"],
+    ),
+    (
+        "🧑🏿‍💼",
+        "office worker: dark skin tone",
+        &["office_worker_tone5"],
+    ),
+    ("🧑🏿‍🔧", "mechanic: dark skin tone", &["mechanic_tone5"]),
+    ("🧑🏿‍🔬", "scientist: dark skin tone", &["scientist_tone5"]),
+    ("🧑🏿‍🚀", "astronaut: dark skin tone", &["astronaut_tone5"]),
+    ("🧑🏿‍🚒", "firefighter: dark skin tone", &["firefighter_tone5"]),
+    (
+        "🧑🏿‍🤝‍🧑🏻",
+        "people holding hands: dark skin tone, light skin tone",
+        &["people_holding_hands_tone5-1"],
+    ),
+    (
+        "🧑🏿‍🤝‍🧑🏼",
+        "people holding hands: dark skin tone, medium-light skin tone",
+        &["people_holding_hands_tone5-2"],
+    ),
+    (
+        "🧑🏿‍🤝‍🧑🏽",
+        "people holding hands: dark skin tone, medium skin tone",
+        &["people_holding_hands_tone5-3"],
+    ),
+    (
+        "🧑🏿‍🤝‍🧑🏾",
+        "people holding hands: dark skin tone, medium-dark skin tone",
+        &["people_holding_hands_tone5-4"],
+    ),
+    (
+        "🧑🏿‍🤝‍🧑🏿",
+        "people holding hands: dark skin tone",
+        &["people_holding_hands_tone5"],
+    ),
+    (
+        "🧑🏿‍🦯‍➡️",
+        "person with white cane facing right: dark skin tone",
+        &["person_with_white_cane_right_tone5"],
+    ),
+    (
+        "🧑🏿‍🦯",
+        "person with white cane: dark skin tone",
+        &[
+            "person_with_probing_cane_tone5",
+            "person_with_white_cane_tone5",
+        ],
+    ),
+    (
+        "🧑🏿‍🦰",
+        "person: red hair: dark skin tone",
+        &["red_haired_tone5"],
+    ),
+    (
+        "🧑🏿‍🦱",
+        "person: curly hair: dark skin tone",
+        &["curly_haired_tone5"],
+    ),
+    ("🧑🏿‍🦲", "person: bald: dark skin tone", &["bald_tone5"]),
+    (
+        "🧑🏿‍🦳",
+        "person: white hair: dark skin tone",
+        &["white_haired_tone5"],
+    ),
+    (
+        "🧑🏿‍🦼‍➡️",
+        "person in motorized wheelchair facing right: dark skin tone",
+        &["person_in_motorized_wheelchair_right_tone5"],
+    ),
+    (
+        "🧑🏿‍🦼",
+        "person in motorized wheelchair: dark skin tone",
+        &["person_in_motorized_wheelchair_tone5"],
+    ),
+    (
+        "🧑🏿‍🦽‍➡️",
+        "person in manual wheelchair facing right: dark skin tone",
+        &["person_in_manual_wheelchair_right_tone5"],
+    ),
+    (
+        "🧑🏿‍🦽",
+        "person in manual wheelchair: dark skin tone",
+        &["person_in_manual_wheelchair_tone5"],
+    ),
+    ("🧑🏿‍🩰", "ballet dancers: dark skin tone", &[]),
+    (
+        "🧑🏿‍🫯‍🧑🏻",
+        "people wrestling: dark skin tone, light skin tone",
+        &[],
+    ),
+    (
+        "🧑🏿‍🫯‍🧑🏼",
+        "people wrestling: dark skin tone, medium-light skin tone",
+        &[],
+    ),
+    (
+        "🧑🏿‍🫯‍🧑🏽",
+        "people wrestling: dark skin tone, medium skin tone",
+        &[],
+    ),
+    (
+        "🧑🏿‍🫯‍🧑🏾",
+        "people wrestling: dark skin tone, medium-dark skin tone",
+        &[],
+    ),
+    (
+        "🧑🏿‍⚕️",
+        "health worker: dark skin tone",
+        &["health_worker_tone5"],
+    ),
+    ("🧑🏿‍⚖️", "judge: dark skin tone", &["judge_tone5"]),
+    ("🧑🏿‍✈️", "pilot: dark skin tone", &["pilot_tone5"]),
+    (
+        "🧑🏿‍❤️‍💋‍🧑🏻",
+        "kiss: dark skin tone, light skin tone",
+        &["couple_kiss_tone5-1", "couplekiss_tone5-1"],
+    ),
+    (
+        "🧑🏿‍❤️‍💋‍🧑🏼",
+        "kiss: dark skin tone, medium-light skin tone",
+        &["couple_kiss_tone5-2", "couplekiss_tone5-2"],
+    ),
+    (
+        "🧑🏿‍❤️‍💋‍🧑🏽",
+        "kiss: dark skin tone, medium skin tone",
+        &["couple_kiss_tone5-3", "couplekiss_tone5-3"],
+    ),
+    (
+        "🧑🏿‍❤️‍💋‍🧑🏾",
+        "kiss: dark skin tone, medium-dark skin tone",
+        &["couple_kiss_tone5-4", "couplekiss_tone5-4"],
+    ),
+    (
+        "🧑🏿‍❤️‍🧑🏻",
+        "couple with heart: dark skin tone, light skin tone",
+        &["couple_with_heart_tone5-1"],
+    ),
+    (
+        "🧑🏿‍❤️‍🧑🏼",
+        "couple with heart: dark skin tone, medium-light skin tone",
+        &["couple_with_heart_tone5-2"],
+    ),
+    (
+        "🧑🏿‍❤️‍🧑🏽",
+        "couple with heart: dark skin tone, medium skin tone",
+        &["couple_with_heart_tone5-3"],
+    ),
+    (
+        "🧑🏿‍❤️‍🧑🏾",
+        "couple with heart: dark skin tone, medium-dark skin tone",
+        &["couple_with_heart_tone5-4"],
+    ),
+    ("🧑🏿", "person: dark skin tone", &["adult_tone5"]),
+    ("🧑‍🌾", "farmer", &["farmer"]),
+    ("🧑‍🍳", "cook", &["cook"]),
+    ("🧑‍🍼", "person feeding baby", &["person_feeding_baby"]),
+    ("🧑‍🎄", "Mx Claus", &["mx_claus"]),
+    ("🧑‍🎓", "student", &["student"]),
+    ("🧑‍🎤", "singer", &["singer"]),
+    ("🧑‍🎨", "artist", &["artist"]),
+    ("🧑‍🏫", "teacher", &["teacher"]),
+    ("🧑‍🏭", "factory worker", &["factory_worker"]),
+    ("🧑‍💻", "technologist", &["technologist"]),
+    ("🧑‍💼", "office worker", &["office_worker"]),
+    ("🧑‍🔧", "mechanic", &["mechanic"]),
+    ("🧑‍🔬", "scientist", &["scientist"]),
+    ("🧑‍🚀", "astronaut", &["astronaut"]),
+    ("🧑‍🚒", "firefighter", &["firefighter"]),
+    ("🧑‍🤝‍🧑", "people holding hands", &["people_holding_hands"]),
+    (
+        "🧑‍🦯‍➡️",
+        "person with white cane facing right",
+        &["person_with_white_cane_right"],
+    ),
+    (
+        "🧑‍🦯",
+        "person with white cane",
+        &["person_with_probing_cane", "person_with_white_cane"],
+    ),
+    ("🧑‍🦰", "person: red hair", &["red_haired"]),
+    ("🧑‍🦱", "person: curly hair", &["curly_haired"]),
+    ("🧑‍🦲", "person: bald", &["bald"]),
+    ("🧑‍🦳", "person: white hair", &["white_haired"]),
+    (
+        "🧑‍🦼‍➡️",
+        "person in motorized wheelchair facing right",
+        &["person_in_motorized_wheelchair_right"],
+    ),
+    (
+        "🧑‍🦼",
+        "person in motorized wheelchair",
+        &["person_in_motorized_wheelchair"],
+    ),
+    (
+        "🧑‍🦽‍➡️",
+        "person in manual wheelchair facing right",
+        &["person_in_manual_wheelchair_right"],
+    ),
+    (
+        "🧑‍🦽",
+        "person in manual wheelchair",
+        &["person_in_manual_wheelchair"],
+    ),
+    ("🧑‍🧑‍🧒‍🧒", "family: adult, adult, child, child", &["family_aacc"]),
+    ("🧑‍🧑‍🧒", "family: adult, adult, child", &["family_aac"]),
+    ("🧑‍🧒‍🧒", "family: adult, child, child", &["family_acc"]),
+    ("🧑‍🧒", "family: adult, child", &["family_ac"]),
+    ("🧑‍🩰", "ballet dancers", &[]),
+    ("🧑‍⚕️", "health worker", &["health_worker"]),
+    ("🧑‍⚖️", "judge", &["judge"]),
+    ("🧑‍✈️", "pilot", &["pilot"]),
+    ("🧑", "person", &["adult"]),
+    ("🧒🏻", "child: light skin tone", &["child_tone1"]),
+    ("🧒🏼", "child: medium-light skin tone", &["child_tone2"]),
+    ("🧒🏽", "child: medium skin tone", &["child_tone3"]),
+    ("🧒🏾", "child: medium-dark skin tone", &["child_tone4"]),
+    ("🧒🏿", "child: dark skin tone", &["child_tone5"]),
+    ("🧒", "child", &["child"]),
+    (
+        "🧓🏻",
+        "older person: light skin tone",
+        &["older_adult_tone1"],
+    ),
+    (
+        "🧓🏼",
+        "older person: medium-light skin tone",
+        &["older_adult_tone2"],
+    ),
+    (
+        "🧓🏽",
+        "older person: medium skin tone",
+        &["older_adult_tone3"],
+    ),
+    (
+        "🧓🏾",
+        "older person: medium-dark skin tone",
+        &["older_adult_tone4"],
+    ),
+    ("🧓🏿", "older person: dark skin tone", &["older_adult_tone5"]),
+    ("🧓", "older person", &["older_adult"]),
+    (
+        "🧔🏻‍♀️",
+        "woman: beard: light skin tone",
+        &["woman_bearded_tone1"],
+    ),
+    ("🧔🏻‍♂️", "man: beard: light skin tone", &["man_bearded_tone1"]),
+    (
+        "🧔🏻",
+        "person: beard: light skin tone",
+        &["person_bearded_tone1"],
+    ),
+    (
+        "🧔🏼‍♀️",
+        "woman: beard: medium-light skin tone",
+        &["woman_bearded_tone2"],
+    ),
+    (
+        "🧔🏼‍♂️",
+        "man: beard: medium-light skin tone",
+        &["man_bearded_tone2"],
+    ),
+    (
+        "🧔🏼",
+        "person: beard: medium-light skin tone",
+        &["person_bearded_tone2"],
+    ),
+    (
+        "🧔🏽‍♀️",
+        "woman: beard: medium skin tone",
+        &["woman_bearded_tone3"],
+    ),
+    ("🧔🏽‍♂️", "man: beard: medium skin tone", &["man_bearded_tone3"]),
+    (
+        "🧔🏽",
+        "person: beard: medium skin tone",
+        &["person_bearded_tone3"],
+    ),
+    (
+        "🧔🏾‍♀️",
+        "woman: beard: medium-dark skin tone",
+        &["woman_bearded_tone4"],
+    ),
+    (
+        "🧔🏾‍♂️",
+        "man: beard: medium-dark skin tone",
+        &["man_bearded_tone4"],
+    ),
+    (
+        "🧔🏾",
+        "person: beard: medium-dark skin tone",
+        &["person_bearded_tone4"],
+    ),
+    (
+        "🧔🏿‍♀️",
+        "woman: beard: dark skin tone",
+        &["woman_bearded_tone5"],
+    ),
+    ("🧔🏿‍♂️", "man: beard: dark skin tone", &["man_bearded_tone5"]),
+    (
+        "🧔🏿",
+        "person: beard: dark skin tone",
+        &["person_bearded_tone5"],
+    ),
+    ("🧔‍♀️", "woman: beard", &["woman_bearded"]),
+    ("🧔‍♂️", "man: beard", &["man_bearded"]),
+    ("🧔", "person: beard", &["person_bearded"]),
+    (
+        "🧕🏻",
+        "woman with headscarf: light skin tone",
+        &["woman_with_headscarf_tone1"],
+    ),
+    (
+        "🧕🏼",
+        "woman with headscarf: medium-light skin tone",
+        &["woman_with_headscarf_tone2"],
+    ),
+    (
+        "🧕🏽",
+        "woman with headscarf: medium skin tone",
+        &["woman_with_headscarf_tone3"],
+    ),
+    (
+        "🧕🏾",
+        "woman with headscarf: medium-dark skin tone",
+        &["woman_with_headscarf_tone4"],
+    ),
+    (
+        "🧕🏿",
+        "woman with headscarf: dark skin tone",
+        &["woman_with_headscarf_tone5"],
+    ),
+    ("🧕", "woman with headscarf", &["woman_with_headscarf"]),
+    (
+        "🧖🏻‍♀️",
+        "woman in steamy room: light skin tone",
+        &["woman_in_steamy_room_tone1"],
+    ),
+    (
+        "🧖🏻‍♂️",
+        "man in steamy room: light skin tone",
+        &["man_in_steamy_room_tone1"],
+    ),
+    (
+        "🧖🏻",
+        "person in steamy room: light skin tone",
+        &["person_in_steamy_room_tone1"],
+    ),
+    (
+        "🧖🏼‍♀️",
+        "woman in steamy room: medium-light skin tone",
+        &["woman_in_steamy_room_tone2"],
+    ),
+    (
+        "🧖🏼‍♂️",
+        "man in steamy room: medium-light skin tone",
+        &["man_in_steamy_room_tone2"],
+    ),
+    (
+        "🧖🏼",
+        "person in steamy room: medium-light skin tone",
+        &["person_in_steamy_room_tone2"],
+    ),
+    (
+        "🧖🏽‍♀️",
+        "woman in steamy room: medium skin tone",
+        &["woman_in_steamy_room_tone3"],
+    ),
+    (
+        "🧖🏽‍♂️",
+        "man in steamy room: medium skin tone",
+        &["man_in_steamy_room_tone3"],
+    ),
+    (
+        "🧖🏽",
+        "person in steamy room: medium skin tone",
+        &["person_in_steamy_room_tone3"],
+    ),
+    (
+        "🧖🏾‍♀️",
+        "woman in steamy room: medium-dark skin tone",
+        &["woman_in_steamy_room_tone4"],
+    ),
+    (
+        "🧖🏾‍♂️",
+        "man in steamy room: medium-dark skin tone",
+        &["man_in_steamy_room_tone4"],
+    ),
+    (
+        "🧖🏾",
+        "person in steamy room: medium-dark skin tone",
+        &["person_in_steamy_room_tone4"],
+    ),
+    (
+        "🧖🏿‍♀️",
+        "woman in steamy room: dark skin tone",
+        &["woman_in_steamy_room_tone5"],
+    ),
+    (
+        "🧖🏿‍♂️",
+        "man in steamy room: dark skin tone",
+        &["man_in_steamy_room_tone5"],
+    ),
+    (
+        "🧖🏿",
+        "person in steamy room: dark skin tone",
+        &["person_in_steamy_room_tone5"],
+    ),
+    ("🧖‍♀️", "woman in steamy room", &["woman_in_steamy_room"]),
+    ("🧖‍♂️", "man in steamy room", &["man_in_steamy_room"]),
+    ("🧖", "person in steamy room", &["person_in_steamy_room"]),
+    (
+        "🧗🏻‍♀️",
+        "woman climbing: light skin tone",
+        &["woman_climbing_tone1"],
+    ),
+    (
+        "🧗🏻‍♂️",
+        "man climbing: light skin tone",
+        &["man_climbing_tone1"],
+    ),
+    (
+        "🧗🏻",
+        "person climbing: light skin tone",
+        &["climbing_tone1", "person_climbing_tone1"],
+    ),
+    (
+        "🧗🏼‍♀️",
+        "woman climbing: medium-light skin tone",
+        &["woman_climbing_tone2"],
+    ),
+    (
+        "🧗🏼‍♂️",
+        "man climbing: medium-light skin tone",
+        &["man_climbing_tone2"],
+    ),
+    (
+        "🧗🏼",
+        "person climbing: medium-light skin tone",
+        &["climbing_tone2", "person_climbing_tone2"],
+    ),
+    (
+        "🧗🏽‍♀️",
+        "woman climbing: medium skin tone",
+        &["woman_climbing_tone3"],
+    ),
+    (
+        "🧗🏽‍♂️",
+        "man climbing: medium skin tone",
+        &["man_climbing_tone3"],
+    ),
+    (
+        "🧗🏽",
+        "person climbing: medium skin tone",
+        &["climbing_tone3", "person_climbing_tone3"],
+    ),
+    (
+        "🧗🏾‍♀️",
+        "woman climbing: medium-dark skin tone",
+        &["woman_climbing_tone4"],
+    ),
+    (
+        "🧗🏾‍♂️",
+        "man climbing: medium-dark skin tone",
+        &["man_climbing_tone4"],
+    ),
+    (
+        "🧗🏾",
+        "person climbing: medium-dark skin tone",
+        &["climbing_tone4", "person_climbing_tone4"],
+    ),
+    (
+        "🧗🏿‍♀️",
+        "woman climbing: dark skin tone",
+        &["woman_climbing_tone5"],
+    ),
+    (
+        "🧗🏿‍♂️",
+        "man climbing: dark skin tone",
+        &["man_climbing_tone5"],
+    ),
+    (
+        "🧗🏿",
+        "person climbing: dark skin tone",
+        &["climbing_tone5", "person_climbing_tone5"],
+    ),
+    ("🧗‍♀️", "woman climbing", &["woman_climbing"]),
+    ("🧗‍♂️", "man climbing", &["man_climbing"]),
+    ("🧗", "person climbing", &["climbing", "person_climbing"]),
+    (
+        "🧘🏻‍♀️",
+        "woman in lotus position: light skin tone",
+        &["woman_in_lotus_position_tone1"],
+    ),
+    (
+        "🧘🏻‍♂️",
+        "man in lotus position: light skin tone",
+        &["man_in_lotus_position_tone1"],
+    ),
+    (
+        "🧘🏻",
+        "person in lotus position: light skin tone",
+        &["person_in_lotus_position_tone1"],
+    ),
+    (
+        "🧘🏼‍♀️",
+        "woman in lotus position: medium-light skin tone",
+        &["woman_in_lotus_position_tone2"],
+    ),
+    (
+        "🧘🏼‍♂️",
+        "man in lotus position: medium-light skin tone",
+        &["man_in_lotus_position_tone2"],
+    ),
+    (
+        "🧘🏼",
+        "person in lotus position: medium-light skin tone",
+        &["person_in_lotus_position_tone2"],
+    ),
+    (
+        "🧘🏽‍♀️",
+        "woman in lotus position: medium skin tone",
+        &["woman_in_lotus_position_tone3"],
+    ),
+    (
+        "🧘🏽‍♂️",
+        "man in lotus position: medium skin tone",
+        &["man_in_lotus_position_tone3"],
+    ),
+    (
+        "🧘🏽",
+        "person in lotus position: medium skin tone",
+        &["person_in_lotus_position_tone3"],
+    ),
+    (
+        "🧘🏾‍♀️",
+        "woman in lotus position: medium-dark skin tone",
+        &["woman_in_lotus_position_tone4"],
+    ),
+    (
+        "🧘🏾‍♂️",
+        "man in lotus position: medium-dark skin tone",
+        &["man_in_lotus_position_tone4"],
+    ),
+    (
+        "🧘🏾",
+        "person in lotus position: medium-dark skin tone",
+        &["person_in_lotus_position_tone4"],
+    ),
+    (
+        "🧘🏿‍♀️",
+        "woman in lotus position: dark skin tone",
+        &["woman_in_lotus_position_tone5"],
+    ),
+    (
+        "🧘🏿‍♂️",
+        "man in lotus position: dark skin tone",
+        &["man_in_lotus_position_tone5"],
+    ),
+    (
+        "🧘🏿",
+        "person in lotus position: dark skin tone",
+        &["person_in_lotus_position_tone5"],
+    ),
+    (
+        "🧘‍♀️",
+        "woman in lotus position",
+        &["woman_in_lotus_position"],
+    ),
+    ("🧘‍♂️", "man in lotus position", &["man_in_lotus_position"]),
+    (
+        "🧘",
+        "person in lotus position",
+        &["person_in_lotus_position"],
+    ),
+    ("🧙🏻‍♀️", "woman mage: light skin tone", &["woman_mage_tone1"]),
+    ("🧙🏻‍♂️", "man mage: light skin tone", &["man_mage_tone1"]),
+    ("🧙🏻", "mage: light skin tone", &["mage_tone1"]),
+    (
+        "🧙🏼‍♀️",
+        "woman mage: medium-light skin tone",
+        &["woman_mage_tone2"],
+    ),
+    (
+        "🧙🏼‍♂️",
+        "man mage: medium-light skin tone",
+        &["man_mage_tone2"],
+    ),
+    ("🧙🏼", "mage: medium-light skin tone", &["mage_tone2"]),
+    ("🧙🏽‍♀️", "woman mage: medium skin tone", &["woman_mage_tone3"]),
+    ("🧙🏽‍♂️", "man mage: medium skin tone", &["man_mage_tone3"]),
+    ("🧙🏽", "mage: medium skin tone", &["mage_tone3"]),
+    (
+        "🧙🏾‍♀️",
+        "woman mage: medium-dark skin tone",
+        &["woman_mage_tone4"],
+    ),
+    ("🧙🏾‍♂️", "man mage: medium-dark skin tone", &["man_mage_tone4"]),
+    ("🧙🏾", "mage: medium-dark skin tone", &["mage_tone4"]),
+    ("🧙🏿‍♀️", "woman mage: dark skin tone", &["woman_mage_tone5"]),
+    ("🧙🏿‍♂️", "man mage: dark skin tone", &["man_mage_tone5"]),
+    ("🧙🏿", "mage: dark skin tone", &["mage_tone5"]),
+    ("🧙‍♀️", "woman mage", &["woman_mage"]),
+    ("🧙‍♂️", "man mage", &["man_mage"]),
+    ("🧙", "mage", &["mage"]),
+    ("🧚🏻‍♀️", "woman fairy: light skin tone", &["woman_fairy_tone1"]),
+    ("🧚🏻‍♂️", "man fairy: light skin tone", &["man_fairy_tone1"]),
+    ("🧚🏻", "fairy: light skin tone", &["fairy_tone1"]),
+    (
+        "🧚🏼‍♀️",
+        "woman fairy: medium-light skin tone",
+        &["woman_fairy_tone2"],
+    ),
+    (
+        "🧚🏼‍♂️",
+        "man fairy: medium-light skin tone",
+        &["man_fairy_tone2"],
+    ),
+    ("🧚🏼", "fairy: medium-light skin tone", &["fairy_tone2"]),
+    (
+        "🧚🏽‍♀️",
+        "woman fairy: medium skin tone",
+        &["woman_fairy_tone3"],
+    ),
+    ("🧚🏽‍♂️", "man fairy: medium skin tone", &["man_fairy_tone3"]),
+    ("🧚🏽", "fairy: medium skin tone", &["fairy_tone3"]),
+    (
+        "🧚🏾‍♀️",
+        "woman fairy: medium-dark skin tone",
+        &["woman_fairy_tone4"],
+    ),
+    (
+        "🧚🏾‍♂️",
+        "man fairy: medium-dark skin tone",
+        &["man_fairy_tone4"],
+    ),
+    ("🧚🏾", "fairy: medium-dark skin tone", &["fairy_tone4"]),
+    ("🧚🏿‍♀️", "woman fairy: dark skin tone", &["woman_fairy_tone5"]),
+    ("🧚🏿‍♂️", "man fairy: dark skin tone", &["man_fairy_tone5"]),
+    ("🧚🏿", "fairy: dark skin tone", &["fairy_tone5"]),
+    ("🧚‍♀️", "woman fairy", &["woman_fairy"]),
+    ("🧚‍♂️", "man fairy", &["man_fairy"]),
+    ("🧚", "fairy", &["fairy"]),
+    (
+        "🧛🏻‍♀️",
+        "woman vampire: light skin tone",
+        &["woman_vampire_tone1"],
+    ),
+    ("🧛🏻‍♂️", "man vampire: light skin tone", &["man_vampire_tone1"]),
+    ("🧛🏻", "vampire: light skin tone", &["vampire_tone1"]),
+    (
+        "🧛🏼‍♀️",
+        "woman vampire: medium-light skin tone",
+        &["woman_vampire_tone2"],
+    ),
+    (
+        "🧛🏼‍♂️",
+        "man vampire: medium-light skin tone",
+        &["man_vampire_tone2"],
+    ),
+    ("🧛🏼", "vampire: medium-light skin tone", &["vampire_tone2"]),
+    (
+        "🧛🏽‍♀️",
+        "woman vampire: medium skin tone",
+        &["woman_vampire_tone3"],
+    ),
+    (
+        "🧛🏽‍♂️",
+        "man vampire: medium skin tone",
+        &["man_vampire_tone3"],
+    ),
+    ("🧛🏽", "vampire: medium skin tone", &["vampire_tone3"]),
+    (
+        "🧛🏾‍♀️",
+        "woman vampire: medium-dark skin tone",
+        &["woman_vampire_tone4"],
+    ),
+    (
+        "🧛🏾‍♂️",
+        "man vampire: medium-dark skin tone",
+        &["man_vampire_tone4"],
+    ),
+    ("🧛🏾", "vampire: medium-dark skin tone", &["vampire_tone4"]),
+    (
+        "🧛🏿‍♀️",
+        "woman vampire: dark skin tone",
+        &["woman_vampire_tone5"],
+    ),
+    ("🧛🏿‍♂️", "man vampire: dark skin tone", &["man_vampire_tone5"]),
+    ("🧛🏿", "vampire: dark skin tone", &["vampire_tone5"]),
+    ("🧛‍♀️", "woman vampire", &["woman_vampire"]),
+    ("🧛‍♂️", "man vampire", &["man_vampire"]),
+    ("🧛", "vampire", &["vampire"]),
+    ("🧜🏻‍♀️", "mermaid: light skin tone", &["mermaid_tone1"]),
+    ("🧜🏻‍♂️", "merman: light skin tone", &["merman_tone1"]),
+    ("🧜🏻", "merperson: light skin tone", &["merperson_tone1"]),
+    ("🧜🏼‍♀️", "mermaid: medium-light skin tone", &["mermaid_tone2"]),
+    ("🧜🏼‍♂️", "merman: medium-light skin tone", &["merman_tone2"]),
+    (
+        "🧜🏼",
+        "merperson: medium-light skin tone",
+        &["merperson_tone2"],
+    ),
+    ("🧜🏽‍♀️", "mermaid: medium skin tone", &["mermaid_tone3"]),
+    ("🧜🏽‍♂️", "merman: medium skin tone", &["merman_tone3"]),
+    ("🧜🏽", "merperson: medium skin tone", &["merperson_tone3"]),
+    ("🧜🏾‍♀️", "mermaid: medium-dark skin tone", &["mermaid_tone4"]),
+    ("🧜🏾‍♂️", "merman: medium-dark skin tone", &["merman_tone4"]),
+    (
+        "🧜🏾",
+        "merperson: medium-dark skin tone",
+        &["merperson_tone4"],
+    ),
+    ("🧜🏿‍♀️", "mermaid: dark skin tone", &["mermaid_tone5"]),
+    ("🧜🏿‍♂️", "merman: dark skin tone", &["merman_tone5"]),
+    ("🧜🏿", "merperson: dark skin tone", &["merperson_tone5"]),
+    ("🧜‍♀️", "mermaid", &["mermaid"]),
+    ("🧜‍♂️", "merman", &["merman"]),
+    ("🧜", "merperson", &["merperson"]),
+    ("🧝🏻‍♀️", "woman elf: light skin tone", &["woman_elf_tone1"]),
+    ("🧝🏻‍♂️", "man elf: light skin tone", &["man_elf_tone1"]),
+    ("🧝🏻", "elf: light skin tone", &["elf_tone1"]),
+    (
+        "🧝🏼‍♀️",
+        "woman elf: medium-light skin tone",
+        &["woman_elf_tone2"],
+    ),
+    ("🧝🏼‍♂️", "man elf: medium-light skin tone", &["man_elf_tone2"]),
+    ("🧝🏼", "elf: medium-light skin tone", &["elf_tone2"]),
+    ("🧝🏽‍♀️", "woman elf: medium skin tone", &["woman_elf_tone3"]),
+    ("🧝🏽‍♂️", "man elf: medium skin tone", &["man_elf_tone3"]),
+    ("🧝🏽", "elf: medium skin tone", &["elf_tone3"]),
+    (
+        "🧝🏾‍♀️",
+        "woman elf: medium-dark skin tone",
+        &["woman_elf_tone4"],
+    ),
+    ("🧝🏾‍♂️", "man elf: medium-dark skin tone", &["man_elf_tone4"]),
+    ("🧝🏾", "elf: medium-dark skin tone", &["elf_tone4"]),
+    ("🧝🏿‍♀️", "woman elf: dark skin tone", &["woman_elf_tone5"]),
+    ("🧝🏿‍♂️", "man elf: dark skin tone", &["man_elf_tone5"]),
+    ("🧝🏿", "elf: dark skin tone", &["elf_tone5"]),
+    ("🧝‍♀️", "woman elf", &["woman_elf"]),
+    ("🧝‍♂️", "man elf", &["man_elf"]),
+    ("🧝", "elf", &["elf"]),
+    ("🧞‍♀️", "woman genie", &["woman_genie"]),
+    ("🧞‍♂️", "man genie", &["man_genie"]),
+    ("🧞", "genie", &["genie"]),
+    ("🧟‍♀️", "woman zombie", &["woman_zombie"]),
+    ("🧟‍♂️", "man zombie", &["man_zombie"]),
+    ("🧟", "zombie", &["zombie"]),
+    ("🧠", "brain", &["brain"]),
+    ("🧡", "orange heart", &["orange_heart"]),
+    ("🧢", "billed cap", &["billed_cap"]),
+    ("🧣", "scarf", &["scarf"]),
+    ("🧤", "gloves", &["gloves"]),
+    ("🧥", "coat", &["coat"]),
+    ("🧦", "socks", &["socks"]),
+    ("🧧", "red envelope", &["red_envelope"]),
+    ("🧨", "firecracker", &["firecracker"]),
+    ("🧩", "puzzle piece", &["jigsaw", "puzzle_piece"]),
+    ("🧪", "test tube", &["test_tube"]),
+    ("🧫", "petri dish", &["petri_dish"]),
+    ("🧬", "dna", &["dna", "double_helix"]),
+    ("🧭", "compass", &["compass"]),
+    ("🧮", "abacus", &["abacus"]),
+    ("🧯", "fire extinguisher", &["fire_extinguisher"]),
+    ("🧰", "toolbox", &["toolbox"]),
+    ("🧱", "brick", &["bricks"]),
+    ("🧲", "magnet", &["magnet"]),
+    ("🧳", "luggage", &["luggage"]),
+    ("🧴", "lotion bottle", &["lotion_bottle"]),
+    ("🧵", "thread", &["thread"]),
+    ("🧶", "yarn", &["yarn"]),
+    ("🧷", "safety pin", &["safety_pin"]),
+    ("🧸", "teddy bear", &["teddy_bear"]),
+    ("🧹", "broom", &["broom"]),
+    ("🧺", "basket", &["basket"]),
+    ("🧻", "roll of paper", &["roll_of_paper", "toilet_paper"]),
+    ("🧼", "soap", &["soap"]),
+    ("🧽", "sponge", &["sponge"]),
+    ("🧾", "receipt", &["receipt"]),
+    ("🧿", "nazar amulet", &["nazar_amulet"]),
+    ("🩰", "ballet shoes", &["ballet_shoes"]),
+    ("🩱", "one-piece swimsuit", &["one_piece_swimsuit"]),
+    ("🩲", "briefs", &["briefs"]),
+    ("🩳", "shorts", &["shorts"]),
+    ("🩴", "thong sandal", &["thong_sandal"]),
+    ("🩵", "light blue heart", &["light_blue_heart"]),
+    ("🩶", "grey heart", &["gray_heart", "grey_heart"]),
+    ("🩷", "pink heart", &["pink_heart"]),
+    ("🩸", "drop of blood", &["drop_of_blood"]),
+    ("🩹", "adhesive bandage", &["adhesive_bandage", "bandaid"]),
+    ("🩺", "stethoscope", &["stethoscope"]),
+    ("🩻", "x-ray", &["x-ray", "xray"]),
+    ("🩼", "crutch", &["crutch"]),
+    ("🪀", "yo-yo", &["yo_yo"]),
+    ("🪁", "kite", &["kite"]),
+    ("🪂", "parachute", &["parachute"]),
+    ("🪃", "boomerang", &["boomerang"]),
+    ("🪄", "magic wand", &["magic_wand"]),
+    ("🪅", "piñata", &["pinata"]),
+    ("🪆", "nesting dolls", &["nesting_dolls"]),
+    ("🪇", "maracas", &["maracas"]),
+    ("🪈", "flute", &["flute"]),
+    ("🪉", "harp", &["harp"]),
+    ("🪊", "trombone", &[]),
+    ("🪎", "treasure chest", &[]),
+    ("🪏", "shovel", &["shovel"]),
+    ("🪐", "ringed planet", &["ringed_planet", "saturn"]),
+    ("🪑", "chair", &["chair"]),
+    ("🪒", "razor", &["razor"]),
+    ("🪓", "axe", &["axe"]),
+    ("🪔", "diya lamp", &["diya_lamp"]),
+    ("🪕", "banjo", &["banjo"]),
+    ("🪖", "military helmet", &["military_helmet"]),
+    ("🪗", "accordion", &["accordion"]),
+    ("🪘", "long drum", &["long_drum"]),
+    ("🪙", "coin", &["coin"]),
+    ("🪚", "carpentry saw", &["carpentry_saw"]),
+    ("🪛", "screwdriver", &["screwdriver"]),
+    ("🪜", "ladder", &["ladder"]),
+    ("🪝", "hook", &["hook"]),
+    ("🪞", "mirror", &["mirror"]),
+    ("🪟", "window", &["window"]),
+    ("🪠", "plunger", &["plunger"]),
+    ("🪡", "sewing needle", &["sewing_needle"]),
+    ("🪢", "knot", &["knot"]),
+    ("🪣", "bucket", &["bucket"]),
+    ("🪤", "mouse trap", &["mouse_trap"]),
+    ("🪥", "toothbrush", &["toothbrush"]),
+    ("🪦", "headstone", &["headstone"]),
+    ("🪧", "placard", &["placard"]),
+    ("🪨", "rock", &["rock"]),
+    ("🪩", "mirror ball", &["disco", "disco_ball", "mirror_ball"]),
+    ("🪪", "identification card", &["id_card"]),
+    ("🪫", "low battery", &["low_battery"]),
+    ("🪬", "hamsa", &["hamsa"]),
+    ("🪭", "folding hand fan", &["folding_fan"]),
+    ("🪮", "hair pick", &["hair_pick"]),
+    ("🪯", "khanda", &["khanda"]),
+    ("🪰", "fly", &["fly"]),
+    ("🪱", "worm", &["worm"]),
+    ("🪲", "beetle", &["beetle"]),
+    ("🪳", "cockroach", &["cockroach"]),
+    ("🪴", "potted plant", &["potted_plant"]),
+    ("🪵", "wood", &["wood"]),
+    ("🪶", "feather", &["feather"]),
+    ("🪷", "lotus", &["lotus"]),
+    ("🪸", "coral", &["coral"]),
+    ("🪹", "empty nest", &["empty_nest", "nest"]),
+    ("🪺", "nest with eggs", &["nest_with_eggs"]),
+    ("🪻", "hyacinth", &["hyacinth"]),
+    ("🪼", "jellyfish", &["jellyfish"]),
+    ("🪽", "wing", &["wing"]),
+    ("🪾", "leafless tree", &["leafless_tree"]),
+    ("🪿", "goose", &["goose"]),
+    ("🫀", "anatomical heart", &["anatomical_heart"]),
+    ("🫁", "lungs", &["lungs"]),
+    ("🫂", "people hugging", &["people_hugging"]),
+    (
+        "🫃🏻",
+        "pregnant man: light skin tone",
+        &["pregnant_man_tone1"],
+    ),
+    (
+        "🫃🏼",
+        "pregnant man: medium-light skin tone",
+        &["pregnant_man_tone2"],
+    ),
+    (
+        "🫃🏽",
+        "pregnant man: medium skin tone",
+        &["pregnant_man_tone3"],
+    ),
+    (
+        "🫃🏾",
+        "pregnant man: medium-dark skin tone",
+        &["pregnant_man_tone4"],
+    ),
+    (
+        "🫃🏿",
+        "pregnant man: dark skin tone",
+        &["pregnant_man_tone5"],
+    ),
+    ("🫃", "pregnant man", &["pregnant_man"]),
+    (
+        "🫄🏻",
+        "pregnant person: light skin tone",
+        &["pregnant_person_tone1"],
+    ),
+    (
+        "🫄🏼",
+        "pregnant person: medium-light skin tone",
+        &["pregnant_person_tone2"],
+    ),
+    (
+        "🫄🏽",
+        "pregnant person: medium skin tone",
+        &["pregnant_person_tone3"],
+    ),
+    (
+        "🫄🏾",
+        "pregnant person: medium-dark skin tone",
+        &["pregnant_person_tone4"],
+    ),
+    (
+        "🫄🏿",
+        "pregnant person: dark skin tone",
+        &["pregnant_person_tone5"],
+    ),
+    ("🫄", "pregnant person", &["pregnant_person"]),
+    (
+        "🫅🏻",
+        "person with crown: light skin tone",
+        &["person_with_crown_tone1", "royalty_tone1"],
+    ),
+    (
+        "🫅🏼",
+        "person with crown: medium-light skin tone",
+        &["person_with_crown_tone2", "royalty_tone2"],
+    ),
+    (
+        "🫅🏽",
+        "person with crown: medium skin tone",
+        &["person_with_crown_tone3", "royalty_tone3"],
+    ),
+    (
+        "🫅🏾",
+        "person with crown: medium-dark skin tone",
+        &["person_with_crown_tone4", "royalty_tone4"],
+    ),
+    (
+        "🫅🏿",
+        "person with crown: dark skin tone",
+        &["person_with_crown_tone5", "royalty_tone5"],
+    ),
+    ("🫅", "person with crown", &["person_with_crown", "royalty"]),
+    ("🫆", "fingerprint", &["fingerprint"]),
+    ("🫈", "hairy creature", &[]),
+    ("🫍", "orca", &[]),
+    ("🫎", "moose", &["moose"]),
+    ("🫏", "donkey", &["donkey"]),
+    ("🫐", "blueberries", &["blueberries"]),
+    ("🫑", "bell pepper", &["bell_pepper"]),
+    ("🫒", "olive", &["olive"]),
+    ("🫓", "flatbread", &["flatbread"]),
+    ("🫔", "tamale", &["tamale"]),
+    ("🫕", "fondue", &["fondue"]),
+    ("🫖", "teapot", &["teapot"]),
+    ("🫗", "pouring liquid", &["pour", "pouring_liquid"]),
+    ("🫘", "beans", &["beans"]),
+    ("🫙", "jar", &["jar"]),
+    ("🫚", "ginger root", &["ginger"]),
+    ("🫛", "pea pod", &["pea"]),
+    ("🫜", "root vegetable", &["root_vegetable"]),
+    ("🫟", "splatter", &["splatter"]),
+    ("🫠", "melting face", &["melt", "melting_face"]),
+    ("🫡", "saluting face", &["salute", "saluting_face"]),
+    (
+        "🫢",
+        "face with open eyes and hand over mouth",
+        &["face_with_open_eyes_hand_over_mouth", "gasp"],
+    ),
+    (
+        "🫣",
+        "face with peeking eye",
+        &["face_with_peeking_eye", "peek"],
+    ),
+    (
+        "🫤",
+        "face with diagonal mouth",
+        &["face_with_diagonal_mouth"],
+    ),
+    ("🫥", "dotted line face", &["dotted_line_face"]),
+    ("🫦", "biting lip", &["biting_lip"]),
+    ("🫧", "bubbles", &["bubbles"]),
+    ("🫨", "shaking face", &["shaking", "shaking_face"]),
+    ("🫩", "face with bags under eyes", &["face_with_eye_bags"]),
+    ("🫪", "distorted face", &[]),
+    ("🫯", "fight cloud", &[]),
+    (
+        "🫰🏻",
+        "hand with index finger and thumb crossed: light skin tone",
+        &["hand_with_index_finger_and_thumb_crossed_tone1"],
+    ),
+    (
+        "🫰🏼",
+        "hand with index finger and thumb crossed: medium-light skin tone",
+        &["hand_with_index_finger_and_thumb_crossed_tone2"],
+    ),
+    (
+        "🫰🏽",
+        "hand with index finger and thumb crossed: medium skin tone",
+        &["hand_with_index_finger_and_thumb_crossed_tone3"],
+    ),
+    (
+        "🫰🏾",
+        "hand with index finger and thumb crossed: medium-dark skin tone",
+        &["hand_with_index_finger_and_thumb_crossed_tone4"],
+    ),
+    (
+        "🫰🏿",
+        "hand with index finger and thumb crossed: dark skin tone",
+        &["hand_with_index_finger_and_thumb_crossed_tone5"],
+    ),
+    (
+        "🫰",
+        "hand with index finger and thumb crossed",
+        &["hand_with_index_finger_and_thumb_crossed"],
+    ),
+    (
+        "🫱🏻‍🫲🏼",
+        "handshake: light skin tone, medium-light skin tone",
+        &["handshake_tone1-2"],
+    ),
+    (
+        "🫱🏻‍🫲🏽",
+        "handshake: light skin tone, medium skin tone",
+        &["handshake_tone1-3"],
+    ),
+    (
+        "🫱🏻‍🫲🏾",
+        "handshake: light skin tone, medium-dark skin tone",
+        &["handshake_tone1-4"],
+    ),
+    (
+        "🫱🏻‍🫲🏿",
+        "handshake: light skin tone, dark skin tone",
+        &["handshake_tone1-5"],
+    ),
+    (
+        "🫱🏻",
+        "rightwards hand: light skin tone",
+        &["rightwards_hand_tone1"],
+    ),
+    (
+        "🫱🏼‍🫲🏻",
+        "handshake: medium-light skin tone, light skin tone",
+        &["handshake_tone2-1"],
+    ),
+    (
+        "🫱🏼‍🫲🏽",
+        "handshake: medium-light skin tone, medium skin tone",
+        &["handshake_tone2-3"],
+    ),
+    (
+        "🫱🏼‍🫲🏾",
+        "handshake: medium-light skin tone, medium-dark skin tone",
+        &["handshake_tone2-4"],
+    ),
+    (
+        "🫱🏼‍🫲🏿",
+        "handshake: medium-light skin tone, dark skin tone",
+        &["handshake_tone2-5"],
+    ),
+    (
+        "🫱🏼",
+        "rightwards hand: medium-light skin tone",
+        &["rightwards_hand_tone2"],
+    ),
+    (
+        "🫱🏽‍🫲🏻",
+        "handshake: medium skin tone, light skin tone",
+        &["handshake_tone3-1"],
+    ),
+    (
+        "🫱🏽‍🫲🏼",
+        "handshake: medium skin tone, medium-light skin tone",
+        &["handshake_tone3-2"],
+    ),
+    (
+        "🫱🏽‍🫲🏾",
+        "handshake: medium skin tone, medium-dark skin tone",
+        &["handshake_tone3-4"],
+    ),
+    (
+        "🫱🏽‍🫲🏿",
+        "handshake: medium skin tone, dark skin tone",
+        &["handshake_tone3-5"],
+    ),
+    (
+        "🫱🏽",
+        "rightwards hand: medium skin tone",
+        &["rightwards_hand_tone3"],
+    ),
+    (
+        "🫱🏾‍🫲🏻",
+        "handshake: medium-dark skin tone, light skin tone",
+        &["handshake_tone4-1"],
+    ),
+    (
+        "🫱🏾‍🫲🏼",
+        "handshake: medium-dark skin tone, medium-light skin tone",
+        &["handshake_tone4-2"],
+    ),
+    (
+        "🫱🏾‍🫲🏽",
+        "handshake: medium-dark skin tone, medium skin tone",
+        &["handshake_tone4-3"],
+    ),
+    (
+        "🫱🏾‍🫲🏿",
+        "handshake: medium-dark skin tone, dark skin tone",
+        &["handshake_tone4-5"],
+    ),
+    (
+        "🫱🏾",
+        "rightwards hand: medium-dark skin tone",
+        &["rightwards_hand_tone4"],
+    ),
+    (
+        "🫱🏿‍🫲🏻",
+        "handshake: dark skin tone, light skin tone",
+        &["handshake_tone5-1"],
+    ),
+    (
+        "🫱🏿‍🫲🏼",
+        "handshake: dark skin tone, medium-light skin tone",
+        &["handshake_tone5-2"],
+    ),
+    (
+        "🫱🏿‍🫲🏽",
+        "handshake: dark skin tone, medium skin tone",
+        &["handshake_tone5-3"],
+    ),
+    (
+        "🫱🏿‍🫲🏾",
+        "handshake: dark skin tone, medium-dark skin tone",
+        &["handshake_tone5-4"],
+    ),
+    (
+        "🫱🏿",
+        "rightwards hand: dark skin tone",
+        &["rightwards_hand_tone5"],
+    ),
+    ("🫱", "rightwards hand", &["rightwards_hand"]),
+    (
+        "🫲🏻",
+        "leftwards hand: light skin tone",
+        &["leftwards_hand_tone1"],
+    ),
+    (
+        "🫲🏼",
+        "leftwards hand: medium-light skin tone",
+        &["leftwards_hand_tone2"],
+    ),
+    (
+        "🫲🏽",
+        "leftwards hand: medium skin tone",
+        &["leftwards_hand_tone3"],
+    ),
+    (
+        "🫲🏾",
+        "leftwards hand: medium-dark skin tone",
+        &["leftwards_hand_tone4"],
+    ),
+    (
+        "🫲🏿",
+        "leftwards hand: dark skin tone",
+        &["leftwards_hand_tone5"],
+    ),
+    ("🫲", "leftwards hand", &["leftwards_hand"]),
+    (
+        "🫳🏻",
+        "palm down hand: light skin tone",
+        &["palm_down_tone1"],
+    ),
+    (
+        "🫳🏼",
+        "palm down hand: medium-light skin tone",
+        &["palm_down_tone2"],
+    ),
+    (
+        "🫳🏽",
+        "palm down hand: medium skin tone",
+        &["palm_down_tone3"],
+    ),
+    (
+        "🫳🏾",
+        "palm down hand: medium-dark skin tone",
+        &["palm_down_tone4"],
+    ),
+    ("🫳🏿", "palm down hand: dark skin tone", &["palm_down_tone5"]),
+    ("🫳", "palm down hand", &["palm_down"]),
+    ("🫴🏻", "palm up hand: light skin tone", &["palm_up_tone1"]),
+    (
+        "🫴🏼",
+        "palm up hand: medium-light skin tone",
+        &["palm_up_tone2"],
+    ),
+    ("🫴🏽", "palm up hand: medium skin tone", &["palm_up_tone3"]),
+    (
+        "🫴🏾",
+        "palm up hand: medium-dark skin tone",
+        &["palm_up_tone4"],
+    ),
+    ("🫴🏿", "palm up hand: dark skin tone", &["palm_up_tone5"]),
+    ("🫴", "palm up hand", &["palm_up"]),
+    (
+        "🫵🏻",
+        "index pointing at the viewer: light skin tone",
+        &["point_forward_tone1"],
+    ),
+    (
+        "🫵🏼",
+        "index pointing at the viewer: medium-light skin tone",
+        &["point_forward_tone2"],
+    ),
+    (
+        "🫵🏽",
+        "index pointing at the viewer: medium skin tone",
+        &["point_forward_tone3"],
+    ),
+    (
+        "🫵🏾",
+        "index pointing at the viewer: medium-dark skin tone",
+        &["point_forward_tone4"],
+    ),
+    (
+        "🫵🏿",
+        "index pointing at the viewer: dark skin tone",
+        &["point_forward_tone5"],
+    ),
+    ("🫵", "index pointing at the viewer", &["point_forward"]),
+    ("🫶🏻", "heart hands: light skin tone", &["heart_hands_tone1"]),
+    (
+        "🫶🏼",
+        "heart hands: medium-light skin tone",
+        &["heart_hands_tone2"],
+    ),
+    (
+        "🫶🏽",
+        "heart hands: medium skin tone",
+        &["heart_hands_tone3"],
+    ),
+    (
+        "🫶🏾",
+        "heart hands: medium-dark skin tone",
+        &["heart_hands_tone4"],
+    ),
+    ("🫶🏿", "heart hands: dark skin tone", &["heart_hands_tone5"]),
+    ("🫶", "heart hands", &["heart_hands"]),
+    (
+        "🫷🏻",
+        "leftwards pushing hand: light skin tone",
+        &["leftwards_pushing_hand_tone1"],
+    ),
+    (
+        "🫷🏼",
+        "leftwards pushing hand: medium-light skin tone",
+        &["leftwards_pushing_hand_tone2"],
+    ),
+    (
+        "🫷🏽",
+        "leftwards pushing hand: medium skin tone",
+        &["leftwards_pushing_hand_tone3"],
+    ),
+    (
+        "🫷🏾",
+        "leftwards pushing hand: medium-dark skin tone",
+        &["leftwards_pushing_hand_tone4"],
+    ),
+    (
+        "🫷🏿",
+        "leftwards pushing hand: dark skin tone",
+        &["leftwards_pushing_hand_tone5"],
+    ),
+    ("🫷", "leftwards pushing hand", &["leftwards_pushing_hand"]),
+    (
+        "🫸🏻",
+        "rightwards pushing hand: light skin tone",
+        &["rightwards_pushing_hand_tone1"],
+    ),
+    (
+        "🫸🏼",
+        "rightwards pushing hand: medium-light skin tone",
+        &["rightwards_pushing_hand_tone2"],
+    ),
+    (
+        "🫸🏽",
+        "rightwards pushing hand: medium skin tone",
+        &["rightwards_pushing_hand_tone3"],
+    ),
+    (
+        "🫸🏾",
+        "rightwards pushing hand: medium-dark skin tone",
+        &["rightwards_pushing_hand_tone4"],
+    ),
+    (
+        "🫸🏿",
+        "rightwards pushing hand: dark skin tone",
+        &["rightwards_pushing_hand_tone5"],
+    ),
+    (
+        "🫸",
+        "rightwards pushing hand",
+        &["rightwards_pushing_hand"],
+    ),
+    (
+        "‼",
+        "double exclamation mark",
+        &["bangbang", "double_exclamation"],
+    ),
+    (
+        "⁉",
+        "exclamation question mark",
+        &["exclamation_question", "interrobang"],
+    ),
+    ("™", "trade mark", &["tm", "trade_mark"]),
+    ("ℹ", "information", &["info", "information_source"]),
+    ("↔", "left-right arrow", &["left_right_arrow"]),
+    ("↕", "up-down arrow", &["arrow_up_down"]),
+    ("↖", "up-left arrow", &["arrow_upper_left"]),
+    ("↗", "up-right arrow", &["arrow_upper_right"]),
+    ("↘", "down-right arrow", &["arrow_lower_right"]),
+    ("↙", "down-left arrow", &["arrow_lower_left"]),
+    (
+        "↩",
+        "right arrow curving left",
+        &["arrow_left_hook", "leftwards_arrow_with_hook"],
+    ),
+    (
+        "↪",
+        "left arrow curving right",
+        &["arrow_right_hook", "rightwards_arrow_with_hook"],
+    ),
+    ("#⃣", "keycap: #", &["hash"]),
+    ("⌚", "watch", &["watch"]),
+    ("⌛", "hourglass done", &["hourglass"]),
+    ("⌨", "keyboard", &["keyboard"]),
+    ("⏏", "eject button", &["eject"]),
+    ("⏩", "fast-forward button", &["fast_forward"]),
+    ("⏪", "fast reverse button", &["fast_reverse", "rewind"]),
+    ("⏫", "fast up button", &["arrow_double_up", "fast_up"]),
+    (
+        "⏬",
+        "fast down button",
+        &["arrow_double_down", "fast_down"],
+    ),
+    ("⏭", "next track button", &["next_track"]),
+    ("⏮", "last track button", &["previous_track"]),
+    ("⏯", "play or pause button", &["play_pause"]),
+    ("⏰", "alarm clock", &["alarm_clock"]),
+    ("⏱", "stopwatch", &["stopwatch"]),
+    ("⏲", "timer clock", &["timer_clock"]),
+    ("⏳", "hourglass not done", &["hourglass_flowing_sand"]),
+    ("⏸", "pause button", &["pause"]),
+    ("⏹", "stop button", &["stop"]),
+    ("⏺", "record button", &["record"]),
+    ("Ⓜ", "circled M", &["m"]),
+    ("▪", "black small square", &["black_small_square"]),
+    ("▫", "white small square", &["white_small_square"]),
+    ("▶", "play button", &["arrow_forward", "play"]),
+    ("◀", "reverse button", &["arrow_backward", "reverse"]),
+    ("◻", "white medium square", &["white_medium_square"]),
+    ("◼", "black medium square", &["black_medium_square"]),
+    (
+        "◽",
+        "white medium-small square",
+        &["white_medium_small_square"],
+    ),
+    (
+        "◾",
+        "black medium-small square",
+        &["black_medium_small_square"],
+    ),
+    ("☀", "sun", &["sun"]),
+    ("☁", "cloud", &["cloud"]),
+    ("☂", "umbrella", &["umbrella"]),
+    ("☃", "snowman", &["snowman2"]),
+    ("☄", "comet", &["comet"]),
+    ("☎", "telephone", &["telephone"]),
+    ("☑", "check box with check", &["ballot_box_with_check"]),
+    ("☔", "umbrella with rain drops", &["umbrella_with_rain"]),
+    ("☕", "hot beverage", &["coffee"]),
+    ("☘", "shamrock", &["shamrock"]),
+    (
+        "☝🏻",
+        "index pointing up: light skin tone",
+        &["point_up_2_tone1"],
+    ),
+    (
+        "☝🏼",
+        "index pointing up: medium-light skin tone",
+        &["point_up_2_tone2"],
+    ),
+    (
+        "☝🏽",
+        "index pointing up: medium skin tone",
+        &["point_up_2_tone3"],
+    ),
+    (
+        "☝🏾",
+        "index pointing up: medium-dark skin tone",
+        &["point_up_2_tone4"],
+    ),
+    (
+        "☝🏿",
+        "index pointing up: dark skin tone",
+        &["point_up_2_tone5"],
+    ),
+    ("☝", "index pointing up", &["point_up_2"]),
+    ("☠", "skull and crossbones", &["skull_and_crossbones"]),
+    ("☢", "radioactive", &["radioactive"]),
+    ("☣", "biohazard", &["biohazard"]),
+    ("☦", "orthodox cross", &["orthodox_cross"]),
+    ("☪", "star and crescent", &["star_and_crescent"]),
+    ("☮", "peace symbol", &["peace", "peace_symbol"]),
+    ("☯", "yin yang", &["yin_yang"]),
+    ("☸", "wheel of dharma", &["wheel_of_dharma"]),
+    ("☹", "frowning face", &["white_frowning_face"]),
+    ("☺", "smiling face", &["relaxed", "smiling_face"]),
+    ("♀", "female sign", &["female", "female_sign"]),
+    ("♂", "male sign", &["male", "male_sign"]),
+    ("♈", "Aries", &["aries"]),
+    ("♉", "Taurus", &["taurus"]),
+    ("♊", "Gemini", &["gemini"]),
+    ("♋", "Cancer", &["cancer"]),
+    ("♌", "Leo", &["leo"]),
+    ("♍", "Virgo", &["virgo"]),
+    ("♎", "Libra", &["libra"]),
+    ("♏", "Scorpio", &["scorpius"]),
+    ("♐", "Sagittarius", &["sagittarius"]),
+    ("♑", "Capricorn", &["capricorn"]),
+    ("♒", "Aquarius", &["aquarius"]),
+    ("♓", "Pisces", &["pisces"]),
+    ("♟", "chess pawn", &["chess_pawn"]),
+    ("♠", "spade suit", &["spades"]),
+    ("♣", "club suit", &["clubs"]),
+    ("♥", "heart suit", &["hearts"]),
+    ("♦", "diamond suit", &["diamonds"]),
+    ("♨", "hot springs", &["hotsprings"]),
+    ("♻", "recycling symbol", &["recycle", "recycling_symbol"]),
+    ("♾", "infinity", &["infinity"]),
+    ("♿", "wheelchair symbol", &["handicapped", "wheelchair"]),
+    ("⚒", "hammer and pick", &["hammer_and_pick"]),
+    ("⚓", "anchor", &["anchor"]),
+    ("⚔", "crossed swords", &["crossed_swords"]),
+    ("⚕", "medical symbol", &["medical", "medical_symbol"]),
+    ("⚖", "balance scale", &["scales"]),
+    ("⚗", "alembic", &["alembic"]),
+    ("⚙", "gear", &["gear"]),
+    ("⚛", "atom symbol", &["atom", "atom_symbol"]),
+    ("⚜", "fleur-de-lis", &["fleur-de-lis"]),
+    ("⚠", "warning", &["warning"]),
+    ("⚡", "high voltage", &["high_voltage", "zap"]),
+    ("⚧", "transgender symbol", &["transgender_symbol"]),
+    ("⚪", "white circle", &["white_circle"]),
+    ("⚫", "black circle", &["black_circle"]),
+    ("⚰", "coffin", &["coffin"]),
+    ("⚱", "funeral urn", &["funeral_urn"]),
+    ("⚽", "soccer ball", &["soccer"]),
+    ("⚾", "baseball", &["baseball"]),
+    ("⛄", "snowman without snow", &["snowman"]),
+    (
+        "⛅",
+        "sun behind cloud",
+        &["partly_sunny", "sun_behind_cloud"],
+    ),
+    (
+        "⛈",
+        "cloud with lightning and rain",
+        &["stormy", "thunder_cloud_and_rain"],
+    ),
+    ("⛎", "Ophiuchus", &["ophiuchus"]),
+    ("⛏", "pick", &["pick"]),
+    (
+        "⛑",
+        "rescue worker’s helmet",
+        &["helmet_with_cross", "rescue_worker_helmet"],
+    ),
+    ("⛓️‍💥", "broken chain", &["broken_chain"]),
+    ("⛓", "chains", &["chains"]),
+    ("⛔", "no entry", &["no_entry"]),
+    ("⛩", "shinto shrine", &["shinto_shrine"]),
+    ("⛪", "church", &["church"]),
+    ("⛰", "mountain", &["mountain"]),
+    (
+        "⛱",
+        "umbrella on ground",
+        &["beach_umbrella", "umbrella_on_ground"],
+    ),
+    ("⛲", "fountain", &["fountain"]),
+    ("⛳", "flag in hole", &["golf"]),
+    ("⛴", "ferry", &["ferry"]),
+    ("⛵", "sailboat", &["sailboat"]),
+    ("⛷🏻", "skier: light skin tone", &[]),
+    ("⛷🏼", "skier: medium-light skin tone", &[]),
+    ("⛷🏽", "skier: medium skin tone", &[]),
+    ("⛷🏾", "skier: medium-dark skin tone", &[]),
+    ("⛷🏿", "skier: dark skin tone", &[]),
+    ("⛷", "skier", &["person_skiing", "skier", "skiing"]),
+    ("⛸", "ice skate", &["ice_skate"]),
+    (
+        "⛹🏻‍♀️",
+        "woman bouncing ball: light skin tone",
+        &["woman_bouncing_ball_tone1"],
+    ),
+    (
+        "⛹🏻‍♂️",
+        "man bouncing ball: light skin tone",
+        &["man_bouncing_ball_tone1"],
+    ),
+    (
+        "⛹🏻",
+        "person bouncing ball: light skin tone",
+        &["person_bouncing_ball_tone1"],
+    ),
+    (
+        "⛹🏼‍♀️",
+        "woman bouncing ball: medium-light skin tone",
+        &["woman_bouncing_ball_tone2"],
+    ),
+    (
+        "⛹🏼‍♂️",
+        "man bouncing ball: medium-light skin tone",
+        &["man_bouncing_ball_tone2"],
+    ),
+    (
+        "⛹🏼",
+        "person bouncing ball: medium-light skin tone",
+        &["person_bouncing_ball_tone2"],
+    ),
+    (
+        "⛹🏽‍♀️",
+        "woman bouncing ball: medium skin tone",
+        &["woman_bouncing_ball_tone3"],
+    ),
+    (
+        "⛹🏽‍♂️",
+        "man bouncing ball: medium skin tone",
+        &["man_bouncing_ball_tone3"],
+    ),
+    (
+        "⛹🏽",
+        "person bouncing ball: medium skin tone",
+        &["person_bouncing_ball_tone3"],
+    ),
+    (
+        "⛹🏾‍♀️",
+        "woman bouncing ball: medium-dark skin tone",
+        &["woman_bouncing_ball_tone4"],
+    ),
+    (
+        "⛹🏾‍♂️",
+        "man bouncing ball: medium-dark skin tone",
+        &["man_bouncing_ball_tone4"],
+    ),
+    (
+        "⛹🏾",
+        "person bouncing ball: medium-dark skin tone",
+        &["person_bouncing_ball_tone4"],
+    ),
+    (
+        "⛹🏿‍♀️",
+        "woman bouncing ball: dark skin tone",
+        &["woman_bouncing_ball_tone5"],
+    ),
+    (
+        "⛹🏿‍♂️",
+        "man bouncing ball: dark skin tone",
+        &["man_bouncing_ball_tone5"],
+    ),
+    (
+        "⛹🏿",
+        "person bouncing ball: dark skin tone",
+        &["person_bouncing_ball_tone5"],
+    ),
+    ("⛹️‍♀️", "woman bouncing ball", &["woman_bouncing_ball"]),
+    ("⛹️‍♂️", "man bouncing ball", &["man_bouncing_ball"]),
+    ("⛹", "person bouncing ball", &["person_bouncing_ball"]),
+    ("⛺", "tent", &["tent"]),
+    ("⛽", "fuel pump", &["fuelpump"]),
+    ("✂", "scissors", &["scissors"]),
+    (
+        "✅",
+        "check mark button",
+        &["check_mark_button", "white_check_mark"],
+    ),
+    ("✈", "airplane", &["airplane"]),
+    ("✉", "envelope", &["envelope"]),
+    ("✊🏻", "raised fist: light skin tone", &["fist_tone1"]),
+    ("✊🏼", "raised fist: medium-light skin tone", &["fist_tone2"]),
+    ("✊🏽", "raised fist: medium skin tone", &["fist_tone3"]),
+    ("✊🏾", "raised fist: medium-dark skin tone", &["fist_tone4"]),
+    ("✊🏿", "raised fist: dark skin tone", &["fist_tone5"]),
+    ("✊", "raised fist", &["fist"]),
+    (
+        "✋🏻",
+        "raised hand: light skin tone",
+        &["high_five_tone1", "raised_hand_tone1"],
+    ),
+    (
+        "✋🏼",
+        "raised hand: medium-light skin tone",
+        &["high_five_tone2", "raised_hand_tone2"],
+    ),
+    (
+        "✋🏽",
+        "raised hand: medium skin tone",
+        &["high_five_tone3", "raised_hand_tone3"],
+    ),
+    (
+        "✋🏾",
+        "raised hand: medium-dark skin tone",
+        &["high_five_tone4", "raised_hand_tone4"],
+    ),
+    (
+        "✋🏿",
+        "raised hand: dark skin tone",
+        &["high_five_tone5", "raised_hand_tone5"],
+    ),
+    ("✋", "raised hand", &["high_five", "raised_hand"]),
+    (
+        "✌🏻",
+        "victory hand: light skin tone",
+        &["v_tone1", "victory_tone1"],
+    ),
+    (
+        "✌🏼",
+        "victory hand: medium-light skin tone",
+        &["v_tone2", "victory_tone2"],
+    ),
+    (
+        "✌🏽",
+        "victory hand: medium skin tone",
+        &["v_tone3", "victory_tone3"],
+    ),
+    (
+        "✌🏾",
+        "victory hand: medium-dark skin tone",
+        &["v_tone4", "victory_tone4"],
+    ),
+    (
+        "✌🏿",
+        "victory hand: dark skin tone",
+        &["v_tone5", "victory_tone5"],
+    ),
+    ("✌", "victory hand", &["v", "victory"]),
+    (
+        "✍🏻",
+        "writing hand: light skin tone",
+        &["writing_hand_tone1"],
+    ),
+    (
+        "✍🏼",
+        "writing hand: medium-light skin tone",
+        &["writing_hand_tone2"],
+    ),
+    (
+        "✍🏽",
+        "writing hand: medium skin tone",
+        &["writing_hand_tone3"],
+    ),
+    (
+        "✍🏾",
+        "writing hand: medium-dark skin tone",
+        &["writing_hand_tone4"],
+    ),
+    (
+        "✍🏿",
+        "writing hand: dark skin tone",
+        &["writing_hand_tone5"],
+    ),
+    ("✍", "writing hand", &["writing_hand"]),
+    ("✏", "pencil", &["pencil"]),
+    ("✒", "black nib", &["black_nib"]),
+    ("✔", "check mark", &["check_mark", "heavy_check_mark"]),
+    ("✖", "multiply", &["multiplication", "multiply"]),
+    ("✝", "latin cross", &["latin_cross"]),
+    ("✡", "star of David", &["star_of_david"]),
+    ("✨", "sparkles", &["sparkles"]),
+    ("✳", "eight-spoked asterisk", &["eight_spoked_asterisk"]),
+    ("✴", "eight-pointed star", &["eight_pointed_black_star"]),
+    ("❄", "snowflake", &["snowflake"]),
+    ("❇", "sparkle", &["sparkle"]),
+    ("❌", "cross mark", &["cross_mark", "x"]),
+    (
+        "❎",
+        "cross mark button",
+        &["cross_mark_button", "negative_squared_cross_mark"],
+    ),
+    ("❓", "red question mark", &["question"]),
+    ("❔", "white question mark", &["white_question"]),
+    ("❕", "white exclamation mark", &["white_exclamation"]),
+    ("❗", "red exclamation mark", &["exclamation"]),
+    ("❣", "heart exclamation", &["heart_exclamation"]),
+    ("❤️‍🔥", "heart on fire", &["heart_on_fire"]),
+    ("❤️‍🩹", "mending heart", &["mending_heart"]),
+    ("❤", "red heart", &["heart", "red_heart"]),
+    ("➕", "plus", &["plus"]),
+    ("➖", "minus", &["minus"]),
+    ("➗", "divide", &["divide", "division"]),
+    ("➡", "right arrow", &["arrow_right"]),
+    ("➰", "curly loop", &["curly_loop"]),
+    ("➿", "double curly loop", &["double_curly_loop", "loop"]),
+    ("⤴", "right arrow curving up", &["arrow_heading_up"]),
+    ("⤵", "right arrow curving down", &["arrow_heading_down"]),
+    ("*⃣", "keycap: *", &["asterisk"]),
+    ("⬅", "left arrow", &["arrow_left"]),
+    ("⬆", "up arrow", &["arrow_up"]),
+    ("⬇", "down arrow", &["arrow_down"]),
+    ("⬛", "black large square", &["black_large_square"]),
+    ("⬜", "white large square", &["white_large_square"]),
+    ("⭐", "star", &["star"]),
+    ("⭕", "hollow red circle", &["hollow_red_circle", "red_o"]),
+    ("0⃣", "keycap: 0", &["zero"]),
+    ("〰", "wavy dash", &["wavy_dash"]),
+    ("〽", "part alternation mark", &["part_alternation_mark"]),
+    ("1⃣", "keycap: 1", &["one"]),
+    ("2⃣", "keycap: 2", &["two"]),
+    (
+        "㊗",
+        "Japanese “congratulations” button",
+        &["congratulations", "ja_congratulations"],
+    ),
+    ("㊙", "Japanese “secret” button", &["ja_secret", "secret"]),
+    ("3⃣", "keycap: 3", &["three"]),
+    ("4⃣", "keycap: 4", &["four"]),
+    ("5⃣", "keycap: 5", &["five"]),
+    ("6⃣", "keycap: 6", &["six"]),
+    ("7⃣", "keycap: 7", &["seven"]),
+    ("8⃣", "keycap: 8", &["eight"]),
+    ("9⃣", "keycap: 9", &["nine"]),
+    ("©", "copyright", &["copyright"]),
+    ("®", "registered", &["registered"]),
+    ("", "", &[]),
+];
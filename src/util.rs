@@ -0,0 +1,33 @@
+//! Helpers for working with emoji in plain strings.
+
+use crate::{EmojiAssetProvider, TwemojiProvider};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Remove all emojis from `text`, e.g. for places that can't display them (like window titles).
+///
+/// Everything around the emojis, including whitespace, is kept as-is.
+///
+/// ```rust
+/// use egui_twemoji::util::strip_emoji;
+///
+/// # #[cfg(any(feature = "svg", feature = "png", feature = "both"))] {
+/// assert_eq!(strip_emoji("🚀 Launch"), " Launch");
+/// # }
+/// ```
+pub fn strip_emoji(text: &str) -> String {
+    text.graphemes(true)
+        .filter(|grapheme| !TwemojiProvider::is_emoji(grapheme))
+        .collect()
+}
+
+#[cfg(all(test, any(feature = "svg", feature = "png", feature = "both")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strip_emoji_keeps_surrounding_text() {
+        assert_eq!(strip_emoji("Hello 😊 World 🌍"), "Hello  World ");
+        assert_eq!(strip_emoji("👨‍👩‍👧🇺🇸"), "");
+        assert_eq!(strip_emoji("no emojis"), "no emojis");
+    }
+}